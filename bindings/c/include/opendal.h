@@ -85,6 +85,10 @@ typedef enum opendal_code {
    * The range of the content is not satisfied.
    */
   OPENDAL_RANGE_NOT_SATISFIED,
+  /**
+   * The checksum of the content is not match.
+   */
+  OPENDAL_CHECKSUM_MISMATCH,
 } opendal_code;
 
 /**
@@ -49,6 +49,8 @@ pub enum opendal_code {
     OPENDAL_CONDITION_NOT_MATCH,
     /// The range of the content is not satisfied.
     OPENDAL_RANGE_NOT_SATISFIED,
+    /// The checksum of the content is not match.
+    OPENDAL_CHECKSUM_MISMATCH,
 }
 
 impl From<core::ErrorKind> for opendal_code {
@@ -66,6 +68,7 @@ impl From<core::ErrorKind> for opendal_code {
             core::ErrorKind::IsSameFile => opendal_code::OPENDAL_IS_SAME_FILE,
             core::ErrorKind::ConditionNotMatch => opendal_code::OPENDAL_CONDITION_NOT_MATCH,
             core::ErrorKind::RangeNotSatisfied => opendal_code::OPENDAL_RANGE_NOT_SATISFIED,
+            core::ErrorKind::ChecksumMismatch => opendal_code::OPENDAL_CHECKSUM_MISMATCH,
             // if this is triggered, check the [`core`] crate and add a
             // new error code accordingly
             _ => unimplemented!(
@@ -87,7 +87,7 @@ impl From<od::Metadata> for Metadata {
         let mode = match val.mode() {
             od::EntryMode::FILE => EntryMode::File,
             od::EntryMode::DIR => EntryMode::Dir,
-            od::EntryMode::Unknown => EntryMode::Unknown,
+            od::EntryMode::Symlink | od::EntryMode::Unknown => EntryMode::Unknown,
         };
 
         let cache_control = match val.cache_control() {
@@ -58,6 +58,7 @@ impl Error {
             ErrorKind::IsSameFile => "IsSameFile",
             ErrorKind::ConditionNotMatch => "ConditionNotMatch",
             ErrorKind::RangeNotSatisfied => "RangeNotSatisfied",
+            ErrorKind::ChecksumMismatch => "ChecksumMismatch",
             _ => "Unexpected",
         })?;
         let message = env.new_string(format!("{:?}", self.inner))?;
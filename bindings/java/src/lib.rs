@@ -144,7 +144,7 @@ fn make_metadata<'a>(env: &mut JNIEnv<'a>, metadata: Metadata) -> Result<JObject
     let mode = match metadata.mode() {
         EntryMode::FILE => 0,
         EntryMode::DIR => 1,
-        EntryMode::Unknown => 2,
+        EntryMode::Symlink | EntryMode::Unknown => 2,
     };
 
     let last_modified = metadata.last_modified().map_or_else(
@@ -829,7 +829,7 @@ impl From<opendal::EntryMode> for EntryMode {
         match mode {
             opendal::EntryMode::FILE => EntryMode::FILE,
             opendal::EntryMode::DIR => EntryMode::DIR,
-            opendal::EntryMode::Unknown => EntryMode::Unknown,
+            opendal::EntryMode::Symlink | opendal::EntryMode::Unknown => EntryMode::Unknown,
         }
     }
 }
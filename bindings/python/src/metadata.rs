@@ -212,7 +212,7 @@ impl EntryMode {
         match mode {
             ocore::EntryMode::FILE => Self::FILE,
             ocore::EntryMode::DIR => Self::DIR,
-            ocore::EntryMode::Unknown => Self::Unknown,
+            ocore::EntryMode::Symlink | ocore::EntryMode::Unknown => Self::Unknown,
         }
     }
 }
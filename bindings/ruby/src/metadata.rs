@@ -50,7 +50,7 @@ impl Metadata {
         match self.0.mode() {
             ocore::EntryMode::FILE => "File",
             ocore::EntryMode::DIR => "Directory",
-            ocore::EntryMode::Unknown => "Unknown",
+            ocore::EntryMode::Symlink | ocore::EntryMode::Unknown => "Unknown",
         }
     }
 
@@ -301,6 +301,102 @@ impl Operator {
         self.handle.block_on(self.op.create_dir(path))
     }
 
+    /// Create a symbolic link at `path` which points to `target`.
+    ///
+    /// Refer to [`crate::Operator::create_symlink`] for more details.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use opendal::Result;
+    /// use opendal::blocking;
+    /// # fn test(op: blocking::Operator) -> Result<()> {
+    /// op.create_symlink("path/to/link", "file")?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn create_symlink(&self, path: &str, target: &str) -> Result<()> {
+        self.handle.block_on(self.op.create_symlink(path, target))
+    }
+
+    /// Read the target of the symbolic link at `path`.
+    ///
+    /// Refer to [`crate::Operator::read_link`] for more details.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use opendal::Result;
+    /// use opendal::blocking;
+    /// # fn test(op: blocking::Operator) -> Result<()> {
+    /// let target = op.read_link("path/to/link")?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn read_link(&self, path: &str) -> Result<String> {
+        self.handle.block_on(self.op.read_link(path))
+    }
+
+    /// List unfinished multipart uploads whose path starts with `prefix`.
+    ///
+    /// Refer to [`crate::Operator::list_pending_uploads`] for more details.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use opendal::Result;
+    /// use opendal::blocking;
+    /// # fn test(op: blocking::Operator) -> Result<()> {
+    /// let uploads = op.list_pending_uploads("path/to/dir/")?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn list_pending_uploads(&self, prefix: &str) -> Result<Vec<PendingUpload>> {
+        self.handle.block_on(self.op.list_pending_uploads(prefix))
+    }
+
+    /// List one page of unfinished multipart uploads whose path starts with `prefix`.
+    ///
+    /// Refer to [`crate::Operator::list_pending_uploads_page`] for more details.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use opendal::Result;
+    /// use opendal::blocking;
+    /// # fn test(op: blocking::Operator) -> Result<()> {
+    /// let (uploads, next) = op.list_pending_uploads_page("path/to/dir/", None)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn list_pending_uploads_page(
+        &self,
+        prefix: &str,
+        page_token: Option<&str>,
+    ) -> Result<(Vec<PendingUpload>, Option<String>)> {
+        self.handle
+            .block_on(self.op.list_pending_uploads_page(prefix, page_token))
+    }
+
+    /// Abort an unfinished multipart upload and purge all its uploaded parts.
+    ///
+    /// Refer to [`crate::Operator::abort_pending_upload`] for more details.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use opendal::Result;
+    /// use opendal::blocking;
+    /// # fn test(op: blocking::Operator) -> Result<()> {
+    /// op.abort_pending_upload("path/to/file", "upload_id")?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn abort_pending_upload(&self, path: &str, upload_id: &str) -> Result<()> {
+        self.handle
+            .block_on(self.op.abort_pending_upload(path, upload_id))
+    }
+
     /// Read the whole path into a bytes.
     ///
     /// This function will allocate a new bytes internally. For more precise memory control or
@@ -611,7 +707,7 @@ impl Operator {
     ///         EntryMode::DIR => {
     ///             println!("Handling dir {}", entry.path())
     ///         }
-    ///         EntryMode::Unknown => continue,
+    ///         EntryMode::Symlink | EntryMode::Unknown => continue,
     ///     }
     /// }
     /// # Ok(())
@@ -670,7 +766,7 @@ impl Operator {
     ///         EntryMode::DIR => {
     ///             println!("Handling dir like start a new list via meta.path()")
     ///         }
-    ///         EntryMode::Unknown => continue,
+    ///         EntryMode::Symlink | EntryMode::Unknown => continue,
     ///     }
     /// }
     /// # Ok(())
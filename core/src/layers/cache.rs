@@ -0,0 +1,615 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::fmt::Formatter;
+use std::sync::Arc;
+use std::sync::Mutex;
+
+use crate::raw::*;
+use crate::*;
+
+/// Eviction policy used by [`CacheLayer`] when the cache tier is full.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum CachePolicy {
+    /// Evict the entry that has not been read for the longest time.
+    #[default]
+    Lru,
+    /// Evict the entry that has been read the fewest times.
+    Lfu,
+    /// Evict the entry that was filled first.
+    Fifo,
+}
+
+/// Add a read-through cache backed by another [`Operator`].
+///
+/// # Notes
+///
+/// `CacheLayer` serves `read` and `reader` from the cache operator and fills
+/// it with the whole object on miss. The requested range is then sliced from
+/// the cached content.
+///
+/// Every read will `stat` the underlying storage first, cached content is only
+/// served if `etag`, `last_modified` and `content_length` still match. Objects
+/// written, copied, renamed or deleted through this layer are invalidated
+/// immediately.
+///
+/// The cache index lives in memory and is shared by all clones of the layer.
+/// Content left in the cache operator by a previous process will not be
+/// served and will be overwritten on the next fill. Please use a dedicated
+/// root for the cache operator since the same paths are used on both sides.
+///
+/// Reads with `version` or conditional headers bypass the cache.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use opendal::layers::CacheLayer;
+/// # use opendal::layers::CachePolicy;
+/// # use opendal::services;
+/// # use opendal::Operator;
+/// # use opendal::Result;
+///
+/// # fn main() -> Result<()> {
+/// let cache = Operator::new(services::Memory::default())?.finish();
+///
+/// let _ = Operator::new(services::Memory::default())?
+///     .layer(
+///         CacheLayer::new(cache)
+///             .with_capacity(64 * 1024 * 1024)
+///             .with_max_object_size(4 * 1024 * 1024)
+///             .with_policy(CachePolicy::Lru),
+///     )
+///     .finish();
+/// Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct CacheLayer {
+    cache: Operator,
+    capacity: u64,
+    max_object_size: u64,
+    policy: CachePolicy,
+    index: Arc<Mutex<CacheIndex>>,
+}
+
+impl CacheLayer {
+    /// Create a new `CacheLayer` that uses `cache` as the cache tier.
+    ///
+    /// By default, the cache can hold 256 MiB with objects up to 16 MiB and
+    /// evicts entries in LRU order.
+    pub fn new(cache: Operator) -> Self {
+        Self {
+            cache,
+            capacity: 256 * 1024 * 1024,
+            max_object_size: 16 * 1024 * 1024,
+            policy: CachePolicy::default(),
+            index: Arc::new(Mutex::new(CacheIndex::default())),
+        }
+    }
+
+    /// Set the total bytes that can be stored in the cache tier.
+    pub fn with_capacity(mut self, capacity: u64) -> Self {
+        self.capacity = capacity;
+        self
+    }
+
+    /// Set the max size of a single object that will be cached.
+    ///
+    /// Objects larger than this will always be read from the underlying storage.
+    pub fn with_max_object_size(mut self, size: u64) -> Self {
+        self.max_object_size = size;
+        self
+    }
+
+    /// Set the eviction policy of the cache tier.
+    pub fn with_policy(mut self, policy: CachePolicy) -> Self {
+        self.policy = policy;
+        self
+    }
+}
+
+impl<A: Access> Layer<A> for CacheLayer {
+    type LayeredAccess = CacheAccessor<A>;
+
+    fn layer(&self, inner: A) -> Self::LayeredAccess {
+        CacheAccessor {
+            inner,
+            cache: self.cache.clone(),
+            capacity: self.capacity,
+            max_object_size: self.max_object_size.min(self.capacity),
+            policy: self.policy,
+            index: self.index.clone(),
+        }
+    }
+}
+
+pub struct CacheAccessor<A: Access> {
+    inner: A,
+    cache: Operator,
+    capacity: u64,
+    max_object_size: u64,
+    policy: CachePolicy,
+    index: Arc<Mutex<CacheIndex>>,
+}
+
+impl<A: Access> Debug for CacheAccessor<A> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CacheAccessor")
+            .field("inner", &self.inner)
+            .field("cache", &self.cache)
+            .field("capacity", &self.capacity)
+            .field("max_object_size", &self.max_object_size)
+            .field("policy", &self.policy)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<A: Access> CacheAccessor<A> {
+    /// Remove path from the cache tier.
+    async fn invalidate(&self, path: &str) {
+        invalidate(&self.cache, &self.index, path).await
+    }
+
+    /// Read the requested range from the cache tier if the entry is still valid.
+    async fn read_cached(
+        &self,
+        path: &str,
+        version: &CacheVersion,
+        range: BytesRange,
+    ) -> Option<Buffer> {
+        if !self.index.lock().unwrap().hit(path, version) {
+            return None;
+        }
+
+        let (start, end) = range_bounds(range, version.content_length);
+        match self.cache.read_with(path).range(start..end).await {
+            Ok(bs) => Some(bs),
+            Err(err) => {
+                log::warn!("cache layer failed to read {path} from cache: {err}");
+                self.index.lock().unwrap().remove(path);
+                None
+            }
+        }
+    }
+
+    /// Store the content into the cache tier and evict entries if needed.
+    async fn fill(&self, path: &str, version: CacheVersion, content: Buffer) {
+        if let Err(err) = self.cache.write(path, content).await {
+            log::warn!("cache layer failed to fill {path}: {err}");
+            return;
+        }
+
+        let victims = self
+            .index
+            .lock()
+            .unwrap()
+            .insert(path, version, self.capacity, self.policy);
+        for victim in victims {
+            if let Err(err) = self.cache.delete(&victim).await {
+                log::warn!("cache layer failed to evict {victim}: {err}");
+            }
+        }
+    }
+}
+
+impl<A: Access> LayeredAccess for CacheAccessor<A> {
+    type Inner = A;
+    type Reader = TwoWays<A::Reader, Buffer>;
+    type Writer = CacheWriter<A::Writer>;
+    type Lister = A::Lister;
+    type Deleter = CacheDeleter<A::Deleter>;
+
+    fn inner(&self) -> &Self::Inner {
+        &self.inner
+    }
+
+    async fn read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::Reader)> {
+        if args.version().is_some()
+            || args.if_match().is_some()
+            || args.if_none_match().is_some()
+            || args.if_modified_since().is_some()
+            || args.if_unmodified_since().is_some()
+        {
+            return self
+                .inner
+                .read(path, args)
+                .await
+                .map(|(rp, r)| (rp, TwoWays::One(r)));
+        }
+
+        let meta = self.inner.stat(path, OpStat::new()).await?.into_metadata();
+        let version = CacheVersion::new(&meta);
+        let range = args.range();
+
+        if let Some(bs) = self.read_cached(path, &version, range).await {
+            let rp = read_reply(RpRead::new(), range, version.content_length);
+            return Ok((rp, TwoWays::Two(bs)));
+        }
+
+        if version.content_length > self.max_object_size {
+            return self
+                .inner
+                .read(path, args)
+                .await
+                .map(|(rp, r)| (rp, TwoWays::One(r)));
+        }
+
+        let (rp, mut r) = self
+            .inner
+            .read(path, args.with_range(BytesRange::default()))
+            .await?;
+        let content = oio::Read::read_all(&mut r).await?;
+
+        // Object has been changed between stat and read, don't cache it.
+        let version = if content.len() as u64 == version.content_length {
+            Some(version)
+        } else {
+            None
+        };
+
+        let size = content.len() as u64;
+        let (start, end) = range_bounds(range, size);
+        let bs = content.slice(start as usize..end as usize);
+        // The inner reply describes the whole object, narrow it to the requested range.
+        let rp = read_reply(rp, range, size);
+
+        match version {
+            Some(version) => self.fill(path, version, content).await,
+            None => self.invalidate(path).await,
+        }
+
+        Ok((rp, TwoWays::Two(bs)))
+    }
+
+    async fn write(&self, path: &str, args: OpWrite) -> Result<(RpWrite, Self::Writer)> {
+        self.inner.write(path, args).await.map(|(rp, w)| {
+            (
+                rp,
+                CacheWriter {
+                    inner: w,
+                    cache: self.cache.clone(),
+                    index: self.index.clone(),
+                    path: path.to_string(),
+                },
+            )
+        })
+    }
+
+    async fn copy(&self, from: &str, to: &str, args: OpCopy) -> Result<RpCopy> {
+        self.invalidate(to).await;
+        self.inner.copy(from, to, args).await
+    }
+
+    async fn rename(&self, from: &str, to: &str, args: OpRename) -> Result<RpRename> {
+        self.invalidate(from).await;
+        self.invalidate(to).await;
+        self.inner.rename(from, to, args).await
+    }
+
+    async fn delete(&self) -> Result<(RpDelete, Self::Deleter)> {
+        self.inner.delete().await.map(|(rp, d)| {
+            (
+                rp,
+                CacheDeleter {
+                    inner: d,
+                    cache: self.cache.clone(),
+                    index: self.index.clone(),
+                    invalidated: Vec::new(),
+                },
+            )
+        })
+    }
+
+    async fn list(&self, path: &str, args: OpList) -> Result<(RpList, Self::Lister)> {
+        self.inner.list(path, args).await
+    }
+}
+
+/// Remove path from the cache tier.
+async fn invalidate(cache: &Operator, index: &Mutex<CacheIndex>, path: &str) {
+    let removed = index.lock().unwrap().remove(path);
+    if removed {
+        if let Err(err) = cache.delete(path).await {
+            log::warn!("cache layer failed to invalidate {path}: {err}");
+        }
+    }
+}
+
+/// Clamp the requested range into `[start, end)` of an object with `size` bytes.
+fn range_bounds(range: BytesRange, size: u64) -> (u64, u64) {
+    let start = range.offset().min(size);
+    let end = range.size().map_or(size, |v| (start + v).min(size));
+    (start, end)
+}
+
+/// Build the reply of reading `range` from an object with `size` bytes.
+fn read_reply(rp: RpRead, range: BytesRange, size: u64) -> RpRead {
+    let (start, end) = range_bounds(range, size);
+    let content_range = (end > start).then(|| {
+        BytesContentRange::default()
+            .with_range(start, end - 1)
+            .with_size(size)
+    });
+    rp.with_size(Some(end - start)).with_range(content_range)
+}
+
+pub struct CacheWriter<W> {
+    inner: W,
+    cache: Operator,
+    index: Arc<Mutex<CacheIndex>>,
+    path: String,
+}
+
+impl<W: oio::Write> oio::Write for CacheWriter<W> {
+    async fn write(&mut self, bs: Buffer) -> Result<()> {
+        self.inner.write(bs).await
+    }
+
+    async fn close(&mut self) -> Result<Metadata> {
+        let res = self.inner.close().await;
+        // Invalidate on close since the old content could be filled again while writing.
+        invalidate(&self.cache, &self.index, &self.path).await;
+        res
+    }
+
+    async fn abort(&mut self) -> Result<()> {
+        self.inner.abort().await
+    }
+}
+
+pub struct CacheDeleter<D> {
+    inner: D,
+    cache: Operator,
+    index: Arc<Mutex<CacheIndex>>,
+    invalidated: Vec<String>,
+}
+
+impl<D: oio::Delete> oio::Delete for CacheDeleter<D> {
+    fn delete(&mut self, path: &str, args: OpDelete) -> Result<()> {
+        // Drop the entry before the real delete so that it will never be served again.
+        if self.index.lock().unwrap().remove(path) {
+            self.invalidated.push(path.to_string());
+        }
+        self.inner.delete(path, args)
+    }
+
+    async fn flush(&mut self) -> Result<usize> {
+        for path in self.invalidated.drain(..) {
+            if let Err(err) = self.cache.delete(&path).await {
+                log::warn!("cache layer failed to invalidate {path}: {err}");
+            }
+        }
+        self.inner.flush().await
+    }
+}
+
+/// The fields used to decide whether a cached entry is still fresh.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct CacheVersion {
+    etag: Option<String>,
+    last_modified: Option<Timestamp>,
+    content_length: u64,
+}
+
+impl CacheVersion {
+    fn new(meta: &Metadata) -> Self {
+        Self {
+            etag: meta.etag().map(|v| v.to_string()),
+            last_modified: meta.last_modified(),
+            content_length: meta.content_length(),
+        }
+    }
+}
+
+struct CacheEntry {
+    version: CacheVersion,
+    filled_at: u64,
+    accessed_at: u64,
+    hits: u64,
+}
+
+#[derive(Default)]
+struct CacheIndex {
+    entries: HashMap<String, CacheEntry>,
+    used: u64,
+    tick: u64,
+}
+
+impl CacheIndex {
+    /// Check whether the cached entry matches given version.
+    ///
+    /// Stale entries will be kept until they are refilled or evicted.
+    fn hit(&mut self, path: &str, version: &CacheVersion) -> bool {
+        self.tick += 1;
+        let tick = self.tick;
+
+        match self.entries.get_mut(path) {
+            Some(entry) if &entry.version == version => {
+                entry.accessed_at = tick;
+                entry.hits += 1;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    fn remove(&mut self, path: &str) -> bool {
+        match self.entries.remove(path) {
+            Some(entry) => {
+                self.used -= entry.version.content_length;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Insert a new entry and return the paths that have been evicted.
+    fn insert(
+        &mut self,
+        path: &str,
+        version: CacheVersion,
+        capacity: u64,
+        policy: CachePolicy,
+    ) -> Vec<String> {
+        self.remove(path);
+
+        let size = version.content_length;
+        let mut victims = Vec::new();
+        while self.used + size > capacity {
+            let victim = self
+                .entries
+                .iter()
+                .min_by_key(|(_, e)| match policy {
+                    CachePolicy::Lru => (e.accessed_at, 0),
+                    CachePolicy::Lfu => (e.hits, e.accessed_at),
+                    CachePolicy::Fifo => (e.filled_at, 0),
+                })
+                .map(|(k, _)| k.clone());
+            let Some(victim) = victim else {
+                break;
+            };
+            self.remove(&victim);
+            victims.push(victim);
+        }
+
+        self.tick += 1;
+        self.used += size;
+        self.entries.insert(
+            path.to_string(),
+            CacheEntry {
+                version,
+                filled_at: self.tick,
+                accessed_at: self.tick,
+                hits: 0,
+            },
+        );
+        victims
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::services;
+
+    fn new_operators(
+        layer: impl FnOnce(CacheLayer) -> CacheLayer,
+    ) -> (Operator, Operator, Operator) {
+        let cache = Operator::new(services::Memory::default()).unwrap().finish();
+        let backend = Operator::new(services::Memory::default()).unwrap().finish();
+        let op = backend.clone().layer(layer(CacheLayer::new(cache.clone())));
+        (op, backend, cache)
+    }
+
+    #[tokio::test]
+    async fn test_read_fill_and_hit() -> Result<()> {
+        let (op, backend, cache) = new_operators(|l| l);
+
+        backend.write("test", "Hello, World!").await?;
+        assert!(!cache.exists("test").await?);
+
+        let bs = op.read_with("test").range(7..12).await?;
+        assert_eq!(bs.to_vec(), b"World");
+        assert_eq!(cache.read("test").await?.to_vec(), b"Hello, World!");
+
+        let bs = op.read("test").await?;
+        assert_eq!(bs.to_vec(), b"Hello, World!");
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_stale_entry_refilled() -> Result<()> {
+        let (op, backend, cache) = new_operators(|l| l);
+
+        backend.write("test", "Hello").await?;
+        assert_eq!(op.read("test").await?.to_vec(), b"Hello");
+
+        // Update the object behind the layer.
+        backend.write("test", "Hello, World!").await?;
+        assert_eq!(op.read("test").await?.to_vec(), b"Hello, World!");
+        assert_eq!(cache.read("test").await?.to_vec(), b"Hello, World!");
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_write_and_delete_invalidate() -> Result<()> {
+        let (op, _, cache) = new_operators(|l| l);
+
+        op.write("test", "Hello").await?;
+        op.read("test").await?;
+        assert!(cache.exists("test").await?);
+
+        op.write("test", "World").await?;
+        assert!(!cache.exists("test").await?);
+        assert_eq!(op.read("test").await?.to_vec(), b"World");
+
+        op.delete("test").await?;
+        assert!(!cache.exists("test").await?);
+        assert!(op.read("test").await.is_err());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_writer_close_invalidate() -> Result<()> {
+        let (op, _, cache) = new_operators(|l| l);
+
+        op.write("test", "Hello").await?;
+        let mut w = op.writer("test").await?;
+        w.write("World").await?;
+        // The old content is filled again before the writer is closed.
+        assert_eq!(op.read("test").await?.to_vec(), b"Hello");
+        assert!(cache.exists("test").await?);
+
+        w.close().await?;
+        assert!(!cache.exists("test").await?);
+        assert_eq!(op.read("test").await?.to_vec(), b"World");
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_eviction() -> Result<()> {
+        let (op, _, cache) = new_operators(|l| l.with_capacity(10).with_policy(CachePolicy::Lru));
+
+        op.write("a", "12345").await?;
+        op.write("b", "12345").await?;
+        op.write("c", "12345").await?;
+
+        op.read("a").await?;
+        op.read("b").await?;
+        // Touch `a` so that `b` is the least recently used entry.
+        op.read("a").await?;
+        op.read("c").await?;
+
+        assert!(cache.exists("a").await?);
+        assert!(!cache.exists("b").await?);
+        assert!(cache.exists("c").await?);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_large_object_bypass() -> Result<()> {
+        let (op, _, cache) = new_operators(|l| l.with_max_object_size(4));
+
+        op.write("test", "Hello").await?;
+        assert_eq!(op.read("test").await?.to_vec(), b"Hello");
+        assert!(!cache.exists("test").await?);
+        Ok(())
+    }
+}
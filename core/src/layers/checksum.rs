@@ -0,0 +1,791 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::collections::HashSet;
+use std::fmt::Debug;
+use std::sync::Arc;
+
+use base64::Engine;
+use base64::prelude::BASE64_STANDARD;
+use md5::Digest;
+
+use crate::raw::*;
+use crate::*;
+
+/// Key of the user metadata that stores the checksum.
+const CHECKSUM_METADATA_KEY: &str = "opendal-checksum";
+/// Suffix of the sidecar object that stores the checksum.
+const CHECKSUM_SIDECAR_SUFFIX: &str = ".opendal-checksum";
+
+/// Default max size of content that can be buffered to store checksum in user metadata.
+const DEFAULT_BUFFER_SIZE: usize = 8 * 1024 * 1024;
+
+/// Add end-to-end checksum verification for the underlying storage.
+///
+/// # Notes
+///
+/// `ChecksumLayer` calculates the checksum of content while writing, and
+/// verifies it on full reads. A read that returns corrupted or truncated
+/// content fails with [`ErrorKind::ChecksumMismatch`] at the end of the stream.
+///
+/// Supported algorithms are [`ChecksumAlgorithm::Crc32c`],
+/// [`ChecksumAlgorithm::Sha256`] and [`ChecksumAlgorithm::Md5`].
+///
+/// The checksum is stored in the following ways:
+///
+/// - If the storage returns a checksum of the same algorithm in
+///   [`Metadata::checksum`] after writing, nothing will be stored. If it
+///   doesn't match the calculated one, the written object is deleted and the
+///   write fails with [`ErrorKind::ChecksumMismatch`].
+/// - If the storage supports `write_with_user_metadata` and the content is not
+///   larger than `buffer_size`, the content is buffered and written with the
+///   checksum in user metadata `opendal-checksum`.
+/// - Otherwise, the checksum is written into a sidecar object at
+///   `{path}.opendal-checksum`.
+///
+/// Sidecar objects are hidden from `list`, and deleted, copied or renamed along
+/// with their objects. Objects modified without this layer may leave a stale
+/// sidecar behind, which will fail the verification.
+///
+/// Range reads, objects without checksum and versioned reads of objects whose
+/// checksum is stored in sidecar objects are not verified. Appending is not
+/// supported while this layer is enabled.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use opendal::layers::ChecksumLayer;
+/// # use opendal::services;
+/// # use opendal::ChecksumAlgorithm;
+/// # use opendal::Operator;
+/// # use opendal::Result;
+///
+/// # fn main() -> Result<()> {
+/// let _ = Operator::new(services::Memory::default())?
+///     .layer(ChecksumLayer::new(ChecksumAlgorithm::Crc32c))
+///     .finish();
+/// Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct ChecksumLayer {
+    algorithm: ChecksumAlgorithm,
+    buffer_size: usize,
+}
+
+impl ChecksumLayer {
+    /// Create a new `ChecksumLayer` with given algorithm.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the algorithm is not supported by this layer.
+    pub fn new(algorithm: ChecksumAlgorithm) -> Self {
+        assert!(
+            Hasher::new(algorithm).is_some(),
+            "checksum algorithm {algorithm} is not supported by checksum layer"
+        );
+
+        Self {
+            algorithm,
+            buffer_size: DEFAULT_BUFFER_SIZE,
+        }
+    }
+
+    /// Set the max size of content that will be buffered to store checksum in
+    /// user metadata.
+    ///
+    /// Larger content will be streamed to the storage and store checksum in a
+    /// sidecar object. Set to `0` to always use sidecar objects.
+    ///
+    /// The default value is 8 MiB.
+    pub fn with_buffer_size(mut self, size: usize) -> Self {
+        self.buffer_size = size;
+        self
+    }
+}
+
+impl<A: Access> Layer<A> for ChecksumLayer {
+    type LayeredAccess = ChecksumAccessor<A>;
+
+    fn layer(&self, inner: A) -> Self::LayeredAccess {
+        let info = inner.info();
+        info.update_full_capability(|mut cap| {
+            cap.write_can_append = false;
+            cap.write_can_resume = false;
+            cap
+        });
+
+        ChecksumAccessor {
+            inner: Arc::new(inner),
+            algorithm: self.algorithm,
+            buffer_size: self.buffer_size,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct ChecksumAccessor<A: Access> {
+    inner: Arc<A>,
+    algorithm: ChecksumAlgorithm,
+    buffer_size: usize,
+}
+
+impl<A: Access> ChecksumAccessor<A> {
+    /// Load the checksum recorded for given path.
+    async fn expected_checksum(&self, path: &str, args: &OpRead) -> Result<Option<Checksum>> {
+        let mut stat = OpStat::new();
+        if let Some(v) = args.version() {
+            stat = stat.with_version(v);
+        }
+        let meta = self.inner.stat(path, stat).await?.into_metadata();
+
+        if let Some(checksum) = meta.checksum() {
+            if checksum.algorithm() == self.algorithm {
+                return Ok(Some(checksum.clone()));
+            }
+        }
+        if let Some(v) = meta
+            .user_metadata()
+            .and_then(|v| v.get(CHECKSUM_METADATA_KEY))
+        {
+            return v.parse().map(Some);
+        }
+        // Sidecar objects are not versioned, they belong to the current version only.
+        if args.version().is_some() {
+            return Ok(None);
+        }
+
+        let (_, mut r) = match self.inner.read(&sidecar_path(path), OpRead::new()).await {
+            Ok(v) => v,
+            Err(err) if err.kind() == ErrorKind::NotFound => return Ok(None),
+            Err(err) => return Err(err),
+        };
+        let bs = oio::Read::read_all(&mut r).await?;
+        String::from_utf8_lossy(&bs.to_vec())
+            .trim()
+            .parse()
+            .map(Some)
+    }
+
+    /// Copy or rename the sidecar object of `from` to `to`.
+    async fn transfer_sidecar(&self, from: &str, to: &str, rename: bool) -> Result<()> {
+        let (from, to) = (sidecar_path(from), sidecar_path(to));
+        let res = if rename {
+            self.inner
+                .rename(&from, &to, OpRename::new())
+                .await
+                .map(|_| ())
+        } else {
+            self.inner.copy(&from, &to, OpCopy::new()).await.map(|_| ())
+        };
+
+        match res {
+            // `from` doesn't have sidecar, remove the stale one of `to`.
+            Err(err) if err.kind() == ErrorKind::NotFound => {
+                delete_sidecars(&self.inner, vec![to]).await
+            }
+            res => res,
+        }
+    }
+}
+
+impl<A: Access> LayeredAccess for ChecksumAccessor<A> {
+    type Inner = A;
+    type Reader = TwoWays<A::Reader, ChecksumReader<A::Reader>>;
+    type Writer = ChecksumWriter<A>;
+    type Lister = ChecksumLister<A::Lister>;
+    type Deleter = ChecksumDeleter<A>;
+
+    fn inner(&self) -> &Self::Inner {
+        &self.inner
+    }
+
+    async fn read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::Reader)> {
+        if !args.range().is_full() {
+            return self
+                .inner
+                .read(path, args)
+                .await
+                .map(|(rp, r)| (rp, TwoWays::One(r)));
+        }
+
+        let expected = self.expected_checksum(path, &args).await?;
+        let (rp, r) = self.inner.read(path, args).await?;
+        let reader = match expected.and_then(|v| Some((Hasher::new(v.algorithm())?, v))) {
+            Some((hasher, expected)) => TwoWays::Two(ChecksumReader {
+                inner: r,
+                hasher: Some(hasher),
+                expected,
+            }),
+            None => TwoWays::One(r),
+        };
+        Ok((rp, reader))
+    }
+
+    async fn write(&self, path: &str, args: OpWrite) -> Result<(RpWrite, Self::Writer)> {
+        if args.append() {
+            return Err(Error::new(
+                ErrorKind::Unsupported,
+                "checksum layer doesn't support append",
+            )
+            .with_operation(Operation::Write)
+            .with_context("path", path));
+        }
+        if args.resume().is_some() {
+            return Err(Error::new(
+                ErrorKind::Unsupported,
+                "checksum layer doesn't support resume",
+            )
+            .with_operation(Operation::Write)
+            .with_context("path", path));
+        }
+
+        let buffer_size = if self
+            .inner
+            .info()
+            .native_capability()
+            .write_with_user_metadata
+        {
+            self.buffer_size
+        } else {
+            0
+        };
+
+        let writer = ChecksumWriter {
+            inner: self.inner.clone(),
+            path: path.to_string(),
+            args,
+            writer: None,
+            hasher: Hasher::new(self.algorithm).expect("algorithm must be supported"),
+            buffer_size,
+            buf: oio::QueueBuf::new(),
+        };
+        Ok((RpWrite::new(), writer))
+    }
+
+    async fn copy(&self, from: &str, to: &str, args: OpCopy) -> Result<RpCopy> {
+        let rp = self.inner.copy(from, to, args).await?;
+        self.transfer_sidecar(from, to, false).await?;
+        Ok(rp)
+    }
+
+    async fn rename(&self, from: &str, to: &str, args: OpRename) -> Result<RpRename> {
+        let rp = self.inner.rename(from, to, args).await?;
+        self.transfer_sidecar(from, to, true).await?;
+        Ok(rp)
+    }
+
+    async fn delete(&self) -> Result<(RpDelete, Self::Deleter)> {
+        self.inner.delete().await.map(|(rp, d)| {
+            (
+                rp,
+                ChecksumDeleter {
+                    inner: d,
+                    accessor: self.inner.clone(),
+                    queued: 0,
+                    paths: HashSet::new(),
+                },
+            )
+        })
+    }
+
+    async fn list(&self, path: &str, args: OpList) -> Result<(RpList, Self::Lister)> {
+        self.inner
+            .list(path, args)
+            .await
+            .map(|(rp, l)| (rp, ChecksumLister { inner: l }))
+    }
+}
+
+fn sidecar_path(path: &str) -> String {
+    format!("{path}{CHECKSUM_SIDECAR_SUFFIX}")
+}
+
+fn new_checksum_mismatch_error(expected: &Checksum, actual: &Checksum) -> Error {
+    Error::new(
+        ErrorKind::ChecksumMismatch,
+        "checksum of content is not match",
+    )
+    .with_context("expected", expected.to_string())
+    .with_context("actual", actual.to_string())
+}
+
+/// Delete given sidecar objects, missing objects are ignored.
+async fn delete_sidecars<A: Access>(accessor: &A, paths: Vec<String>) -> Result<()> {
+    for path in paths {
+        let (_, mut d) = accessor.delete().await?;
+        oio::Delete::delete(&mut d, &path, OpDelete::new())?;
+        match oio::Delete::flush(&mut d).await {
+            Err(err) if err.kind() != ErrorKind::NotFound => return Err(err),
+            _ => {}
+        }
+    }
+    Ok(())
+}
+
+/// Hasher calculates checksum in streaming.
+enum Hasher {
+    Crc32c(u32),
+    Md5(md5::Md5),
+    Sha256(sha2::Sha256),
+}
+
+impl Hasher {
+    /// Create a new hasher, returns `None` if the algorithm is not supported.
+    fn new(algorithm: ChecksumAlgorithm) -> Option<Self> {
+        match algorithm {
+            ChecksumAlgorithm::Crc32c => Some(Hasher::Crc32c(0)),
+            ChecksumAlgorithm::Md5 => Some(Hasher::Md5(md5::Md5::new())),
+            ChecksumAlgorithm::Sha256 => Some(Hasher::Sha256(sha2::Sha256::new())),
+            _ => None,
+        }
+    }
+
+    fn update(&mut self, bs: &Buffer) {
+        for b in bs.clone() {
+            match self {
+                Hasher::Crc32c(crc) => *crc = crc32c::crc32c_append(*crc, &b),
+                Hasher::Md5(h) => h.update(&b),
+                Hasher::Sha256(h) => h.update(&b),
+            }
+        }
+    }
+
+    fn finish(self) -> Checksum {
+        let (algorithm, digest) = match self {
+            Hasher::Crc32c(crc) => (ChecksumAlgorithm::Crc32c, crc.to_be_bytes().to_vec()),
+            Hasher::Md5(h) => (ChecksumAlgorithm::Md5, h.finalize().to_vec()),
+            Hasher::Sha256(h) => (ChecksumAlgorithm::Sha256, h.finalize().to_vec()),
+        };
+        Checksum::new(algorithm, BASE64_STANDARD.encode(digest))
+    }
+}
+
+pub struct ChecksumReader<R> {
+    inner: R,
+    /// Hasher will be taken after the content has been verified.
+    hasher: Option<Hasher>,
+    expected: Checksum,
+}
+
+impl<R: oio::Read> oio::Read for ChecksumReader<R> {
+    async fn read(&mut self) -> Result<Buffer> {
+        let bs = self.inner.read().await?;
+        if !bs.is_empty() {
+            if let Some(hasher) = self.hasher.as_mut() {
+                hasher.update(&bs);
+            }
+            return Ok(bs);
+        }
+
+        if let Some(hasher) = self.hasher.take() {
+            let actual = hasher.finish();
+            if actual != self.expected {
+                return Err(new_checksum_mismatch_error(&self.expected, &actual));
+            }
+        }
+        Ok(bs)
+    }
+}
+
+pub struct ChecksumWriter<A: Access> {
+    inner: Arc<A>,
+    path: String,
+    args: OpWrite,
+    /// The underlying writer, will be created once content can't be buffered.
+    writer: Option<A::Writer>,
+    hasher: Hasher,
+    buffer_size: usize,
+    buf: oio::QueueBuf,
+}
+
+impl<A: Access> ChecksumWriter<A> {
+    /// Create the underlying writer and write all buffered content into it.
+    async fn open(&mut self, args: OpWrite) -> Result<&mut A::Writer> {
+        let (_, mut w) = self.inner.write(&self.path, args).await?;
+        let buf = self.buf.take().collect();
+        if !buf.is_empty() {
+            oio::Write::write(&mut w, buf).await?;
+        }
+        Ok(self.writer.insert(w))
+    }
+}
+
+impl<A: Access> oio::Write for ChecksumWriter<A> {
+    async fn write(&mut self, bs: Buffer) -> Result<()> {
+        self.hasher.update(&bs);
+
+        if self.writer.is_none() {
+            if self.buf.len() + bs.len() <= self.buffer_size {
+                self.buf.push(bs);
+                return Ok(());
+            }
+            self.open(self.args.clone()).await?;
+        }
+
+        let w = self.writer.as_mut().expect("writer must be created");
+        w.write(bs).await
+    }
+
+    async fn close(&mut self) -> Result<Metadata> {
+        let hasher = std::mem::replace(&mut self.hasher, Hasher::Crc32c(0));
+        let checksum = hasher.finish();
+
+        // All content has been buffered, store checksum in user metadata.
+        if self.writer.is_none() && self.buffer_size > 0 {
+            let mut user_metadata = self.args.user_metadata().cloned().unwrap_or_default();
+            user_metadata.insert(CHECKSUM_METADATA_KEY.to_string(), checksum.to_string());
+            let args = self.args.clone().with_user_metadata(user_metadata);
+
+            let w = self.open(args).await?;
+            let mut meta = w.close().await?;
+            meta.set_checksum(checksum);
+            return Ok(meta);
+        }
+
+        let w = match self.writer.as_mut() {
+            Some(w) => w,
+            None => self.open(self.args.clone()).await?,
+        };
+        let mut meta = w.close().await?;
+
+        match meta.checksum() {
+            Some(native) if native.algorithm() == checksum.algorithm() => {
+                if native != &checksum {
+                    let err = new_checksum_mismatch_error(&checksum, native);
+                    // The corrupted object has been committed, don't leave it behind.
+                    let (_, mut d) = self.inner.delete().await?;
+                    oio::Delete::delete(&mut d, &self.path, OpDelete::new())?;
+                    oio::Delete::flush(&mut d).await?;
+                    return Err(err);
+                }
+            }
+            _ => {
+                let (_, mut w) = self
+                    .inner
+                    .write(&sidecar_path(&self.path), OpWrite::new())
+                    .await?;
+                oio::Write::write(&mut w, Buffer::from(checksum.to_string())).await?;
+                oio::Write::close(&mut w).await?;
+            }
+        }
+
+        meta.set_checksum(checksum);
+        Ok(meta)
+    }
+
+    async fn abort(&mut self) -> Result<()> {
+        self.buf = oio::QueueBuf::new();
+        match self.writer.as_mut() {
+            Some(w) => w.abort().await,
+            None => Ok(()),
+        }
+    }
+}
+
+pub struct ChecksumLister<L> {
+    inner: L,
+}
+
+impl<L: oio::List> oio::List for ChecksumLister<L> {
+    async fn next(&mut self) -> Result<Option<oio::Entry>> {
+        loop {
+            match self.inner.next().await? {
+                Some(entry) if entry.path().ends_with(CHECKSUM_SIDECAR_SUFFIX) => continue,
+                entry => return Ok(entry),
+            }
+        }
+    }
+}
+
+pub struct ChecksumDeleter<A: Access> {
+    inner: A::Deleter,
+    accessor: Arc<A>,
+    /// Number of deletes queued in the inner deleter.
+    queued: usize,
+    /// Paths queued for delete, their sidecar objects will be deleted after
+    /// they have been deleted.
+    ///
+    /// Directories don't have sidecar objects, and deleting a version keeps
+    /// the sidecar of the current object.
+    paths: HashSet<String>,
+}
+
+impl<A: Access> oio::Delete for ChecksumDeleter<A> {
+    fn delete(&mut self, path: &str, args: OpDelete) -> Result<()> {
+        let versioned = args.version().is_some();
+        self.inner.delete(path, args)?;
+        self.queued += 1;
+        if !path.ends_with('/') && !versioned {
+            self.paths.insert(path.to_string());
+        }
+        Ok(())
+    }
+
+    async fn flush(&mut self) -> Result<usize> {
+        let deleted = self.inner.flush().await?;
+        self.queued = self.queued.saturating_sub(deleted);
+
+        // The inner deleter doesn't tell which paths have been deleted, check
+        // the remaining ones unless all queued deletes have been processed.
+        let mut done = Vec::new();
+        if self.queued == 0 || deleted == 0 {
+            self.queued = 0;
+            done.extend(self.paths.drain());
+        } else {
+            for path in &self.paths {
+                match self.accessor.stat(path, OpStat::new()).await {
+                    Ok(_) => {}
+                    Err(err) if err.kind() == ErrorKind::NotFound => done.push(path.clone()),
+                    Err(err) => return Err(err),
+                }
+            }
+            for path in &done {
+                self.paths.remove(path);
+            }
+        }
+
+        delete_sidecars(
+            &self.accessor,
+            done.iter().map(|v| sidecar_path(v)).collect(),
+        )
+        .await?;
+        Ok(deleted)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::*;
+    use crate::services;
+
+    fn new_operators(layer: ChecksumLayer) -> (Operator, Operator) {
+        let backend = Operator::new(services::Memory::default()).unwrap().finish();
+        let op = backend.clone().layer(layer);
+        (op, backend)
+    }
+
+    #[test]
+    fn test_hasher() {
+        let cases = [
+            (ChecksumAlgorithm::Crc32c, "yZRlqg=="),
+            (ChecksumAlgorithm::Md5, "XrY7u+Ae7tCTyyK7j1rNww=="),
+            (
+                ChecksumAlgorithm::Sha256,
+                "uU0nuZNNPgilLlLX2n2r+sSE7+N6U4DukIj3rOLvzek=",
+            ),
+        ];
+        for (algorithm, expected) in cases {
+            let mut hasher = Hasher::new(algorithm).unwrap();
+            hasher.update(&Buffer::from("hello "));
+            hasher.update(&Buffer::from("world"));
+            assert_eq!(hasher.finish(), Checksum::new(algorithm, expected));
+        }
+        assert!(Hasher::new(ChecksumAlgorithm::Sha1).is_none());
+    }
+
+    #[tokio::test]
+    async fn test_user_metadata() -> Result<()> {
+        let (op, backend) = new_operators(ChecksumLayer::new(ChecksumAlgorithm::Crc32c));
+
+        let user_metadata = HashMap::from([("k".to_string(), "v".to_string())]);
+        let meta = op
+            .write_with("test", "hello world")
+            .user_metadata(user_metadata)
+            .await?;
+        assert_eq!(
+            meta.checksum(),
+            Some(&Checksum::new(ChecksumAlgorithm::Crc32c, "yZRlqg=="))
+        );
+
+        let stored = backend.stat("test").await?;
+        let stored = stored.user_metadata().unwrap();
+        assert_eq!(stored.get("k").map(String::as_str), Some("v"));
+        assert_eq!(
+            stored.get(CHECKSUM_METADATA_KEY).map(String::as_str),
+            Some("crc32c:yZRlqg==")
+        );
+        assert!(!backend.exists(&sidecar_path("test")).await?);
+
+        assert_eq!(op.read("test").await?.to_vec(), b"hello world");
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_sidecar() -> Result<()> {
+        let (op, backend) =
+            new_operators(ChecksumLayer::new(ChecksumAlgorithm::Sha256).with_buffer_size(4));
+
+        let mut w = op.writer("dir/test").await?;
+        w.write("hello ").await?;
+        w.write("world").await?;
+        w.close().await?;
+
+        let sidecar = backend.read(&sidecar_path("dir/test")).await?.to_vec();
+        assert!(sidecar.starts_with(b"sha256:"));
+        assert_eq!(op.read("dir/test").await?.to_vec(), b"hello world");
+
+        let entries = op.list("dir/").await?;
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].path(), "dir/test");
+
+        op.delete("dir/test").await?;
+        assert!(!backend.exists(&sidecar_path("dir/test")).await?);
+
+        let paths = ["dir/a", "dir/b", "dir/c"];
+        for path in paths {
+            op.write(path, "hello world").await?;
+        }
+        op.delete_iter(paths.into_iter().chain(["dir/"])).await?;
+        for path in paths {
+            assert!(!backend.exists(path).await?);
+            assert!(!backend.exists(&sidecar_path(path)).await?);
+        }
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_checksum_mismatch() -> Result<()> {
+        let (op, backend) =
+            new_operators(ChecksumLayer::new(ChecksumAlgorithm::Md5).with_buffer_size(0));
+
+        op.write("test", "hello world").await?;
+        backend.write("test", "hello").await?;
+
+        let err = op.read("test").await.unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::ChecksumMismatch);
+
+        // Range reads are not verified.
+        assert_eq!(op.read_with("test").range(0..5).await?.to_vec(), b"hello");
+
+        // Objects without checksum are returned as-is.
+        backend.write("plain", "hello").await?;
+        assert_eq!(op.read("plain").await?.to_vec(), b"hello");
+        Ok(())
+    }
+
+    /// Memory backed accessor that ignores versions and reports a fixed native
+    /// checksum after writing if set.
+    #[derive(Debug)]
+    struct MockAccessor {
+        inner: Accessor,
+        checksum: Option<Checksum>,
+    }
+
+    struct MockWriter {
+        inner: oio::Writer,
+        checksum: Option<Checksum>,
+    }
+
+    impl oio::Write for MockWriter {
+        async fn write(&mut self, bs: Buffer) -> Result<()> {
+            self.inner.write(bs).await
+        }
+
+        async fn close(&mut self) -> Result<Metadata> {
+            let mut meta = self.inner.close().await?;
+            if let Some(checksum) = self.checksum.clone() {
+                meta.set_checksum(checksum);
+            }
+            Ok(meta)
+        }
+
+        async fn abort(&mut self) -> Result<()> {
+            self.inner.abort().await
+        }
+    }
+
+    impl LayeredAccess for MockAccessor {
+        type Inner = Accessor;
+        type Reader = oio::Reader;
+        type Writer = MockWriter;
+        type Lister = oio::Lister;
+        type Deleter = oio::Deleter;
+
+        fn inner(&self) -> &Self::Inner {
+            &self.inner
+        }
+
+        async fn stat(&self, path: &str, _: OpStat) -> Result<RpStat> {
+            self.inner.stat(path, OpStat::new()).await
+        }
+
+        async fn read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::Reader)> {
+            let args = OpRead::new().with_range(args.range());
+            self.inner.read(path, args).await
+        }
+
+        async fn write(&self, path: &str, args: OpWrite) -> Result<(RpWrite, Self::Writer)> {
+            let (rp, w) = self.inner.write(path, args).await?;
+            let w = MockWriter {
+                inner: w,
+                checksum: self.checksum.clone(),
+            };
+            Ok((rp, w))
+        }
+
+        async fn list(&self, path: &str, args: OpList) -> Result<(RpList, Self::Lister)> {
+            self.inner.list(path, args).await
+        }
+
+        async fn delete(&self) -> Result<(RpDelete, Self::Deleter)> {
+            self.inner.delete().await
+        }
+    }
+
+    #[tokio::test]
+    async fn test_native_checksum_mismatch() -> Result<()> {
+        let backend = Operator::new(services::Memory::default()).unwrap().finish();
+        let acc = ChecksumLayer::new(ChecksumAlgorithm::Md5)
+            .with_buffer_size(0)
+            .layer(MockAccessor {
+                inner: backend.inner().clone(),
+                checksum: Some(Checksum::new(
+                    ChecksumAlgorithm::Md5,
+                    "XrY7u+Ae7tCTyyK7j1rNww==",
+                )),
+            });
+
+        let (_, mut w) = LayeredAccess::write(&acc, "test", OpWrite::new()).await?;
+        oio::Write::write(&mut w, Buffer::from("hello")).await?;
+        let err = oio::Write::close(&mut w).await.unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::ChecksumMismatch);
+        assert!(!backend.exists("test").await?);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_versioned_read_skips_sidecar() -> Result<()> {
+        let (op, backend) =
+            new_operators(ChecksumLayer::new(ChecksumAlgorithm::Md5).with_buffer_size(0));
+        op.write("test", "hello world").await?;
+        // The stale sidecar of the current version doesn't belong to other versions.
+        backend.write("test", "hello").await?;
+
+        let acc = ChecksumLayer::new(ChecksumAlgorithm::Md5).layer(MockAccessor {
+            inner: backend.inner().clone(),
+            checksum: None,
+        });
+        let (_, mut r) =
+            LayeredAccess::read(&acc, "test", OpRead::new().with_version("v1")).await?;
+        let bs = oio::Read::read_all(&mut r).await?;
+        assert_eq!(bs.to_vec(), b"hello");
+        Ok(())
+    }
+}
@@ -162,11 +162,13 @@ impl<A: Access> CompleteAccessor<A> {
             (true, false) => {
                 // Forward path that ends with /
                 if path.ends_with('/') {
-                    let p = FlatLister::new(self.inner.clone(), path);
+                    let p =
+                        FlatLister::new(self.inner.clone(), path).with_glob(args.glob().cloned());
                     Ok((RpList::default(), CompleteLister::Two(p)))
                 } else {
                     let parent = get_parent(path);
-                    let p = FlatLister::new(self.inner.clone(), parent);
+                    let p =
+                        FlatLister::new(self.inner.clone(), parent).with_glob(args.glob().cloned());
                     let p = PrefixLister::new(p, path);
                     Ok((RpList::default(), CompleteLister::Four(p)))
                 }
@@ -0,0 +1,887 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::io::Write;
+
+use bytes::Bytes;
+use flate2::write::GzDecoder;
+use flate2::write::GzEncoder;
+
+use crate::raw::*;
+use crate::*;
+
+/// Magic number of zstd skippable frame used by the seek table.
+const ZSTD_SKIPPABLE_MAGIC: u32 = 0x184D2A5E;
+/// Magic number at the end of the zstd seek table.
+const ZSTD_SEEKABLE_MAGIC: u32 = 0x8F92EAB1;
+/// Size of the zstd seek table footer.
+const ZSTD_FOOTER_SIZE: u64 = 9;
+/// Size of the empty gzip member at the end that records the size of the index.
+const GZIP_FOOTER_SIZE: u64 = 42;
+/// Max number of frames recorded in one empty gzip member of the index.
+const GZIP_TABLE_ENTRIES: usize = 4096;
+
+/// The user metadata key that records the algorithm used to compress the object.
+///
+/// `content_encoding` is not used, since services and http clients may decompress
+/// objects with `content-encoding: gzip` on the fly, which breaks range reads.
+pub const COMPRESSION_METADATA_KEY: &str = "opendal-compression";
+
+/// Default decompressed size of every frame.
+const DEFAULT_FRAME_SIZE: usize = 1024 * 1024;
+
+/// Compression algorithms supported by [`CompressionLayer`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum CompressionAlgorithm {
+    /// [gzip](https://www.rfc-editor.org/rfc/rfc1952) compression.
+    ///
+    /// Data is written as multiple gzip members which can be decompressed by
+    /// any gzip implementation.
+    Gzip,
+    /// [zstd](https://facebook.github.io/zstd/) compression.
+    ///
+    /// Data is written in the zstd seekable format which can be decompressed
+    /// by any zstd implementation.
+    Zstd,
+}
+
+impl CompressionAlgorithm {
+    /// The name of this algorithm recorded in [`COMPRESSION_METADATA_KEY`].
+    pub fn name(&self) -> &'static str {
+        match self {
+            CompressionAlgorithm::Gzip => "gzip",
+            CompressionAlgorithm::Zstd => "zstd",
+        }
+    }
+}
+
+/// Add transparent compression for the underlying storage.
+///
+/// # Notes
+///
+/// Data written by `Writer` is split into frames of `frame_size` bytes, and
+/// every frame is compressed independently. An index of the compressed and
+/// decompressed size of every frame is appended after the last frame, so
+/// range reads only fetch and decompress frames that cover the range:
+///
+/// - `zstd` objects are written in the [seekable format](https://github.com/facebook/zstd/blob/dev/contrib/seekable_format/zstd_seekable_compression_format.md).
+/// - `gzip` objects are written as one gzip member per frame, followed by
+///   empty gzip members carrying the index in their extra field.
+///
+/// The algorithm is recorded in user metadata under [`COMPRESSION_METADATA_KEY`]
+/// if the underlying storage supports `write_with_user_metadata`.
+/// `content_encoding` is left untouched, since services and http clients may
+/// decompress such objects on the fly.
+///
+/// Reads detect compressed objects by the index, so objects written without
+/// this layer are returned as-is. `stat` reports the decompressed
+/// `content_length` at the cost of an extra read. `list` can't tell the
+/// decompressed size, so it doesn't return `content_length` of files, use
+/// `stat` to get it instead.
+///
+/// Appending is not supported while this layer is enabled.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use opendal::layers::CompressionAlgorithm;
+/// # use opendal::layers::CompressionLayer;
+/// # use opendal::services;
+/// # use opendal::Operator;
+/// # use opendal::Result;
+///
+/// # fn main() -> Result<()> {
+/// let _ = Operator::new(services::Memory::default())?
+///     .layer(CompressionLayer::new(CompressionAlgorithm::Zstd).with_level(9))
+///     .finish();
+/// Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct CompressionLayer {
+    algorithm: CompressionAlgorithm,
+    level: Option<i32>,
+    frame_size: usize,
+}
+
+impl CompressionLayer {
+    /// Create a new `CompressionLayer` with given algorithm.
+    ///
+    /// The default frame size is 1 MiB.
+    pub fn new(algorithm: CompressionAlgorithm) -> Self {
+        Self {
+            algorithm,
+            level: None,
+            frame_size: DEFAULT_FRAME_SIZE,
+        }
+    }
+
+    /// Set the compression level.
+    ///
+    /// Levels are passed to the algorithm directly, gzip accepts `0..=9` and
+    /// zstd accepts `1..=22`. The default level of the algorithm is used if
+    /// not set.
+    pub fn with_level(mut self, level: i32) -> Self {
+        self.level = Some(level);
+        self
+    }
+
+    /// Set the decompressed size of every frame.
+    ///
+    /// Smaller frames make range reads cheaper but hurt the compression ratio.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `frame_size` is zero or larger than 1 GiB.
+    pub fn with_frame_size(mut self, frame_size: usize) -> Self {
+        assert!(
+            frame_size > 0 && frame_size <= 1024 * 1024 * 1024,
+            "frame size must be in (0, 1GiB]"
+        );
+        self.frame_size = frame_size;
+        self
+    }
+}
+
+impl<A: Access> Layer<A> for CompressionLayer {
+    type LayeredAccess = CompressionAccessor<A>;
+
+    fn layer(&self, inner: A) -> Self::LayeredAccess {
+        let info = inner.info();
+        info.update_full_capability(|mut cap| {
+            cap.write_can_append = false;
+            cap.write_can_resume = false;
+            cap
+        });
+
+        CompressionAccessor {
+            inner,
+            algorithm: self.algorithm,
+            level: self.level,
+            frame_size: self.frame_size,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct CompressionAccessor<A: Access> {
+    inner: A,
+    algorithm: CompressionAlgorithm,
+    level: Option<i32>,
+    frame_size: usize,
+}
+
+impl<A: Access> CompressionAccessor<A> {
+    /// Read given range of the stored object.
+    async fn read_stored(
+        &self,
+        path: &str,
+        args: &OpRead,
+        offset: u64,
+        size: u64,
+    ) -> Result<Bytes> {
+        let args = args.clone().with_range(BytesRange::new(offset, Some(size)));
+        let (_, mut r) = self.inner.read(path, args).await?;
+        Ok(oio::Read::read_all(&mut r).await?.to_bytes())
+    }
+
+    /// Detect the layout of the stored object by its index.
+    async fn layout(&self, path: &str, args: &OpRead, total: u64) -> Result<Layout> {
+        if total < ZSTD_FOOTER_SIZE {
+            return Ok(Layout::Plain);
+        }
+
+        let tail_size = total.min(GZIP_FOOTER_SIZE);
+        let tail = self
+            .read_stored(path, args, total - tail_size, tail_size)
+            .await?;
+
+        let frames = if let Some(table_size) = zstd_seek_table_size(&tail) {
+            if table_size > total {
+                return Ok(Layout::Plain);
+            }
+            let table = if table_size <= tail_size {
+                tail.slice((tail_size - table_size) as usize..)
+            } else {
+                self.read_stored(path, args, total - table_size, table_size)
+                    .await?
+            };
+            parse_zstd_seek_table(&table).map(|frames| (frames, table_size))
+        } else if let Some((count, table_size)) = parse_gzip_footer(&tail) {
+            let index_size = table_size + GZIP_FOOTER_SIZE;
+            if index_size > total {
+                return Ok(Layout::Plain);
+            }
+            let table = self
+                .read_stored(path, args, total - index_size, table_size)
+                .await?;
+            parse_gzip_table(&table)
+                .filter(|frames| frames.len() as u64 == count)
+                .map(|frames| (frames, index_size))
+        } else {
+            None
+        };
+
+        Ok(match frames {
+            Some((frames, index_size))
+                if frames.iter().map(|v| v.0).sum::<u64>() + index_size == total =>
+            {
+                Layout::Framed(frames)
+            }
+            _ => Layout::Plain,
+        })
+    }
+}
+
+impl<A: Access> LayeredAccess for CompressionAccessor<A> {
+    type Inner = A;
+    type Reader = TwoWays<A::Reader, FrameReader<A::Reader>>;
+    type Writer = CompressionWriter<A::Writer>;
+    type Lister = CompressionLister<A::Lister>;
+    type Deleter = A::Deleter;
+
+    fn inner(&self) -> &Self::Inner {
+        &self.inner
+    }
+
+    async fn stat(&self, path: &str, args: OpStat) -> Result<RpStat> {
+        let mut read = OpRead::new();
+        if let Some(v) = args.version() {
+            read = read.with_version(v);
+        }
+
+        let mut meta = self.inner.stat(path, args).await?.into_metadata();
+        if meta.is_file() {
+            let layout = self.layout(path, &read, meta.content_length()).await?;
+            if let Layout::Framed(frames) = layout {
+                meta.set_content_length(frames.iter().map(|v| v.1).sum());
+            }
+        }
+        Ok(RpStat::new(meta))
+    }
+
+    async fn read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::Reader)> {
+        let mut stat = OpStat::new();
+        if let Some(v) = args.version() {
+            stat = stat.with_version(v);
+        }
+        if let Some(v) = args.if_match() {
+            stat = stat.with_if_match(v);
+        }
+        if let Some(v) = args.if_none_match() {
+            stat = stat.with_if_none_match(v);
+        }
+        if let Some(v) = args.if_modified_since() {
+            stat = stat.with_if_modified_since(v);
+        }
+        if let Some(v) = args.if_unmodified_since() {
+            stat = stat.with_if_unmodified_since(v);
+        }
+        let total = self
+            .inner
+            .stat(path, stat)
+            .await?
+            .into_metadata()
+            .content_length();
+
+        let layout = self.layout(path, &args, total).await?;
+        let Layout::Framed(frames) = layout else {
+            return self
+                .inner
+                .read(path, args)
+                .await
+                .map(|(rp, r)| (rp, TwoWays::One(r)));
+        };
+        let size = frames.iter().map(|v| v.1).sum();
+
+        let range = args.range();
+        let start = range.offset().min(size);
+        let end = range.size().map_or(size, |v| (start + v).min(size));
+
+        // Find the frames that cover the range.
+        let mut selected = Vec::new();
+        let (mut offset, mut decompressed) = (0, 0);
+        let (mut read_offset, mut skip) = (0, 0);
+        for (c_size, d_size) in frames {
+            if decompressed + d_size > start && decompressed < end {
+                if selected.is_empty() {
+                    read_offset = offset;
+                    skip = start - decompressed;
+                }
+                selected.push((c_size, d_size));
+            }
+            offset += c_size;
+            decompressed += d_size;
+        }
+        if selected.is_empty() {
+            let rp = RpRead::new().with_size(Some(0));
+            return Ok((rp, TwoWays::Two(FrameReader::empty())));
+        }
+
+        let read_size = selected.iter().map(|v| v.0).sum();
+        let (rp, r) = self
+            .inner
+            .read(
+                path,
+                args.with_range(BytesRange::new(read_offset, Some(read_size))),
+            )
+            .await?;
+        let reader = FrameReader {
+            inner: Some(r),
+            algorithm: self.algorithm,
+            frames: selected.into_iter().rev().collect(),
+            skip: skip as usize,
+            remaining: end - start,
+            buf: Vec::new(),
+        };
+        // The inner reply describes the compressed frames, report the decompressed range instead.
+        let range = BytesContentRange::default()
+            .with_range(start, end - 1)
+            .with_size(size);
+        let rp = rp.with_size(Some(end - start)).with_range(Some(range));
+        Ok((rp, TwoWays::Two(reader)))
+    }
+
+    async fn write(&self, path: &str, mut args: OpWrite) -> Result<(RpWrite, Self::Writer)> {
+        if args.append() {
+            return Err(Error::new(
+                ErrorKind::Unsupported,
+                "compression layer doesn't support append",
+            )
+            .with_operation(Operation::Write)
+            .with_context("path", path));
+        }
+
+        if self
+            .inner
+            .info()
+            .native_capability()
+            .write_with_user_metadata
+        {
+            let mut metadata = args.user_metadata().cloned().unwrap_or_default();
+            metadata
+                .entry(COMPRESSION_METADATA_KEY.to_string())
+                .or_insert_with(|| self.algorithm.name().to_string());
+            args = args.with_user_metadata(metadata);
+        }
+
+        let (rp, w) = self.inner.write(path, args).await?;
+        let writer = CompressionWriter {
+            inner: w,
+            algorithm: self.algorithm,
+            level: self.level,
+            frame_size: self.frame_size,
+            buf: Vec::new(),
+            frames: Vec::new(),
+            written: 0,
+        };
+        Ok((rp, writer))
+    }
+
+    async fn delete(&self) -> Result<(RpDelete, Self::Deleter)> {
+        self.inner.delete().await
+    }
+
+    async fn list(&self, path: &str, args: OpList) -> Result<(RpList, Self::Lister)> {
+        let (rp, lister) = self.inner.list(path, args).await?;
+        Ok((rp, CompressionLister { inner: lister }))
+    }
+}
+
+/// Layout of the stored object.
+enum Layout {
+    /// Object is not written by this layer.
+    Plain,
+    /// Compressed and decompressed size of every frame.
+    Framed(Vec<(u64, u64)>),
+}
+
+/// Returns the size of the whole seek table if `bs` ends with a seek table footer.
+fn zstd_seek_table_size(bs: &[u8]) -> Option<u64> {
+    let footer = bs.get(bs.len().checked_sub(ZSTD_FOOTER_SIZE as usize)?..)?;
+    if u32::from_le_bytes(footer[5..9].try_into().unwrap()) != ZSTD_SEEKABLE_MAGIC {
+        return None;
+    }
+
+    let frames = u32::from_le_bytes(footer[0..4].try_into().unwrap()) as u64;
+    let entry_size = if footer[4] & 0x80 != 0 { 12 } else { 8 };
+    Some(8 + frames * entry_size + ZSTD_FOOTER_SIZE)
+}
+
+/// Parse the zstd seek table into `(compressed, decompressed)` size of every frame.
+fn parse_zstd_seek_table(bs: &[u8]) -> Option<Vec<(u64, u64)>> {
+    let read_u32 = |pos: usize| u32::from_le_bytes(bs[pos..pos + 4].try_into().unwrap()) as u64;
+
+    if bs.len() < 8 + ZSTD_FOOTER_SIZE as usize
+        || read_u32(0) as u32 != ZSTD_SKIPPABLE_MAGIC
+        || read_u32(4) + 8 != bs.len() as u64
+    {
+        return None;
+    }
+
+    let footer = bs.len() - ZSTD_FOOTER_SIZE as usize;
+    let entry_size = if bs[footer + 4] & 0x80 != 0 { 12 } else { 8 };
+    Some(
+        (8..footer)
+            .step_by(entry_size)
+            .map(|pos| (read_u32(pos), read_u32(pos + 4)))
+            .collect(),
+    )
+}
+
+/// Build the zstd seek table for given frames.
+fn build_zstd_seek_table(frames: &[(u32, u32)]) -> Vec<u8> {
+    let mut bs = Vec::with_capacity(8 + frames.len() * 8 + ZSTD_FOOTER_SIZE as usize);
+    bs.extend_from_slice(&ZSTD_SKIPPABLE_MAGIC.to_le_bytes());
+    bs.extend_from_slice(&((frames.len() * 8) as u32 + ZSTD_FOOTER_SIZE as u32).to_le_bytes());
+    for (c_size, d_size) in frames {
+        bs.extend_from_slice(&c_size.to_le_bytes());
+        bs.extend_from_slice(&d_size.to_le_bytes());
+    }
+    bs.extend_from_slice(&(frames.len() as u32).to_le_bytes());
+    bs.push(0);
+    bs.extend_from_slice(&ZSTD_SEEKABLE_MAGIC.to_le_bytes());
+    bs
+}
+
+/// Build an empty gzip member that carries `data` in a subfield of the extra field.
+///
+/// ```text
+/// | header (10) | xlen (2) | id (2) | len (2) | data | empty block (2) | crc32 (4) | isize (4) |
+/// ```
+fn build_gzip_member(id: &[u8; 2], data: &[u8]) -> Vec<u8> {
+    let mut bs = Vec::with_capacity(26 + data.len());
+    // ID1, ID2, CM (deflate), FLG (FEXTRA), MTIME, XFL and OS (unknown).
+    bs.extend_from_slice(&[0x1f, 0x8b, 0x08, 0x04, 0, 0, 0, 0, 0, 0xff]);
+    bs.extend_from_slice(&(4 + data.len() as u16).to_le_bytes());
+    bs.extend_from_slice(id);
+    bs.extend_from_slice(&(data.len() as u16).to_le_bytes());
+    bs.extend_from_slice(data);
+    // An empty final deflate block, then CRC32 and ISIZE of empty content.
+    bs.extend_from_slice(&[0x03, 0x00, 0, 0, 0, 0, 0, 0, 0, 0]);
+    bs
+}
+
+/// Returns the data and size of the empty gzip member built by `build_gzip_member`
+/// at the start of `bs`.
+fn parse_gzip_member<'a>(bs: &'a [u8], id: &[u8; 2]) -> Option<(&'a [u8], usize)> {
+    let header = bs.get(..16)?;
+    let len = u16::from_le_bytes(header[14..16].try_into().unwrap()) as usize;
+    let expected = build_gzip_member(id, &[]);
+    if header[..10] != expected[..10]
+        || u16::from_le_bytes(header[10..12].try_into().unwrap()) as usize != 4 + len
+        || header[12..14] != id[..]
+        || bs.get(16 + len..26 + len)? != &expected[16..]
+    {
+        return None;
+    }
+    Some((&bs[16..16 + len], 26 + len))
+}
+
+/// Build the gzip index for given frames.
+///
+/// The `(compressed, decompressed)` size of frames are stored in empty members
+/// with `OT` subfield, followed by an empty member with `OD` subfield that records
+/// the number of frames and the size of the table.
+fn build_gzip_index(frames: &[(u32, u32)]) -> Vec<u8> {
+    let mut bs = Vec::new();
+    for chunk in frames.chunks(GZIP_TABLE_ENTRIES) {
+        let data: Vec<u8> = chunk
+            .iter()
+            .flat_map(|(c, d)| c.to_le_bytes().into_iter().chain(d.to_le_bytes()))
+            .collect();
+        bs.extend(build_gzip_member(b"OT", &data));
+    }
+
+    let mut footer = Vec::with_capacity(16);
+    footer.extend_from_slice(&(frames.len() as u64).to_le_bytes());
+    footer.extend_from_slice(&(bs.len() as u64).to_le_bytes());
+    bs.extend(build_gzip_member(b"OD", &footer));
+    bs
+}
+
+/// Returns the number of frames and the size of the table if `bs` is the footer
+/// built by `build_gzip_index`.
+fn parse_gzip_footer(bs: &[u8]) -> Option<(u64, u64)> {
+    if bs.len() != GZIP_FOOTER_SIZE as usize {
+        return None;
+    }
+    match parse_gzip_member(bs, b"OD")? {
+        (data, size) if data.len() == 16 && size == bs.len() => Some((
+            u64::from_le_bytes(data[..8].try_into().unwrap()),
+            u64::from_le_bytes(data[8..].try_into().unwrap()),
+        )),
+        _ => None,
+    }
+}
+
+/// Parse the gzip table into `(compressed, decompressed)` size of every frame.
+fn parse_gzip_table(mut bs: &[u8]) -> Option<Vec<(u64, u64)>> {
+    let read_u32 = |bs: &[u8]| u32::from_le_bytes(bs.try_into().unwrap()) as u64;
+
+    let mut frames = Vec::new();
+    while !bs.is_empty() {
+        let (data, size) = parse_gzip_member(bs, b"OT")?;
+        if data.len() % 8 != 0 {
+            return None;
+        }
+        frames.extend(
+            data.chunks_exact(8)
+                .map(|v| (read_u32(&v[..4]), read_u32(&v[4..]))),
+        );
+        bs = &bs[size..];
+    }
+    Some(frames)
+}
+
+fn new_compress_error(err: std::io::Error) -> Error {
+    Error::new(ErrorKind::Unexpected, "failed to compress data").set_source(err)
+}
+
+fn new_decompress_error(err: std::io::Error) -> Error {
+    Error::new(
+        ErrorKind::Unexpected,
+        "failed to decompress data, the object may be corrupted",
+    )
+    .set_source(err)
+}
+
+pub struct CompressionWriter<W> {
+    inner: W,
+    algorithm: CompressionAlgorithm,
+    level: Option<i32>,
+    frame_size: usize,
+    /// Data that not compressed yet.
+    buf: Vec<u8>,
+    /// Compressed and decompressed size of frames written.
+    frames: Vec<(u32, u32)>,
+    /// Decompressed bytes written by users.
+    written: u64,
+}
+
+impl<W: oio::Write> CompressionWriter<W> {
+    async fn write_frame(&mut self, size: usize) -> Result<()> {
+        let data = &self.buf[..size];
+        let compressed = match self.algorithm {
+            CompressionAlgorithm::Gzip => {
+                let level = self.level.map_or(flate2::Compression::default(), |v| {
+                    flate2::Compression::new(v.clamp(0, 9) as u32)
+                });
+                let mut encoder = GzEncoder::new(Vec::new(), level);
+                encoder.write_all(data).map_err(new_compress_error)?;
+                encoder.finish().map_err(new_compress_error)?
+            }
+            CompressionAlgorithm::Zstd => {
+                let level = self.level.unwrap_or(zstd::DEFAULT_COMPRESSION_LEVEL);
+                zstd::bulk::compress(data, level).map_err(new_compress_error)?
+            }
+        };
+
+        self.frames.push((compressed.len() as u32, size as u32));
+        self.buf.drain(..size);
+        self.inner.write(Buffer::from(compressed)).await
+    }
+}
+
+impl<W: oio::Write> oio::Write for CompressionWriter<W> {
+    async fn write(&mut self, bs: Buffer) -> Result<()> {
+        self.written += bs.len() as u64;
+        for b in bs {
+            self.buf.extend_from_slice(&b);
+        }
+
+        while self.buf.len() >= self.frame_size {
+            self.write_frame(self.frame_size).await?;
+        }
+        Ok(())
+    }
+
+    async fn close(&mut self) -> Result<Metadata> {
+        // Always write at least one frame so that empty objects are still valid.
+        if !self.buf.is_empty() || self.frames.is_empty() {
+            self.write_frame(self.buf.len()).await?;
+        }
+
+        let index = match self.algorithm {
+            CompressionAlgorithm::Gzip => build_gzip_index(&self.frames),
+            CompressionAlgorithm::Zstd => build_zstd_seek_table(&self.frames),
+        };
+        self.inner.write(Buffer::from(index)).await?;
+
+        let mut meta = self.inner.close().await?;
+        meta.set_content_length(self.written);
+        Ok(meta)
+    }
+
+    async fn abort(&mut self) -> Result<()> {
+        self.inner.abort().await
+    }
+}
+
+/// Decompress a frame that written by [`CompressionWriter`].
+fn decompress_frame(algorithm: CompressionAlgorithm, bs: &[u8], size: usize) -> Result<Vec<u8>> {
+    let frame = match algorithm {
+        CompressionAlgorithm::Gzip => {
+            let mut decoder = GzDecoder::new(Vec::with_capacity(size));
+            decoder.write_all(bs).map_err(new_decompress_error)?;
+            decoder.finish().map_err(new_decompress_error)?
+        }
+        CompressionAlgorithm::Zstd => {
+            zstd::bulk::decompress(bs, size).map_err(new_decompress_error)?
+        }
+    };
+    if frame.len() != size {
+        return Err(Error::new(
+            ErrorKind::Unexpected,
+            "decompressed frame size doesn't match the index, the object may be corrupted",
+        ));
+    }
+    Ok(frame)
+}
+
+pub struct FrameReader<R> {
+    inner: Option<R>,
+    algorithm: CompressionAlgorithm,
+    /// Frames left to read in reversed order.
+    frames: Vec<(u64, u64)>,
+    /// Bytes to skip in the next decompressed frame.
+    skip: usize,
+    /// Decompressed bytes left to return.
+    remaining: u64,
+    buf: Vec<u8>,
+}
+
+impl<R> FrameReader<R> {
+    fn empty() -> Self {
+        Self {
+            inner: None,
+            algorithm: CompressionAlgorithm::Zstd,
+            frames: Vec::new(),
+            skip: 0,
+            remaining: 0,
+            buf: Vec::new(),
+        }
+    }
+}
+
+impl<R: oio::Read> oio::Read for FrameReader<R> {
+    async fn read(&mut self) -> Result<Buffer> {
+        let Some(inner) = self.inner.as_mut() else {
+            return Ok(Buffer::new());
+        };
+
+        while self.remaining > 0 {
+            let Some((c_size, d_size)) = self.frames.pop() else {
+                break;
+            };
+
+            let c_size = c_size as usize;
+            while self.buf.len() < c_size {
+                let bs = inner.read().await?;
+                if bs.is_empty() {
+                    return Err(Error::new(
+                        ErrorKind::Unexpected,
+                        "compressed object is truncated while reading",
+                    ));
+                }
+                for b in bs {
+                    self.buf.extend_from_slice(&b);
+                }
+            }
+
+            let frame = decompress_frame(self.algorithm, &self.buf[..c_size], d_size as usize)?;
+            self.buf.drain(..c_size);
+
+            let start = self.skip.min(frame.len());
+            let end = frame
+                .len()
+                .min(start + self.remaining.min(usize::MAX as u64) as usize);
+            self.skip = 0;
+            self.remaining -= (end - start) as u64;
+            if start < end {
+                return Ok(Buffer::from(Bytes::from(frame).slice(start..end)));
+            }
+        }
+
+        self.inner = None;
+        Ok(Buffer::new())
+    }
+}
+
+pub struct CompressionLister<L> {
+    inner: L,
+}
+
+impl<L: oio::List> oio::List for CompressionLister<L> {
+    async fn next(&mut self) -> Result<Option<oio::Entry>> {
+        let Some(entry) = self.inner.next().await? else {
+            return Ok(None);
+        };
+        if !entry.mode().is_file() || !entry.metadata().has_content_length() {
+            return Ok(Some(entry));
+        }
+
+        // The stored size is not the decompressed size, drop it so that users
+        // and size filters stat the entry instead.
+        let mut meta = entry.metadata().clone();
+        meta.remove_content_length();
+        Ok(Some(oio::Entry::new(entry.path(), meta)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use flate2::write::MultiGzDecoder;
+
+    use super::*;
+    use crate::services;
+
+    fn new_operators(algorithm: CompressionAlgorithm) -> (Operator, Operator) {
+        let backend = Operator::new(services::Memory::default()).unwrap().finish();
+        let op = backend
+            .clone()
+            .layer(CompressionLayer::new(algorithm).with_frame_size(64));
+        (op, backend)
+    }
+
+    fn content(size: usize) -> Vec<u8> {
+        (0..size).map(|i| b"opendal"[i % 7]).collect()
+    }
+
+    #[tokio::test]
+    async fn test_write_and_read() -> Result<()> {
+        for algorithm in [CompressionAlgorithm::Gzip, CompressionAlgorithm::Zstd] {
+            let (op, backend) = new_operators(algorithm);
+
+            for size in [0, 1, 63, 64, 65, 1000] {
+                let path = format!("file-{size}");
+                let data = content(size);
+                op.write(&path, data.clone()).await?;
+
+                assert_eq!(op.read(&path).await?.to_vec(), data);
+                assert_eq!(op.stat(&path).await?.content_length(), size as u64);
+            }
+
+            // Compressed data can be decompressed by other implementations.
+            let raw = backend.read("file-1000").await?.to_vec();
+            assert!(raw.len() < 1000);
+            let decompressed = match algorithm {
+                CompressionAlgorithm::Gzip => {
+                    let mut decoder = MultiGzDecoder::new(Vec::new());
+                    decoder.write_all(&raw).unwrap();
+                    decoder.finish().unwrap()
+                }
+                CompressionAlgorithm::Zstd => zstd::stream::decode_all(raw.as_slice()).unwrap(),
+            };
+            assert_eq!(decompressed, content(1000));
+        }
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_range_read() -> Result<()> {
+        for algorithm in [CompressionAlgorithm::Gzip, CompressionAlgorithm::Zstd] {
+            let (op, _) = new_operators(algorithm);
+            let data = content(1000);
+
+            let mut w = op.writer("test").await?;
+            for chunk in data.chunks(100) {
+                w.write(chunk.to_vec()).await?;
+            }
+            let meta = w.close().await?;
+            assert_eq!(meta.content_length(), 1000);
+
+            for (start, end) in [(0, 1), (3, 70), (64, 128), (63, 65), (900, 1000)] {
+                let bs = op.read_with("test").range(start..end).await?;
+                assert_eq!(bs.to_vec(), data[start as usize..end as usize]);
+            }
+
+            let r = op.reader_with("test").chunk(100).await?;
+            assert_eq!(r.read(500..).await?.to_vec(), data[500..]);
+
+            // The reply describes the requested range of the original content.
+            let args = OpRead::new().with_range(BytesRange::new(3, Some(17)));
+            let (rp, _) = op.inner().read("test", args).await?;
+            assert_eq!(rp.size(), Some(17));
+            assert_eq!(
+                rp.range(),
+                Some(
+                    BytesContentRange::default()
+                        .with_range(3, 19)
+                        .with_size(1000)
+                )
+            );
+        }
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_gzip_index_with_many_frames() -> Result<()> {
+        let (op, _) = new_operators(CompressionAlgorithm::Gzip);
+        let data = content(64 * (GZIP_TABLE_ENTRIES + 10));
+        op.write("test", data.clone()).await?;
+
+        assert_eq!(op.stat("test").await?.content_length(), data.len() as u64);
+        let bs = op.read_with("test").range(262100..262200).await?;
+        assert_eq!(bs.to_vec(), data[262100..262200]);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_metadata() -> Result<()> {
+        let (op, backend) = new_operators(CompressionAlgorithm::Zstd);
+        op.write("dir/test", content(1000)).await?;
+
+        let meta = backend.stat("dir/test").await?;
+        assert_eq!(
+            meta.user_metadata()
+                .and_then(|v| v.get(COMPRESSION_METADATA_KEY))
+                .map(String::as_str),
+            Some("zstd")
+        );
+        assert!(meta.content_encoding().is_none());
+
+        // list doesn't report the stored size.
+        let entries = op.list("dir/").await?;
+        let entry = entries.iter().find(|v| v.path() == "dir/test").unwrap();
+        assert!(!entry.metadata().has_content_length());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_read_plain_object() -> Result<()> {
+        let (op, backend) = new_operators(CompressionAlgorithm::Zstd);
+
+        for size in [0, 5, 100] {
+            let path = format!("file-{size}");
+            let data = content(size);
+            backend.write(&path, data.clone()).await?;
+
+            assert_eq!(op.read(&path).await?.to_vec(), data);
+            assert_eq!(op.stat(&path).await?.content_length(), size as u64);
+        }
+
+        // gzip data written without this layer is returned as-is.
+        let mut encoder = GzEncoder::new(Vec::new(), flate2::Compression::default());
+        encoder.write_all(&content(100)).unwrap();
+        let gzipped = encoder.finish().unwrap();
+        backend.write("test.gz", gzipped.clone()).await?;
+        assert_eq!(op.read("test.gz").await?.to_vec(), gzipped);
+        Ok(())
+    }
+}
@@ -140,6 +140,13 @@ impl<A: Access> LayeredAccess for CorrectnessAccessor<A> {
                 "append",
             ));
         }
+        if args.resume().is_some() && !capability.write_can_resume {
+            return Err(new_unsupported_error(
+                &self.info,
+                Operation::Write,
+                "resume",
+            ));
+        }
         if args.if_not_exists() && !capability.write_with_if_not_exists {
             return Err(new_unsupported_error(
                 &self.info,
@@ -147,6 +154,23 @@ impl<A: Access> LayeredAccess for CorrectnessAccessor<A> {
                 "if_not_exists",
             ));
         }
+        if args.expires_in().is_some() && !capability.write_with_ttl {
+            return Err(new_unsupported_error(
+                &self.info,
+                Operation::Write,
+                "expires_in",
+            ));
+        }
+        if args.unix_mode().is_some() && !capability.write_with_unix_mode {
+            return Err(new_unsupported_error(
+                &self.info,
+                Operation::Write,
+                "unix_mode",
+            ));
+        }
+        if args.mtime().is_some() && !capability.write_with_mtime {
+            return Err(new_unsupported_error(&self.info, Operation::Write, "mtime"));
+        }
         if let Some(if_none_match) = args.if_none_match() {
             if !capability.write_with_if_none_match {
                 let mut err =
@@ -171,6 +195,13 @@ impl<A: Access> LayeredAccess for CorrectnessAccessor<A> {
                 "version",
             ));
         }
+        if !capability.symlink && args.no_follow_symlink() {
+            return Err(new_unsupported_error(
+                self.info.as_ref(),
+                Operation::Stat,
+                "no_follow_symlink",
+            ));
+        }
         if !capability.stat_with_if_match && args.if_match().is_some() {
             return Err(new_unsupported_error(
                 self.info.as_ref(),
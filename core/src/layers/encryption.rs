@@ -0,0 +1,843 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::fmt::Debug;
+use std::fmt::Formatter;
+use std::future::Future;
+use std::sync::Arc;
+
+use aes_gcm::Aes256Gcm;
+use aes_gcm::KeyInit;
+use aes_gcm::Nonce;
+use aes_gcm::aead::Aead;
+use aes_gcm::aead::AeadCore;
+use aes_gcm::aead::OsRng;
+use aes_gcm::aead::Payload;
+use bytes::Bytes;
+
+use crate::raw::*;
+use crate::*;
+
+/// Magic bytes at the beginning of every encrypted object.
+const MAGIC: &[u8; 4] = b"ODEC";
+/// Version of the encrypted object format.
+const FORMAT_VERSION: u8 = 1;
+/// Size of the fixed header written before the first chunk.
+const HEADER_SIZE: u64 = 256;
+/// Bytes in header used by magic, version, chunk size and key length.
+const HEADER_PREFIX_SIZE: usize = 11;
+/// Max size of the wrapped data key that can be stored in header.
+const MAX_WRAPPED_KEY_SIZE: usize = HEADER_SIZE as usize - HEADER_PREFIX_SIZE;
+/// Size of the AES-GCM authentication tag appended to every chunk.
+const TAG_SIZE: u64 = 16;
+/// Size of the AES-256 data key.
+const DATA_KEY_SIZE: usize = 32;
+/// Size of the AES-GCM nonce.
+const NONCE_SIZE: usize = 12;
+
+/// Default plaintext size of every chunk.
+const DEFAULT_CHUNK_SIZE: usize = 64 * 1024;
+
+/// A data key used to encrypt a single object.
+///
+/// `plaintext` is the 32 bytes AES-256 key used to encrypt content, and
+/// `wrapped` is the form protected by the key provider which will be stored
+/// along with the object.
+pub struct DataKey {
+    plaintext: Vec<u8>,
+    wrapped: Vec<u8>,
+}
+
+impl DataKey {
+    /// Create a new data key.
+    pub fn new(plaintext: Vec<u8>, wrapped: Vec<u8>) -> Self {
+        Self { plaintext, wrapped }
+    }
+}
+
+impl Debug for DataKey {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DataKey")
+            .field("plaintext", &"<redacted>")
+            .field("wrapped", &self.wrapped.len())
+            .finish()
+    }
+}
+
+/// KeyProvider is used by [`EncryptionLayer`] to generate and unwrap data keys.
+///
+/// Every object is encrypted with a new data key. The wrapped form of the key
+/// is stored in the object header, and will be passed back to
+/// [`KeyProvider::decrypt_data_key`] while reading.
+///
+/// Implement this trait to protect data keys with your own KMS.
+pub trait KeyProvider: Debug + Send + Sync + Unpin + 'static {
+    /// Generate a new data key for an object.
+    ///
+    /// The plaintext key must be 32 bytes, and the wrapped key must not be
+    /// larger than 245 bytes.
+    fn generate_data_key(&self) -> impl Future<Output = Result<DataKey>> + MaybeSend;
+
+    /// Unwrap a data key returned by `generate_data_key` to get its plaintext.
+    fn decrypt_data_key(&self, wrapped: &[u8])
+    -> impl Future<Output = Result<Vec<u8>>> + MaybeSend;
+}
+
+/// StaticKeyProvider protects data keys with a fixed master key.
+///
+/// Data keys are wrapped by AES-256-GCM with the master key. The master key
+/// never leaves the process, so it must be kept safe by users.
+#[derive(Clone)]
+pub struct StaticKeyProvider {
+    cipher: Aes256Gcm,
+}
+
+impl StaticKeyProvider {
+    /// Create a new `StaticKeyProvider` with given 32 bytes master key.
+    pub fn new(master_key: [u8; 32]) -> Self {
+        Self {
+            cipher: Aes256Gcm::new(&master_key.into()),
+        }
+    }
+}
+
+impl Debug for StaticKeyProvider {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("StaticKeyProvider").finish_non_exhaustive()
+    }
+}
+
+impl KeyProvider for StaticKeyProvider {
+    async fn generate_data_key(&self) -> Result<DataKey> {
+        let key = Aes256Gcm::generate_key(OsRng);
+        let nonce = Aes256Gcm::generate_nonce(OsRng);
+        let sealed = self
+            .cipher
+            .encrypt(&nonce, &key[..])
+            .map_err(|_| Error::new(ErrorKind::Unexpected, "failed to wrap data key"))?;
+
+        let mut wrapped = Vec::with_capacity(NONCE_SIZE + sealed.len());
+        wrapped.extend_from_slice(&nonce);
+        wrapped.extend_from_slice(&sealed);
+        Ok(DataKey::new(key.to_vec(), wrapped))
+    }
+
+    async fn decrypt_data_key(&self, wrapped: &[u8]) -> Result<Vec<u8>> {
+        let Some((nonce, sealed)) = wrapped.split_first_chunk::<NONCE_SIZE>() else {
+            return Err(Error::new(
+                ErrorKind::Unexpected,
+                "wrapped data key is too short",
+            ));
+        };
+
+        self.cipher
+            .decrypt(&Nonce::from(*nonce), sealed)
+            .map_err(|_| {
+                Error::new(
+                    ErrorKind::PermissionDenied,
+                    "failed to unwrap data key, the master key may not match",
+                )
+            })
+    }
+}
+
+/// Add client-side envelope encryption for the underlying storage.
+///
+/// # Notes
+///
+/// Every object written through this layer is encrypted with a new AES-256-GCM
+/// data key generated by the [`KeyProvider`]. The wrapped data key is stored
+/// in a fixed size header at the beginning of the object, followed by the
+/// content split into fixed size chunks. Every chunk is sealed independently
+/// with its own nonce, and the last chunk is marked so that truncated objects
+/// will be detected.
+///
+/// Since chunks can be decrypted independently, range reads only fetch the
+/// chunks that cover the requested range.
+///
+/// `stat` and `list` report the plaintext `content_length`, which is computed
+/// with the chunk size configured on this layer. Please keep the chunk size
+/// unchanged for existing data. Reads always use the chunk size recorded in
+/// the object header.
+///
+/// Appending and presigning are not supported while this layer is enabled.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use opendal::layers::EncryptionLayer;
+/// # use opendal::layers::StaticKeyProvider;
+/// # use opendal::services;
+/// # use opendal::Operator;
+/// # use opendal::Result;
+///
+/// # fn main() -> Result<()> {
+/// let provider = StaticKeyProvider::new([0; 32]);
+///
+/// let _ = Operator::new(services::Memory::default())?
+///     .layer(EncryptionLayer::new(provider).with_chunk_size(1024 * 1024))
+///     .finish();
+/// Ok(())
+/// # }
+/// ```
+pub struct EncryptionLayer<K: KeyProvider> {
+    provider: Arc<K>,
+    chunk_size: usize,
+}
+
+impl<K: KeyProvider> Clone for EncryptionLayer<K> {
+    fn clone(&self) -> Self {
+        Self {
+            provider: self.provider.clone(),
+            chunk_size: self.chunk_size,
+        }
+    }
+}
+
+impl<K: KeyProvider> EncryptionLayer<K> {
+    /// Create a new `EncryptionLayer` with given key provider.
+    ///
+    /// The default chunk size is 64 KiB.
+    pub fn new(provider: K) -> Self {
+        Self {
+            provider: Arc::new(provider),
+            chunk_size: DEFAULT_CHUNK_SIZE,
+        }
+    }
+
+    /// Set the plaintext size of every encrypted chunk.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `chunk_size` is zero or larger than `u32::MAX`.
+    pub fn with_chunk_size(mut self, chunk_size: usize) -> Self {
+        assert!(
+            chunk_size > 0 && chunk_size <= u32::MAX as usize,
+            "chunk size must be in (0, u32::MAX]"
+        );
+        self.chunk_size = chunk_size;
+        self
+    }
+}
+
+impl<A: Access, K: KeyProvider> Layer<A> for EncryptionLayer<K> {
+    type LayeredAccess = EncryptionAccessor<A, K>;
+
+    fn layer(&self, inner: A) -> Self::LayeredAccess {
+        let info = inner.info();
+        info.update_full_capability(|mut cap| {
+            cap.write_can_append = false;
+            cap.write_can_resume = false;
+            cap.presign = false;
+            cap.presign_read = false;
+            cap.presign_write = false;
+            cap
+        });
+
+        EncryptionAccessor {
+            inner,
+            provider: self.provider.clone(),
+            chunk_size: self.chunk_size,
+        }
+    }
+}
+
+pub struct EncryptionAccessor<A: Access, K: KeyProvider> {
+    inner: A,
+    provider: Arc<K>,
+    chunk_size: usize,
+}
+
+impl<A: Access, K: KeyProvider> Debug for EncryptionAccessor<A, K> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("EncryptionAccessor")
+            .field("inner", &self.inner)
+            .field("provider", &self.provider)
+            .field("chunk_size", &self.chunk_size)
+            .finish()
+    }
+}
+
+impl<A: Access, K: KeyProvider> EncryptionAccessor<A, K> {
+    /// Read and parse the header of the encrypted object.
+    async fn read_header(&self, path: &str, args: &OpRead) -> Result<Header> {
+        let args = args
+            .clone()
+            .with_range(BytesRange::new(0, Some(HEADER_SIZE)));
+        let (_, mut r) = self.inner.read(path, args).await?;
+        let bs = oio::Read::read_all(&mut r).await?;
+        Header::decode(&bs.to_bytes())
+    }
+
+    /// Build the cipher for the data key stored in header.
+    async fn cipher(&self, header: &Header) -> Result<Aes256Gcm> {
+        let key = self.provider.decrypt_data_key(&header.wrapped_key).await?;
+        new_cipher(&key)
+    }
+}
+
+impl<A: Access, K: KeyProvider> LayeredAccess for EncryptionAccessor<A, K> {
+    type Inner = A;
+    type Reader = EncryptionReader<A::Reader>;
+    type Writer = EncryptionWriter<A::Writer>;
+    type Lister = EncryptionLister<A::Lister>;
+    type Deleter = A::Deleter;
+
+    fn inner(&self) -> &Self::Inner {
+        &self.inner
+    }
+
+    async fn stat(&self, path: &str, args: OpStat) -> Result<RpStat> {
+        let mut meta = self.inner.stat(path, args).await?.into_metadata();
+        decrypt_metadata(&mut meta, self.chunk_size);
+        Ok(RpStat::new(meta))
+    }
+
+    async fn read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::Reader)> {
+        let mut stat = OpStat::new();
+        if let Some(v) = args.version() {
+            stat = stat.with_version(v);
+        }
+        if let Some(v) = args.if_match() {
+            stat = stat.with_if_match(v);
+        }
+        if let Some(v) = args.if_none_match() {
+            stat = stat.with_if_none_match(v);
+        }
+        if let Some(v) = args.if_modified_since() {
+            stat = stat.with_if_modified_since(v);
+        }
+        if let Some(v) = args.if_unmodified_since() {
+            stat = stat.with_if_unmodified_since(v);
+        }
+        let total = self
+            .inner
+            .stat(path, stat)
+            .await?
+            .into_metadata()
+            .content_length();
+
+        let header = self.read_header(path, &args).await?;
+        let chunk_size = header.chunk_size as u64;
+        let plain_size = plaintext_size(total, chunk_size).ok_or_else(|| {
+            Error::new(ErrorKind::Unexpected, "encrypted object is truncated")
+                .with_context("path", path)
+                .with_context("size", total.to_string())
+        })?;
+
+        let range = args.range();
+        let start = range.offset().min(plain_size);
+        let end = range
+            .size()
+            .map_or(plain_size, |v| (start + v).min(plain_size));
+        if start >= end {
+            return Ok((RpRead::new().with_size(Some(0)), EncryptionReader::empty()));
+        }
+
+        let cipher = self.cipher(&header).await?;
+        let sealed_size = chunk_size + TAG_SIZE;
+        let first = start / chunk_size;
+        let last = (end - 1) / chunk_size;
+        let offset = HEADER_SIZE + first * sealed_size;
+        let size = (HEADER_SIZE + (last + 1) * sealed_size).min(total) - offset;
+
+        let (rp, r) = self
+            .inner
+            .read(path, args.with_range(BytesRange::new(offset, Some(size))))
+            .await?;
+
+        let reader = EncryptionReader {
+            inner: Some(r),
+            cipher,
+            aad: header.encoded,
+            chunk_size: chunk_size as usize,
+            index: first,
+            last_index: (total - HEADER_SIZE).div_ceil(sealed_size) - 1,
+            skip: (start - first * chunk_size) as usize,
+            remaining: end - start,
+            buf: Vec::new(),
+            eof: false,
+        };
+        // The inner reply describes the sealed chunks, report the plaintext range instead.
+        let range = BytesContentRange::default()
+            .with_range(start, end - 1)
+            .with_size(plain_size);
+        let rp = rp.with_size(Some(end - start)).with_range(Some(range));
+        Ok((rp, reader))
+    }
+
+    async fn write(&self, path: &str, args: OpWrite) -> Result<(RpWrite, Self::Writer)> {
+        if args.append() {
+            return Err(Error::new(
+                ErrorKind::Unsupported,
+                "encryption layer doesn't support append",
+            )
+            .with_operation(Operation::Write)
+            .with_context("path", path));
+        }
+
+        let key = self.provider.generate_data_key().await?;
+        let cipher = new_cipher(&key.plaintext)?;
+        let header = Header::new(self.chunk_size as u32, key.wrapped)?;
+
+        let (rp, w) = self.inner.write(path, args).await?;
+        let writer = EncryptionWriter {
+            inner: w,
+            cipher,
+            aad: header.encoded,
+            chunk_size: self.chunk_size,
+            index: 0,
+            header_written: false,
+            buf: Vec::new(),
+            written: 0,
+        };
+        Ok((rp, writer))
+    }
+
+    async fn delete(&self) -> Result<(RpDelete, Self::Deleter)> {
+        self.inner.delete().await
+    }
+
+    async fn list(&self, path: &str, args: OpList) -> Result<(RpList, Self::Lister)> {
+        self.inner.list(path, args).await.map(|(rp, l)| {
+            (
+                rp,
+                EncryptionLister {
+                    inner: l,
+                    chunk_size: self.chunk_size,
+                },
+            )
+        })
+    }
+}
+
+/// Header of the encrypted object.
+///
+/// ```text
+/// | magic (4) | version (1) | chunk size (4) | key len (2) | wrapped key (padded to 245) |
+/// ```
+struct Header {
+    chunk_size: u32,
+    wrapped_key: Vec<u8>,
+    /// The encoded header which is also used as AAD of every chunk.
+    encoded: Bytes,
+}
+
+impl Header {
+    fn new(chunk_size: u32, wrapped_key: Vec<u8>) -> Result<Self> {
+        if wrapped_key.len() > MAX_WRAPPED_KEY_SIZE {
+            return Err(Error::new(
+                ErrorKind::Unexpected,
+                "wrapped data key is too large to fit in header",
+            )
+            .with_context("size", wrapped_key.len().to_string()));
+        }
+
+        let mut bs = Vec::with_capacity(HEADER_SIZE as usize);
+        bs.extend_from_slice(MAGIC);
+        bs.push(FORMAT_VERSION);
+        bs.extend_from_slice(&chunk_size.to_be_bytes());
+        bs.extend_from_slice(&(wrapped_key.len() as u16).to_be_bytes());
+        bs.extend_from_slice(&wrapped_key);
+        bs.resize(HEADER_SIZE as usize, 0);
+
+        Ok(Self {
+            chunk_size,
+            wrapped_key,
+            encoded: bs.into(),
+        })
+    }
+
+    fn decode(bs: &Bytes) -> Result<Self> {
+        if bs.len() != HEADER_SIZE as usize || &bs[..4] != MAGIC {
+            return Err(Error::new(
+                ErrorKind::Unexpected,
+                "object is not encrypted by encryption layer",
+            ));
+        }
+        if bs[4] != FORMAT_VERSION {
+            return Err(Error::new(
+                ErrorKind::Unsupported,
+                "encrypted object format version is not supported",
+            )
+            .with_context("version", bs[4].to_string()));
+        }
+
+        let chunk_size = u32::from_be_bytes([bs[5], bs[6], bs[7], bs[8]]);
+        let key_len = u16::from_be_bytes([bs[9], bs[10]]) as usize;
+        if chunk_size == 0 || key_len > MAX_WRAPPED_KEY_SIZE {
+            return Err(Error::new(
+                ErrorKind::Unexpected,
+                "encrypted object header is corrupted",
+            ));
+        }
+
+        Ok(Self {
+            chunk_size,
+            wrapped_key: bs[HEADER_PREFIX_SIZE..HEADER_PREFIX_SIZE + key_len].to_vec(),
+            encoded: bs.clone(),
+        })
+    }
+}
+
+fn new_cipher(key: &[u8]) -> Result<Aes256Gcm> {
+    if key.len() != DATA_KEY_SIZE {
+        return Err(
+            Error::new(ErrorKind::Unexpected, "data key must be 32 bytes")
+                .with_context("size", key.len().to_string()),
+        );
+    }
+    Aes256Gcm::new_from_slice(key)
+        .map_err(|_| Error::new(ErrorKind::Unexpected, "invalid data key"))
+}
+
+/// Nonce of every chunk is built from its index and whether it's the last one.
+fn chunk_nonce(index: u64, last: bool) -> [u8; NONCE_SIZE] {
+    let mut nonce = [0; NONCE_SIZE];
+    nonce[..8].copy_from_slice(&index.to_be_bytes());
+    nonce[NONCE_SIZE - 1] = last as u8;
+    nonce
+}
+
+/// Calculate the plaintext size from the size of encrypted object.
+///
+/// Returns `None` if the size can't be produced by the encryption layer.
+fn plaintext_size(total: u64, chunk_size: u64) -> Option<u64> {
+    if total < HEADER_SIZE + TAG_SIZE {
+        return None;
+    }
+    let body = total - HEADER_SIZE;
+    let chunks = body.div_ceil(chunk_size + TAG_SIZE);
+    let size = body - chunks * TAG_SIZE;
+    // Only the last chunk could be empty, and only if it's the only one.
+    if chunks > 1 && size <= (chunks - 1) * chunk_size {
+        return None;
+    }
+    Some(size)
+}
+
+/// Replace the content length of files with their plaintext size.
+fn decrypt_metadata(meta: &mut Metadata, chunk_size: usize) {
+    if !meta.is_file() {
+        return;
+    }
+    if let Some(size) = plaintext_size(meta.content_length(), chunk_size as u64) {
+        meta.set_content_length(size);
+    }
+}
+
+pub struct EncryptionReader<R> {
+    inner: Option<R>,
+    cipher: Aes256Gcm,
+    aad: Bytes,
+    chunk_size: usize,
+    /// Index of the next chunk to decrypt.
+    index: u64,
+    /// Index of the last chunk in the object.
+    last_index: u64,
+    /// Bytes to skip in the next decrypted chunk.
+    skip: usize,
+    /// Plaintext bytes left to return.
+    remaining: u64,
+    buf: Vec<u8>,
+    eof: bool,
+}
+
+impl<R> EncryptionReader<R> {
+    fn empty() -> Self {
+        Self {
+            inner: None,
+            cipher: Aes256Gcm::new(&[0; DATA_KEY_SIZE].into()),
+            aad: Bytes::new(),
+            chunk_size: 0,
+            index: 0,
+            last_index: 0,
+            skip: 0,
+            remaining: 0,
+            buf: Vec::new(),
+            eof: true,
+        }
+    }
+}
+
+impl<R: oio::Read> oio::Read for EncryptionReader<R> {
+    async fn read(&mut self) -> Result<Buffer> {
+        let Some(inner) = self.inner.as_mut() else {
+            return Ok(Buffer::new());
+        };
+
+        let sealed_size = self.chunk_size + TAG_SIZE as usize;
+        while self.remaining > 0 {
+            while self.buf.len() < sealed_size && !self.eof {
+                let bs = inner.read().await?;
+                if bs.is_empty() {
+                    self.eof = true;
+                }
+                for b in bs {
+                    self.buf.extend_from_slice(&b);
+                }
+            }
+
+            let last = self.index == self.last_index;
+            let n = self.buf.len().min(sealed_size);
+            if n < TAG_SIZE as usize || (!last && n < sealed_size) {
+                return Err(Error::new(
+                    ErrorKind::Unexpected,
+                    "encrypted object is truncated while reading",
+                )
+                .with_context("chunk", self.index.to_string()));
+            }
+
+            let nonce = chunk_nonce(self.index, last);
+            let plain = self
+                .cipher
+                .decrypt(
+                    &Nonce::from(nonce),
+                    Payload {
+                        msg: &self.buf[..n],
+                        aad: &self.aad,
+                    },
+                )
+                .map_err(|_| {
+                    Error::new(
+                        ErrorKind::Unexpected,
+                        "failed to decrypt chunk, the object may be corrupted",
+                    )
+                    .with_context("chunk", self.index.to_string())
+                })?;
+            self.buf.drain(..n);
+            self.index += 1;
+
+            let start = self.skip.min(plain.len());
+            let end = plain
+                .len()
+                .min(start + self.remaining.min(usize::MAX as u64) as usize);
+            self.skip = 0;
+            self.remaining -= (end - start) as u64;
+            if start < end {
+                return Ok(Buffer::from(Bytes::from(plain).slice(start..end)));
+            }
+        }
+
+        self.inner = None;
+        Ok(Buffer::new())
+    }
+}
+
+pub struct EncryptionWriter<W> {
+    inner: W,
+    cipher: Aes256Gcm,
+    aad: Bytes,
+    chunk_size: usize,
+    /// Index of the next chunk to encrypt.
+    index: u64,
+    header_written: bool,
+    /// Plaintext that not encrypted yet.
+    buf: Vec<u8>,
+    /// Plaintext bytes written by users.
+    written: u64,
+}
+
+impl<W: oio::Write> EncryptionWriter<W> {
+    fn seal(&mut self, size: usize, last: bool) -> Result<Buffer> {
+        let nonce = chunk_nonce(self.index, last);
+        let sealed = self
+            .cipher
+            .encrypt(
+                &Nonce::from(nonce),
+                Payload {
+                    msg: &self.buf[..size],
+                    aad: &self.aad,
+                },
+            )
+            .map_err(|_| Error::new(ErrorKind::Unexpected, "failed to encrypt chunk"))?;
+        self.buf.drain(..size);
+        self.index += 1;
+        Ok(Buffer::from(sealed))
+    }
+
+    async fn write_header(&mut self) -> Result<()> {
+        if !self.header_written {
+            self.inner.write(Buffer::from(self.aad.clone())).await?;
+            self.header_written = true;
+        }
+        Ok(())
+    }
+}
+
+impl<W: oio::Write> oio::Write for EncryptionWriter<W> {
+    async fn write(&mut self, bs: Buffer) -> Result<()> {
+        self.write_header().await?;
+
+        self.written += bs.len() as u64;
+        for b in bs {
+            self.buf.extend_from_slice(&b);
+        }
+
+        // Always keep the last chunk in buffer since we don't know whether
+        // it's the final one until close.
+        while self.buf.len() > self.chunk_size {
+            let sealed = self.seal(self.chunk_size, false)?;
+            self.inner.write(sealed).await?;
+        }
+        Ok(())
+    }
+
+    async fn close(&mut self) -> Result<Metadata> {
+        self.write_header().await?;
+
+        let sealed = self.seal(self.buf.len(), true)?;
+        self.inner.write(sealed).await?;
+
+        let mut meta = self.inner.close().await?;
+        meta.set_content_length(self.written);
+        Ok(meta)
+    }
+
+    async fn abort(&mut self) -> Result<()> {
+        self.inner.abort().await
+    }
+}
+
+pub struct EncryptionLister<L> {
+    inner: L,
+    chunk_size: usize,
+}
+
+impl<L: oio::List> oio::List for EncryptionLister<L> {
+    async fn next(&mut self) -> Result<Option<oio::Entry>> {
+        let Some(entry) = self.inner.next().await? else {
+            return Ok(None);
+        };
+
+        let mut meta = entry.metadata().clone();
+        decrypt_metadata(&mut meta, self.chunk_size);
+        Ok(Some(oio::Entry::new(entry.path(), meta)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::services;
+
+    fn new_operators(chunk_size: usize) -> (Operator, Operator) {
+        let backend = Operator::new(services::Memory::default()).unwrap().finish();
+        let op = backend.clone().layer(
+            EncryptionLayer::new(StaticKeyProvider::new([42; 32])).with_chunk_size(chunk_size),
+        );
+        (op, backend)
+    }
+
+    fn content(size: usize) -> Vec<u8> {
+        (0..size).map(|i| (i % 251) as u8).collect()
+    }
+
+    #[test]
+    fn test_plaintext_size() {
+        assert_eq!(plaintext_size(0, 4), None);
+        assert_eq!(plaintext_size(HEADER_SIZE + TAG_SIZE, 4), Some(0));
+        assert_eq!(plaintext_size(HEADER_SIZE + TAG_SIZE + 3, 4), Some(3));
+        assert_eq!(plaintext_size(HEADER_SIZE + TAG_SIZE + 4, 4), Some(4));
+        assert_eq!(plaintext_size(HEADER_SIZE + 2 * TAG_SIZE + 5, 4), Some(5));
+        assert_eq!(plaintext_size(HEADER_SIZE + 2 * TAG_SIZE + 4, 4), None);
+    }
+
+    #[tokio::test]
+    async fn test_write_and_read() -> Result<()> {
+        let (op, backend) = new_operators(16);
+
+        for size in [0, 1, 15, 16, 17, 32, 100] {
+            let path = format!("file-{size}");
+            let data = content(size);
+            op.write(&path, data.clone()).await?;
+
+            let raw = backend.read(&path).await?.to_vec();
+            assert_eq!(&raw[..4], MAGIC);
+            assert_ne!(raw, data);
+
+            assert_eq!(op.read(&path).await?.to_vec(), data);
+            assert_eq!(op.stat(&path).await?.content_length(), size as u64);
+        }
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_range_read() -> Result<()> {
+        let (op, _) = new_operators(16);
+        let data = content(100);
+
+        let mut w = op.writer("test").await?;
+        for chunk in data.chunks(7) {
+            w.write(chunk.to_vec()).await?;
+        }
+        let meta = w.close().await?;
+        assert_eq!(meta.content_length(), 100);
+
+        for (start, end) in [(0, 1), (3, 20), (16, 32), (15, 17), (90, 100), (0, 100)] {
+            let bs = op.read_with("test").range(start..end).await?;
+            assert_eq!(bs.to_vec(), data[start as usize..end as usize]);
+        }
+
+        let bs = op.read_with("test").range(50..).await?;
+        assert_eq!(bs.to_vec(), data[50..]);
+
+        // The reply describes the requested range of the original content.
+        let args = OpRead::new().with_range(BytesRange::new(3, Some(17)));
+        let (rp, _) = op.inner().read("test", args).await?;
+        assert_eq!(rp.size(), Some(17));
+        assert_eq!(
+            rp.range(),
+            Some(
+                BytesContentRange::default()
+                    .with_range(3, 19)
+                    .with_size(100)
+            )
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_tampered_object() -> Result<()> {
+        let (op, backend) = new_operators(16);
+        op.write("test", content(40)).await?;
+
+        let mut raw = backend.read("test").await?.to_vec();
+        raw[HEADER_SIZE as usize + 1] ^= 1;
+        backend.write("tampered", raw.clone()).await?;
+        assert!(op.read("tampered").await.is_err());
+
+        // Drop the last chunk, the remaining chunks are not marked as last.
+        raw.truncate(HEADER_SIZE as usize + 2 * (16 + TAG_SIZE as usize));
+        backend.write("truncated", raw).await?;
+        assert!(op.read("truncated").await.is_err());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_wrong_key() -> Result<()> {
+        let (op, backend) = new_operators(16);
+        op.write("test", content(40)).await?;
+
+        let other = backend
+            .clone()
+            .layer(EncryptionLayer::new(StaticKeyProvider::new([7; 32])).with_chunk_size(16));
+        let err = other.read("test").await.unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::PermissionDenied);
+        Ok(())
+    }
+}
@@ -185,6 +185,62 @@ impl<A: Access> LayeredAccess for ErrorContextAccessor<A> {
                 .with_context("path", path)
         })
     }
+    async fn create_symlink(
+        &self,
+        path: &str,
+        target: &str,
+        args: OpCreateSymlink,
+    ) -> Result<RpCreateSymlink> {
+        self.inner
+            .create_symlink(path, target, args)
+            .await
+            .map_err(|err| {
+                err.with_operation(Operation::CreateSymlink)
+                    .with_context("service", self.info.scheme())
+                    .with_context("path", path)
+                    .with_context("target", target)
+            })
+    }
+
+    async fn read_link(&self, path: &str, args: OpReadLink) -> Result<RpReadLink> {
+        self.inner.read_link(path, args).await.map_err(|err| {
+            err.with_operation(Operation::ReadLink)
+                .with_context("service", self.info.scheme())
+                .with_context("path", path)
+        })
+    }
+
+    async fn list_pending_uploads(
+        &self,
+        path: &str,
+        args: OpListPendingUploads,
+    ) -> Result<RpListPendingUploads> {
+        self.inner
+            .list_pending_uploads(path, args)
+            .await
+            .map_err(|err| {
+                err.with_operation(Operation::ListPendingUploads)
+                    .with_context("service", self.info.scheme())
+                    .with_context("path", path)
+            })
+    }
+
+    async fn abort_pending_upload(
+        &self,
+        path: &str,
+        upload_id: &str,
+        args: OpAbortPendingUpload,
+    ) -> Result<RpAbortPendingUpload> {
+        self.inner
+            .abort_pending_upload(path, upload_id, args)
+            .await
+            .map_err(|err| {
+                err.with_operation(Operation::AbortPendingUpload)
+                    .with_context("service", self.info.scheme())
+                    .with_context("path", path)
+                    .with_context("upload_id", upload_id)
+            })
+    }
 }
 
 pub struct ErrorContextWrapper<T> {
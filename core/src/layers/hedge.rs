@@ -0,0 +1,605 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::fmt::Debug;
+use std::fmt::Formatter;
+use std::future::Future;
+use std::pin::pin;
+use std::sync::Arc;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
+use std::time::Duration;
+use std::time::Instant;
+
+use futures::future::Either;
+use futures::future::select;
+
+use super::tail_cut::TailCutStats;
+use crate::raw::oio::Read;
+use crate::raw::*;
+use crate::*;
+
+/// Builder for HedgeLayer.
+///
+/// Use this to configure the layer, then call `build()` to create a layer
+/// that can be cloned and shared across multiple operators.
+///
+/// # Examples
+///
+/// ```no_run
+/// use opendal::layers::HedgeLayer;
+/// use std::time::Duration;
+/// # use opendal::services;
+/// # use opendal::Operator;
+/// # use opendal::Result;
+///
+/// # fn main() -> Result<()> {
+/// let layer = HedgeLayer::builder()
+///     .percentile(95)
+///     .budget(10)
+///     .build();
+///
+/// let op = Operator::new(services::Memory::default())?
+///     .layer(layer)
+///     .finish();
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct HedgeLayerBuilder {
+    percentile: u8,
+    window: Duration,
+    min_samples: usize,
+    min_delay: Duration,
+    max_delay: Duration,
+    budget: u8,
+    max_buffer_size: u64,
+}
+
+impl Default for HedgeLayerBuilder {
+    fn default() -> Self {
+        Self {
+            percentile: 95,
+            window: Duration::from_secs(60),
+            min_samples: 100,
+            min_delay: Duration::from_millis(10),
+            max_delay: Duration::from_secs(5),
+            budget: 10,
+            max_buffer_size: 4 * 1024 * 1024,
+        }
+    }
+}
+
+impl HedgeLayerBuilder {
+    /// Create a new builder with default settings.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the percentile after which a hedged request is issued
+    /// (e.g., 95 for P95).
+    ///
+    /// Default: 95
+    ///
+    /// # Panics
+    ///
+    /// Panics if percentile is not between 50 and 99.
+    pub fn percentile(mut self, percentile: u8) -> Self {
+        assert!(
+            (50..=99).contains(&percentile),
+            "percentile must be between 50 and 99"
+        );
+        self.percentile = percentile;
+        self
+    }
+
+    /// Set the sliding window duration for statistics collection.
+    ///
+    /// Default: 60 seconds
+    ///
+    /// # Panics
+    ///
+    /// Panics if window is greater than 120 seconds.
+    pub fn window(mut self, window: Duration) -> Self {
+        assert!(
+            window <= Duration::from_secs(120),
+            "window must be <= 120 seconds"
+        );
+        self.window = window;
+        self
+    }
+
+    /// Set the minimum number of samples required before hedging starts.
+    ///
+    /// During cold start (when sample count < min_samples), requests are
+    /// sent only once.
+    ///
+    /// Default: 100
+    pub fn min_samples(mut self, min_samples: usize) -> Self {
+        self.min_samples = min_samples;
+        self
+    }
+
+    /// Set the minimum delay (floor) before a hedged request is issued.
+    ///
+    /// Default: 10ms
+    pub fn min_delay(mut self, delay: Duration) -> Self {
+        self.min_delay = delay;
+        self
+    }
+
+    /// Set the maximum delay (ceiling) before a hedged request is issued.
+    ///
+    /// Default: 5s
+    pub fn max_delay(mut self, delay: Duration) -> Self {
+        self.max_delay = delay;
+        self
+    }
+
+    /// Set the budget of hedged requests as a percentage of all hedgeable
+    /// requests.
+    ///
+    /// Once the budget is exhausted, slow requests are simply awaited instead
+    /// of being duplicated. This prevents hedging from amplifying load when
+    /// the whole backend slows down.
+    ///
+    /// Default: 10
+    ///
+    /// # Panics
+    ///
+    /// Panics if budget is greater than 100.
+    pub fn budget(mut self, percent: u8) -> Self {
+        assert!(percent <= 100, "budget must be between 0 and 100");
+        self.budget = percent;
+        self
+    }
+
+    /// Set the largest bounded read that will be hedged as a whole.
+    ///
+    /// Reads with a known size up to this limit (like the chunks fetched by
+    /// `Reader::fetch` or concurrent chunked reads) are buffered in memory so
+    /// that the complete transfer can be raced. Larger or unbounded reads only
+    /// hedge opening the reader.
+    ///
+    /// Default: 4 MiB
+    pub fn max_buffer_size(mut self, size: u64) -> Self {
+        self.max_buffer_size = size;
+        self
+    }
+
+    /// Build the layer.
+    ///
+    /// The returned layer can be cloned to share statistics and budget
+    /// across operators.
+    pub fn build(self) -> HedgeLayer {
+        HedgeLayer {
+            config: Arc::new(HedgeConfig {
+                percentile: self.percentile,
+                window: self.window,
+                min_samples: self.min_samples,
+                min_delay: self.min_delay,
+                max_delay: self.max_delay,
+                budget: self.budget,
+                max_buffer_size: self.max_buffer_size,
+            }),
+            stats: Arc::new(TailCutStats::new()),
+            budget: Arc::new(HedgeBudget::default()),
+        }
+    }
+}
+
+/// Configuration for HedgeLayer (immutable).
+#[derive(Debug)]
+struct HedgeConfig {
+    percentile: u8,
+    window: Duration,
+    min_samples: usize,
+    min_delay: Duration,
+    max_delay: Duration,
+    budget: u8,
+    max_buffer_size: u64,
+}
+
+/// Layer that races a duplicate request when the first one is slow.
+///
+/// This layer tracks the latency distribution of `read` and `stat`. Once a
+/// request runs longer than the configured percentile, an identical request
+/// is issued and whichever finishes first wins; the other one is cancelled.
+///
+/// Unlike [`TailCutLayer`](crate::layers::TailCutLayer), slow requests are
+/// never failed, they only get a second chance. Extra requests are capped by
+/// a budget expressed as a percentage of traffic.
+///
+/// Only operations that are safe to repeat are hedged. Bounded reads up to
+/// `max_buffer_size` are fetched completely inside the race, which covers
+/// chunk fetches done by `Reader::fetch` and concurrent chunked reads.
+///
+/// # Examples
+///
+/// ```no_run
+/// use opendal::layers::HedgeLayer;
+/// use std::time::Duration;
+/// # use opendal::services;
+/// # use opendal::Operator;
+/// # use opendal::Result;
+///
+/// # fn main() -> Result<()> {
+/// let layer = HedgeLayer::builder()
+///     .percentile(90)
+///     .min_delay(Duration::from_millis(20))
+///     .budget(5)
+///     .build();
+///
+/// let op = Operator::new(services::Memory::default())?
+///     .layer(layer)
+///     .finish();
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct HedgeLayer {
+    config: Arc<HedgeConfig>,
+    stats: Arc<TailCutStats>,
+    budget: Arc<HedgeBudget>,
+}
+
+impl HedgeLayer {
+    /// Create a builder to configure the layer.
+    pub fn builder() -> HedgeLayerBuilder {
+        HedgeLayerBuilder::new()
+    }
+
+    /// Create a layer with default settings.
+    ///
+    /// This is equivalent to `HedgeLayer::builder().build()`.
+    pub fn new() -> Self {
+        Self::builder().build()
+    }
+}
+
+impl Default for HedgeLayer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<A: Access> Layer<A> for HedgeLayer {
+    type LayeredAccess = HedgeAccessor<A>;
+
+    fn layer(&self, inner: A) -> Self::LayeredAccess {
+        HedgeAccessor {
+            inner,
+            config: self.config.clone(),
+            stats: self.stats.clone(),
+            budget: self.budget.clone(),
+        }
+    }
+}
+
+/// Counters used to cap hedged requests as a share of all requests.
+#[derive(Default)]
+struct HedgeBudget {
+    requests: AtomicU64,
+    hedged: AtomicU64,
+}
+
+impl HedgeBudget {
+    fn record_request(&self) {
+        self.requests.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Try to spend one hedged request from the budget.
+    ///
+    /// The check is not strictly atomic: concurrent callers may overshoot
+    /// the budget by a few requests, which is fine for our purpose.
+    fn try_acquire(&self, percent: u8) -> bool {
+        let requests = self.requests.load(Ordering::Relaxed);
+        let hedged = self.hedged.load(Ordering::Relaxed);
+
+        if hedged * 100 < percent as u64 * requests {
+            self.hedged.fetch_add(1, Ordering::Relaxed);
+            true
+        } else {
+            false
+        }
+    }
+}
+
+/// Accessor that implements hedging logic.
+#[derive(Clone)]
+pub struct HedgeAccessor<A: Access> {
+    inner: A,
+    config: Arc<HedgeConfig>,
+    stats: Arc<TailCutStats>,
+    budget: Arc<HedgeBudget>,
+}
+
+impl<A: Access> Debug for HedgeAccessor<A> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("HedgeAccessor")
+            .field("config", &self.config)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<A: Access> HedgeAccessor<A> {
+    /// Calculate the delay before hedging a given operation and size.
+    fn calculate_delay(&self, op: Operation, size: Option<u64>) -> Option<Duration> {
+        let op_stats = self.stats.stats_for(op);
+
+        if op_stats.total_samples(size, self.config.window) < self.config.min_samples {
+            return None;
+        }
+
+        let q = self.config.percentile as f64 / 100.0;
+        let pctl = op_stats.quantile(size, q, self.config.window)?;
+
+        Some(pctl.clamp(self.config.min_delay, self.config.max_delay))
+    }
+
+    /// Run the request built by `f`, racing a second one if the first
+    /// doesn't finish within the hedging delay.
+    async fn hedge<F, Fut, T>(&self, op: Operation, size: Option<u64>, f: F) -> Result<T>
+    where
+        F: Fn() -> Fut,
+        Fut: Future<Output = Result<T>>,
+    {
+        self.budget.record_request();
+        let start = Instant::now();
+
+        let result = if let Some(delay) = self.calculate_delay(op, size) {
+            let mut first = pin!(f());
+
+            match tokio::time::timeout(delay, &mut first).await {
+                Ok(res) => res,
+                Err(_) if !self.budget.try_acquire(self.config.budget) => first.await,
+                Err(_) => {
+                    let second = pin!(f());
+
+                    // Return the first success. If one side fails, keep
+                    // waiting for the other one. The loser is dropped here,
+                    // which cancels it.
+                    match select(first, second).await {
+                        Either::Left((Ok(v), _)) | Either::Right((Ok(v), _)) => Ok(v),
+                        Either::Left((Err(_), second)) => second.await,
+                        Either::Right((Err(_), first)) => first.await,
+                    }
+                }
+            }
+        } else {
+            f().await
+        };
+
+        if result.is_ok() {
+            let latency = start.elapsed();
+            self.stats.stats_for(op).record(size, latency);
+        }
+
+        result
+    }
+}
+
+impl<A: Access> LayeredAccess for HedgeAccessor<A> {
+    type Inner = A;
+    type Reader = TwoWays<A::Reader, Buffer>;
+    type Writer = A::Writer;
+    type Lister = A::Lister;
+    type Deleter = A::Deleter;
+
+    fn inner(&self) -> &Self::Inner {
+        &self.inner
+    }
+
+    async fn read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::Reader)> {
+        match args.range().size() {
+            Some(size) if size <= self.config.max_buffer_size => {
+                let (rp, buf) = self
+                    .hedge(Operation::Read, Some(size), || {
+                        let args = args.clone();
+                        async move {
+                            let (rp, mut r) = self.inner.read(path, args).await?;
+                            let buf = r.read_all().await?;
+                            Ok((rp, buf))
+                        }
+                    })
+                    .await?;
+                Ok((rp, TwoWays::Two(buf)))
+            }
+            // Only opening the reader is hedged here. All of these share the
+            // unbounded bucket so their latency is not mixed with buffered
+            // reads.
+            _ => {
+                let (rp, r) = self
+                    .hedge(Operation::Read, None, || {
+                        self.inner.read(path, args.clone())
+                    })
+                    .await?;
+                Ok((rp, TwoWays::One(r)))
+            }
+        }
+    }
+
+    async fn write(&self, path: &str, args: OpWrite) -> Result<(RpWrite, Self::Writer)> {
+        self.inner.write(path, args).await
+    }
+
+    async fn stat(&self, path: &str, args: OpStat) -> Result<RpStat> {
+        self.hedge(Operation::Stat, None, || {
+            self.inner.stat(path, args.clone())
+        })
+        .await
+    }
+
+    async fn delete(&self) -> Result<(RpDelete, Self::Deleter)> {
+        self.inner.delete().await
+    }
+
+    async fn list(&self, path: &str, args: OpList) -> Result<(RpList, Self::Lister)> {
+        self.inner.list(path, args).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::AtomicUsize;
+
+    use bytes::Bytes;
+    use tokio::time::sleep;
+    use tokio::time::timeout;
+
+    use super::*;
+    use crate::layers::TypeEraseLayer;
+
+    #[derive(Debug, Clone, Default)]
+    struct MockService {
+        calls: Arc<AtomicUsize>,
+    }
+
+    impl MockService {
+        /// Only the first call is slow, so a hedged request always wins.
+        async fn maybe_stall(&self) {
+            if self.calls.fetch_add(1, Ordering::SeqCst) == 0 {
+                sleep(Duration::from_secs(10)).await;
+            }
+        }
+    }
+
+    impl Access for MockService {
+        type Reader = Buffer;
+        type Writer = ();
+        type Lister = ();
+        type Deleter = ();
+
+        fn info(&self) -> Arc<AccessorInfo> {
+            let am = AccessorInfo::default();
+            am.set_native_capability(Capability {
+                read: true,
+                stat: true,
+                ..Default::default()
+            });
+
+            am.into()
+        }
+
+        async fn stat(&self, _: &str, _: OpStat) -> Result<RpStat> {
+            self.maybe_stall().await;
+            Ok(RpStat::new(Metadata::new(EntryMode::FILE)))
+        }
+
+        async fn read(&self, _: &str, args: OpRead) -> Result<(RpRead, Self::Reader)> {
+            self.maybe_stall().await;
+            let size = args.range().size().unwrap_or(4) as usize;
+            Ok((RpRead::new(), Buffer::from(Bytes::from(vec![1; size]))))
+        }
+    }
+
+    fn prime(layer: &HedgeLayer, op: Operation, size: Option<u64>) {
+        for _ in 0..layer.config.min_samples {
+            layer
+                .stats
+                .stats_for(op)
+                .record(size, Duration::from_millis(1));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_hedge_layer_build() {
+        let layer = HedgeLayer::builder()
+            .percentile(90)
+            .window(Duration::from_secs(30))
+            .min_samples(50)
+            .min_delay(Duration::from_millis(5))
+            .max_delay(Duration::from_secs(1))
+            .budget(20)
+            .max_buffer_size(1024)
+            .build();
+
+        assert_eq!(layer.config.percentile, 90);
+        assert_eq!(layer.config.window, Duration::from_secs(30));
+        assert_eq!(layer.config.min_samples, 50);
+        assert_eq!(layer.config.min_delay, Duration::from_millis(5));
+        assert_eq!(layer.config.max_delay, Duration::from_secs(1));
+        assert_eq!(layer.config.budget, 20);
+        assert_eq!(layer.config.max_buffer_size, 1024);
+
+        let cloned = layer.clone();
+        assert!(Arc::ptr_eq(&layer.stats, &cloned.stats));
+        assert!(Arc::ptr_eq(&layer.budget, &cloned.budget));
+    }
+
+    #[test]
+    fn test_hedge_budget() {
+        let budget = HedgeBudget::default();
+        for _ in 0..10 {
+            budget.record_request();
+        }
+
+        assert!(budget.try_acquire(10));
+        assert!(!budget.try_acquire(10));
+        assert!(!budget.try_acquire(0));
+    }
+
+    #[tokio::test]
+    async fn test_hedge_stat() {
+        let srv = MockService::default();
+        let layer = HedgeLayer::new();
+        prime(&layer, Operation::Stat, None);
+
+        let acc = Arc::new(TypeEraseLayer.layer(srv.clone())) as Accessor;
+        let op = Operator::from_inner(acc).layer(layer);
+
+        let meta = timeout(Duration::from_secs(2), op.stat("test"))
+            .await
+            .expect("hedged stat should not wait for the slow request")
+            .unwrap();
+        assert!(meta.is_file());
+        assert_eq!(srv.calls.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_hedge_chunk_fetch() {
+        let srv = MockService::default();
+        let layer = HedgeLayer::new();
+        prime(&layer, Operation::Read, Some(4));
+
+        let acc = Arc::new(TypeEraseLayer.layer(srv.clone())) as Accessor;
+        let op = Operator::from_inner(acc).layer(layer);
+
+        let reader = op.reader_with("test").gap(1).await.unwrap();
+        let bufs = timeout(Duration::from_secs(2), reader.fetch(vec![0..4, 8..12]))
+            .await
+            .expect("hedged fetch should not wait for the slow request")
+            .unwrap();
+        assert_eq!(bufs.len(), 2);
+        assert_eq!(bufs[0].to_vec(), vec![1; 4]);
+        assert_eq!(bufs[1].to_vec(), vec![1; 4]);
+        // Two chunks plus one hedged request for the stalled chunk.
+        assert_eq!(srv.calls.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn test_hedge_skipped_during_cold_start() {
+        let srv = MockService::default();
+        srv.calls.store(1, Ordering::SeqCst);
+
+        let acc = Arc::new(TypeEraseLayer.layer(srv.clone())) as Accessor;
+        let op = Operator::from_inner(acc).layer(HedgeLayer::new());
+
+        op.stat("test").await.unwrap();
+        assert_eq!(srv.calls.load(Ordering::SeqCst), 2);
+    }
+}
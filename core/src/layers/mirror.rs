@@ -0,0 +1,833 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::collections::HashSet;
+use std::fmt::Debug;
+use std::fmt::Formatter;
+use std::future::Future;
+use std::sync::Arc;
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+
+use backon::ExponentialBuilder;
+use backon::Retryable;
+use futures::future::join;
+use futures::future::join_all;
+
+use crate::raw::oio::Read;
+use crate::raw::oio::Write;
+use crate::raw::*;
+use crate::*;
+
+/// Consistency level of operations fanned out by [`MirrorLayer`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum MirrorConsistency {
+    /// Wait for the primary and all secondaries, fail if any of them fails.
+    #[default]
+    All,
+    /// Wait for the primary and all secondaries, fail unless a majority of all
+    /// targets (primary included) succeeded.
+    Quorum,
+    /// Wait for the primary only, secondaries are updated in background and
+    /// retried on failure.
+    BestEffort,
+}
+
+/// Mirror writes to one or more secondary [`Operator`]s.
+///
+/// # Notes
+///
+/// The operator this layer is applied to acts as the primary. `write`,
+/// `delete`, `copy`, `rename` and `create_dir` succeed on the primary first
+/// and are then applied to every secondary according to the
+/// [`MirrorConsistency`]:
+///
+/// - `All` and `Quorum` stream every written chunk to all targets at the same
+///   time, the object is never buffered as a whole.
+/// - `BestEffort` only waits for the primary. Secondaries are updated by
+///   background tasks that copy the object from the primary and are retried
+///   up to [`MirrorLayer::with_max_retries`] times. Failures after the last
+///   retry are logged.
+///
+/// Conditions and versions refer to the primary and are not sent to
+/// secondaries, deletes with a version only apply to the primary. Metadata
+/// like `content_type` is only sent to secondaries that support it.
+///
+/// Reads, stats and lists are served by the primary. `read` and `stat` fail
+/// over to secondaries in order if the primary returns `NotFound` or a
+/// temporary error and no version or condition is specified.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use opendal::layers::MirrorConsistency;
+/// # use opendal::layers::MirrorLayer;
+/// # use opendal::services;
+/// # use opendal::Operator;
+/// # use opendal::Result;
+///
+/// # fn main() -> Result<()> {
+/// let secondary = Operator::new(services::Memory::default())?.finish();
+///
+/// let _ = Operator::new(services::Memory::default())?
+///     .layer(MirrorLayer::new(secondary).with_consistency(MirrorConsistency::Quorum))
+///     .finish();
+/// Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct MirrorLayer {
+    secondaries: Vec<Operator>,
+    consistency: MirrorConsistency,
+    max_retries: usize,
+    pending: Arc<AtomicUsize>,
+}
+
+impl MirrorLayer {
+    /// Create a new `MirrorLayer` that mirrors to `secondary`.
+    pub fn new(secondary: Operator) -> Self {
+        Self {
+            secondaries: vec![secondary],
+            consistency: MirrorConsistency::default(),
+            max_retries: 3,
+            pending: Arc::new(AtomicUsize::new(0)),
+        }
+    }
+
+    /// Add another secondary operator.
+    pub fn with_secondary(mut self, secondary: Operator) -> Self {
+        self.secondaries.push(secondary);
+        self
+    }
+
+    /// Set the consistency level, [`MirrorConsistency::All`] by default.
+    pub fn with_consistency(mut self, consistency: MirrorConsistency) -> Self {
+        self.consistency = consistency;
+        self
+    }
+
+    /// Set the max retries of background tasks in
+    /// [`MirrorConsistency::BestEffort`], 3 by default.
+    pub fn with_max_retries(mut self, max_retries: usize) -> Self {
+        self.max_retries = max_retries;
+        self
+    }
+
+    /// Get the number of background tasks that are still running.
+    pub fn pending_tasks(&self) -> usize {
+        self.pending.load(Ordering::Relaxed)
+    }
+}
+
+impl<A: Access> Layer<A> for MirrorLayer {
+    type LayeredAccess = MirrorAccessor<A>;
+
+    fn layer(&self, inner: A) -> Self::LayeredAccess {
+        MirrorAccessor {
+            core: Arc::new(MirrorCore {
+                inner,
+                secondaries: self.secondaries.clone(),
+                consistency: self.consistency,
+                max_retries: self.max_retries,
+                pending: self.pending.clone(),
+            }),
+        }
+    }
+}
+
+struct MirrorCore<A: Access> {
+    inner: A,
+    secondaries: Vec<Operator>,
+    consistency: MirrorConsistency,
+    max_retries: usize,
+    pending: Arc<AtomicUsize>,
+}
+
+impl<A: Access> MirrorCore<A> {
+    /// Check the errors of secondaries against the consistency level.
+    fn check(&self, acked: usize, mut errors: Vec<Error>) -> Result<()> {
+        if errors.is_empty() {
+            return Ok(());
+        }
+
+        // The primary always succeeds before secondaries are involved.
+        let targets = self.secondaries.len() + 1;
+        if self.consistency == MirrorConsistency::Quorum && acked + 1 > targets / 2 {
+            for err in errors {
+                log::warn!("mirror layer failed on secondary: {err}");
+            }
+            return Ok(());
+        }
+        Err(errors.swap_remove(0))
+    }
+
+    /// Apply `f` to every secondary according to the consistency level.
+    async fn fan_out<F, Fut>(self: &Arc<Self>, op: &'static str, path: &str, f: F) -> Result<()>
+    where
+        F: Fn(Arc<Self>, Operator) -> Fut + Clone + MaybeSend + 'static,
+        Fut: Future<Output = Result<()>> + MaybeSend + 'static,
+    {
+        if self.consistency == MirrorConsistency::BestEffort {
+            for secondary in &self.secondaries {
+                self.spawn(op, path, secondary.clone(), f.clone());
+            }
+            return Ok(());
+        }
+
+        let results = join_all(
+            self.secondaries
+                .iter()
+                .map(|secondary| f(self.clone(), secondary.clone())),
+        )
+        .await;
+        let errors: Vec<_> = results.into_iter().filter_map(|v| v.err()).collect();
+        self.check(self.secondaries.len() - errors.len(), errors)
+    }
+
+    /// Run `f` on the secondary in background with retry.
+    fn spawn<F, Fut>(self: &Arc<Self>, op: &'static str, path: &str, secondary: Operator, f: F)
+    where
+        F: Fn(Arc<Self>, Operator) -> Fut + MaybeSend + 'static,
+        Fut: Future<Output = Result<()>> + MaybeSend + 'static,
+    {
+        let core = self.clone();
+        let path = path.to_string();
+        let backoff = ExponentialBuilder::default().with_max_times(self.max_retries);
+
+        self.pending.fetch_add(1, Ordering::Relaxed);
+        let fut = async move {
+            let (c, s) = (core.clone(), secondary.clone());
+            let res = { move || f(c.clone(), s.clone()) }.retry(backoff).await;
+            if let Err(err) = res {
+                log::warn!(
+                    "mirror layer failed to {op} {path} on {}: {err}",
+                    secondary.info().scheme()
+                );
+            }
+            core.pending.fetch_sub(1, Ordering::Relaxed);
+        };
+        self.inner
+            .info()
+            .executor()
+            .into_inner()
+            .execute(Box::pin(fut));
+    }
+
+    /// Copy the object at `path` from the primary to the secondary.
+    async fn replicate(&self, secondary: &Operator, path: &str, args: &OpWrite) -> Result<()> {
+        let (_, mut r) = self.inner.read(path, OpRead::new()).await?;
+        let mut w = secondary
+            .writer_options(path, write_options(secondary, args, false))
+            .await?;
+
+        loop {
+            let bs = match r.read().await {
+                Ok(bs) => bs,
+                Err(err) => {
+                    let _ = w.abort().await;
+                    return Err(err);
+                }
+            };
+            if bs.is_empty() {
+                break;
+            }
+            if let Err(err) = w.write(bs).await {
+                let _ = w.abort().await;
+                return Err(err);
+            }
+        }
+        w.close().await?;
+        Ok(())
+    }
+}
+
+/// Build write options for the secondary, conditions are dropped and
+/// metadata unsupported by the secondary is skipped.
+fn write_options(secondary: &Operator, args: &OpWrite, append: bool) -> options::WriteOptions {
+    let cap = secondary.info().full_capability();
+    let pick = |supported: bool, v: Option<&str>| v.filter(|_| supported).map(String::from);
+
+    options::WriteOptions {
+        append,
+        cache_control: pick(cap.write_with_cache_control, args.cache_control()),
+        content_type: pick(cap.write_with_content_type, args.content_type()),
+        content_disposition: pick(
+            cap.write_with_content_disposition,
+            args.content_disposition(),
+        ),
+        content_encoding: pick(cap.write_with_content_encoding, args.content_encoding()),
+        user_metadata: args
+            .user_metadata()
+            .filter(|_| cap.write_with_user_metadata)
+            .cloned(),
+        expires_in: args.expires_in().filter(|_| cap.write_with_ttl),
+        unix_mode: args.unix_mode().filter(|_| cap.write_with_unix_mode),
+        mtime: args.mtime().filter(|_| cap.write_with_mtime),
+        ..Default::default()
+    }
+}
+
+/// Whether the failed request can be served by secondaries instead.
+fn can_fail_over(err: &Error, version: Option<&str>, conditional: bool) -> bool {
+    (err.kind() == ErrorKind::NotFound || err.is_temporary()) && version.is_none() && !conditional
+}
+
+pub struct MirrorAccessor<A: Access> {
+    core: Arc<MirrorCore<A>>,
+}
+
+impl<A: Access> Debug for MirrorAccessor<A> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MirrorAccessor")
+            .field("inner", &self.core.inner)
+            .field("secondaries", &self.core.secondaries)
+            .field("consistency", &self.core.consistency)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<A: Access> LayeredAccess for MirrorAccessor<A> {
+    type Inner = A;
+    type Reader = TwoWays<A::Reader, oio::Reader>;
+    type Writer = MirrorWriter<A>;
+    type Lister = A::Lister;
+    type Deleter = MirrorDeleter<A>;
+
+    fn inner(&self) -> &Self::Inner {
+        &self.core.inner
+    }
+
+    async fn create_dir(&self, path: &str, args: OpCreateDir) -> Result<RpCreateDir> {
+        let rp = self.core.inner.create_dir(path, args).await?;
+
+        let p = path.to_string();
+        self.core
+            .fan_out("create_dir", path, move |_, secondary| {
+                let p = p.clone();
+                async move {
+                    if !secondary.info().full_capability().create_dir {
+                        return Ok(());
+                    }
+                    secondary.create_dir(&p).await
+                }
+            })
+            .await?;
+        Ok(rp)
+    }
+
+    async fn stat(&self, path: &str, args: OpStat) -> Result<RpStat> {
+        let conditional = args.if_match().is_some()
+            || args.if_none_match().is_some()
+            || args.if_modified_since().is_some()
+            || args.if_unmodified_since().is_some();
+
+        let err = match self.core.inner.stat(path, args.clone()).await {
+            Ok(rp) => return Ok(rp),
+            Err(err) if can_fail_over(&err, args.version(), conditional) => err,
+            Err(err) => return Err(err),
+        };
+        for secondary in &self.core.secondaries {
+            if let Ok(rp) = secondary.inner().stat(path, OpStat::new()).await {
+                return Ok(rp);
+            }
+        }
+        Err(err)
+    }
+
+    async fn read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::Reader)> {
+        let conditional = args.if_match().is_some()
+            || args.if_none_match().is_some()
+            || args.if_modified_since().is_some()
+            || args.if_unmodified_since().is_some();
+
+        let err = match self.core.inner.read(path, args.clone()).await {
+            Ok((rp, r)) => return Ok((rp, TwoWays::One(r))),
+            Err(err) if can_fail_over(&err, args.version(), conditional) => err,
+            Err(err) => return Err(err),
+        };
+        for secondary in &self.core.secondaries {
+            let op = OpRead::new().with_range(args.range());
+            if let Ok((rp, r)) = secondary.inner().read(path, op).await {
+                return Ok((rp, TwoWays::Two(r)));
+            }
+        }
+        Err(err)
+    }
+
+    async fn write(&self, path: &str, args: OpWrite) -> Result<(RpWrite, Self::Writer)> {
+        let (rp, mut w) = self.core.inner.write(path, args.clone()).await?;
+
+        let mut secondaries = vec![];
+        if self.core.consistency != MirrorConsistency::BestEffort {
+            let results = join_all(self.core.secondaries.iter().map(|secondary| {
+                secondary.writer_options(path, write_options(secondary, &args, args.append()))
+            }))
+            .await;
+
+            let mut errors = vec![];
+            for res in results {
+                match res {
+                    Ok(w) => secondaries.push(Some(w)),
+                    Err(err) => errors.push(err),
+                }
+            }
+            if let Err(err) = self.core.check(secondaries.len(), errors) {
+                let _ = join(
+                    w.abort(),
+                    join_all(secondaries.iter_mut().flatten().map(|w| w.abort())),
+                )
+                .await;
+                return Err(err);
+            }
+        }
+
+        Ok((
+            rp,
+            MirrorWriter {
+                core: self.core.clone(),
+                path: path.to_string(),
+                args,
+                primary: w,
+                secondaries,
+            },
+        ))
+    }
+
+    async fn delete(&self) -> Result<(RpDelete, Self::Deleter)> {
+        let (rp, d) = self.core.inner.delete().await?;
+        Ok((
+            rp,
+            MirrorDeleter {
+                core: self.core.clone(),
+                inner: d,
+                queued: 0,
+                paths: HashSet::new(),
+            },
+        ))
+    }
+
+    async fn list(&self, path: &str, args: OpList) -> Result<(RpList, Self::Lister)> {
+        self.core.inner.list(path, args).await
+    }
+
+    async fn copy(&self, from: &str, to: &str, args: OpCopy) -> Result<RpCopy> {
+        let rp = self.core.inner.copy(from, to, args).await?;
+
+        let (f, t) = (from.to_string(), to.to_string());
+        self.core
+            .fan_out("copy", to, move |core, secondary| {
+                let (f, t) = (f.clone(), t.clone());
+                async move {
+                    if secondary.info().full_capability().copy {
+                        secondary.copy(&f, &t).await
+                    } else {
+                        core.replicate(&secondary, &t, &OpWrite::new()).await
+                    }
+                }
+            })
+            .await?;
+        Ok(rp)
+    }
+
+    async fn rename(&self, from: &str, to: &str, args: OpRename) -> Result<RpRename> {
+        let rp = self.core.inner.rename(from, to, args).await?;
+
+        let (f, t) = (from.to_string(), to.to_string());
+        self.core
+            .fan_out("rename", to, move |core, secondary| {
+                let (f, t) = (f.clone(), t.clone());
+                async move {
+                    if secondary.info().full_capability().rename {
+                        secondary.rename(&f, &t).await
+                    } else {
+                        core.replicate(&secondary, &t, &OpWrite::new()).await?;
+                        secondary.delete(&f).await
+                    }
+                }
+            })
+            .await?;
+        Ok(rp)
+    }
+}
+
+/// MirrorWriter streams every chunk to the primary and all secondaries.
+pub struct MirrorWriter<A: Access> {
+    core: Arc<MirrorCore<A>>,
+    path: String,
+    args: OpWrite,
+
+    primary: A::Writer,
+    /// Writers of secondaries, set to `None` once failed.
+    secondaries: Vec<Option<Writer>>,
+}
+
+impl<A: Access> MirrorWriter<A> {
+    /// Abort and drop failed secondaries, then check the rest against the consistency level.
+    async fn settle(&mut self, results: Vec<Result<()>>) -> Result<()> {
+        let mut errors = vec![];
+        let mut failed = vec![];
+        for (w, res) in self
+            .secondaries
+            .iter_mut()
+            .filter(|w| w.is_some())
+            .zip(results)
+        {
+            if let Err(err) = res {
+                failed.extend(w.take());
+                errors.push(err);
+            }
+        }
+        // Abort failed writers so that their uploaded parts are not left behind.
+        join_all(failed.iter_mut().map(|w| w.abort())).await;
+
+        let acked = self.secondaries.iter().filter(|w| w.is_some()).count();
+        self.core.check(acked, errors)
+    }
+}
+
+impl<A: Access> oio::Write for MirrorWriter<A> {
+    async fn write(&mut self, bs: Buffer) -> Result<()> {
+        let (primary, results) = join(
+            self.primary.write(bs.clone()),
+            join_all(
+                self.secondaries
+                    .iter_mut()
+                    .flatten()
+                    .map(|w| w.write(bs.clone())),
+            ),
+        )
+        .await;
+        primary?;
+        self.settle(results).await
+    }
+
+    async fn close(&mut self) -> Result<Metadata> {
+        let (primary, results) = join(
+            self.primary.close(),
+            join_all(
+                self.secondaries
+                    .iter_mut()
+                    .flatten()
+                    .map(|w| async { w.close().await.map(|_| ()) }),
+            ),
+        )
+        .await;
+        let meta = primary?;
+        self.settle(results).await?;
+
+        if self.core.consistency == MirrorConsistency::BestEffort {
+            let (p, args) = (self.path.clone(), self.args.clone());
+            self.core
+                .fan_out("write", &self.path, move |core, secondary| {
+                    let (p, args) = (p.clone(), args.clone());
+                    async move { core.replicate(&secondary, &p, &args).await }
+                })
+                .await?;
+        }
+        Ok(meta)
+    }
+
+    async fn abort(&mut self) -> Result<()> {
+        let (primary, _) = join(
+            self.primary.abort(),
+            join_all(self.secondaries.iter_mut().flatten().map(|w| w.abort())),
+        )
+        .await;
+        primary
+    }
+}
+
+/// MirrorDeleter deletes from the primary and then from all secondaries.
+///
+/// Versioned deletes only apply to the primary, since versions of the primary
+/// don't exist on secondaries.
+pub struct MirrorDeleter<A: Access> {
+    core: Arc<MirrorCore<A>>,
+    inner: A::Deleter,
+    queued: usize,
+    paths: HashSet<String>,
+}
+
+impl<A: Access> oio::Delete for MirrorDeleter<A> {
+    fn delete(&mut self, path: &str, args: OpDelete) -> Result<()> {
+        let versioned = args.version().is_some();
+        self.inner.delete(path, args)?;
+        self.queued += 1;
+        if !versioned {
+            self.paths.insert(path.to_string());
+        }
+        Ok(())
+    }
+
+    async fn flush(&mut self) -> Result<usize> {
+        let deleted = self.inner.flush().await?;
+        self.queued = self.queued.saturating_sub(deleted);
+
+        // The inner deleter doesn't tell which paths have been deleted, check
+        // the remaining ones unless all queued deletes have been processed.
+        let mut done = Vec::new();
+        if self.queued == 0 || deleted == 0 {
+            self.queued = 0;
+            done.extend(self.paths.drain());
+        } else {
+            for path in &self.paths {
+                match self.core.inner.stat(path, OpStat::new()).await {
+                    Ok(_) => {}
+                    Err(err) if err.kind() == ErrorKind::NotFound => done.push(path.clone()),
+                    Err(err) => return Err(err),
+                }
+            }
+            for path in &done {
+                self.paths.remove(path);
+            }
+        }
+        if done.is_empty() {
+            return Ok(deleted);
+        }
+
+        let desc = done.join(",");
+        self.core
+            .fan_out("delete", &desc, move |_, secondary| {
+                let paths = done.clone();
+                async move { secondary.delete_iter(paths).await }
+            })
+            .await?;
+        Ok(deleted)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+    use crate::services::Memory;
+
+    fn memory() -> Operator {
+        Operator::new(Memory::default()).unwrap().finish()
+    }
+
+    #[tokio::test]
+    async fn test_mirror_all() {
+        let secondary = memory();
+        let op = memory().layer(MirrorLayer::new(secondary.clone()));
+
+        let mut w = op.writer("a").await.unwrap();
+        w.write("hello ").await.unwrap();
+        w.write("world").await.unwrap();
+        w.close().await.unwrap();
+        assert_eq!(secondary.read("a").await.unwrap().to_vec(), b"hello world");
+
+        op.write("b", "bye").await.unwrap();
+        assert_eq!(secondary.read("b").await.unwrap().to_vec(), b"bye");
+
+        op.delete_iter(["a", "b"]).await.unwrap();
+        assert!(!secondary.exists("a").await.unwrap());
+        assert!(!secondary.exists("b").await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_mirror_read_fail_over() {
+        let secondary = memory();
+        let op = memory().layer(MirrorLayer::new(secondary.clone()));
+
+        secondary.write("only-secondary", "data").await.unwrap();
+        assert_eq!(op.stat("only-secondary").await.unwrap().content_length(), 4);
+        assert_eq!(
+            op.read_with("only-secondary")
+                .range(1..3)
+                .await
+                .unwrap()
+                .to_vec(),
+            b"at"
+        );
+
+        let err = op.read("missing").await.unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::NotFound);
+    }
+
+    /// Deleter that deletes only one path per batch and ignores versions.
+    struct PartialDelete(Operator);
+
+    impl oio::BatchDelete for PartialDelete {
+        async fn delete_once(&self, path: String, _: OpDelete) -> Result<()> {
+            self.0.delete(&path).await
+        }
+
+        async fn delete_batch(
+            &self,
+            batch: Vec<(String, OpDelete)>,
+        ) -> Result<oio::BatchDeleteResult> {
+            let mut result = oio::BatchDeleteResult::default();
+            for (i, (path, args)) in batch.into_iter().enumerate() {
+                if i == 0 {
+                    self.0.delete(&path).await?;
+                    result.succeeded.push((path, args));
+                } else {
+                    let err = Error::new(ErrorKind::Unexpected, "busy").set_temporary();
+                    result.failed.push((path, args, err));
+                }
+            }
+            Ok(result)
+        }
+    }
+
+    /// Memory backed accessor with failing writes and partial batch deletes.
+    #[derive(Debug)]
+    struct MockAccessor {
+        inner: Operator,
+        fail_write: bool,
+    }
+
+    impl MockAccessor {
+        fn new(inner: Operator) -> Self {
+            Self {
+                inner,
+                fail_write: false,
+            }
+        }
+
+        fn failing() -> Operator {
+            let acc = Self {
+                inner: memory(),
+                fail_write: true,
+            };
+            Operator::from_inner(Arc::new(acc))
+        }
+    }
+
+    impl LayeredAccess for MockAccessor {
+        type Inner = Accessor;
+        type Reader = oio::Reader;
+        type Writer = oio::Writer;
+        type Lister = oio::Lister;
+        type Deleter = oio::Deleter;
+
+        fn inner(&self) -> &Self::Inner {
+            self.inner.inner()
+        }
+
+        async fn read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::Reader)> {
+            self.inner.inner().read(path, args).await
+        }
+
+        async fn write(&self, path: &str, args: OpWrite) -> Result<(RpWrite, Self::Writer)> {
+            if self.fail_write {
+                return Err(Error::new(ErrorKind::Unexpected, "write failed"));
+            }
+            self.inner.inner().write(path, args).await
+        }
+
+        async fn list(&self, path: &str, args: OpList) -> Result<(RpList, Self::Lister)> {
+            self.inner.inner().list(path, args).await
+        }
+
+        async fn delete(&self) -> Result<(RpDelete, Self::Deleter)> {
+            let d = oio::BatchDeleter::new(PartialDelete(self.inner.clone()));
+            Ok((RpDelete::default(), Box::new(d)))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_mirror_quorum() {
+        let good = memory();
+        let op = memory().layer(
+            MirrorLayer::new(good.clone())
+                .with_secondary(MockAccessor::failing())
+                .with_consistency(MirrorConsistency::Quorum),
+        );
+        op.write("a", "hello").await.unwrap();
+        assert_eq!(good.read("a").await.unwrap().to_vec(), b"hello");
+
+        let op = memory().layer(
+            MirrorLayer::new(MockAccessor::failing())
+                .with_secondary(MockAccessor::failing())
+                .with_consistency(MirrorConsistency::Quorum),
+        );
+        assert!(op.write("a", "hello").await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_mirror_versioned_delete() {
+        let (primary, secondary) = (memory(), memory());
+        primary.write("a", "hello").await.unwrap();
+        secondary.write("a", "hello").await.unwrap();
+
+        let acc = MirrorLayer::new(secondary.clone()).layer(MockAccessor::new(primary));
+        let (_, mut d) = LayeredAccess::delete(&acc).await.unwrap();
+        oio::Delete::delete(&mut d, "a", OpDelete::new().with_version("v1")).unwrap();
+        oio::Delete::flush(&mut d).await.unwrap();
+        assert!(secondary.exists("a").await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_mirror_partial_flush() {
+        let (primary, secondary) = (memory(), memory());
+        for path in ["a", "b"] {
+            primary.write(path, "hello").await.unwrap();
+            secondary.write(path, "hello").await.unwrap();
+        }
+
+        let acc = MirrorLayer::new(secondary.clone()).layer(MockAccessor::new(primary.clone()));
+        let (_, mut d) = LayeredAccess::delete(&acc).await.unwrap();
+        oio::Delete::delete(&mut d, "a", OpDelete::new()).unwrap();
+        oio::Delete::delete(&mut d, "b", OpDelete::new()).unwrap();
+
+        // Only the path deleted from the primary is deleted from the secondary.
+        assert_eq!(oio::Delete::flush(&mut d).await.unwrap(), 1);
+        for path in ["a", "b"] {
+            assert_eq!(
+                primary.exists(path).await.unwrap(),
+                secondary.exists(path).await.unwrap()
+            );
+        }
+
+        assert_eq!(oio::Delete::flush(&mut d).await.unwrap(), 1);
+        assert!(!secondary.exists("a").await.unwrap());
+        assert!(!secondary.exists("b").await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_mirror_best_effort() {
+        let (first, second) = (memory(), memory());
+        let layer = MirrorLayer::new(first.clone())
+            .with_secondary(second.clone())
+            .with_consistency(MirrorConsistency::BestEffort);
+        let op = memory().layer(layer.clone());
+
+        op.write_with("a", "hello")
+            .content_type("text/plain")
+            .await
+            .unwrap();
+        while layer.pending_tasks() > 0 {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+        for secondary in [&first, &second] {
+            assert_eq!(secondary.read("a").await.unwrap().to_vec(), b"hello");
+            let meta = secondary.stat("a").await.unwrap();
+            assert_eq!(meta.content_type(), Some("text/plain"));
+        }
+
+        op.delete("a").await.unwrap();
+        while layer.pending_tasks() > 0 {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+        assert!(!first.exists("a").await.unwrap());
+        assert!(!second.exists("a").await.unwrap());
+    }
+}
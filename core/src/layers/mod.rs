@@ -32,6 +32,17 @@ pub use concurrent_limit::ConcurrentLimitLayer;
 mod immutable_index;
 pub use immutable_index::ImmutableIndexLayer;
 
+mod cache;
+pub use cache::CacheLayer;
+pub use cache::CachePolicy;
+
+mod stat_cache;
+pub use stat_cache::StatCacheLayer;
+
+mod mirror;
+pub use mirror::MirrorConsistency;
+pub use mirror::MirrorLayer;
+
 mod logging;
 pub use logging::LoggingInterceptor;
 pub use logging::LoggingLayer;
@@ -75,6 +86,10 @@ pub use self::fastmetrics::FastmetricsLayer;
 #[cfg(feature = "layers-fastmetrics")]
 pub use self::fastmetrics::FastmetricsLayerBuilder;
 
+mod hedge;
+pub use self::hedge::HedgeLayer;
+pub use self::hedge::HedgeLayerBuilder;
+
 mod retry;
 pub use self::retry::RetryInterceptor;
 pub use self::retry::RetryLayer;
@@ -123,6 +138,31 @@ mod dtrace;
 #[cfg(all(target_os = "linux", feature = "layers-dtrace"))]
 pub use self::dtrace::DtraceLayer;
 
+#[cfg(feature = "layers-encryption")]
+mod encryption;
+#[cfg(feature = "layers-encryption")]
+pub use self::encryption::DataKey;
+#[cfg(feature = "layers-encryption")]
+pub use self::encryption::EncryptionLayer;
+#[cfg(feature = "layers-encryption")]
+pub use self::encryption::KeyProvider;
+#[cfg(feature = "layers-encryption")]
+pub use self::encryption::StaticKeyProvider;
+
+#[cfg(feature = "layers-compression")]
+mod compression;
+#[cfg(feature = "layers-compression")]
+pub use self::compression::COMPRESSION_METADATA_KEY;
+#[cfg(feature = "layers-compression")]
+pub use self::compression::CompressionAlgorithm;
+#[cfg(feature = "layers-compression")]
+pub use self::compression::CompressionLayer;
+
+#[cfg(feature = "layers-checksum")]
+mod checksum;
+#[cfg(feature = "layers-checksum")]
+pub use self::checksum::ChecksumLayer;
+
 pub mod observe;
 
 mod correctness_check;
@@ -132,3 +172,85 @@ pub use capability_check::CapabilityCheckLayer;
 
 mod http_client;
 pub use http_client::HttpClientLayer;
+
+#[cfg(all(
+    test,
+    any(
+        feature = "layers-checksum",
+        feature = "layers-compression",
+        feature = "layers-encryption"
+    )
+))]
+mod tests {
+    use std::sync::Arc;
+
+    use super::*;
+    use crate::raw::*;
+    use crate::*;
+
+    /// Accessor that supports resuming writers.
+    #[derive(Debug)]
+    struct ResumableAccessor {
+        info: Arc<AccessorInfo>,
+    }
+
+    impl Access for ResumableAccessor {
+        type Reader = oio::Reader;
+        type Writer = oio::Writer;
+        type Lister = oio::Lister;
+        type Deleter = oio::Deleter;
+
+        fn info(&self) -> Arc<AccessorInfo> {
+            self.info.clone()
+        }
+
+        async fn write(&self, _: &str, _: OpWrite) -> Result<(RpWrite, Self::Writer)> {
+            Ok((RpWrite::default(), Box::new(())))
+        }
+    }
+
+    /// Layers that transform the content while writing can't resume writers.
+    #[tokio::test]
+    async fn test_resume_is_rejected() {
+        type ApplyLayer = fn(Operator) -> Operator;
+        let cases: Vec<(&str, ApplyLayer)> = vec![
+            #[cfg(feature = "layers-encryption")]
+            ("encryption", |op| {
+                op.layer(EncryptionLayer::new(StaticKeyProvider::new([42; 32])))
+            }),
+            #[cfg(feature = "layers-compression")]
+            ("compression", |op| {
+                op.layer(CompressionLayer::new(CompressionAlgorithm::Zstd))
+            }),
+            #[cfg(feature = "layers-checksum")]
+            ("checksum", |op| {
+                op.layer(ChecksumLayer::new(ChecksumAlgorithm::Crc32c))
+            }),
+        ];
+
+        for (name, layer) in cases {
+            let info = AccessorInfo::default();
+            info.set_native_capability(Capability {
+                write: true,
+                write_can_resume: true,
+                ..Default::default()
+            });
+            let acc = ResumableAccessor {
+                info: Arc::new(info),
+            };
+            let op = OperatorBuilder::new(acc).finish();
+            op.resume_writer("file", UploadCheckpoint::default())
+                .await
+                .expect("resume must be supported without layers");
+
+            let op = layer(op);
+            assert!(!op.info().full_capability().write_can_resume, "{name}");
+            let res = op.resume_writer("file", UploadCheckpoint::default()).await;
+            assert_eq!(
+                res.err().map(|err| err.kind()),
+                Some(ErrorKind::Unsupported),
+                "{name}"
+            );
+        }
+    }
+}
@@ -0,0 +1,615 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::collections::HashMap;
+use std::collections::VecDeque;
+use std::fmt::Debug;
+use std::fmt::Formatter;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::time::Duration;
+use std::time::Instant;
+
+use crate::raw::*;
+use crate::*;
+
+/// Add an in-memory cache for `stat` results.
+///
+/// # Notes
+///
+/// `StatCacheLayer` caches the metadata returned by `stat` per path, including
+/// `NotFound` results (negative caching), so that `stat` and `exists` don't
+/// need to hit the underlying storage every time.
+///
+/// Entries are expired after `ttl` (or `negative_ttl` for `NotFound`) and the
+/// oldest entries are dropped once `capacity` is reached.
+///
+/// `write`, `create_dir`, `delete`, `copy` and `rename` that go through the same
+/// operator invalidate the affected path and all of its parent directories.
+/// Changes made by other clients will only be visible after the entry expires.
+///
+/// `stat` with `version`, conditional headers or overrides bypass the cache.
+///
+/// # Default
+///
+/// - ttl: 60 seconds
+/// - negative_ttl: 10 seconds
+/// - capacity: 100000 entries
+/// - fill_on_list: false
+///
+/// # Examples
+///
+/// ```no_run
+/// # use std::time::Duration;
+///
+/// # use opendal::layers::StatCacheLayer;
+/// # use opendal::services;
+/// # use opendal::Operator;
+/// # use opendal::Result;
+///
+/// # fn main() -> Result<()> {
+/// let _ = Operator::new(services::Memory::default())?
+///     .layer(
+///         StatCacheLayer::new()
+///             .with_ttl(Duration::from_secs(30))
+///             .with_negative_ttl(Duration::from_secs(5))
+///             .with_capacity(10000)
+///             .with_fill_on_list(true),
+///     )
+///     .finish();
+/// Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct StatCacheLayer {
+    ttl: Duration,
+    negative_ttl: Duration,
+    capacity: usize,
+    fill_on_list: bool,
+    cache: Arc<Mutex<StatCache>>,
+}
+
+impl Default for StatCacheLayer {
+    fn default() -> Self {
+        Self {
+            ttl: Duration::from_secs(60),
+            negative_ttl: Duration::from_secs(10),
+            capacity: 100_000,
+            fill_on_list: false,
+            cache: Arc::new(Mutex::new(StatCache::default())),
+        }
+    }
+}
+
+impl StatCacheLayer {
+    /// Create a new `StatCacheLayer` with default settings.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the time to live of cached metadata.
+    pub fn with_ttl(mut self, ttl: Duration) -> Self {
+        self.ttl = ttl;
+        self
+    }
+
+    /// Set the time to live of cached `NotFound` results.
+    ///
+    /// Set to `Duration::ZERO` to disable negative caching.
+    pub fn with_negative_ttl(mut self, ttl: Duration) -> Self {
+        self.negative_ttl = ttl;
+        self
+    }
+
+    /// Set the max number of paths that can be cached.
+    pub fn with_capacity(mut self, capacity: usize) -> Self {
+        self.capacity = capacity;
+        self
+    }
+
+    /// Fill the cache with the metadata of entries returned by `list`.
+    ///
+    /// Metadata returned by `list` may be less complete than `stat` on some
+    /// services, please only enable this if the listed metadata is enough.
+    pub fn with_fill_on_list(mut self, enabled: bool) -> Self {
+        self.fill_on_list = enabled;
+        self
+    }
+}
+
+impl<A: Access> Layer<A> for StatCacheLayer {
+    type LayeredAccess = StatCacheAccessor<A>;
+
+    fn layer(&self, inner: A) -> Self::LayeredAccess {
+        StatCacheAccessor {
+            inner,
+            ctx: Arc::new(StatCacheContext {
+                ttl: self.ttl,
+                negative_ttl: self.negative_ttl,
+                capacity: self.capacity,
+                cache: self.cache.clone(),
+            }),
+            fill_on_list: self.fill_on_list,
+        }
+    }
+}
+
+struct StatCacheContext {
+    ttl: Duration,
+    negative_ttl: Duration,
+    capacity: usize,
+    cache: Arc<Mutex<StatCache>>,
+}
+
+impl StatCacheContext {
+    fn get(&self, path: &str) -> Option<Option<Metadata>> {
+        self.cache.lock().unwrap().get(path)
+    }
+
+    fn insert(&self, path: &str, meta: Option<Metadata>) {
+        let ttl = if meta.is_some() {
+            self.ttl
+        } else {
+            self.negative_ttl
+        };
+        if ttl.is_zero() || self.capacity == 0 {
+            return;
+        }
+
+        self.cache
+            .lock()
+            .unwrap()
+            .insert(path, meta, Instant::now() + ttl, self.capacity);
+    }
+
+    /// Invalidate path and all its parents.
+    ///
+    /// Parents are invalidated too since creating or removing a file could
+    /// change the existence of its parent directories.
+    fn invalidate(&self, path: &str) {
+        let mut cache = self.cache.lock().unwrap();
+
+        let mut path = path;
+        loop {
+            cache.remove(path);
+            if path == "/" {
+                break;
+            }
+            path = get_parent(path);
+        }
+    }
+}
+
+pub struct StatCacheAccessor<A: Access> {
+    inner: A,
+    ctx: Arc<StatCacheContext>,
+    fill_on_list: bool,
+}
+
+impl<A: Access> Debug for StatCacheAccessor<A> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("StatCacheAccessor")
+            .field("inner", &self.inner)
+            .field("ttl", &self.ctx.ttl)
+            .field("negative_ttl", &self.ctx.negative_ttl)
+            .field("capacity", &self.ctx.capacity)
+            .field("fill_on_list", &self.fill_on_list)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<A: Access> LayeredAccess for StatCacheAccessor<A> {
+    type Inner = A;
+    type Reader = A::Reader;
+    type Writer = StatCacheWrapper<A::Writer>;
+    type Lister = StatCacheWrapper<A::Lister>;
+    type Deleter = StatCacheDeleter<A::Deleter>;
+
+    fn inner(&self) -> &Self::Inner {
+        &self.inner
+    }
+
+    async fn create_dir(&self, path: &str, args: OpCreateDir) -> Result<RpCreateDir> {
+        self.ctx.invalidate(path);
+        self.inner.create_dir(path, args).await
+    }
+
+    async fn read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::Reader)> {
+        self.inner.read(path, args).await
+    }
+
+    async fn write(&self, path: &str, args: OpWrite) -> Result<(RpWrite, Self::Writer)> {
+        self.ctx.invalidate(path);
+        self.inner.write(path, args).await.map(|(rp, w)| {
+            (
+                rp,
+                StatCacheWrapper::new(w, self.ctx.clone(), path.to_string(), false),
+            )
+        })
+    }
+
+    async fn copy(&self, from: &str, to: &str, args: OpCopy) -> Result<RpCopy> {
+        self.ctx.invalidate(to);
+        let res = self.inner.copy(from, to, args).await;
+        self.ctx.invalidate(to);
+        res
+    }
+
+    async fn rename(&self, from: &str, to: &str, args: OpRename) -> Result<RpRename> {
+        self.ctx.invalidate(from);
+        self.ctx.invalidate(to);
+        let res = self.inner.rename(from, to, args).await;
+        self.ctx.invalidate(from);
+        self.ctx.invalidate(to);
+        res
+    }
+
+    async fn stat(&self, path: &str, args: OpStat) -> Result<RpStat> {
+        let cacheable = args.version().is_none()
+            && args.if_match().is_none()
+            && args.if_none_match().is_none()
+            && args.if_modified_since().is_none()
+            && args.if_unmodified_since().is_none()
+            && args.override_content_type().is_none()
+            && args.override_cache_control().is_none()
+            && args.override_content_disposition().is_none()
+            // The cache key doesn't tell the link from its target.
+            && !args.no_follow_symlink();
+        if !cacheable {
+            return self.inner.stat(path, args).await;
+        }
+
+        match self.ctx.get(path) {
+            Some(Some(meta)) => return Ok(RpStat::new(meta)),
+            Some(None) => {
+                return Err(Error::new(
+                    ErrorKind::NotFound,
+                    "path not found (cached by stat cache layer)",
+                ));
+            }
+            None => {}
+        }
+
+        match self.inner.stat(path, args).await {
+            Ok(rp) => {
+                let meta = rp.into_metadata();
+                self.ctx.insert(path, Some(meta.clone()));
+                Ok(RpStat::new(meta))
+            }
+            Err(err) if err.kind() == ErrorKind::NotFound => {
+                self.ctx.insert(path, None);
+                Err(err)
+            }
+            Err(err) => Err(err),
+        }
+    }
+
+    async fn delete(&self) -> Result<(RpDelete, Self::Deleter)> {
+        self.inner
+            .delete()
+            .await
+            .map(|(rp, d)| (rp, StatCacheDeleter::new(d, self.ctx.clone())))
+    }
+
+    async fn list(&self, path: &str, args: OpList) -> Result<(RpList, Self::Lister)> {
+        // Entries listed with versions or deleted are not the current state of the path.
+        let fill = self.fill_on_list && !args.versions() && !args.deleted();
+        self.inner.list(path, args).await.map(|(rp, l)| {
+            (
+                rp,
+                StatCacheWrapper::new(l, self.ctx.clone(), path.to_string(), fill),
+            )
+        })
+    }
+}
+
+pub struct StatCacheWrapper<R> {
+    inner: R,
+    ctx: Arc<StatCacheContext>,
+    path: String,
+    fill: bool,
+}
+
+impl<R> StatCacheWrapper<R> {
+    fn new(inner: R, ctx: Arc<StatCacheContext>, path: String, fill: bool) -> Self {
+        Self {
+            inner,
+            ctx,
+            path,
+            fill,
+        }
+    }
+}
+
+impl<R: oio::Write> oio::Write for StatCacheWrapper<R> {
+    async fn write(&mut self, bs: Buffer) -> Result<()> {
+        self.inner.write(bs).await
+    }
+
+    async fn close(&mut self) -> Result<Metadata> {
+        let res = self.inner.close().await;
+        // Invalidate again in case the old metadata has been cached during writing.
+        self.ctx.invalidate(&self.path);
+        res
+    }
+
+    async fn abort(&mut self) -> Result<()> {
+        self.inner.abort().await
+    }
+}
+
+impl<R: oio::List> oio::List for StatCacheWrapper<R> {
+    async fn next(&mut self) -> Result<Option<oio::Entry>> {
+        let entry = self.inner.next().await?;
+
+        if self.fill {
+            if let Some(entry) = &entry {
+                // Skip the listed path itself and files whose listed metadata doesn't
+                // carry the content length, they are not complete enough to serve `stat`.
+                let meta = entry.metadata();
+                if entry.path() != self.path && (meta.is_dir() || meta.content_length() > 0) {
+                    self.ctx.insert(entry.path(), Some(meta.clone()));
+                }
+            }
+        }
+
+        Ok(entry)
+    }
+}
+
+pub struct StatCacheDeleter<D> {
+    inner: D,
+    ctx: Arc<StatCacheContext>,
+    /// Paths queued but not confirmed to be deleted yet.
+    paths: Vec<String>,
+    deleted: usize,
+}
+
+impl<D> StatCacheDeleter<D> {
+    fn new(inner: D, ctx: Arc<StatCacheContext>) -> Self {
+        Self {
+            inner,
+            ctx,
+            paths: Vec::new(),
+            deleted: 0,
+        }
+    }
+}
+
+impl<D: oio::Delete> oio::Delete for StatCacheDeleter<D> {
+    fn delete(&mut self, path: &str, args: OpDelete) -> Result<()> {
+        self.inner.delete(path, args)?;
+        self.paths.push(path.to_string());
+        Ok(())
+    }
+
+    async fn flush(&mut self) -> Result<usize> {
+        let n = self.inner.flush().await?;
+
+        // Invalidate only after the paths have been deleted, otherwise a `stat`
+        // between queueing and flushing could cache the old metadata again.
+        //
+        // `flush` doesn't tell which paths have been deleted, so all queued paths
+        // are invalidated and kept until the queue has been drained.
+        for path in &self.paths {
+            self.ctx.invalidate(path);
+        }
+        self.deleted += n;
+        if n == 0 || self.deleted >= self.paths.len() {
+            self.paths.clear();
+            self.deleted = 0;
+        }
+        Ok(n)
+    }
+}
+
+struct StatCacheEntry {
+    meta: Option<Metadata>,
+    expires_at: Instant,
+    seq: u64,
+}
+
+#[derive(Default)]
+struct StatCache {
+    entries: HashMap<String, StatCacheEntry>,
+    // Insertion order used for eviction, outdated items are skipped lazily.
+    order: VecDeque<(String, u64)>,
+    seq: u64,
+}
+
+impl StatCache {
+    fn get(&mut self, path: &str) -> Option<Option<Metadata>> {
+        let entry = self.entries.get(path)?;
+        if entry.expires_at <= Instant::now() {
+            self.entries.remove(path);
+            return None;
+        }
+        Some(entry.meta.clone())
+    }
+
+    fn insert(&mut self, path: &str, meta: Option<Metadata>, expires_at: Instant, capacity: usize) {
+        self.seq += 1;
+        self.entries.insert(
+            path.to_string(),
+            StatCacheEntry {
+                meta,
+                expires_at,
+                seq: self.seq,
+            },
+        );
+        self.order.push_back((path.to_string(), self.seq));
+
+        while self.entries.len() > capacity {
+            let Some((path, seq)) = self.order.pop_front() else {
+                break;
+            };
+            if self.entries.get(&path).is_some_and(|e| e.seq == seq) {
+                self.entries.remove(&path);
+            }
+        }
+
+        // Keep the order queue bounded when the same paths are refreshed repeatedly.
+        if self.order.len() > capacity.saturating_mul(2) {
+            let entries = &self.entries;
+            self.order
+                .retain(|(path, seq)| entries.get(path).is_some_and(|e| e.seq == *seq));
+        }
+    }
+
+    fn remove(&mut self, path: &str) {
+        self.entries.remove(path);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::services;
+
+    fn new_operators(layer: StatCacheLayer) -> (Operator, Operator) {
+        let backend = Operator::new(services::Memory::default()).unwrap().finish();
+        let op = backend.clone().layer(layer);
+        (op, backend)
+    }
+
+    #[tokio::test]
+    async fn test_stat_cached() -> Result<()> {
+        let (op, backend) = new_operators(StatCacheLayer::new());
+
+        backend.write("test", "Hello").await?;
+        assert_eq!(op.stat("test").await?.content_length(), 5);
+
+        // Changes behind the layer are not visible until the entry expired.
+        backend.write("test", "Hello, World!").await?;
+        assert_eq!(op.stat("test").await?.content_length(), 5);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_negative_cache() -> Result<()> {
+        let (op, backend) = new_operators(StatCacheLayer::new());
+
+        assert!(!op.exists("test").await?);
+        backend.write("test", "Hello").await?;
+        assert!(!op.exists("test").await?);
+
+        let (op, backend) = new_operators(StatCacheLayer::new().with_negative_ttl(Duration::ZERO));
+        assert!(!op.exists("test").await?);
+        backend.write("test", "Hello").await?;
+        assert!(op.exists("test").await?);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_invalidate() -> Result<()> {
+        let (op, _) = new_operators(StatCacheLayer::new());
+
+        assert!(!op.exists("dir/test").await?);
+        assert!(!op.exists("dir/").await?);
+        op.write("dir/test", "Hello").await?;
+        assert!(op.exists("dir/test").await?);
+        assert!(op.exists("dir/").await?);
+
+        assert_eq!(op.stat("dir/test").await?.content_length(), 5);
+        op.write("dir/test", "Hello, World!").await?;
+        assert_eq!(op.stat("dir/test").await?.content_length(), 13);
+
+        op.delete("dir/test").await?;
+        assert!(!op.exists("dir/test").await?);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_invalidate_after_flush() -> Result<()> {
+        let (op, _) = new_operators(StatCacheLayer::new());
+
+        op.write("test", "Hello").await?;
+        let mut deleter = op.deleter().await?;
+        deleter.delete("test").await?;
+        // The path is only queued, stat should not cache it again as existing.
+        assert!(op.exists("test").await?);
+        deleter.close().await?;
+        assert!(!op.exists("test").await?);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_ttl_and_capacity() -> Result<()> {
+        let (op, backend) = new_operators(
+            StatCacheLayer::new()
+                .with_ttl(Duration::from_millis(50))
+                .with_capacity(1),
+        );
+
+        backend.write("a", "Hello").await?;
+        backend.write("b", "Hello").await?;
+        op.stat("a").await?;
+        op.stat("b").await?;
+
+        // `a` has been evicted since capacity is 1.
+        backend.write("a", "Hello, World!").await?;
+        assert_eq!(op.stat("a").await?.content_length(), 13);
+
+        backend.write("a", "Hi").await?;
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        assert_eq!(op.stat("a").await?.content_length(), 2);
+        Ok(())
+    }
+
+    #[cfg(feature = "services-fs")]
+    #[tokio::test]
+    async fn test_no_follow_symlink_bypass_cache() -> Result<()> {
+        let root = std::env::temp_dir().join(uuid::Uuid::new_v4().to_string());
+        let builder = services::Fs::default().root(&root.to_string_lossy());
+        let op = Operator::new(builder)?
+            .finish()
+            .layer(StatCacheLayer::new());
+
+        op.write("target", "Hello").await?;
+        op.create_symlink("link", "target").await?;
+        assert!(op.stat("link").await?.mode().is_file());
+        assert!(
+            op.stat_with("link")
+                .no_follow_symlink(true)
+                .await?
+                .mode()
+                .is_symlink()
+        );
+        assert!(op.stat("link").await?.mode().is_file());
+
+        std::fs::remove_dir_all(root).ok();
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_fill_on_list() -> Result<()> {
+        let (op, backend) = new_operators(StatCacheLayer::new().with_fill_on_list(true));
+
+        backend.write("dir/test", "Hello").await?;
+        backend.write("dir/sub/test", "Hello").await?;
+        op.list("dir/").await?;
+
+        // Listed dirs are filled.
+        backend.delete("dir/sub/test").await?;
+        assert!(op.exists("dir/sub/").await?);
+
+        // Memory doesn't return content length in list, so files are not filled.
+        backend.write("dir/test", "Hello, World!").await?;
+        assert_eq!(op.stat("dir/test").await?.content_length(), 13);
+        Ok(())
+    }
+}
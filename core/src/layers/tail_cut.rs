@@ -606,19 +606,19 @@ impl<R: oio::Delete> oio::Delete for TailCutWrapper<R> {
 }
 
 /// Statistics engine for tail cut layer.
-struct TailCutStats {
+pub(super) struct TailCutStats {
     // Statistics for each operation type (7 operations)
     operations: [Arc<OperationStats>; 7],
 }
 
 impl TailCutStats {
-    fn new() -> Self {
+    pub(super) fn new() -> Self {
         Self {
             operations: std::array::from_fn(|_| Arc::new(OperationStats::new())),
         }
     }
 
-    fn stats_for(&self, op: Operation) -> &Arc<OperationStats> {
+    pub(super) fn stats_for(&self, op: Operation) -> &Arc<OperationStats> {
         let idx = match op {
             Operation::Read => 0,
             Operation::Write => 1,
@@ -634,7 +634,7 @@ impl TailCutStats {
 }
 
 /// Statistics for a single operation type.
-struct OperationStats {
+pub(super) struct OperationStats {
     buckets: Vec<SizeBucket>,
 }
 
@@ -661,15 +661,15 @@ impl OperationStats {
             .unwrap_or(&self.buckets[self.buckets.len() - 1])
     }
 
-    fn record(&self, size: Option<u64>, latency: Duration) {
+    pub(super) fn record(&self, size: Option<u64>, latency: Duration) {
         self.bucket_for(size).histogram.record(latency);
     }
 
-    fn quantile(&self, size: Option<u64>, q: f64, window: Duration) -> Option<Duration> {
+    pub(super) fn quantile(&self, size: Option<u64>, q: f64, window: Duration) -> Option<Duration> {
         self.bucket_for(size).histogram.quantile(q, window)
     }
 
-    fn total_samples(&self, size: Option<u64>, window: Duration) -> usize {
+    pub(super) fn total_samples(&self, size: Option<u64>, window: Duration) -> usize {
         self.bucket_for(size).histogram.total_samples(window)
     }
 }
@@ -708,10 +708,17 @@ struct WindowedHistogram {
 
 impl WindowedHistogram {
     fn new() -> Self {
+        let now = Self::now_ms();
+        let slices: Box<[TimeSlice; NUM_SLICES]> =
+            Box::new(std::array::from_fn(|_| TimeSlice::new()));
+        // The first slice is active right away, otherwise samples recorded
+        // before the first rotation would never show up in snapshots.
+        slices[0].start_epoch_ms.store(now, Ordering::Relaxed);
+
         Self {
-            slices: Box::new(std::array::from_fn(|_| TimeSlice::new())),
+            slices,
             current_idx: AtomicUsize::new(0),
-            last_rotate: AtomicU64::new(Self::now_ms()),
+            last_rotate: AtomicU64::new(now),
         }
     }
 
@@ -915,6 +922,23 @@ mod tests {
         assert!(p50.is_some());
     }
 
+    #[test]
+    fn test_histogram_before_first_rotation() {
+        let hist = WindowedHistogram::new();
+
+        // Samples are visible before the first slice rotation.
+        hist.record(Duration::from_millis(10));
+        hist.record(Duration::from_millis(20));
+        assert_eq!(hist.total_samples(Duration::from_secs(60)), 2);
+
+        // Other slices are still inactive.
+        assert!(
+            hist.slices[1..]
+                .iter()
+                .all(|v| v.start_epoch_ms.load(Ordering::Acquire) == 0)
+        );
+    }
+
     #[tokio::test]
     async fn test_tail_cut_layer_build() {
         let layer = TailCutLayer::builder()
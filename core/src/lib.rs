@@ -166,6 +166,7 @@ pub mod docs;
 pub mod layers;
 pub mod raw;
 pub mod services;
+pub mod sync;
 
 #[cfg(test)]
 mod tests {
@@ -180,8 +181,8 @@ mod tests {
     #[test]
     fn assert_size() {
         assert_eq!(16, size_of::<Operator>());
-        assert_eq!(360, size_of::<Entry>());
-        assert_eq!(336, size_of::<Metadata>());
+        assert_eq!(368, size_of::<Entry>());
+        assert_eq!(344, size_of::<Metadata>());
         assert_eq!(1, size_of::<EntryMode>());
     }
 
@@ -238,6 +238,91 @@ pub trait Access: Send + Sync + Debug + Unpin + 'static {
         )))
     }
 
+    /// Invoke the `create_symlink` operation on the specified path.
+    ///
+    /// Require [`Capability::symlink`]
+    ///
+    /// # Behavior
+    ///
+    /// - `path` MUST be file path, DON'T NEED to check mode.
+    /// - `target` SHOULD be stored as is, services MUST NOT normalize it.
+    fn create_symlink(
+        &self,
+        path: &str,
+        target: &str,
+        args: OpCreateSymlink,
+    ) -> impl Future<Output = Result<RpCreateSymlink>> + MaybeSend {
+        let (_, _, _) = (path, target, args);
+
+        ready(Err(Error::new(
+            ErrorKind::Unsupported,
+            "operation is not supported",
+        )))
+    }
+
+    /// Invoke the `read_link` operation on the specified path.
+    ///
+    /// Require [`Capability::symlink`]
+    ///
+    /// # Behavior
+    ///
+    /// - `read_link` on a path that is not a symlink SHOULD return an error.
+    fn read_link(
+        &self,
+        path: &str,
+        args: OpReadLink,
+    ) -> impl Future<Output = Result<RpReadLink>> + MaybeSend {
+        let (_, _) = (path, args);
+
+        ready(Err(Error::new(
+            ErrorKind::Unsupported,
+            "operation is not supported",
+        )))
+    }
+
+    /// Invoke the `list_pending_uploads` operation on the specified path.
+    ///
+    /// Require [`Capability::pending_upload`]
+    ///
+    /// # Behavior
+    ///
+    /// - `path` is a prefix, all unfinished multipart uploads under it SHOULD be returned.
+    /// - Paths of returned uploads MUST be relative to the root.
+    fn list_pending_uploads(
+        &self,
+        path: &str,
+        args: OpListPendingUploads,
+    ) -> impl Future<Output = Result<RpListPendingUploads>> + MaybeSend {
+        let (_, _) = (path, args);
+
+        ready(Err(Error::new(
+            ErrorKind::Unsupported,
+            "operation is not supported",
+        )))
+    }
+
+    /// Invoke the `abort_pending_upload` operation on the specified path.
+    ///
+    /// Require [`Capability::pending_upload`]
+    ///
+    /// # Behavior
+    ///
+    /// - `path` MUST be file path, DON'T NEED to check mode.
+    /// - Aborting an upload that doesn't exist SHOULD return `NotFound` error.
+    fn abort_pending_upload(
+        &self,
+        path: &str,
+        upload_id: &str,
+        args: OpAbortPendingUpload,
+    ) -> impl Future<Output = Result<RpAbortPendingUpload>> + MaybeSend {
+        let (_, _, _) = (path, upload_id, args);
+
+        ready(Err(Error::new(
+            ErrorKind::Unsupported,
+            "operation is not supported",
+        )))
+    }
+
     /// Invoke the `presign` operation on the specified path.
     ///
     /// Require [`Capability::presign`]
@@ -306,6 +391,32 @@ pub trait AccessDyn: Send + Sync + Debug + Unpin {
         to: &'a str,
         args: OpRename,
     ) -> BoxedFuture<'a, Result<RpRename>>;
+    /// Dyn version of [`Accessor::create_symlink`]
+    fn create_symlink_dyn<'a>(
+        &'a self,
+        path: &'a str,
+        target: &'a str,
+        args: OpCreateSymlink,
+    ) -> BoxedFuture<'a, Result<RpCreateSymlink>>;
+    /// Dyn version of [`Accessor::read_link`]
+    fn read_link_dyn<'a>(
+        &'a self,
+        path: &'a str,
+        args: OpReadLink,
+    ) -> BoxedFuture<'a, Result<RpReadLink>>;
+    /// Dyn version of [`Accessor::list_pending_uploads`]
+    fn list_pending_uploads_dyn<'a>(
+        &'a self,
+        path: &'a str,
+        args: OpListPendingUploads,
+    ) -> BoxedFuture<'a, Result<RpListPendingUploads>>;
+    /// Dyn version of [`Accessor::abort_pending_upload`]
+    fn abort_pending_upload_dyn<'a>(
+        &'a self,
+        path: &'a str,
+        upload_id: &'a str,
+        args: OpAbortPendingUpload,
+    ) -> BoxedFuture<'a, Result<RpAbortPendingUpload>>;
     /// Dyn version of [`Accessor::presign`]
     fn presign_dyn<'a>(
         &'a self,
@@ -385,6 +496,40 @@ where
         Box::pin(self.rename(from, to, args))
     }
 
+    fn create_symlink_dyn<'a>(
+        &'a self,
+        path: &'a str,
+        target: &'a str,
+        args: OpCreateSymlink,
+    ) -> BoxedFuture<'a, Result<RpCreateSymlink>> {
+        Box::pin(self.create_symlink(path, target, args))
+    }
+
+    fn read_link_dyn<'a>(
+        &'a self,
+        path: &'a str,
+        args: OpReadLink,
+    ) -> BoxedFuture<'a, Result<RpReadLink>> {
+        Box::pin(self.read_link(path, args))
+    }
+
+    fn list_pending_uploads_dyn<'a>(
+        &'a self,
+        path: &'a str,
+        args: OpListPendingUploads,
+    ) -> BoxedFuture<'a, Result<RpListPendingUploads>> {
+        Box::pin(self.list_pending_uploads(path, args))
+    }
+
+    fn abort_pending_upload_dyn<'a>(
+        &'a self,
+        path: &'a str,
+        upload_id: &'a str,
+        args: OpAbortPendingUpload,
+    ) -> BoxedFuture<'a, Result<RpAbortPendingUpload>> {
+        Box::pin(self.abort_pending_upload(path, upload_id, args))
+    }
+
     fn presign_dyn<'a>(
         &'a self,
         path: &'a str,
@@ -436,6 +581,36 @@ impl Access for dyn AccessDyn {
         self.rename_dyn(from, to, args).await
     }
 
+    async fn create_symlink(
+        &self,
+        path: &str,
+        target: &str,
+        args: OpCreateSymlink,
+    ) -> Result<RpCreateSymlink> {
+        self.create_symlink_dyn(path, target, args).await
+    }
+
+    async fn read_link(&self, path: &str, args: OpReadLink) -> Result<RpReadLink> {
+        self.read_link_dyn(path, args).await
+    }
+
+    async fn list_pending_uploads(
+        &self,
+        path: &str,
+        args: OpListPendingUploads,
+    ) -> Result<RpListPendingUploads> {
+        self.list_pending_uploads_dyn(path, args).await
+    }
+
+    async fn abort_pending_upload(
+        &self,
+        path: &str,
+        upload_id: &str,
+        args: OpAbortPendingUpload,
+    ) -> Result<RpAbortPendingUpload> {
+        self.abort_pending_upload_dyn(path, upload_id, args).await
+    }
+
     async fn presign(&self, path: &str, args: OpPresign) -> Result<RpPresign> {
         self.presign_dyn(path, args).await
     }
@@ -531,6 +706,44 @@ impl<T: Access + ?Sized> Access for Arc<T> {
         async move { self.as_ref().rename(from, to, args).await }
     }
 
+    fn create_symlink(
+        &self,
+        path: &str,
+        target: &str,
+        args: OpCreateSymlink,
+    ) -> impl Future<Output = Result<RpCreateSymlink>> + MaybeSend {
+        async move { self.as_ref().create_symlink(path, target, args).await }
+    }
+
+    fn read_link(
+        &self,
+        path: &str,
+        args: OpReadLink,
+    ) -> impl Future<Output = Result<RpReadLink>> + MaybeSend {
+        async move { self.as_ref().read_link(path, args).await }
+    }
+
+    fn list_pending_uploads(
+        &self,
+        path: &str,
+        args: OpListPendingUploads,
+    ) -> impl Future<Output = Result<RpListPendingUploads>> + MaybeSend {
+        async move { self.as_ref().list_pending_uploads(path, args).await }
+    }
+
+    fn abort_pending_upload(
+        &self,
+        path: &str,
+        upload_id: &str,
+        args: OpAbortPendingUpload,
+    ) -> impl Future<Output = Result<RpAbortPendingUpload>> + MaybeSend {
+        async move {
+            self.as_ref()
+                .abort_pending_upload(path, upload_id, args)
+                .await
+        }
+    }
+
     fn presign(
         &self,
         path: &str,
@@ -0,0 +1,576 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Chunked storage of large values on top of a plain key-value store.
+//!
+//! KV services usually store an object as one value, which caps the object
+//! size and forces writers to buffer the whole object. [`ChunkedKv`] splits
+//! objects into fixed-size chunk keys and a manifest key:
+//!
+//! - The manifest is stored at the object's key. It starts with
+//!   [`ChunkedKv::MANIFEST_MAGIC`] followed by a JSON document describing
+//!   the object size, chunk size and the id of the write.
+//! - Chunks are stored at `/.opendal/chunks/{id}/{index}`. Object keys built
+//!   by `build_abs_path` never start with `/`, so chunk keys never clash
+//!   with them. Use [`ChunkedKv::is_chunk_key`] to hide them from listings.
+//! - Objects that fit into a single chunk are stored inline at the object's
+//!   key, which keeps values written before chunking was enabled readable.
+//!
+//! Writers stream chunks as soon as they are full and commit by swapping the
+//! manifest in a single `set`, so readers never observe a partial object.
+//! Chunks of the replaced object are removed afterwards on a best-effort
+//! basis.
+
+use std::future::Future;
+use std::sync::Arc;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::raw::*;
+use crate::*;
+
+/// The key-value operations required by [`ChunkedKv`].
+///
+/// `set` on a single key must be atomic: it's used to commit a write by
+/// replacing the manifest.
+pub trait ChunkedKvStore: Send + Sync + 'static {
+    /// Get the value of given key, returns `None` if not exist.
+    fn get(&self, key: &str) -> impl Future<Output = Result<Option<Buffer>>> + MaybeSend;
+
+    /// Set the value of given key.
+    fn set(&self, key: &str, value: Buffer) -> impl Future<Output = Result<()>> + MaybeSend;
+
+    /// Delete given key, deleting a not existing key should succeed.
+    fn delete(&self, key: &str) -> impl Future<Output = Result<()>> + MaybeSend;
+
+    /// Move the value of `from` to `to`, returns `false` if `from` not exist.
+    ///
+    /// The default implementation sets `to` before deleting `from`, so the
+    /// value is never lost but can be observed at both keys in between.
+    /// Stores with transactions should override it to move atomically.
+    fn rename(&self, from: &str, to: &str) -> impl Future<Output = Result<bool>> + MaybeSend {
+        async move {
+            let Some(value) = self.get(from).await? else {
+                return Ok(false);
+            };
+            self.set(to, value).await?;
+            self.delete(from).await?;
+            Ok(true)
+        }
+    }
+}
+
+/// Manifest describing a chunked object.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+struct ChunkManifest {
+    id: String,
+    size: u64,
+    chunk_size: u64,
+}
+
+impl ChunkManifest {
+    fn chunks(&self) -> u64 {
+        self.size.div_ceil(self.chunk_size)
+    }
+
+    fn encode(&self) -> Result<Buffer> {
+        let mut bs = ChunkedKv::<()>::MANIFEST_MAGIC.to_vec();
+        serde_json::to_writer(&mut bs, self).map_err(new_json_serialize_error)?;
+        Ok(Buffer::from(bs))
+    }
+}
+
+/// A stored value, either inline or described by a manifest.
+enum ChunkValue {
+    Inline(Buffer),
+    Chunked(ChunkManifest),
+}
+
+impl ChunkValue {
+    fn decode(value: Buffer) -> Result<Self> {
+        let magic = ChunkedKv::<()>::MANIFEST_MAGIC;
+        if value.len() < magic.len() || value.slice(..magic.len()).to_bytes() != magic {
+            return Ok(ChunkValue::Inline(value));
+        }
+
+        let manifest = serde_json::from_slice(&value.slice(magic.len()..).to_bytes())
+            .map_err(new_json_deserialize_error)?;
+        Ok(ChunkValue::Chunked(manifest))
+    }
+
+    fn size(&self) -> u64 {
+        match self {
+            ChunkValue::Inline(bs) => bs.len() as u64,
+            ChunkValue::Chunked(m) => m.size,
+        }
+    }
+}
+
+/// Splits objects into fixed-size chunks on top of a [`ChunkedKvStore`].
+///
+/// See the [module docs](self) for the storage layout.
+pub struct ChunkedKv<S> {
+    store: Arc<S>,
+    chunk_size: usize,
+}
+
+impl<S> Clone for ChunkedKv<S> {
+    fn clone(&self) -> Self {
+        Self {
+            store: self.store.clone(),
+            chunk_size: self.chunk_size,
+        }
+    }
+}
+
+impl<S> std::fmt::Debug for ChunkedKv<S> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ChunkedKv")
+            .field("chunk_size", &self.chunk_size)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<S> ChunkedKv<S> {
+    /// The prefix of manifest values.
+    pub const MANIFEST_MAGIC: &'static [u8] = b"\0opendal-chunked-v1\n";
+
+    /// The prefix of all chunk keys.
+    pub const CHUNK_KEY_PREFIX: &'static str = "/.opendal/chunks/";
+
+    /// Check if given key is a chunk key instead of an object key.
+    pub fn is_chunk_key(key: &str) -> bool {
+        key.starts_with(Self::CHUNK_KEY_PREFIX)
+    }
+
+    fn chunk_key(id: &str, index: u64) -> String {
+        format!("{}{id}/{index}", Self::CHUNK_KEY_PREFIX)
+    }
+}
+
+impl<S: ChunkedKvStore> ChunkedKv<S> {
+    /// Create a new chunked kv with given chunk size.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `chunk_size` is 0.
+    pub fn new(store: Arc<S>, chunk_size: usize) -> Self {
+        assert!(chunk_size > 0, "chunk_size must be greater than 0");
+        Self { store, chunk_size }
+    }
+
+    /// The chunk size used by new writes.
+    pub fn chunk_size(&self) -> usize {
+        self.chunk_size
+    }
+
+    async fn load(&self, key: &str) -> Result<Option<ChunkValue>> {
+        match self.store.get(key).await? {
+            Some(bs) => Ok(Some(ChunkValue::decode(bs)?)),
+            None => Ok(None),
+        }
+    }
+
+    /// Get the size of the object at given key, returns `None` if not exist.
+    ///
+    /// Only the manifest is fetched for chunked objects.
+    pub async fn stat(&self, key: &str) -> Result<Option<u64>> {
+        Ok(self.load(key).await?.map(|v| v.size()))
+    }
+
+    /// Read given range of the object at given key, returns `None` if not exist.
+    ///
+    /// The returned reader only fetches the chunks covered by `range`.
+    pub async fn read(&self, key: &str, range: BytesRange) -> Result<Option<ChunkedKvReader<S>>> {
+        let Some(value) = self.load(key).await? else {
+            return Ok(None);
+        };
+
+        let size = value.size();
+        let start = range.offset().min(size);
+        let end = match range.size() {
+            Some(n) => start.saturating_add(n).min(size),
+            None => size,
+        };
+
+        let reader = match value {
+            ChunkValue::Inline(bs) => ChunkedKvReader {
+                kv: self.clone(),
+                manifest: None,
+                inline: Some(bs.slice(start as usize..end as usize)),
+                pos: start,
+                end,
+            },
+            ChunkValue::Chunked(manifest) => ChunkedKvReader {
+                kv: self.clone(),
+                manifest: Some(manifest),
+                inline: None,
+                pos: start,
+                end,
+            },
+        };
+        Ok(Some(reader))
+    }
+
+    /// Create a writer that streams the object at given key chunk by chunk.
+    pub fn writer(&self, key: &str) -> ChunkedKvWriter<S> {
+        ChunkedKvWriter {
+            kv: self.clone(),
+            key: key.to_string(),
+            id: uuid::Uuid::new_v4().to_string(),
+            buffer: oio::QueueBuf::new(),
+            chunks: 0,
+            size: 0,
+        }
+    }
+
+    /// Delete the object at given key together with its chunks.
+    pub async fn delete(&self, key: &str) -> Result<()> {
+        let old = self.load(key).await?;
+        self.store.delete(key).await?;
+        if let Some(ChunkValue::Chunked(manifest)) = old {
+            self.delete_chunks(&manifest.id, manifest.chunks()).await?;
+        }
+        Ok(())
+    }
+
+    /// Move the object at `from` to `to`, returns `false` if `from` not exist.
+    ///
+    /// Only the manifest is moved, chunks are shared with the new key.
+    pub async fn rename(&self, from: &str, to: &str) -> Result<bool> {
+        let old = self.load(to).await?;
+        if !self.store.rename(from, to).await? {
+            return Ok(false);
+        }
+        self.delete_replaced(old).await;
+        Ok(true)
+    }
+
+    /// Copy the object at `from` to `to`, returns `false` if `from` not exist.
+    ///
+    /// Chunks are copied under a new id before the manifest is stored, so
+    /// the copy becomes visible at once.
+    pub async fn copy(&self, from: &str, to: &str) -> Result<bool> {
+        let Some(value) = self.load(from).await? else {
+            return Ok(false);
+        };
+        let old = self.load(to).await?;
+
+        match value {
+            ChunkValue::Inline(bs) => self.store.set(to, bs).await?,
+            ChunkValue::Chunked(manifest) => {
+                let copied = ChunkManifest {
+                    id: uuid::Uuid::new_v4().to_string(),
+                    ..manifest.clone()
+                };
+                for index in 0..manifest.chunks() {
+                    let key = Self::chunk_key(&manifest.id, index);
+                    let chunk = self.store.get(&key).await?.ok_or_else(|| {
+                        Error::new(
+                            ErrorKind::Unexpected,
+                            "chunk is missing, the object may have been overwritten",
+                        )
+                        .with_context("chunk", key)
+                    })?;
+                    self.store
+                        .set(&Self::chunk_key(&copied.id, index), chunk)
+                        .await?;
+                }
+                self.store.set(to, copied.encode()?).await?;
+            }
+        }
+
+        self.delete_replaced(old).await;
+        Ok(true)
+    }
+
+    /// Delete the chunks of a replaced object on a best-effort basis.
+    async fn delete_replaced(&self, old: Option<ChunkValue>) {
+        if let Some(ChunkValue::Chunked(old)) = old {
+            if let Err(err) = self.delete_chunks(&old.id, old.chunks()).await {
+                log::warn!("failed to delete chunks of replaced object: {err}");
+            }
+        }
+    }
+
+    async fn delete_chunks(&self, id: &str, chunks: u64) -> Result<()> {
+        for index in 0..chunks {
+            self.store.delete(&Self::chunk_key(id, index)).await?;
+        }
+        Ok(())
+    }
+}
+
+/// Reader returned by [`ChunkedKv::read`].
+///
+/// Every call to `read` returns the data of at most one chunk.
+pub struct ChunkedKvReader<S> {
+    kv: ChunkedKv<S>,
+    manifest: Option<ChunkManifest>,
+    inline: Option<Buffer>,
+    pos: u64,
+    end: u64,
+}
+
+impl<S: ChunkedKvStore> oio::Read for ChunkedKvReader<S> {
+    async fn read(&mut self) -> Result<Buffer> {
+        if let Some(bs) = self.inline.take() {
+            self.pos = self.end;
+            return Ok(bs);
+        }
+        let Some(manifest) = &self.manifest else {
+            return Ok(Buffer::new());
+        };
+        if self.pos >= self.end {
+            return Ok(Buffer::new());
+        }
+
+        let index = self.pos / manifest.chunk_size;
+        let chunk_start = index * manifest.chunk_size;
+        let key = ChunkedKv::<S>::chunk_key(&manifest.id, index);
+        let chunk = self.kv.store.get(&key).await?.ok_or_else(|| {
+            Error::new(
+                ErrorKind::Unexpected,
+                "chunk is missing, the object may have been overwritten",
+            )
+            .with_context("chunk", key)
+        })?;
+
+        let from = (self.pos - chunk_start) as usize;
+        let to = ((self.end - chunk_start) as usize).min(chunk.len());
+        if from >= to {
+            return Err(
+                Error::new(ErrorKind::Unexpected, "chunk is shorter than expected")
+                    .with_context("chunk", ChunkedKv::<S>::chunk_key(&manifest.id, index)),
+            );
+        }
+
+        self.pos = chunk_start + to as u64;
+        Ok(chunk.slice(from..to))
+    }
+}
+
+/// Writer returned by [`ChunkedKv::writer`].
+///
+/// Full chunks are stored as soon as they're written, the object becomes
+/// visible when `close` swaps in the new manifest.
+pub struct ChunkedKvWriter<S> {
+    kv: ChunkedKv<S>,
+    key: String,
+    id: String,
+    buffer: oio::QueueBuf,
+    chunks: u64,
+    size: u64,
+}
+
+impl<S: ChunkedKvStore> ChunkedKvWriter<S> {
+    async fn flush_chunk(&mut self, chunk: Buffer) -> Result<()> {
+        let key = ChunkedKv::<S>::chunk_key(&self.id, self.chunks);
+        self.size += chunk.len() as u64;
+        self.kv.store.set(&key, chunk).await?;
+        self.chunks += 1;
+        Ok(())
+    }
+}
+
+impl<S: ChunkedKvStore> oio::Write for ChunkedKvWriter<S> {
+    async fn write(&mut self, bs: Buffer) -> Result<()> {
+        self.buffer.push(bs);
+
+        let chunk_size = self.kv.chunk_size;
+        // Keep up to one chunk buffered so that small values can be stored inline.
+        while self.buffer.len() > chunk_size {
+            let buf = self.buffer.take().collect();
+            self.buffer.push(buf.slice(chunk_size..));
+            self.flush_chunk(buf.slice(..chunk_size)).await?;
+        }
+        Ok(())
+    }
+
+    async fn close(&mut self) -> Result<Metadata> {
+        let rest = self.buffer.take().collect();
+        let old = self.kv.load(&self.key).await?;
+
+        // Small values are stored inline, unless they could be mistaken
+        // for a manifest.
+        let magic = ChunkedKv::<S>::MANIFEST_MAGIC;
+        let is_ambiguous =
+            rest.len() >= magic.len() && rest.slice(..magic.len()).to_bytes() == magic;
+        if self.chunks == 0 && !is_ambiguous {
+            self.size = rest.len() as u64;
+            self.kv.store.set(&self.key, rest).await?;
+        } else {
+            if !rest.is_empty() {
+                self.flush_chunk(rest).await?;
+            }
+            let manifest = ChunkManifest {
+                id: self.id.clone(),
+                size: self.size,
+                chunk_size: self.kv.chunk_size as u64,
+            };
+            self.kv.store.set(&self.key, manifest.encode()?).await?;
+        }
+
+        self.kv.delete_replaced(old).await;
+
+        Ok(Metadata::new(EntryMode::from_path(&self.key)).with_content_length(self.size))
+    }
+
+    async fn abort(&mut self) -> Result<()> {
+        self.buffer.clear();
+        self.kv.delete_chunks(&self.id, self.chunks).await?;
+        self.chunks = 0;
+        self.size = 0;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+    use std::sync::Mutex;
+    use std::sync::atomic::AtomicUsize;
+    use std::sync::atomic::Ordering;
+
+    use super::*;
+    use crate::raw::oio::Read;
+    use crate::raw::oio::Write;
+
+    #[derive(Default)]
+    struct MemoryStore {
+        data: Mutex<BTreeMap<String, Buffer>>,
+        gets: AtomicUsize,
+    }
+
+    impl ChunkedKvStore for MemoryStore {
+        async fn get(&self, key: &str) -> Result<Option<Buffer>> {
+            self.gets.fetch_add(1, Ordering::SeqCst);
+            Ok(self.data.lock().unwrap().get(key).cloned())
+        }
+
+        async fn set(&self, key: &str, value: Buffer) -> Result<()> {
+            self.data.lock().unwrap().insert(key.to_string(), value);
+            Ok(())
+        }
+
+        async fn delete(&self, key: &str) -> Result<()> {
+            self.data.lock().unwrap().remove(key);
+            Ok(())
+        }
+    }
+
+    impl MemoryStore {
+        fn chunk_keys(&self) -> usize {
+            let data = self.data.lock().unwrap();
+            data.keys()
+                .filter(|k| ChunkedKv::<Self>::is_chunk_key(k))
+                .count()
+        }
+    }
+
+    async fn write(kv: &ChunkedKv<MemoryStore>, key: &str, content: &[u8]) {
+        let mut w = kv.writer(key);
+        // Write in uneven pieces to exercise the chunk boundaries.
+        for piece in content.chunks(3) {
+            w.write(Buffer::from(piece.to_vec())).await.unwrap();
+        }
+        let meta = w.close().await.unwrap();
+        assert_eq!(meta.content_length(), content.len() as u64);
+    }
+
+    async fn read(kv: &ChunkedKv<MemoryStore>, key: &str, range: BytesRange) -> Vec<u8> {
+        let mut r = kv.read(key, range).await.unwrap().unwrap();
+        r.read_all().await.unwrap().to_vec()
+    }
+
+    #[tokio::test]
+    async fn test_chunked_roundtrip() {
+        let store = Arc::new(MemoryStore::default());
+        let kv = ChunkedKv::new(store.clone(), 4);
+        let content: Vec<u8> = (0..18).collect();
+
+        write(&kv, "large", &content).await;
+        assert_eq!(store.chunk_keys(), 5);
+        assert_eq!(kv.stat("large").await.unwrap(), Some(18));
+        assert_eq!(read(&kv, "large", BytesRange::default()).await, content);
+
+        // Only the manifest and the chunks covering 5..10 are fetched.
+        store.gets.store(0, Ordering::SeqCst);
+        assert_eq!(
+            read(&kv, "large", BytesRange::new(5, Some(5))).await,
+            content[5..10]
+        );
+        assert_eq!(store.gets.load(Ordering::SeqCst), 3);
+
+        // Overwriting replaces the manifest and removes old chunks.
+        write(&kv, "large", b"small").await;
+        assert_eq!(store.chunk_keys(), 2);
+        write(&kv, "large", b"tiny").await;
+        assert_eq!(store.chunk_keys(), 0);
+        assert_eq!(read(&kv, "large", BytesRange::default()).await, b"tiny");
+
+        kv.delete("large").await.unwrap();
+        assert_eq!(kv.stat("large").await.unwrap(), None);
+    }
+
+    #[tokio::test]
+    async fn test_chunked_rename_and_copy() {
+        let store = Arc::new(MemoryStore::default());
+        let kv = ChunkedKv::new(store.clone(), 4);
+        let content: Vec<u8> = (0..10).collect();
+
+        write(&kv, "src", &content).await;
+        write(&kv, "dst", &content).await;
+        assert_eq!(store.chunk_keys(), 6);
+
+        // Renaming moves the manifest and drops the chunks of the replaced object.
+        assert!(kv.rename("src", "dst").await.unwrap());
+        assert_eq!(store.chunk_keys(), 3);
+        assert_eq!(kv.stat("src").await.unwrap(), None);
+        assert_eq!(read(&kv, "dst", BytesRange::default()).await, content);
+
+        // Copies own their chunks, so deleting one keeps the other readable.
+        assert!(kv.copy("dst", "copied").await.unwrap());
+        assert_eq!(store.chunk_keys(), 6);
+        kv.delete("dst").await.unwrap();
+        assert_eq!(read(&kv, "copied", BytesRange::default()).await, content);
+
+        assert!(!kv.rename("src", "dst").await.unwrap());
+        assert!(!kv.copy("src", "dst").await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_chunked_inline_and_abort() {
+        let store = Arc::new(MemoryStore::default());
+        let kv = ChunkedKv::new(store.clone(), 64);
+
+        // Values that look like a manifest must not be stored inline.
+        let mut content = ChunkedKv::<MemoryStore>::MANIFEST_MAGIC.to_vec();
+        content.extend_from_slice(b"not a manifest");
+        write(&kv, "tricky", &content).await;
+        assert_eq!(store.chunk_keys(), 1);
+        assert_eq!(read(&kv, "tricky", BytesRange::default()).await, content);
+
+        let mut w = kv.writer("aborted");
+        w.write(Buffer::from(vec![0; 200])).await.unwrap();
+        assert_eq!(store.chunk_keys(), 4);
+        w.abort().await.unwrap();
+        assert_eq!(store.chunk_keys(), 1);
+        assert_eq!(kv.stat("aborted").await.unwrap(), None);
+    }
+}
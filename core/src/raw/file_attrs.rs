@@ -0,0 +1,428 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::collections::HashMap;
+use std::io;
+use std::path::Path;
+use std::path::PathBuf;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::raw::*;
+use crate::*;
+
+/// Extended attribute of `Content-Type`, follows the freedesktop convention.
+const XATTR_CONTENT_TYPE: &str = "user.mime_type";
+/// Extended attribute of `Cache-Control`.
+const XATTR_CACHE_CONTROL: &str = "user.cache_control";
+/// Prefix of extended attributes that store user metadata.
+const XATTR_USER_METADATA_PREFIX: &str = "user.meta.";
+/// Suffix of the sidecar file used while extended attributes are not supported.
+const SIDECAR_SUFFIX: &str = ".opendal-meta";
+
+/// Object metadata of a local file that can't be represented by the
+/// filesystem itself.
+///
+/// The metadata is stored in `user.*` extended attributes:
+///
+/// - `content_type` in `user.mime_type`
+/// - `cache_control` in `user.cache_control`
+/// - `user_metadata` in `user.meta.{key}`
+///
+/// If the filesystem or platform doesn't support extended attributes, they
+/// will be stored in a hidden sidecar file `.{name}.opendal-meta` next to
+/// the file instead.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub(crate) struct FileAttrs {
+    pub content_type: Option<String>,
+    pub cache_control: Option<String>,
+    pub user_metadata: HashMap<String, String>,
+}
+
+impl FileAttrs {
+    /// Build attributes from the write op.
+    pub fn from_op(op: &OpWrite) -> Self {
+        Self {
+            content_type: op.content_type().map(String::from),
+            cache_control: op.cache_control().map(String::from),
+            user_metadata: op.user_metadata().cloned().unwrap_or_default(),
+        }
+    }
+
+    /// Returns `true` if there are no attributes to store.
+    pub fn is_empty(&self) -> bool {
+        self.content_type.is_none() && self.cache_control.is_none() && self.user_metadata.is_empty()
+    }
+
+    /// Fill the attributes into metadata.
+    pub fn into_metadata(self, mut meta: Metadata) -> Metadata {
+        if let Some(v) = self.content_type {
+            meta = meta.with_content_type(v);
+        }
+        if let Some(v) = self.cache_control {
+            meta = meta.with_cache_control(v);
+        }
+        if !self.user_metadata.is_empty() {
+            meta = meta.with_user_metadata(self.user_metadata);
+        }
+        meta
+    }
+
+    /// Load the attributes of the file at `path`.
+    pub fn load(path: &Path) -> Result<Self> {
+        match xattr::load(path).map_err(new_std_io_error)? {
+            Some(attrs) if !attrs.is_empty() => Ok(attrs),
+            // Attributes that don't fit in extended attributes are stored
+            // in the sidecar file.
+            _ => load_sidecar(path),
+        }
+    }
+
+    /// Replace the attributes of the file at `path`.
+    ///
+    /// Attributes not managed by OpenDAL are kept as is.
+    pub fn store(&self, path: &Path) -> Result<()> {
+        if self.is_empty() {
+            return Self::clear(path);
+        }
+        if xattr::store(path, self).map_err(new_std_io_error)? {
+            return remove_file_attrs(path);
+        }
+        store_sidecar(path, self)
+    }
+
+    /// Remove all attributes of the file at `path`.
+    pub fn clear(path: &Path) -> Result<()> {
+        xattr::clear(path).map_err(new_std_io_error)?;
+        remove_file_attrs(path)
+    }
+}
+
+/// Returns `true` if the file name is a sidecar file that should be hidden
+/// from listing.
+pub(crate) fn is_sidecar_file(name: &str) -> bool {
+    name.starts_with('.') && name.ends_with(SIDECAR_SUFFIX) && name.len() > SIDECAR_SUFFIX.len() + 1
+}
+
+/// Remove the sidecar file of `path` if exists.
+///
+/// Extended attributes are removed together with the file, so only the
+/// sidecar file needs to be cleaned up.
+pub(crate) fn remove_file_attrs(path: &Path) -> Result<()> {
+    let Some(sidecar) = sidecar_path(path) else {
+        return Ok(());
+    };
+    match std::fs::remove_file(sidecar) {
+        Err(err) if err.kind() != io::ErrorKind::NotFound => Err(new_std_io_error(err)),
+        _ => Ok(()),
+    }
+}
+
+/// Move the sidecar file of `from` to `to` if exists.
+pub(crate) fn rename_file_attrs(from: &Path, to: &Path) -> Result<()> {
+    let (Some(from), Some(to)) = (sidecar_path(from), sidecar_path(to)) else {
+        return Ok(());
+    };
+    match std::fs::rename(from, &to) {
+        Ok(()) => Ok(()),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => match std::fs::remove_file(to) {
+            Err(err) if err.kind() != io::ErrorKind::NotFound => Err(new_std_io_error(err)),
+            _ => Ok(()),
+        },
+        Err(err) => Err(new_std_io_error(err)),
+    }
+}
+
+/// Copy the attributes of `from` to `to`.
+pub(crate) fn copy_file_attrs(from: &Path, to: &Path) -> Result<()> {
+    FileAttrs::load(from)?.store(to)
+}
+
+fn sidecar_path(path: &Path) -> Option<PathBuf> {
+    let name = path.file_name()?.to_string_lossy();
+    Some(path.with_file_name(format!(".{name}{SIDECAR_SUFFIX}")))
+}
+
+fn load_sidecar(path: &Path) -> Result<FileAttrs> {
+    let Some(sidecar) = sidecar_path(path) else {
+        return Ok(FileAttrs::default());
+    };
+    match std::fs::read(sidecar) {
+        Ok(bs) => serde_json::from_slice(&bs).map_err(new_json_deserialize_error),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(FileAttrs::default()),
+        Err(err) => Err(new_std_io_error(err)),
+    }
+}
+
+fn store_sidecar(path: &Path, attrs: &FileAttrs) -> Result<()> {
+    if attrs.is_empty() {
+        return remove_file_attrs(path);
+    }
+    let Some(sidecar) = sidecar_path(path) else {
+        return Ok(());
+    };
+    let bs = serde_json::to_vec(attrs).map_err(new_json_serialize_error)?;
+    std::fs::write(sidecar, bs).map_err(new_std_io_error)
+}
+
+#[cfg(any(target_os = "linux", target_os = "android", target_vendor = "apple"))]
+mod xattr {
+    use rustix::fs;
+    use rustix::io::Errno;
+
+    use super::*;
+
+    fn is_unsupported(err: Errno) -> bool {
+        err == Errno::NOTSUP || err == Errno::OPNOTSUPP
+    }
+
+    /// Returns `true` if the attribute can't be stored because of its size, or
+    /// the space for extended attributes is exhausted.
+    fn is_too_large(err: Errno) -> bool {
+        err == Errno::NOSPC || err == Errno::TOOBIG || err == Errno::RANGE
+    }
+
+    fn list(path: &Path) -> std::result::Result<Vec<String>, Errno> {
+        loop {
+            let size = fs::listxattr(path, &mut [0u8; 0])?;
+            let mut buf = vec![0; size];
+            match fs::listxattr(path, &mut buf[..]) {
+                Ok(n) => {
+                    buf.truncate(n);
+                    return Ok(buf
+                        .split(|b| *b == 0)
+                        .filter(|name| !name.is_empty())
+                        .map(|name| String::from_utf8_lossy(name).to_string())
+                        .collect());
+                }
+                // The list changed between two calls, try again.
+                Err(Errno::RANGE) => continue,
+                Err(err) => return Err(err),
+            }
+        }
+    }
+
+    fn get(path: &Path, name: &str) -> std::result::Result<String, Errno> {
+        loop {
+            let size = fs::getxattr(path, name, &mut [0u8; 0])?;
+            let mut buf = vec![0; size];
+            match fs::getxattr(path, name, &mut buf[..]) {
+                Ok(n) => {
+                    buf.truncate(n);
+                    return Ok(String::from_utf8_lossy(&buf).to_string());
+                }
+                Err(Errno::RANGE) => continue,
+                Err(err) => return Err(err),
+            }
+        }
+    }
+
+    fn is_managed(name: &str) -> bool {
+        name == XATTR_CONTENT_TYPE
+            || name == XATTR_CACHE_CONTROL
+            || name.starts_with(XATTR_USER_METADATA_PREFIX)
+    }
+
+    /// Returns `None` if extended attributes are not supported.
+    pub fn load(path: &Path) -> io::Result<Option<FileAttrs>> {
+        let names = match list(path) {
+            Ok(names) => names,
+            Err(err) if is_unsupported(err) => return Ok(None),
+            Err(err) => return Err(err.into()),
+        };
+
+        let mut attrs = FileAttrs::default();
+        for name in names.iter().filter(|name| is_managed(name)) {
+            let value = match get(path, name) {
+                Ok(v) => v,
+                // The attribute has been removed after listing.
+                Err(Errno::NODATA) => continue,
+                Err(err) => return Err(err.into()),
+            };
+            if name == XATTR_CONTENT_TYPE {
+                attrs.content_type = Some(value);
+            } else if name == XATTR_CACHE_CONTROL {
+                attrs.cache_control = Some(value);
+            } else if let Some(key) = name.strip_prefix(XATTR_USER_METADATA_PREFIX) {
+                attrs.user_metadata.insert(key.to_string(), value);
+            }
+        }
+        Ok(Some(attrs))
+    }
+
+    /// Remove all managed attributes.
+    pub fn clear(path: &Path) -> io::Result<()> {
+        let names = match list(path) {
+            Ok(names) => names,
+            Err(err) if is_unsupported(err) => return Ok(()),
+            Err(err) => return Err(err.into()),
+        };
+
+        for name in names.iter().filter(|name| is_managed(name)) {
+            match fs::removexattr(path, name.as_str()) {
+                Ok(()) | Err(Errno::NODATA) => {}
+                Err(err) => return Err(err.into()),
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns `false` if extended attributes are not supported or the
+    /// attributes don't fit in them.
+    pub fn store(path: &Path, attrs: &FileAttrs) -> io::Result<bool> {
+        clear(path)?;
+
+        let set = |name: &str, value: &str| -> io::Result<bool> {
+            match fs::setxattr(path, name, value.as_bytes(), fs::XattrFlags::empty()) {
+                Ok(()) => Ok(true),
+                Err(err) if is_unsupported(err) => Ok(false),
+                Err(err) if is_too_large(err) => {
+                    // Don't leave a part of attributes behind, they will be
+                    // stored in the sidecar file together.
+                    clear(path)?;
+                    Ok(false)
+                }
+                Err(err) => Err(err.into()),
+            }
+        };
+        if let Some(v) = &attrs.content_type {
+            if !set(XATTR_CONTENT_TYPE, v)? {
+                return Ok(false);
+            }
+        }
+        if let Some(v) = &attrs.cache_control {
+            if !set(XATTR_CACHE_CONTROL, v)? {
+                return Ok(false);
+            }
+        }
+        for (k, v) in &attrs.user_metadata {
+            if !set(&format!("{XATTR_USER_METADATA_PREFIX}{k}"), v)? {
+                return Ok(false);
+            }
+        }
+        Ok(true)
+    }
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "android", target_vendor = "apple")))]
+mod xattr {
+    use super::*;
+
+    pub fn load(_: &Path) -> io::Result<Option<FileAttrs>> {
+        Ok(None)
+    }
+
+    pub fn clear(_: &Path) -> io::Result<()> {
+        Ok(())
+    }
+
+    pub fn store(_: &Path, _: &FileAttrs) -> io::Result<bool> {
+        Ok(false)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn attrs() -> FileAttrs {
+        FileAttrs {
+            content_type: Some("text/plain".to_string()),
+            cache_control: Some("no-cache".to_string()),
+            user_metadata: HashMap::from([("location".to_string(), "everywhere".to_string())]),
+        }
+    }
+
+    fn temp_dir() -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("opendal-attrs-{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn test_store_and_load() {
+        let dir = temp_dir();
+        let path = dir.join("file");
+        std::fs::write(&path, "hello").unwrap();
+
+        attrs().store(&path).unwrap();
+        assert_eq!(FileAttrs::load(&path).unwrap(), attrs());
+
+        // Store replaces all managed attributes.
+        let updated = FileAttrs {
+            content_type: Some("text/html".to_string()),
+            ..Default::default()
+        };
+        updated.store(&path).unwrap();
+        assert_eq!(FileAttrs::load(&path).unwrap(), updated);
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_store_too_large_fallback_to_sidecar() {
+        let dir = temp_dir();
+        let path = dir.join("file");
+        std::fs::write(&path, "hello").unwrap();
+
+        // Larger than the max size of an extended attribute value.
+        let mut large = attrs();
+        large
+            .user_metadata
+            .insert("large".to_string(), "x".repeat(128 * 1024));
+        large.store(&path).unwrap();
+        assert!(dir.join(".file.opendal-meta").exists());
+        assert_eq!(FileAttrs::load(&path).unwrap(), large);
+
+        // Storing small attributes again moves them back.
+        attrs().store(&path).unwrap();
+        assert_eq!(FileAttrs::load(&path).unwrap(), attrs());
+
+        FileAttrs::clear(&path).unwrap();
+        assert_eq!(FileAttrs::load(&path).unwrap(), FileAttrs::default());
+        assert!(!dir.join(".file.opendal-meta").exists());
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_sidecar() {
+        let dir = temp_dir();
+        let path = dir.join("file");
+        std::fs::write(&path, "hello").unwrap();
+
+        store_sidecar(&path, &attrs()).unwrap();
+        assert!(dir.join(".file.opendal-meta").exists());
+        assert_eq!(load_sidecar(&path).unwrap(), attrs());
+
+        let to = dir.join("renamed");
+        rename_file_attrs(&path, &to).unwrap();
+        assert_eq!(load_sidecar(&to).unwrap(), attrs());
+        assert_eq!(load_sidecar(&path).unwrap(), FileAttrs::default());
+
+        store_sidecar(&to, &FileAttrs::default()).unwrap();
+        assert!(!dir.join(".renamed.opendal-meta").exists());
+
+        assert!(is_sidecar_file(".file.opendal-meta"));
+        assert!(!is_sidecar_file("file.opendal-meta"));
+        assert!(!is_sidecar_file(".opendal-meta"));
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}
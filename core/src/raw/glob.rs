@@ -0,0 +1,468 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use crate::*;
+
+/// GlobMatcher matches paths against a glob pattern.
+///
+/// Supported syntax:
+///
+/// - `*` matches any sequence of characters except `/`.
+/// - `**` matches any sequence of characters including `/`; `**/` also
+///   matches zero directories.
+/// - `?` matches any single character except `/`.
+/// - `[abc]`, `[a-z]` and `[!a-z]` match a single character in (or not in)
+///   the given set.
+/// - `{a,b}` matches either `a` or `b`, braces can be nested.
+/// - `\` escapes the next character.
+#[derive(Debug, Clone)]
+pub struct GlobMatcher {
+    pattern: String,
+    alternatives: Vec<Vec<Token>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Token {
+    Literal(char),
+    AnyChar,
+    Star,
+    DoubleStar,
+    DoubleStarSlash,
+    Class {
+        negated: bool,
+        ranges: Vec<(char, char)>,
+    },
+}
+
+impl GlobMatcher {
+    /// Compile the given glob pattern.
+    pub fn new(pattern: &str) -> Result<Self> {
+        let alternatives = expand_braces(pattern)?
+            .iter()
+            .map(|v| parse(v))
+            .collect::<Result<Vec<_>>>()
+            .map_err(|err| err.with_context("pattern", pattern))?;
+
+        Ok(Self {
+            pattern: pattern.to_string(),
+            alternatives,
+        })
+    }
+
+    /// Check whether the given string contains glob meta characters.
+    pub fn is_glob(pattern: &str) -> bool {
+        pattern.contains(['*', '?', '[', '{'])
+    }
+
+    /// Escape glob meta characters so that the given string is matched literally.
+    pub fn escape(s: &str) -> String {
+        let mut escaped = String::with_capacity(s.len());
+        for c in s.chars() {
+            if matches!(c, '\\' | '*' | '?' | '[' | ']' | '{' | '}') {
+                escaped.push('\\');
+            }
+            escaped.push(c);
+        }
+        escaped
+    }
+
+    /// Get the pattern of this matcher.
+    pub fn pattern(&self) -> &str {
+        &self.pattern
+    }
+
+    /// Check whether the path matches the pattern.
+    pub fn matches(&self, path: &str) -> bool {
+        let text: Vec<char> = path.chars().collect();
+        self.alternatives
+            .iter()
+            .any(|tokens| match_tokens(tokens, &text))
+    }
+
+    /// Check whether any path that starts with the given prefix could match the pattern.
+    ///
+    /// This is used to skip dirs that can't contain matched entries.
+    pub fn matches_prefix(&self, prefix: &str) -> bool {
+        let text: Vec<char> = prefix.chars().collect();
+        self.alternatives
+            .iter()
+            .any(|tokens| match_prefix_tokens(tokens, &text))
+    }
+
+    /// Get the longest literal prefix that all matched paths start with.
+    pub fn literal_prefix(&self) -> String {
+        let mut prefix: Option<Vec<char>> = None;
+        for tokens in &self.alternatives {
+            let literal: Vec<char> = tokens
+                .iter()
+                .map_while(|token| match token {
+                    Token::Literal(c) => Some(*c),
+                    _ => None,
+                })
+                .collect();
+
+            prefix = Some(match prefix {
+                None => literal,
+                Some(prefix) => prefix
+                    .into_iter()
+                    .zip(literal)
+                    .take_while(|(a, b)| a == b)
+                    .map(|(a, _)| a)
+                    .collect(),
+            });
+        }
+        prefix.unwrap_or_default().into_iter().collect()
+    }
+
+    /// Check whether the pattern could match paths nested more than one level
+    /// under the given dir.
+    ///
+    /// The dir must be a prefix of [`GlobMatcher::literal_prefix`].
+    pub fn matches_nested(&self, dir: &str) -> bool {
+        let skip = dir.chars().count();
+        self.alternatives.iter().any(|tokens| {
+            let rest = tokens.get(skip..).unwrap_or_default();
+            rest.iter().enumerate().any(|(idx, token)| match token {
+                // A trailing `/` only matches the dir itself.
+                Token::Literal('/') => idx + 1 != rest.len(),
+                Token::DoubleStar | Token::DoubleStarSlash => true,
+                _ => false,
+            })
+        })
+    }
+}
+
+/// Expand `{a,b}` into separate patterns so that matching only needs to
+/// handle the remaining tokens.
+fn expand_braces(pattern: &str) -> Result<Vec<String>> {
+    let chars: Vec<char> = pattern.chars().collect();
+
+    // Find the first top level brace group.
+    let mut start = None;
+    let mut idx = 0;
+    while idx < chars.len() {
+        match chars[idx] {
+            '\\' => idx += 1,
+            '{' => {
+                start = Some(idx);
+                break;
+            }
+            '}' => {
+                return Err(Error::new(
+                    ErrorKind::ConfigInvalid,
+                    "glob pattern has unmatched '}'",
+                ));
+            }
+            _ => {}
+        }
+        idx += 1;
+    }
+    let Some(start) = start else {
+        return Ok(vec![pattern.to_string()]);
+    };
+
+    let mut depth = 0;
+    let mut parts = vec![];
+    let mut part_start = start + 1;
+    let mut end = None;
+    let mut idx = start;
+    while idx < chars.len() {
+        match chars[idx] {
+            '\\' => idx += 1,
+            '{' => depth += 1,
+            '}' => {
+                depth -= 1;
+                if depth == 0 {
+                    parts.push(&chars[part_start..idx]);
+                    end = Some(idx);
+                    break;
+                }
+            }
+            ',' if depth == 1 => {
+                parts.push(&chars[part_start..idx]);
+                part_start = idx + 1;
+            }
+            _ => {}
+        }
+        idx += 1;
+    }
+    let Some(end) = end else {
+        return Err(Error::new(
+            ErrorKind::ConfigInvalid,
+            "glob pattern has unmatched '{'",
+        ));
+    };
+
+    let prefix: String = chars[..start].iter().collect();
+    let suffix: String = chars[end + 1..].iter().collect();
+    let mut expanded = vec![];
+    for part in parts {
+        let part: String = part.iter().collect();
+        expanded.extend(expand_braces(&format!("{prefix}{part}{suffix}"))?);
+    }
+    Ok(expanded)
+}
+
+fn parse(pattern: &str) -> Result<Vec<Token>> {
+    let chars: Vec<char> = pattern.chars().collect();
+    let mut tokens = vec![];
+    let mut idx = 0;
+
+    while idx < chars.len() {
+        match chars[idx] {
+            '\\' => {
+                idx += 1;
+                let c = chars.get(idx).ok_or_else(|| {
+                    Error::new(ErrorKind::ConfigInvalid, "glob pattern ends with '\\'")
+                })?;
+                tokens.push(Token::Literal(*c));
+            }
+            '*' if chars.get(idx + 1) == Some(&'*') => {
+                idx += 1;
+                if chars.get(idx + 1) == Some(&'/') {
+                    idx += 1;
+                    tokens.push(Token::DoubleStarSlash);
+                } else {
+                    tokens.push(Token::DoubleStar);
+                }
+            }
+            '*' => tokens.push(Token::Star),
+            '?' => tokens.push(Token::AnyChar),
+            '[' => {
+                idx += 1;
+                let negated = matches!(chars.get(idx), Some('!') | Some('^'));
+                if negated {
+                    idx += 1;
+                }
+
+                let mut ranges = vec![];
+                let mut first = true;
+                loop {
+                    let Some(&c) = chars.get(idx) else {
+                        return Err(Error::new(
+                            ErrorKind::ConfigInvalid,
+                            "glob pattern has unmatched '['",
+                        ));
+                    };
+                    if c == ']' && !first {
+                        break;
+                    }
+                    first = false;
+
+                    if chars.get(idx + 1) == Some(&'-')
+                        && chars.get(idx + 2).is_some_and(|v| *v != ']')
+                    {
+                        ranges.push((c, chars[idx + 2]));
+                        idx += 3;
+                    } else {
+                        ranges.push((c, c));
+                        idx += 1;
+                    }
+                }
+                tokens.push(Token::Class { negated, ranges });
+            }
+            c => tokens.push(Token::Literal(c)),
+        }
+        idx += 1;
+    }
+
+    Ok(tokens)
+}
+
+fn match_tokens(tokens: &[Token], text: &[char]) -> bool {
+    let Some((token, rest)) = tokens.split_first() else {
+        return text.is_empty();
+    };
+
+    match token {
+        Token::Literal(c) => text.first() == Some(c) && match_tokens(rest, &text[1..]),
+        Token::AnyChar => text.first().is_some_and(|v| *v != '/') && match_tokens(rest, &text[1..]),
+        Token::Class { negated, ranges } => match text.first() {
+            Some(&c) if c != '/' => {
+                let hit = ranges.iter().any(|(lo, hi)| *lo <= c && c <= *hi);
+                hit != *negated && match_tokens(rest, &text[1..])
+            }
+            _ => false,
+        },
+        Token::Star => {
+            for idx in 0..=text.len() {
+                if match_tokens(rest, &text[idx..]) {
+                    return true;
+                }
+                if text.get(idx) == Some(&'/') {
+                    break;
+                }
+            }
+            false
+        }
+        Token::DoubleStar => (0..=text.len()).any(|idx| match_tokens(rest, &text[idx..])),
+        Token::DoubleStarSlash => {
+            match_tokens(rest, text)
+                || (1..=text.len())
+                    .filter(|idx| text[idx - 1] == '/')
+                    .any(|idx| match_tokens(rest, &text[idx..]))
+        }
+    }
+}
+
+/// Check whether the text could be the prefix of a matched string.
+fn match_prefix_tokens(tokens: &[Token], text: &[char]) -> bool {
+    if text.is_empty() {
+        return true;
+    }
+    let Some((token, rest)) = tokens.split_first() else {
+        return false;
+    };
+
+    match token {
+        Token::Literal(c) => text[0] == *c && match_prefix_tokens(rest, &text[1..]),
+        Token::AnyChar => text[0] != '/' && match_prefix_tokens(rest, &text[1..]),
+        Token::Class { negated, ranges } => {
+            let c = text[0];
+            let hit = ranges.iter().any(|(lo, hi)| *lo <= c && c <= *hi);
+            c != '/' && hit != *negated && match_prefix_tokens(rest, &text[1..])
+        }
+        Token::Star => {
+            for idx in 0..=text.len() {
+                if match_prefix_tokens(rest, &text[idx..]) {
+                    return true;
+                }
+                if text.get(idx) == Some(&'/') {
+                    break;
+                }
+            }
+            false
+        }
+        // `**` and `**/` can consume the whole text and still be continued.
+        Token::DoubleStar | Token::DoubleStarSlash => true,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_glob_matches() {
+        let cases = [
+            ("*.txt", "a.txt", true),
+            ("*.txt", "dir/a.txt", false),
+            ("**/*.txt", "a.txt", true),
+            ("**/*.txt", "dir/sub/a.txt", true),
+            ("logs/**", "logs/", true),
+            ("logs/**", "logs/2024/01.log", true),
+            ("logs/**", "log/a", false),
+            ("file-?.csv", "file-1.csv", true),
+            ("file-?.csv", "file-10.csv", false),
+            ("data/[a-c]*", "data/banana", true),
+            ("data/[!a-c]*", "data/banana", false),
+            ("*.{jpg,png}", "cat.png", true),
+            ("*.{jpg,png}", "cat.gif", false),
+            ("{a,b/{c,d}}/x", "b/d/x", true),
+            ("\\*.txt", "*.txt", true),
+            ("\\*.txt", "a.txt", false),
+        ];
+
+        for (pattern, path, expected) in cases {
+            let matcher = GlobMatcher::new(pattern).unwrap();
+            assert_eq!(
+                matcher.matches(path),
+                expected,
+                "pattern {pattern} against {path}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_glob_invalid() {
+        for pattern in ["{a,b", "a}", "[ab", "a\\"] {
+            assert!(GlobMatcher::new(pattern).is_err(), "pattern {pattern}");
+        }
+    }
+
+    #[test]
+    fn test_glob_matches_prefix() {
+        let cases = [
+            ("data/*.csv", "data/", true),
+            ("data/*.csv", "data/sub/", false),
+            ("data/*.csv", "logs/", false),
+            ("data/**/*.csv", "data/a/b/", true),
+            ("data/*/2024/*.csv", "data/x/", true),
+            ("data/*/2024/*.csv", "data/x/2023/", false),
+            ("{data,logs}/*.csv", "logs/", true),
+            ("{data,logs}/*.csv", "other/", false),
+            ("data/[0-9]*/x", "data/1a/", true),
+            ("data/[0-9]*/x", "data/a1/", false),
+        ];
+
+        for (pattern, prefix, expected) in cases {
+            let matcher = GlobMatcher::new(pattern).unwrap();
+            assert_eq!(
+                matcher.matches_prefix(prefix),
+                expected,
+                "pattern {pattern} against prefix {prefix}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_glob_literal_prefix() {
+        let cases = [
+            ("data/*.csv", "data/"),
+            ("data/2024-*.csv", "data/2024-"),
+            ("**/*.csv", ""),
+            ("data/{2023,2024}/*.csv", "data/202"),
+            ("a\\*b/*", "a*b/"),
+            ("data/file.csv", "data/file.csv"),
+        ];
+
+        for (pattern, expected) in cases {
+            let matcher = GlobMatcher::new(pattern).unwrap();
+            assert_eq!(matcher.literal_prefix(), expected, "pattern {pattern}");
+        }
+    }
+
+    #[test]
+    fn test_glob_matches_nested() {
+        let cases = [
+            ("data/*.csv", "data/", false),
+            ("data/*/", "data/", false),
+            ("data/*/*.csv", "data/", true),
+            ("data/**", "data/", true),
+            ("data/2024-*.csv", "data/", false),
+            ("**/*.csv", "", true),
+        ];
+
+        for (pattern, dir, expected) in cases {
+            let matcher = GlobMatcher::new(pattern).unwrap();
+            assert_eq!(
+                matcher.matches_nested(dir),
+                expected,
+                "pattern {pattern} under {dir}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_glob_escape() {
+        let pattern = format!("{}*.csv", GlobMatcher::escape("data[1]/{a}*/"));
+        let matcher = GlobMatcher::new(&pattern).unwrap();
+        assert_eq!(matcher.literal_prefix(), "data[1]/{a}*/");
+        assert!(matcher.matches("data[1]/{a}*/x.csv"));
+        assert!(!matcher.matches("data1/a/x.csv"));
+    }
+}
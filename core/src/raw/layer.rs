@@ -177,6 +177,40 @@ pub trait LayeredAccess: Send + Sync + Debug + Unpin + 'static {
     ) -> impl Future<Output = Result<RpPresign>> + MaybeSend {
         self.inner().presign(path, args)
     }
+
+    fn create_symlink(
+        &self,
+        path: &str,
+        target: &str,
+        args: OpCreateSymlink,
+    ) -> impl Future<Output = Result<RpCreateSymlink>> + MaybeSend {
+        self.inner().create_symlink(path, target, args)
+    }
+
+    fn read_link(
+        &self,
+        path: &str,
+        args: OpReadLink,
+    ) -> impl Future<Output = Result<RpReadLink>> + MaybeSend {
+        self.inner().read_link(path, args)
+    }
+
+    fn list_pending_uploads(
+        &self,
+        path: &str,
+        args: OpListPendingUploads,
+    ) -> impl Future<Output = Result<RpListPendingUploads>> + MaybeSend {
+        self.inner().list_pending_uploads(path, args)
+    }
+
+    fn abort_pending_upload(
+        &self,
+        path: &str,
+        upload_id: &str,
+        args: OpAbortPendingUpload,
+    ) -> impl Future<Output = Result<RpAbortPendingUpload>> + MaybeSend {
+        self.inner().abort_pending_upload(path, upload_id, args)
+    }
 }
 
 impl<L: LayeredAccess> Access for L {
@@ -224,6 +258,36 @@ impl<L: LayeredAccess> Access for L {
     async fn presign(&self, path: &str, args: OpPresign) -> Result<RpPresign> {
         LayeredAccess::presign(self, path, args).await
     }
+
+    async fn create_symlink(
+        &self,
+        path: &str,
+        target: &str,
+        args: OpCreateSymlink,
+    ) -> Result<RpCreateSymlink> {
+        LayeredAccess::create_symlink(self, path, target, args).await
+    }
+
+    async fn read_link(&self, path: &str, args: OpReadLink) -> Result<RpReadLink> {
+        LayeredAccess::read_link(self, path, args).await
+    }
+
+    async fn list_pending_uploads(
+        &self,
+        path: &str,
+        args: OpListPendingUploads,
+    ) -> Result<RpListPendingUploads> {
+        LayeredAccess::list_pending_uploads(self, path, args).await
+    }
+
+    async fn abort_pending_upload(
+        &self,
+        path: &str,
+        upload_id: &str,
+        args: OpAbortPendingUpload,
+    ) -> Result<RpAbortPendingUpload> {
+        LayeredAccess::abort_pending_upload(self, path, upload_id, args).await
+    }
 }
 
 #[cfg(test)]
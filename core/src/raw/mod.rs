@@ -42,12 +42,35 @@ mod azure;
 ))]
 pub(crate) use azure::*;
 
+#[cfg(any(
+    feature = "services-cos",
+    feature = "services-obs",
+    feature = "services-oss",
+    feature = "services-s3"
+))]
+mod multipart_upload;
+#[cfg(any(
+    feature = "services-cos",
+    feature = "services-obs",
+    feature = "services-oss",
+    feature = "services-s3"
+))]
+pub(crate) use multipart_upload::*;
+
+#[cfg(any(feature = "services-fs", feature = "services-compfs"))]
+mod file_attrs;
+#[cfg(any(feature = "services-fs", feature = "services-compfs"))]
+pub(crate) use file_attrs::*;
+
 mod layer;
 pub use layer::*;
 
 mod path;
 pub use path::*;
 
+mod glob;
+pub use glob::GlobMatcher;
+
 #[cfg(feature = "internal-path-cache")]
 mod path_cache;
 #[cfg(feature = "internal-path-cache")]
@@ -94,6 +117,12 @@ pub use enum_utils::*;
 mod atomic_util;
 pub use atomic_util::*;
 
+mod chunked_kv;
+pub use chunked_kv::ChunkedKv;
+pub use chunked_kv::ChunkedKvReader;
+pub use chunked_kv::ChunkedKvStore;
+pub use chunked_kv::ChunkedKvWriter;
+
 // Expose as a pub mod to avoid confusing.
 pub mod oio;
 #[cfg(feature = "tests")]
@@ -0,0 +1,216 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Multipart upload helpers.
+//!
+//! This module provides shared abstractions for services that implement the
+//! s3 compatible `ListMultipartUploads` API, such as s3, oss, cos and obs.
+
+use bytes::Buf;
+use serde::Deserialize;
+
+use crate::raw::*;
+use crate::*;
+
+/// Result of ListMultipartUploads
+#[derive(Default, Debug, Deserialize)]
+#[serde(default, rename_all = "PascalCase")]
+pub struct ListMultipartUploadsResult {
+    pub is_truncated: Option<bool>,
+    pub next_key_marker: Option<String>,
+    pub next_upload_id_marker: Option<String>,
+    pub upload: Vec<ListMultipartUploadsResultUpload>,
+}
+
+#[derive(Default, Debug, Eq, PartialEq, Deserialize)]
+#[serde(default, rename_all = "PascalCase")]
+pub struct ListMultipartUploadsResultUpload {
+    pub key: String,
+    pub upload_id: String,
+    pub initiated: String,
+}
+
+/// Split the page token of `list_pending_uploads` into `(key_marker, upload_id_marker)`.
+///
+/// The token is built by [`parse_list_multipart_uploads`] as
+/// `{upload_id_marker}\n{key_marker}`, upload ids never contain `\n` while
+/// keys could.
+pub fn parse_multipart_uploads_token(args: &OpListPendingUploads) -> (&str, &str) {
+    match args.page_token().and_then(|v| v.split_once('\n')) {
+        Some((upload_id_marker, key_marker)) => (key_marker, upload_id_marker),
+        None => ("", ""),
+    }
+}
+
+/// Parse a page of `ListMultipartUploads` response.
+pub fn parse_list_multipart_uploads(root: &str, body: Buffer) -> Result<RpListPendingUploads> {
+    let output: ListMultipartUploadsResult =
+        quick_xml::de::from_reader(body.reader()).map_err(new_xml_deserialize_error)?;
+
+    let mut uploads = Vec::with_capacity(output.upload.len());
+    for upload in output.upload {
+        let path = build_rel_path(root, &upload.key);
+        uploads.push(
+            PendingUpload::new(&path, &upload.upload_id).with_initiated(upload.initiated.parse()?),
+        );
+    }
+
+    let token = match (output.is_truncated, output.next_key_marker) {
+        (Some(true), Some(key_marker)) if !key_marker.is_empty() => Some(format!(
+            "{}\n{key_marker}",
+            output.next_upload_id_marker.unwrap_or_default()
+        )),
+        _ => None,
+    };
+    Ok(RpListPendingUploads::new(uploads).with_next_page_token(token))
+}
+
+#[cfg(test)]
+mod tests {
+    use bytes::Bytes;
+
+    use super::*;
+
+    /// This example is from https://docs.aws.amazon.com/AmazonS3/latest/API/API_ListMultipartUploads.html#API_ListMultipartUploads_Examples
+    #[test]
+    fn test_deserialize_list_multipart_uploads_result() {
+        let bs = Bytes::from(
+            r#"<?xml version="1.0" encoding="UTF-8"?>
+            <ListMultipartUploadsResult xmlns="http://s3.amazonaws.com/doc/2006-03-01/">
+              <Bucket>bucket</Bucket>
+              <KeyMarker></KeyMarker>
+              <UploadIdMarker></UploadIdMarker>
+              <NextKeyMarker>my-movie.m2ts</NextKeyMarker>
+              <NextUploadIdMarker>YW55IGlkZWEgd2h5IGVsdmluZydzIHVwbG9hZCBmYWlsZWQ</NextUploadIdMarker>
+              <MaxUploads>3</MaxUploads>
+              <IsTruncated>true</IsTruncated>
+              <Upload>
+                <Key>my-divisor</Key>
+                <UploadId>XMgbGlrZSBlbHZpbmcncyBub3QgaGF2aW5nIG11Y2ggbHVjaw</UploadId>
+                <StorageClass>STANDARD</StorageClass>
+                <Initiated>2010-11-10T20:48:33.000Z</Initiated>
+              </Upload>
+              <Upload>
+                <Key>my-movie.m2ts</Key>
+                <UploadId>VXBsb2FkIElEIGZvciBlbHZpbmcncyBteS1tb3ZpZS5tMnRzIHVwbG9hZA</UploadId>
+                <StorageClass>STANDARD</StorageClass>
+                <Initiated>2010-11-10T20:48:33.000Z</Initiated>
+              </Upload>
+            </ListMultipartUploadsResult>"#,
+        );
+
+        let out: ListMultipartUploadsResult =
+            quick_xml::de::from_reader(bs.reader()).expect("must success");
+
+        assert_eq!(out.is_truncated, Some(true));
+        assert_eq!(out.next_key_marker.as_deref(), Some("my-movie.m2ts"));
+        assert_eq!(
+            out.next_upload_id_marker.as_deref(),
+            Some("YW55IGlkZWEgd2h5IGVsdmluZydzIHVwbG9hZCBmYWlsZWQ")
+        );
+        assert_eq!(
+            out.upload,
+            vec![
+                ListMultipartUploadsResultUpload {
+                    key: "my-divisor".to_string(),
+                    upload_id: "XMgbGlrZSBlbHZpbmcncyBub3QgaGF2aW5nIG11Y2ggbHVjaw".to_string(),
+                    initiated: "2010-11-10T20:48:33.000Z".to_string(),
+                },
+                ListMultipartUploadsResultUpload {
+                    key: "my-movie.m2ts".to_string(),
+                    upload_id: "VXBsb2FkIElEIGZvciBlbHZpbmcncyBteS1tb3ZpZS5tMnRzIHVwbG9hZA"
+                        .to_string(),
+                    initiated: "2010-11-10T20:48:33.000Z".to_string(),
+                },
+            ]
+        );
+    }
+
+    /// This example is from https://docs.aws.amazon.com/AmazonS3/latest/API/API_ListMultipartUploads.html#API_ListMultipartUploads_Examples
+    #[test]
+    fn test_parse_list_multipart_uploads() {
+        let bs = Buffer::from(
+            r#"<?xml version="1.0" encoding="UTF-8"?>
+            <ListMultipartUploadsResult xmlns="http://s3.amazonaws.com/doc/2006-03-01/">
+              <Bucket>bucket</Bucket>
+              <KeyMarker></KeyMarker>
+              <UploadIdMarker></UploadIdMarker>
+              <NextKeyMarker>root/my-movie.m2ts</NextKeyMarker>
+              <NextUploadIdMarker>YW55IGlkZWEgd2h5IGVsdmluZydzIHVwbG9hZCBmYWlsZWQ</NextUploadIdMarker>
+              <MaxUploads>3</MaxUploads>
+              <IsTruncated>true</IsTruncated>
+              <Upload>
+                <Key>root/my-divisor</Key>
+                <UploadId>XMgbGlrZSBlbHZpbmcncyBub3QgaGF2aW5nIG11Y2ggbHVjaw</UploadId>
+                <StorageClass>STANDARD</StorageClass>
+                <Initiated>2010-11-10T20:48:33.000Z</Initiated>
+              </Upload>
+              <Upload>
+                <Key>root/my-movie.m2ts</Key>
+                <UploadId>VXBsb2FkIElEIGZvciBlbHZpbmcncyBteS1tb3ZpZS5tMnRzIHVwbG9hZA</UploadId>
+                <StorageClass>STANDARD</StorageClass>
+                <Initiated>2010-11-10T20:48:33.000Z</Initiated>
+              </Upload>
+            </ListMultipartUploadsResult>"#
+                .as_bytes()
+                .to_vec(),
+        );
+
+        let (uploads, token) = parse_list_multipart_uploads("/root/", bs)
+            .expect("must success")
+            .into_page();
+        let initiated: Timestamp = "2010-11-10T20:48:33.000Z".parse().unwrap();
+        assert_eq!(
+            uploads,
+            vec![
+                PendingUpload::new(
+                    "my-divisor",
+                    "XMgbGlrZSBlbHZpbmcncyBub3QgaGF2aW5nIG11Y2ggbHVjaw"
+                )
+                .with_initiated(initiated),
+                PendingUpload::new(
+                    "my-movie.m2ts",
+                    "VXBsb2FkIElEIGZvciBlbHZpbmcncyBteS1tb3ZpZS5tMnRzIHVwbG9hZA"
+                )
+                .with_initiated(initiated),
+            ]
+        );
+
+        let args = OpListPendingUploads::new().with_page_token(&token.unwrap());
+        assert_eq!(
+            parse_multipart_uploads_token(&args),
+            (
+                "root/my-movie.m2ts",
+                "YW55IGlkZWEgd2h5IGVsdmluZydzIHVwbG9hZCBmYWlsZWQ"
+            )
+        );
+        assert_eq!(
+            parse_multipart_uploads_token(&OpListPendingUploads::new()),
+            ("", "")
+        );
+    }
+
+    #[test]
+    fn test_parse_last_page() {
+        let bs = Buffer::from(
+            r#"<ListMultipartUploadsResult><IsTruncated>false</IsTruncated></ListMultipartUploadsResult>"#,
+        );
+        let rp = parse_list_multipart_uploads("/", bs).expect("must success");
+        assert!(rp.uploads().is_empty());
+        assert_eq!(rp.next_page_token(), None);
+    }
+}
@@ -0,0 +1,62 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::sync::Arc;
+use std::sync::Mutex;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+/// PageCursor is the position of a paged listing.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PageCursor {
+    /// The token used to fetch the current page, empty for the first page.
+    pub token: String,
+    /// The number of entries that have been consumed in the current page.
+    pub skip: usize,
+}
+
+/// ListContinuation is shared between [`Lister`] and the underlying listers
+/// to record the position of the listing.
+///
+/// [`oio::PageLister`] updates it while entries are consumed, and users can
+/// read it via [`Lister::continuation_token`].
+///
+/// [`Lister`]: crate::Lister
+/// [`Lister::continuation_token`]: crate::Lister::continuation_token
+/// [`oio::PageLister`]: crate::raw::oio::PageLister
+#[derive(Debug, Clone, Default)]
+pub struct ListContinuation(Arc<Mutex<Option<PageCursor>>>);
+
+impl ListContinuation {
+    /// Get the latest cursor.
+    pub fn get(&self) -> Option<PageCursor> {
+        self.0.lock().expect("lock must be acquired").clone()
+    }
+
+    /// Replace the cursor.
+    pub fn set(&self, cursor: Option<PageCursor>) {
+        *self.0.lock().expect("lock must be acquired") = cursor;
+    }
+
+    /// Record an entry that has been consumed in the current page.
+    pub fn advance(&self) {
+        if let Some(cursor) = self.0.lock().expect("lock must be acquired").as_mut() {
+            cursor.skip += 1;
+        }
+    }
+}
@@ -59,6 +59,7 @@ pub struct FlatLister<A: Access, L> {
 
     next_dir: Option<oio::Entry>,
     active_lister: Vec<(Option<oio::Entry>, L)>,
+    glob: Option<GlobMatcher>,
 }
 
 /// # Safety
@@ -80,8 +81,21 @@ where
             acc,
             next_dir: Some(oio::Entry::new(path, Metadata::new(EntryMode::DIR))),
             active_lister: vec![],
+            glob: None,
         }
     }
+
+    /// Skip walking into dirs that can't contain entries matching the glob.
+    ///
+    /// Skipped dirs themselves are still returned.
+    pub fn with_glob(mut self, glob: Option<GlobMatcher>) -> Self {
+        self.glob = glob;
+        self
+    }
+
+    fn should_walk(&self, dir: &str) -> bool {
+        self.glob.as_ref().is_none_or(|g| g.matches_prefix(dir))
+    }
 }
 
 impl<A, L> oio::List for FlatLister<A, L>
@@ -99,6 +113,9 @@ where
                     if v.mode().is_dir() {
                         // should not loop itself again
                         if v.path() != de.path() {
+                            if !self.should_walk(v.path()) {
+                                return Ok(Some(v));
+                            }
                             self.next_dir = Some(v);
                             continue;
                         }
@@ -117,6 +134,9 @@ where
                 Some(v) if v.mode().is_dir() => {
                     // should not loop itself again
                     if v.path() != de.as_ref().expect("de should not be none here").path() {
+                        if !self.should_walk(v.path()) {
+                            return Ok(Some(v));
+                        }
                         self.next_dir = Some(v);
                         continue;
                     }
@@ -54,6 +54,22 @@ impl<P> HierarchyLister<P> {
         }
     }
 
+    /// Skip the dir that contains `start_after`.
+    ///
+    /// Entries are listed in order, so the dir has been returned before
+    /// `start_after` and must not be returned again while listing the keys
+    /// after it.
+    pub fn with_start_after(mut self, start_after: Option<&str>) -> Self {
+        let Some(rest) = start_after.and_then(|v| v.strip_prefix(&self.path)) else {
+            return self;
+        };
+        if let Some(idx) = rest.find('/') {
+            let dir = format!("{}{}", self.path, &rest[..=idx]);
+            self.visited.insert(dir);
+        }
+        self
+    }
+
     /// ## NOTES
     ///
     /// We take `&mut Entry` here because we need to perform modification on entry in the case like
@@ -0,0 +1,214 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::future::Future;
+use std::sync::Arc;
+use std::vec::IntoIter;
+
+use crate::raw::*;
+use crate::*;
+
+/// Default number of keys fetched per scan.
+const DEFAULT_SCAN_LIMIT: usize = 1000;
+
+/// KeyScan is used to implement [`oio::List`] based on ordered key range scans,
+/// like SQL tables indexed on the key column.
+///
+/// # Architecture
+///
+/// The architecture after adopting [`KeyScan`]:
+///
+/// - Services impl `KeyScan`
+/// - `KeyScanLister` impl `List`
+/// - Expose `KeyScanLister` as `Accessor::Lister`
+pub trait KeyScan: Send + Sync + 'static {
+    /// Scan at most `limit` keys starting at `prefix` in ascending byte order.
+    ///
+    /// If `after` is given, only keys strictly greater than it must be returned.
+    /// Keys outside of `prefix` may be returned, [`KeyScanLister`] stops at the
+    /// first of them.
+    fn scan(
+        &self,
+        prefix: &str,
+        after: Option<&str>,
+        limit: usize,
+    ) -> impl Future<Output = Result<Vec<String>>> + MaybeSend;
+}
+
+/// KeyScanLister implements [`oio::List`] based on [`KeyScan`].
+///
+/// Each scan starts right after the last key of the previous one, so listing
+/// huge tables never needs `OFFSET`.
+pub struct KeyScanLister<S: KeyScan> {
+    inner: Arc<S>,
+    root: String,
+    prefix: String,
+    cursor: Option<String>,
+    limit: usize,
+
+    keys: IntoIter<String>,
+    done: bool,
+}
+
+impl<S> KeyScanLister<S>
+where
+    S: KeyScan,
+{
+    /// Create a new KeyScanLister for keys under the absolute `prefix`.
+    pub fn new(inner: Arc<S>, root: &str, prefix: String, args: &OpList) -> Self {
+        // `start_after` only matters if it's located after the prefix itself.
+        let cursor = args
+            .start_after()
+            .map(|v| build_abs_path(root, v))
+            .filter(|v| v.as_str() >= prefix.as_str());
+
+        Self {
+            inner,
+            root: root.to_string(),
+            prefix,
+            cursor,
+            limit: args.limit().unwrap_or(DEFAULT_SCAN_LIMIT).max(1),
+
+            keys: Vec::new().into_iter(),
+            done: false,
+        }
+    }
+}
+
+impl<S> oio::List for KeyScanLister<S>
+where
+    S: KeyScan,
+{
+    async fn next(&mut self) -> Result<Option<oio::Entry>> {
+        loop {
+            if let Some(key) = self.keys.next() {
+                let mode = if key.ends_with('/') {
+                    EntryMode::DIR
+                } else {
+                    EntryMode::FILE
+                };
+                let mut path = build_rel_path(&self.root, &key);
+                if path.is_empty() {
+                    path = "/".to_string();
+                }
+                return Ok(Some(oio::Entry::new(&path, Metadata::new(mode))));
+            }
+
+            if self.done {
+                return Ok(None);
+            }
+
+            let mut keys = self
+                .inner
+                .scan(&self.prefix, self.cursor.as_deref(), self.limit)
+                .await?;
+            self.done = keys.len() < self.limit;
+            self.cursor = keys.last().cloned();
+
+            // Keys are returned in byte order, the first key outside of the
+            // prefix means we have reached the end of the range.
+            if let Some(idx) = keys.iter().position(|k| !k.starts_with(&self.prefix)) {
+                keys.truncate(idx);
+                self.done = true;
+            }
+            self.keys = keys.into_iter();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+    use std::sync::Mutex;
+
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::raw::oio::List;
+
+    /// TestKeyScan serves keys from a sorted set and records every scan.
+    struct TestKeyScan {
+        keys: BTreeSet<String>,
+        scans: Mutex<usize>,
+    }
+
+    impl TestKeyScan {
+        fn new(keys: &[&str]) -> Arc<Self> {
+            Arc::new(Self {
+                keys: keys.iter().map(|k| k.to_string()).collect(),
+                scans: Mutex::new(0),
+            })
+        }
+    }
+
+    impl KeyScan for TestKeyScan {
+        async fn scan(
+            &self,
+            prefix: &str,
+            after: Option<&str>,
+            limit: usize,
+        ) -> Result<Vec<String>> {
+            *self.scans.lock().unwrap() += 1;
+            Ok(self
+                .keys
+                .iter()
+                .filter(|k| match after {
+                    Some(after) => k.as_str() > after,
+                    None => k.as_str() >= prefix,
+                })
+                .take(limit)
+                .cloned()
+                .collect())
+        }
+    }
+
+    async fn collect<S: KeyScan>(mut lister: KeyScanLister<S>) -> Result<Vec<String>> {
+        let mut paths = vec![];
+        while let Some(entry) = lister.next().await? {
+            paths.push(entry.path().to_string());
+        }
+        Ok(paths)
+    }
+
+    #[tokio::test]
+    async fn test_key_scan_lister_stops_at_prefix_end() -> Result<()> {
+        let scan = TestKeyScan::new(&["a/1", "b/", "b/1", "b/2", "b/3", "b0", "c/1"]);
+        let args = OpList::new().with_limit(2);
+        let lister = KeyScanLister::new(scan.clone(), "/", "b/".to_string(), &args);
+
+        let paths = collect(lister).await?;
+        assert_eq!(paths, ["b/", "b/1", "b/2", "b/3"]);
+        // `b0` in the third scan ends the listing.
+        assert_eq!(*scan.scans.lock().unwrap(), 3);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_key_scan_lister_with_start_after() -> Result<()> {
+        let scan = TestKeyScan::new(&["b/1", "b/2", "b/3"]);
+
+        let args = OpList::new().with_start_after("b/1");
+        let lister = KeyScanLister::new(scan.clone(), "/", "b/".to_string(), &args);
+        assert_eq!(collect(lister).await?, ["b/2", "b/3"]);
+
+        // `start_after` before the prefix is ignored.
+        let args = OpList::new().with_start_after("a");
+        let lister = KeyScanLister::new(scan, "/", "b/".to_string(), &args);
+        assert_eq!(collect(lister).await?, ["b/1", "b/2", "b/3"]);
+        Ok(())
+    }
+}
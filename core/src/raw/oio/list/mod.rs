@@ -20,6 +20,10 @@ pub use api::List;
 pub use api::ListDyn;
 pub use api::Lister;
 
+mod continuation;
+pub use continuation::ListContinuation;
+pub use continuation::PageCursor;
+
 mod page_list;
 pub use page_list::PageContext;
 pub use page_list::PageList;
@@ -33,3 +37,10 @@ pub use hierarchy_list::HierarchyLister;
 
 mod prefix_list;
 pub use prefix_list::PrefixLister;
+
+mod partition_list;
+pub use partition_list::PartitionLister;
+
+mod key_scan_list;
+pub use key_scan_list::KeyScan;
+pub use key_scan_list::KeyScanLister;
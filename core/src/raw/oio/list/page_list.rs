@@ -63,6 +63,10 @@ pub struct PageContext {
 pub struct PageLister<L: PageList> {
     inner: L,
     ctx: PageContext,
+
+    continuation: Option<oio::ListContinuation>,
+    /// The number of entries to skip in the first page while resuming.
+    skip: usize,
 }
 
 impl<L> PageLister<L>
@@ -78,8 +82,28 @@ where
                 token: "".to_string(),
                 entries: VecDeque::new(),
             },
+            continuation: None,
+            skip: 0,
         }
     }
+
+    /// Record the position of this lister in the continuation of [`OpList`], and
+    /// resume from the cursor in it if exists.
+    ///
+    /// Services should only call this if the token of [`PageContext`] can be reused
+    /// to fetch the same page again.
+    pub fn with_continuation(mut self, args: &OpList) -> Self {
+        let Some(continuation) = args.continuation() else {
+            return self;
+        };
+
+        if let Some(cursor) = continuation.get() {
+            self.ctx.token = cursor.token;
+            self.skip = cursor.skip;
+        }
+        self.continuation = Some(continuation.clone());
+        self
+    }
 }
 
 impl<L> oio::List for PageLister<L>
@@ -89,13 +113,97 @@ where
     async fn next(&mut self) -> Result<Option<oio::Entry>> {
         loop {
             if let Some(entry) = self.ctx.entries.pop_front() {
+                if let Some(continuation) = &self.continuation {
+                    continuation.advance();
+                }
+                if self.skip > 0 {
+                    self.skip -= 1;
+                    continue;
+                }
                 return Ok(Some(entry));
             }
             if self.ctx.done {
                 return Ok(None);
             }
 
+            let token = self.ctx.token.clone();
             self.inner.next_page(&mut self.ctx).await?;
+            if let Some(continuation) = &self.continuation {
+                continuation.set(Some(oio::PageCursor { token, skip: 0 }));
+            }
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::raw::oio::List;
+
+    /// TestPageList returns pages of 3 entries, the token is the index of next page.
+    struct TestPageList {
+        total: usize,
+    }
+
+    impl PageList for TestPageList {
+        async fn next_page(&self, ctx: &mut PageContext) -> Result<()> {
+            let start = if ctx.token.is_empty() {
+                0
+            } else {
+                ctx.token.parse::<usize>().expect("token must be valid")
+            };
+            let end = (start + 3).min(self.total);
+            for idx in start..end {
+                ctx.entries.push_back(oio::Entry::new(
+                    &format!("file-{idx}"),
+                    Metadata::new(EntryMode::FILE),
+                ));
+            }
+            ctx.token = end.to_string();
+            ctx.done = end == self.total;
+            Ok(())
+        }
+    }
+
+    async fn collect(lister: &mut PageLister<TestPageList>, n: usize) -> Result<Vec<String>> {
+        let mut paths = vec![];
+        while paths.len() < n {
+            match lister.next().await? {
+                Some(entry) => paths.push(entry.path().to_string()),
+                None => break,
+            }
+        }
+        Ok(paths)
+    }
+
+    #[tokio::test]
+    async fn test_page_lister_with_continuation() -> Result<()> {
+        let continuation = oio::ListContinuation::default();
+        let args = OpList::new().with_continuation(continuation.clone());
+        let mut lister = PageLister::new(TestPageList { total: 8 }).with_continuation(&args);
+
+        let paths = collect(&mut lister, 5).await?;
+        assert_eq!(paths, ["file-0", "file-1", "file-2", "file-3", "file-4"]);
+        assert_eq!(
+            continuation.get(),
+            Some(oio::PageCursor {
+                token: "3".to_string(),
+                skip: 2,
+            })
+        );
+
+        let continuation = oio::ListContinuation::default();
+        continuation.set(Some(oio::PageCursor {
+            token: "3".to_string(),
+            skip: 2,
+        }));
+        let args = OpList::new().with_continuation(continuation);
+        let mut lister = PageLister::new(TestPageList { total: 8 }).with_continuation(&args);
+
+        let paths = collect(&mut lister, usize::MAX).await?;
+        assert_eq!(paths, ["file-5", "file-6", "file-7"]);
+        Ok(())
+    }
+}
@@ -0,0 +1,677 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::collections::VecDeque;
+use std::future::poll_fn;
+use std::mem;
+use std::task::Context;
+use std::task::Poll;
+use std::task::ready;
+
+use crate::raw::oio::List;
+use crate::raw::*;
+use crate::*;
+
+/// The max number of entries that can be read ahead for each partition.
+const PARTITION_BUFFER: usize = 1000;
+
+/// The max depth of prefixes to split while discovering partitions.
+///
+/// The longest common prefix of the keys is split first, then each of its
+/// sub-prefixes is split again until there are enough partitions.
+const MAX_SPLIT_DEPTH: usize = 2;
+
+type NextEntryFuture = BoxedStaticFuture<(oio::Lister, Result<Option<oio::Entry>>)>;
+type NextPartitionFuture = BoxedStaticFuture<(Box<Discovery>, Result<Option<Partition>>)>;
+
+/// PartitionLister splits a recursive listing into partitions and lists them concurrently.
+///
+/// The keyspace is split by:
+///
+/// - The sub-prefixes of the longest common prefix of all keys if the service supports
+///   `list_with_start_after`. Every sub-prefix is discovered by a listing that starts
+///   after all keys of the previous one, and large sub-prefixes are split again until
+///   there are `concurrent` partitions. Partitions are discovered in the background, so
+///   listing starts as soon as the first partition is found.
+/// - The dirs returned by listing the path non-recursively otherwise. Files under the
+///   path will be kept in memory.
+///
+/// Partitions are merged in key order by default, so the output is sorted if the service
+/// lists in sorted order. With `unordered`, entries are returned as soon as they are listed.
+pub struct PartitionLister {
+    acc: Accessor,
+    path: String,
+    args: OpList,
+    concurrent: usize,
+    unordered: bool,
+
+    discovered: bool,
+    discovery: DiscoveryState,
+    /// The partitions that are not started yet.
+    pending: VecDeque<Partition>,
+    /// The partitions that are being listed in key order.
+    active: VecDeque<Partition>,
+}
+
+/// # Safety
+///
+/// PartitionLister will only be accessed by `&mut Self`.
+unsafe impl Sync for PartitionLister {}
+
+struct Partition {
+    /// The prefix to list, `None` if the partition only contains entries in `buf`.
+    prefix: Option<String>,
+    fut: Option<NextEntryFuture>,
+    buf: VecDeque<oio::Entry>,
+    done: bool,
+}
+
+impl Partition {
+    fn with_prefix(prefix: String) -> Self {
+        Self {
+            prefix: Some(prefix),
+            fut: None,
+            buf: VecDeque::new(),
+            done: false,
+        }
+    }
+
+    fn with_entries(entries: VecDeque<oio::Entry>) -> Self {
+        Self {
+            prefix: None,
+            fut: None,
+            buf: entries,
+            done: true,
+        }
+    }
+
+    fn start(&mut self, acc: Accessor, args: OpList) {
+        let Some(prefix) = self.prefix.clone() else {
+            return;
+        };
+        self.fut = Some(Box::pin(async move {
+            match acc.list(&prefix, args).await {
+                Ok((_, mut lister)) => {
+                    let res = lister.next().await;
+                    (lister, res)
+                }
+                Err(err) => (Box::new(()) as oio::Lister, Err(err)),
+            }
+        }));
+    }
+
+    /// Fill the buffer until it's full or the lister is pending.
+    fn poll_fill(&mut self, cx: &mut Context<'_>) -> Result<()> {
+        while !self.done && self.buf.len() < PARTITION_BUFFER {
+            let Some(fut) = self.fut.as_mut() else {
+                break;
+            };
+            let Poll::Ready((mut lister, res)) = fut.as_mut().poll(cx) else {
+                break;
+            };
+            match res? {
+                Some(entry) => {
+                    self.buf.push_back(entry);
+                    self.fut = Some(Box::pin(async move {
+                        let res = lister.next().await;
+                        (lister, res)
+                    }));
+                }
+                None => {
+                    self.fut = None;
+                    self.done = true;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+enum DiscoveryState {
+    Idle(Box<Discovery>),
+    Running(NextPartitionFuture),
+    Done,
+}
+
+/// Discovery finds partitions in key order with `start_after` skip scans.
+struct Discovery {
+    acc: Accessor,
+    path: String,
+    glob: Option<GlobMatcher>,
+    target: usize,
+
+    /// The number of partitions discovered.
+    count: usize,
+    /// The prefixes being split, the last one is the deepest.
+    frames: Vec<Frame>,
+}
+
+struct Frame {
+    prefix: String,
+    /// Whether `prefix` is the longest common prefix of all keys under it.
+    split: bool,
+    /// Find the next sub-prefix after this key.
+    start_after: Option<String>,
+    /// The next key under this prefix if it's known.
+    next: Option<oio::Entry>,
+}
+
+impl Discovery {
+    fn new(acc: Accessor, path: &str, glob: Option<GlobMatcher>, target: usize) -> Self {
+        let base = if path == "/" { "" } else { path };
+        Self {
+            acc,
+            path: path.to_string(),
+            glob,
+            target,
+
+            count: 0,
+            frames: vec![Frame {
+                prefix: base.to_string(),
+                split: false,
+                start_after: None,
+                next: None,
+            }],
+        }
+    }
+
+    /// Returns the first key after `start_after`.
+    async fn first_key(&self, start_after: Option<&str>) -> Result<Option<oio::Entry>> {
+        let mut args = OpList::new().with_recursive(true).with_limit(1);
+        if let Some(v) = start_after {
+            args = args.with_start_after(v);
+        }
+        let (_, mut lister) = self.acc.list(&self.path, args).await?;
+        lister.next().await
+    }
+
+    /// Returns the longest prefix shared by all keys under `prefix`, given `first`
+    /// is the first of them.
+    ///
+    /// A candidate is shared if the first key after all keys starting with it is not
+    /// under `prefix` anymore, so it can be found by binary search.
+    async fn common_prefix(&self, prefix: &str, first: &str) -> Result<String> {
+        let bounds: Vec<usize> = first[prefix.len()..]
+            .char_indices()
+            .skip(1)
+            .map(|(idx, _)| prefix.len() + idx)
+            .chain([first.len()])
+            .collect();
+
+        // `bounds[..lo]` are shared, `bounds[hi..]` are not.
+        let (mut lo, mut hi) = (0, bounds.len());
+        while lo < hi {
+            let mid = (lo + hi) / 2;
+            let candidate = &first[..bounds[mid]];
+            let next = self
+                .first_key(Some(&format!("{candidate}{}", char::MAX)))
+                .await?;
+            if next.is_none_or(|v| !v.path().starts_with(prefix)) {
+                lo = mid + 1;
+            } else {
+                hi = mid;
+            }
+        }
+        Ok(match lo {
+            0 => prefix.to_string(),
+            n => first[..bounds[n - 1]].to_string(),
+        })
+    }
+
+    async fn next(&mut self) -> Result<Option<Partition>> {
+        loop {
+            let Some(frame) = self.frames.last_mut() else {
+                return Ok(None);
+            };
+            let (prefix, start_after, next) = (
+                frame.prefix.clone(),
+                frame.start_after.clone(),
+                frame.next.take(),
+            );
+
+            let entry = match next {
+                Some(entry) => entry,
+                None => match self.first_key(start_after.as_deref()).await? {
+                    Some(entry) => entry,
+                    None => {
+                        self.frames.clear();
+                        return Ok(None);
+                    }
+                },
+            };
+
+            // All keys under this prefix have been discovered, the entry is the
+            // next key of the parent prefix.
+            if !entry.path().starts_with(&prefix) {
+                self.frames.pop();
+                if let Some(parent) = self.frames.last_mut() {
+                    parent.next = Some(entry);
+                    continue;
+                }
+
+                // Keys could be added while discovering, split the rest of the
+                // path again.
+                let base = if self.path == "/" { "" } else { &self.path };
+                if !entry.path().starts_with(base) {
+                    return Err(Error::new(
+                        ErrorKind::Unexpected,
+                        "service returned entry that doesn't start with the listing path",
+                    )
+                    .with_operation(Operation::List)
+                    .with_context("path", &self.path)
+                    .with_context("entry", entry.path()));
+                }
+                self.frames.push(Frame {
+                    prefix: base.to_string(),
+                    split: false,
+                    start_after: None,
+                    next: Some(entry),
+                });
+                continue;
+            }
+
+            let frame = self.frames.last_mut().expect("frame must exist");
+            // The key of the prefix itself, like the path, is returned before all
+            // keys under it.
+            if entry.path() == prefix || entry.path() == self.path {
+                frame.start_after = Some(entry.path().to_string());
+                self.count += 1;
+                return Ok(Some(Partition::with_entries(VecDeque::from([entry]))));
+            }
+
+            if !frame.split {
+                let lcp = self.common_prefix(&prefix, entry.path()).await?;
+                let frame = self.frames.last_mut().expect("frame must exist");
+                frame.prefix = lcp;
+                frame.split = true;
+                frame.next = Some(entry);
+                continue;
+            }
+
+            let c = entry.path()[prefix.len()..]
+                .chars()
+                .next()
+                .expect("entry must be longer than prefix");
+            let sub = format!("{prefix}{c}");
+            // All keys that start with sub-prefix are sorted before this.
+            frame.start_after = Some(format!("{sub}{}", char::MAX));
+
+            // Skip the sub-prefixes that can't contain entries matching the glob.
+            if self.glob.as_ref().is_some_and(|g| !g.matches_prefix(&sub)) {
+                continue;
+            }
+            if self.frames.len() < MAX_SPLIT_DEPTH && self.count < self.target {
+                self.frames.push(Frame {
+                    prefix: sub,
+                    split: false,
+                    start_after: None,
+                    next: Some(entry),
+                });
+                continue;
+            }
+            self.count += 1;
+            return Ok(Some(Partition::with_prefix(sub)));
+        }
+    }
+}
+
+impl PartitionLister {
+    /// Create a new partition lister.
+    ///
+    /// `args` must be recursive and will be used to list every partition.
+    pub fn new(
+        acc: Accessor,
+        path: &str,
+        args: OpList,
+        concurrent: usize,
+        unordered: bool,
+    ) -> Self {
+        Self {
+            acc,
+            path: path.to_string(),
+            args,
+            concurrent: concurrent.max(1),
+            unordered,
+
+            discovered: false,
+            discovery: DiscoveryState::Done,
+            pending: VecDeque::new(),
+            active: VecDeque::new(),
+        }
+    }
+
+    async fn discover(&mut self) -> Result<()> {
+        if self.acc.info().full_capability().list_with_start_after {
+            self.discovery = DiscoveryState::Idle(Box::new(Discovery::new(
+                self.acc.clone(),
+                &self.path,
+                self.args.glob().cloned(),
+                self.concurrent,
+            )));
+            return Ok(());
+        }
+
+        let mut partitions = self.discover_by_delimiter().await?;
+        // Skip the partitions that can't contain entries matching the glob.
+        if let Some(glob) = self.args.glob() {
+            partitions.retain(|p| p.prefix.as_deref().is_none_or(|v| glob.matches_prefix(v)));
+        }
+        self.pending = partitions;
+        Ok(())
+    }
+
+    async fn discover_by_delimiter(&self) -> Result<VecDeque<Partition>> {
+        let (_, mut lister) = self
+            .acc
+            .list(&self.path, self.args.clone().with_recursive(false))
+            .await?;
+        let mut entries = Vec::new();
+        while let Some(entry) = lister.next().await? {
+            entries.push(entry);
+        }
+        // Recursive listing is emulated by walking dirs if the service can't list
+        // recursively, which returns the path itself as well.
+        if !entries.is_empty()
+            && !self.acc.info().native_capability().list_with_recursive
+            && self.path.ends_with('/')
+            && entries.iter().all(|e| e.path() != self.path)
+        {
+            entries.push(oio::Entry::new(&self.path, Metadata::new(EntryMode::DIR)));
+        }
+        entries.sort_unstable_by(|a, b| a.path().cmp(b.path()));
+
+        let mut partitions = VecDeque::new();
+        let mut files = VecDeque::new();
+        for entry in entries {
+            if entry.mode().is_dir() && entry.path() != self.path {
+                if !files.is_empty() {
+                    partitions.push_back(Partition::with_entries(mem::take(&mut files)));
+                }
+                partitions.push_back(Partition::with_prefix(entry.path().to_string()));
+            } else {
+                files.push_back(entry);
+            }
+        }
+        if !files.is_empty() {
+            partitions.push_back(Partition::with_entries(files));
+        }
+        Ok(partitions)
+    }
+
+    /// Poll the next partition from the background discovery.
+    fn poll_discover(&mut self, cx: &mut Context<'_>) -> Poll<Result<Option<Partition>>> {
+        if let DiscoveryState::Idle(_) = &self.discovery {
+            let DiscoveryState::Idle(mut discovery) =
+                mem::replace(&mut self.discovery, DiscoveryState::Done)
+            else {
+                unreachable!("discovery must be idle")
+            };
+            self.discovery = DiscoveryState::Running(Box::pin(async move {
+                let res = discovery.next().await;
+                (discovery, res)
+            }));
+        }
+        let DiscoveryState::Running(fut) = &mut self.discovery else {
+            return Poll::Ready(Ok(None));
+        };
+
+        let (discovery, res) = ready!(fut.as_mut().poll(cx));
+        self.discovery = match &res {
+            Ok(Some(_)) => DiscoveryState::Idle(discovery),
+            _ => DiscoveryState::Done,
+        };
+        Poll::Ready(res)
+    }
+
+    fn poll_next_entry(&mut self, cx: &mut Context<'_>) -> Poll<Result<Option<oio::Entry>>> {
+        loop {
+            // Keep at most `concurrent` partitions discovered ahead.
+            loop {
+                while self.active.len() < self.concurrent {
+                    let Some(mut partition) = self.pending.pop_front() else {
+                        break;
+                    };
+                    partition.start(self.acc.clone(), self.args.clone());
+                    self.active.push_back(partition);
+                }
+                if self.pending.len() >= self.concurrent {
+                    break;
+                }
+                match self.poll_discover(cx)? {
+                    Poll::Ready(Some(partition)) => self.pending.push_back(partition),
+                    Poll::Ready(None) | Poll::Pending => break,
+                }
+            }
+            if self.active.is_empty() {
+                return match self.discovery {
+                    DiscoveryState::Done => Poll::Ready(Ok(None)),
+                    _ => Poll::Pending,
+                };
+            }
+
+            for partition in self.active.iter_mut() {
+                partition.poll_fill(cx)?;
+            }
+
+            if self.unordered {
+                if let Some(entry) = self.active.iter_mut().find_map(|p| p.buf.pop_front()) {
+                    return Poll::Ready(Ok(Some(entry)));
+                }
+                let len = self.active.len();
+                self.active.retain(|p| !p.done);
+                if self.active.len() == len {
+                    return Poll::Pending;
+                }
+            } else {
+                let head = self.active.front_mut().expect("active must not be empty");
+                if let Some(entry) = head.buf.pop_front() {
+                    return Poll::Ready(Ok(Some(entry)));
+                }
+                if !head.done {
+                    return Poll::Pending;
+                }
+                self.active.pop_front();
+            }
+        }
+    }
+}
+
+impl oio::List for PartitionLister {
+    async fn next(&mut self) -> Result<Option<oio::Entry>> {
+        if !self.discovered {
+            self.discover().await?;
+            self.discovered = true;
+        }
+
+        poll_fn(|cx| self.poll_next_entry(cx)).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+    use std::sync::Arc;
+
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[derive(Debug)]
+    struct MockScan {
+        keys: BTreeSet<String>,
+    }
+
+    impl oio::KeyScan for MockScan {
+        async fn scan(
+            &self,
+            prefix: &str,
+            after: Option<&str>,
+            limit: usize,
+        ) -> Result<Vec<String>> {
+            Ok(self
+                .keys
+                .iter()
+                .filter(|k| match after {
+                    Some(after) => k.as_str() > after,
+                    None => k.as_str() >= prefix,
+                })
+                .take(limit)
+                .cloned()
+                .collect())
+        }
+    }
+
+    #[derive(Debug)]
+    struct MockService {
+        scan: Arc<MockScan>,
+        start_after: bool,
+        /// The prefix that fails to list.
+        fail: Option<String>,
+    }
+
+    impl Access for MockService {
+        type Reader = oio::Reader;
+        type Writer = oio::Writer;
+        type Lister = oio::Lister;
+        type Deleter = oio::Deleter;
+
+        fn info(&self) -> Arc<AccessorInfo> {
+            let info = AccessorInfo::default();
+            info.set_native_capability(Capability {
+                list: true,
+                list_with_recursive: true,
+                list_with_start_after: self.start_after,
+                ..Default::default()
+            });
+            info.into()
+        }
+
+        async fn list(&self, path: &str, args: OpList) -> Result<(RpList, Self::Lister)> {
+            if self.fail.as_deref() == Some(path) {
+                return Err(Error::new(ErrorKind::Unexpected, "mock list failed"));
+            }
+
+            let p = build_abs_path("/", path);
+            let lister = oio::KeyScanLister::new(self.scan.clone(), "/", p, &args);
+            let lister = oio::HierarchyLister::new(lister, path, args.recursive())
+                .with_start_after(args.start_after());
+            Ok((RpList::default(), Box::new(lister)))
+        }
+    }
+
+    fn new_accessor(keys: &[String], start_after: bool, fail: Option<&str>) -> Accessor {
+        Arc::new(MockService {
+            scan: Arc::new(MockScan {
+                keys: keys.iter().cloned().collect(),
+            }),
+            start_after,
+            fail: fail.map(|v| v.to_string()),
+        })
+    }
+
+    /// Keys of 12 months with 2 files each.
+    fn monthly_keys() -> Vec<String> {
+        (1..=12)
+            .flat_map(|m| ["a", "b"].map(|f| format!("data/2024/{m:02}/{f}")))
+            .collect()
+    }
+
+    async fn collect(lister: &mut PartitionLister) -> Result<Vec<String>> {
+        let mut paths = vec![];
+        while let Some(entry) = lister.next().await? {
+            paths.push(entry.path().to_string());
+        }
+        Ok(paths)
+    }
+
+    #[tokio::test]
+    async fn test_discovery_splits_common_prefix() -> Result<()> {
+        let acc = new_accessor(&monthly_keys(), true, None);
+
+        let mut discovery = Discovery::new(acc, "/", None, 4);
+        let mut prefixes = vec![];
+        while let Some(partition) = discovery.next().await? {
+            prefixes.push(partition.prefix.expect("partition must have prefix"));
+        }
+        let mut expected: Vec<String> = (1..=9).map(|m| format!("data/2024/0{m}")).collect();
+        expected.push("data/2024/1".to_string());
+        assert_eq!(prefixes, expected);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_partition_lister_ordered() -> Result<()> {
+        let keys = monthly_keys();
+        for start_after in [true, false] {
+            let acc = new_accessor(&keys, start_after, None);
+            let args = OpList::new().with_recursive(true);
+            let mut lister = PartitionLister::new(acc, "/", args, 4, false);
+            assert_eq!(collect(&mut lister).await?, keys);
+        }
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_partition_lister_unordered() -> Result<()> {
+        let keys = monthly_keys();
+        let acc = new_accessor(&keys, true, None);
+        let args = OpList::new().with_recursive(true);
+        let mut lister = PartitionLister::new(acc, "/", args, 4, true);
+
+        let mut paths = collect(&mut lister).await?;
+        paths.sort();
+        assert_eq!(paths, keys);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_partition_lister_error() -> Result<()> {
+        let acc = new_accessor(&monthly_keys(), true, Some("data/2024/05"));
+        let args = OpList::new().with_recursive(true);
+        let mut lister = PartitionLister::new(acc, "/", args, 4, false);
+
+        let err = collect(&mut lister).await.expect_err("list must fail");
+        assert_eq!(err.kind(), ErrorKind::Unexpected);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_partition_buffer_is_bounded() -> Result<()> {
+        let keys: Vec<String> = (0..PARTITION_BUFFER * 2 + 1)
+            .map(|i| format!("a/{i:05}"))
+            .collect();
+        let acc = new_accessor(&keys, true, None);
+        let args = OpList::new().with_recursive(true);
+
+        let mut partition = Partition::with_prefix("a/".to_string());
+        partition.start(acc, args);
+        let mut total = 0;
+        loop {
+            poll_fn(|cx| Poll::Ready(partition.poll_fill(cx))).await?;
+            assert!(partition.buf.len() <= PARTITION_BUFFER);
+            if partition.buf.is_empty() && partition.done {
+                break;
+            }
+            total += partition.buf.len();
+            partition.buf.clear();
+            // Wait for the pending listers.
+            tokio::task::yield_now().await;
+        }
+        assert_eq!(total, keys.len());
+        Ok(())
+    }
+}
@@ -108,6 +108,9 @@ pub struct BlockWriter<W: BlockWrite> {
 
     started: bool,
     block_ids: Vec<Uuid>,
+    /// The size of blocks that have been submitted, in order.
+    block_sizes: Vec<u64>,
+    checkpoint: Option<oio::WriteCheckpoint>,
     cache: Option<Buffer>,
     tasks: ConcurrentTasks<WriteInput<W>, Uuid>,
 }
@@ -122,6 +125,8 @@ impl<W: BlockWrite> BlockWriter<W> {
             executor: executor.clone(),
             started: false,
             block_ids: Vec::new(),
+            block_sizes: Vec::new(),
+            checkpoint: None,
             cache: None,
 
             tasks: ConcurrentTasks::new(executor, concurrent, 8192, |input| {
@@ -159,12 +164,63 @@ impl<W: BlockWrite> BlockWriter<W> {
         }
     }
 
+    /// Resume the upload from [`OpWrite::resume`] and record the progress
+    /// into [`OpWrite::checkpoint`].
+    ///
+    /// The etag of parts in the checkpoint must be the block id.
+    pub fn with_checkpoint(mut self, op: &OpWrite) -> Result<Self> {
+        if let Some(resume) = op.resume() {
+            for part in &resume.parts {
+                let block_id = Uuid::parse_str(&part.etag).map_err(|err| {
+                    Error::new(
+                        ErrorKind::Unexpected,
+                        "upload checkpoint of block upload must use block id as etag",
+                    )
+                    .with_context("etag", &part.etag)
+                    .set_source(err)
+                })?;
+                self.block_ids.push(block_id);
+                self.block_sizes.push(part.size);
+            }
+            self.started = true;
+        }
+
+        if let Some(checkpoint) = op.checkpoint() {
+            checkpoint.set(op.resume().cloned());
+            self.checkpoint = Some(checkpoint.clone());
+        }
+        Ok(self)
+    }
+
     fn fill_cache(&mut self, bs: Buffer) -> usize {
         let size = bs.len();
         assert!(self.cache.is_none());
         self.cache = Some(bs);
         size
     }
+
+    /// Collect the blocks that have been uploaded in order.
+    async fn collect_blocks(&mut self) -> Result<()> {
+        while self.tasks.has_result() {
+            let Some(block_id) = self.tasks.next().await.transpose()? else {
+                break;
+            };
+            self.push_block(block_id);
+        }
+        Ok(())
+    }
+
+    fn push_block(&mut self, block_id: Uuid) {
+        if let Some(checkpoint) = &self.checkpoint {
+            checkpoint.push_part(UploadPart {
+                part_number: self.block_ids.len(),
+                etag: block_id.to_string(),
+                checksum: None,
+                size: self.block_sizes[self.block_ids.len()],
+            });
+        }
+        self.block_ids.push(block_id);
+    }
 }
 
 impl<W> oio::Write for BlockWriter<W>
@@ -172,15 +228,21 @@ where
     W: BlockWrite,
 {
     async fn write(&mut self, bs: Buffer) -> Result<()> {
-        if !self.started && self.cache.is_none() {
+        if self.cache.is_none() {
             self.fill_cache(bs);
             return Ok(());
         }
 
         // The block upload process has been started.
-        self.started = true;
+        if !self.started {
+            self.started = true;
+            if let Some(checkpoint) = &self.checkpoint {
+                checkpoint.set(Some(UploadCheckpoint::default()));
+            }
+        }
 
         let bytes = self.cache.clone().expect("pending write must exist");
+        let size = bytes.len() as u64;
         self.tasks
             .execute(WriteInput {
                 w: self.w.clone(),
@@ -190,8 +252,9 @@ where
             })
             .await?;
         self.cache = None;
+        self.block_sizes.push(size);
         self.fill_cache(bs);
-        Ok(())
+        self.collect_blocks().await
     }
 
     async fn close(&mut self) -> Result<Metadata> {
@@ -207,6 +270,7 @@ where
         }
 
         if let Some(cache) = self.cache.clone() {
+            let size = cache.len() as u64;
             self.tasks
                 .execute(WriteInput {
                     w: self.w.clone(),
@@ -216,17 +280,23 @@ where
                 })
                 .await?;
             self.cache = None;
+            self.block_sizes.push(size);
         }
 
         loop {
             let Some(result) = self.tasks.next().await.transpose()? else {
                 break;
             };
-            self.block_ids.push(result);
+            self.push_block(result);
         }
 
         let block_ids = self.block_ids.clone();
-        self.w.complete_block(block_ids).await
+        let meta = self.w.complete_block(block_ids).await?;
+        // The upload has been completed and can't be resumed anymore.
+        if let Some(checkpoint) = &self.checkpoint {
+            checkpoint.set(None);
+        }
+        Ok(meta)
     }
 
     async fn abort(&mut self) -> Result<()> {
@@ -237,6 +307,9 @@ where
         self.tasks.clear();
         self.cache = None;
         self.w.abort_block(self.block_ids.clone()).await?;
+        if let Some(checkpoint) = &self.checkpoint {
+            checkpoint.set(None);
+        }
         Ok(())
     }
 }
@@ -402,4 +475,40 @@ mod tests {
             );
         }
     }
+
+    #[tokio::test]
+    async fn test_block_writer_with_checkpoint() {
+        let test = TestWrite::new();
+
+        let checkpoint = oio::WriteCheckpoint::default();
+        let op = OpWrite::default().with_checkpoint(checkpoint.clone());
+        let mut w = BlockWriter::new(Arc::default(), test.clone(), 1)
+            .with_checkpoint(&op)
+            .unwrap();
+        let mut expected = Vec::new();
+        for i in 0..10 {
+            let bs = vec![i; 100];
+            while w.write(bs.clone().into()).await.is_err() {}
+            expected.extend_from_slice(&bs);
+        }
+
+        // The last write is still cached and not committed.
+        let cp = checkpoint.get().expect("checkpoint must exist");
+        assert_eq!(cp.upload_id, None);
+        assert_eq!(cp.parts.len(), 9);
+        assert_eq!(cp.bytes_committed, 900);
+
+        let op = OpWrite::default()
+            .with_resume(cp)
+            .with_checkpoint(checkpoint.clone());
+        let mut w = BlockWriter::new(Arc::default(), test.clone(), 1)
+            .with_checkpoint(&op)
+            .unwrap();
+        while w.write(vec![9; 100].into()).await.is_err() {}
+        while w.close().await.is_err() {}
+
+        let content = test.lock().unwrap().content.clone().unwrap();
+        assert_eq!(content.to_bytes(), expected);
+        assert!(checkpoint.get().is_none());
+    }
 }
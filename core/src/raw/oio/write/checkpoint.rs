@@ -0,0 +1,53 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::sync::Arc;
+use std::sync::Mutex;
+
+use crate::*;
+
+/// WriteCheckpoint is shared between [`Writer`] and the underlying writers
+/// to record the progress of multipart uploads.
+///
+/// Writers like [`oio::MultipartWriter`] update it while parts are
+/// uploaded, and users can read it via [`Writer::checkpoint`].
+///
+/// [`Writer`]: crate::Writer
+/// [`Writer::checkpoint`]: crate::Writer::checkpoint
+/// [`oio::MultipartWriter`]: crate::raw::oio::MultipartWriter
+#[derive(Debug, Clone, Default)]
+pub struct WriteCheckpoint(Arc<Mutex<Option<UploadCheckpoint>>>);
+
+impl WriteCheckpoint {
+    /// Get the latest checkpoint.
+    pub fn get(&self) -> Option<UploadCheckpoint> {
+        self.0.lock().expect("lock must be acquired").clone()
+    }
+
+    /// Replace the checkpoint.
+    pub fn set(&self, checkpoint: Option<UploadCheckpoint>) {
+        *self.0.lock().expect("lock must be acquired") = checkpoint;
+    }
+
+    /// Record a part that has been uploaded.
+    pub fn push_part(&self, part: UploadPart) {
+        if let Some(cp) = self.0.lock().expect("lock must be acquired").as_mut() {
+            cp.bytes_committed += part.size;
+            cp.parts.push(part);
+        }
+    }
+}
@@ -20,6 +20,9 @@ pub use api::Write;
 pub use api::WriteDyn;
 pub use api::Writer;
 
+mod checkpoint;
+pub use checkpoint::WriteCheckpoint;
+
 mod multipart_write;
 pub use multipart_write::MultipartPart;
 pub use multipart_write::MultipartWrite;
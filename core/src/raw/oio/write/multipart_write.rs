@@ -138,6 +138,9 @@ pub struct MultipartWriter<W: MultipartWrite> {
     parts: Vec<MultipartPart>,
     cache: Option<Buffer>,
     next_part_number: usize,
+    /// The size of parts that have been submitted, indexed by part number.
+    part_sizes: Vec<u64>,
+    checkpoint: Option<oio::WriteCheckpoint>,
 
     tasks: ConcurrentTasks<WriteInput<W>, MultipartPart>,
 }
@@ -157,6 +160,8 @@ impl<W: MultipartWrite> MultipartWriter<W> {
             parts: Vec::new(),
             cache: None,
             next_part_number: 0,
+            part_sizes: Vec::new(),
+            checkpoint: None,
 
             tasks: ConcurrentTasks::new(executor, concurrent, 8192, |input| {
                 Box::pin({
@@ -194,12 +199,78 @@ impl<W: MultipartWrite> MultipartWriter<W> {
         }
     }
 
+    /// Resume the upload from [`OpWrite::resume`] and record the progress
+    /// into [`OpWrite::checkpoint`].
+    ///
+    /// Parts in the checkpoint must be numbered continuously from 0.
+    pub fn with_checkpoint(mut self, op: &OpWrite) -> Result<Self> {
+        if let Some(resume) = op.resume() {
+            let Some(upload_id) = resume.upload_id.clone() else {
+                return Err(Error::new(
+                    ErrorKind::Unexpected,
+                    "upload checkpoint of multipart upload must have upload id",
+                ));
+            };
+
+            for (idx, part) in resume.parts.iter().enumerate() {
+                if part.part_number != idx {
+                    return Err(Error::new(
+                        ErrorKind::Unexpected,
+                        "upload checkpoint parts must be numbered continuously from 0",
+                    )
+                    .with_context("upload_id", &upload_id)
+                    .with_context("expected", idx)
+                    .with_context("actual", part.part_number));
+                }
+
+                self.parts.push(MultipartPart {
+                    part_number: part.part_number,
+                    etag: part.etag.clone(),
+                    checksum: part.checksum.clone(),
+                });
+                self.part_sizes.push(part.size);
+            }
+
+            self.upload_id = Some(Arc::new(upload_id));
+            self.next_part_number = self.parts.len();
+        }
+
+        if let Some(checkpoint) = op.checkpoint() {
+            checkpoint.set(op.resume().cloned());
+            self.checkpoint = Some(checkpoint.clone());
+        }
+        Ok(self)
+    }
+
     fn fill_cache(&mut self, bs: Buffer) -> usize {
         let size = bs.len();
         assert!(self.cache.is_none());
         self.cache = Some(bs);
         size
     }
+
+    /// Collect the parts that have been uploaded in order.
+    async fn collect_parts(&mut self) -> Result<()> {
+        while self.tasks.has_result() {
+            let Some(part) = self.tasks.next().await.transpose()? else {
+                break;
+            };
+            self.push_part(part);
+        }
+        Ok(())
+    }
+
+    fn push_part(&mut self, part: MultipartPart) {
+        if let Some(checkpoint) = &self.checkpoint {
+            checkpoint.push_part(UploadPart {
+                part_number: part.part_number,
+                etag: part.etag.clone(),
+                checksum: part.checksum.clone(),
+                size: self.part_sizes[part.part_number],
+            });
+        }
+        self.parts.push(part);
+    }
 }
 
 impl<W> oio::Write for MultipartWriter<W>
@@ -207,16 +278,22 @@ where
     W: MultipartWrite,
 {
     async fn write(&mut self, bs: Buffer) -> Result<()> {
+        // Fill cache with the first write.
+        if self.cache.is_none() {
+            self.fill_cache(bs);
+            return Ok(());
+        }
+
         let upload_id = match self.upload_id.clone() {
             Some(v) => v,
             None => {
-                // Fill cache with the first write.
-                if self.cache.is_none() {
-                    self.fill_cache(bs);
-                    return Ok(());
-                }
-
                 let upload_id = self.w.initiate_part().await?;
+                if let Some(checkpoint) = &self.checkpoint {
+                    checkpoint.set(Some(UploadCheckpoint {
+                        upload_id: Some(upload_id.clone()),
+                        ..Default::default()
+                    }));
+                }
                 let upload_id = Arc::new(upload_id);
                 self.upload_id = Some(upload_id.clone());
                 upload_id
@@ -224,6 +301,7 @@ where
         };
 
         let bytes = self.cache.clone().expect("pending write must exist");
+        let size = bytes.len() as u64;
         let part_number = self.next_part_number;
 
         self.tasks
@@ -237,8 +315,9 @@ where
             .await?;
         self.cache = None;
         self.next_part_number += 1;
+        self.part_sizes.push(size);
         self.fill_cache(bs);
-        Ok(())
+        self.collect_parts().await
     }
 
     async fn close(&mut self) -> Result<Metadata> {
@@ -259,6 +338,7 @@ where
         };
 
         if let Some(cache) = self.cache.clone() {
+            let size = cache.len() as u64;
             let part_number = self.next_part_number;
 
             self.tasks
@@ -272,13 +352,14 @@ where
                 .await?;
             self.cache = None;
             self.next_part_number += 1;
+            self.part_sizes.push(size);
         }
 
         loop {
             let Some(result) = self.tasks.next().await.transpose()? else {
                 break;
             };
-            self.parts.push(result)
+            self.push_part(result)
         }
 
         if self.parts.len() != self.next_part_number {
@@ -290,7 +371,12 @@ where
             .with_context("actual", self.parts.len())
             .with_context("upload_id", upload_id));
         }
-        self.w.complete_part(&upload_id, &self.parts).await
+        let meta = self.w.complete_part(&upload_id, &self.parts).await?;
+        // The upload has been completed and can't be resumed anymore.
+        if let Some(checkpoint) = &self.checkpoint {
+            checkpoint.set(None);
+        }
+        Ok(meta)
     }
 
     async fn abort(&mut self) -> Result<()> {
@@ -301,6 +387,9 @@ where
         self.tasks.clear();
         self.cache = None;
         self.w.abort_part(&upload_id).await?;
+        if let Some(checkpoint) = &self.checkpoint {
+            checkpoint.set(None);
+        }
         Ok(())
     }
 }
@@ -515,4 +604,62 @@ mod tests {
             assert_eq!(inner.content.clone().unwrap().to_bytes(), bs);
         }
     }
+
+    #[tokio::test]
+    async fn test_multipart_writer_with_checkpoint() {
+        let test = TestWrite::new();
+        let upload_id = test.lock().await.upload_id.clone();
+
+        let checkpoint = oio::WriteCheckpoint::default();
+        let op = OpWrite::default().with_checkpoint(checkpoint.clone());
+        let mut w = MultipartWriter::new(Arc::default(), test.clone(), 1)
+            .with_checkpoint(&op)
+            .unwrap();
+        for _ in 0..10 {
+            while w.write(vec![1; 100].into()).await.is_err() {}
+        }
+
+        // The last write is still cached and not committed.
+        let cp = checkpoint.get().expect("checkpoint must exist");
+        assert_eq!(cp.upload_id.as_deref(), Some(upload_id.as_str()));
+        assert_eq!(
+            cp.parts.iter().map(|v| v.part_number).collect::<Vec<_>>(),
+            (0..9).collect::<Vec<_>>()
+        );
+        assert_eq!(cp.bytes_committed, 900);
+
+        let op = OpWrite::default()
+            .with_resume(cp)
+            .with_checkpoint(checkpoint.clone());
+        let mut w = MultipartWriter::new(Arc::default(), test.clone(), 1)
+            .with_checkpoint(&op)
+            .unwrap();
+        while w.write(vec![1; 100].into()).await.is_err() {}
+        while w.close().await.is_err() {}
+
+        let actual_parts: Vec<_> = w.parts.iter().map(|v| v.part_number).collect();
+        assert_eq!(actual_parts, (0..10).collect::<Vec<_>>());
+        assert_eq!(test.lock().await.length, 1000);
+        assert!(checkpoint.get().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_multipart_writer_with_invalid_checkpoint() {
+        let op = OpWrite::default().with_resume(UploadCheckpoint {
+            upload_id: Some("upload_id".to_string()),
+            parts: vec![UploadPart {
+                part_number: 1,
+                etag: "etag".to_string(),
+                checksum: None,
+                size: 100,
+            }],
+            bytes_committed: 100,
+        });
+        let result = MultipartWriter::new(Arc::default(), TestWrite::new(), 1).with_checkpoint(&op);
+        assert!(result.is_err());
+
+        let op = OpWrite::default().with_resume(UploadCheckpoint::default());
+        let result = MultipartWriter::new(Arc::default(), TestWrite::new(), 1).with_checkpoint(&op);
+        assert!(result.is_err());
+    }
 }
@@ -49,6 +49,14 @@ pub enum Operation {
     List,
     /// Operation to generate a presigned URL.
     Presign,
+    /// Operation to create a symbolic link.
+    CreateSymlink,
+    /// Operation to read the target of a symbolic link.
+    ReadLink,
+    /// Operation to list unfinished multipart uploads.
+    ListPendingUploads,
+    /// Operation to abort an unfinished multipart upload.
+    AbortPendingUpload,
 }
 
 impl Operation {
@@ -77,6 +85,10 @@ impl From<Operation> for &'static str {
             Operation::Delete => "delete",
             Operation::List => "list",
             Operation::Presign => "presign",
+            Operation::CreateSymlink => "create_symlink",
+            Operation::ReadLink => "read_link",
+            Operation::ListPendingUploads => "list_pending_uploads",
+            Operation::AbortPendingUpload => "abort_pending_upload",
         }
     }
 }
@@ -19,6 +19,7 @@
 //!
 //! By using ops, users can add more context for operation.
 
+use crate::UploadCheckpoint;
 use crate::options;
 use crate::raw::*;
 use std::collections::HashMap;
@@ -120,6 +121,14 @@ pub struct OpList {
     ///
     /// Default to `false`
     deleted: bool,
+    /// The glob that listed entries will be matched against.
+    ///
+    /// Services could use it to skip dirs that can't contain matched entries,
+    /// entries are still filtered by [`Lister`](crate::Lister).
+    glob: Option<GlobMatcher>,
+    /// The continuation shared with the underlying listers to record and
+    /// resume the position of the listing.
+    continuation: Option<oio::ListContinuation>,
 }
 
 impl OpList {
@@ -215,6 +224,28 @@ impl OpList {
     pub fn deleted(&self) -> bool {
         self.deleted
     }
+
+    /// Change the glob of this list operation
+    pub fn with_glob(mut self, glob: GlobMatcher) -> Self {
+        self.glob = Some(glob);
+        self
+    }
+
+    /// Get the glob of this list operation
+    pub fn glob(&self) -> Option<&GlobMatcher> {
+        self.glob.as_ref()
+    }
+
+    /// Change the continuation of this list operation
+    pub fn with_continuation(mut self, continuation: oio::ListContinuation) -> Self {
+        self.continuation = Some(continuation);
+        self
+    }
+
+    /// Get the continuation of this list operation
+    pub fn continuation(&self) -> Option<&oio::ListContinuation> {
+        self.continuation.as_ref()
+    }
 }
 
 impl From<options::ListOptions> for OpList {
@@ -225,6 +256,10 @@ impl From<options::ListOptions> for OpList {
             recursive: value.recursive,
             versions: value.versions,
             deleted: value.deleted,
+            // The glob will be compiled by `Lister` since it depends on the listing path.
+            glob: None,
+            // The continuation will be decoded by `Lister` from the continuation token.
+            continuation: None,
         }
     }
 }
@@ -564,6 +599,7 @@ pub struct OpStat {
     override_cache_control: Option<String>,
     override_content_disposition: Option<String>,
     version: Option<String>,
+    no_follow_symlink: bool,
 }
 
 impl OpStat {
@@ -660,6 +696,17 @@ impl OpStat {
     pub fn version(&self) -> Option<&str> {
         self.version.as_deref()
     }
+
+    /// Set whether to stat the symlink itself instead of its target.
+    pub fn with_no_follow_symlink(mut self, no_follow_symlink: bool) -> Self {
+        self.no_follow_symlink = no_follow_symlink;
+        self
+    }
+
+    /// Get whether to stat the symlink itself instead of its target.
+    pub fn no_follow_symlink(&self) -> bool {
+        self.no_follow_symlink
+    }
 }
 
 impl From<options::StatOptions> for OpStat {
@@ -673,6 +720,7 @@ impl From<options::StatOptions> for OpStat {
             override_cache_control: value.override_cache_control,
             override_content_disposition: value.override_content_disposition,
             version: value.version,
+            no_follow_symlink: value.no_follow_symlink,
         }
     }
 }
@@ -690,6 +738,11 @@ pub struct OpWrite {
     if_none_match: Option<String>,
     if_not_exists: bool,
     user_metadata: Option<HashMap<String, String>>,
+    expires_in: Option<Duration>,
+    unix_mode: Option<u32>,
+    mtime: Option<Timestamp>,
+    resume: Option<UploadCheckpoint>,
+    checkpoint: Option<oio::WriteCheckpoint>,
 }
 
 impl OpWrite {
@@ -817,6 +870,61 @@ impl OpWrite {
     pub fn user_metadata(&self) -> Option<&HashMap<String, String>> {
         self.user_metadata.as_ref()
     }
+
+    /// Set the time to live of the op.
+    pub fn with_expires_in(mut self, ttl: Duration) -> Self {
+        self.expires_in = Some(ttl);
+        self
+    }
+
+    /// Get the time to live from the op.
+    pub fn expires_in(&self) -> Option<Duration> {
+        self.expires_in
+    }
+
+    /// Set the POSIX permission bits of the op.
+    pub fn with_unix_mode(mut self, mode: u32) -> Self {
+        self.unix_mode = Some(mode);
+        self
+    }
+
+    /// Get the POSIX permission bits from the op.
+    pub fn unix_mode(&self) -> Option<u32> {
+        self.unix_mode
+    }
+
+    /// Set the modification time of the op.
+    pub fn with_mtime(mut self, mtime: Timestamp) -> Self {
+        self.mtime = Some(mtime);
+        self
+    }
+
+    /// Get the modification time from the op.
+    pub fn mtime(&self) -> Option<Timestamp> {
+        self.mtime
+    }
+
+    /// Set the checkpoint to resume the upload from.
+    pub fn with_resume(mut self, checkpoint: UploadCheckpoint) -> Self {
+        self.resume = Some(checkpoint);
+        self
+    }
+
+    /// Get the checkpoint to resume the upload from.
+    pub fn resume(&self) -> Option<&UploadCheckpoint> {
+        self.resume.as_ref()
+    }
+
+    /// Set the checkpoint that records the progress of the upload.
+    pub fn with_checkpoint(mut self, checkpoint: oio::WriteCheckpoint) -> Self {
+        self.checkpoint = Some(checkpoint);
+        self
+    }
+
+    /// Get the checkpoint that records the progress of the upload.
+    pub fn checkpoint(&self) -> Option<&oio::WriteCheckpoint> {
+        self.checkpoint.as_ref()
+    }
 }
 
 /// Args for `writer` operation.
@@ -868,6 +976,11 @@ impl From<options::WriteOptions> for (OpWrite, OpWriter) {
                 if_none_match: value.if_none_match,
                 if_not_exists: value.if_not_exists,
                 user_metadata: value.user_metadata,
+                expires_in: value.expires_in,
+                unix_mode: value.unix_mode,
+                mtime: value.mtime,
+                resume: value.resume,
+                checkpoint: None,
             },
             OpWriter { chunk: value.chunk },
         )
@@ -901,6 +1014,63 @@ impl OpCopy {
     }
 }
 
+/// Args for `create_symlink` operation.
+#[derive(Debug, Clone, Default)]
+pub struct OpCreateSymlink {}
+
+impl OpCreateSymlink {
+    /// Create a new `OpCreateSymlink`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+/// Args for `read_link` operation.
+#[derive(Debug, Clone, Default)]
+pub struct OpReadLink {}
+
+impl OpReadLink {
+    /// Create a new `OpReadLink`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+/// Args for `list_pending_uploads` operation.
+#[derive(Debug, Clone, Default)]
+pub struct OpListPendingUploads {
+    page_token: Option<String>,
+}
+
+impl OpListPendingUploads {
+    /// Create a new `OpListPendingUploads`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the token of the page to list, returned by the previous page.
+    pub fn with_page_token(mut self, token: &str) -> Self {
+        self.page_token = Some(token.to_string());
+        self
+    }
+
+    /// Get the token of the page to list.
+    pub fn page_token(&self) -> Option<&str> {
+        self.page_token.as_deref()
+    }
+}
+
+/// Args for `abort_pending_upload` operation.
+#[derive(Debug, Clone, Default)]
+pub struct OpAbortPendingUpload {}
+
+impl OpAbortPendingUpload {
+    /// Create a new `OpAbortPendingUpload`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
 /// Args for `rename` operation.
 #[derive(Debug, Clone, Default)]
 pub struct OpRename {}
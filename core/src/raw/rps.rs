@@ -199,6 +199,98 @@ impl RpCopy {
     }
 }
 
+/// Reply for `create_symlink` operation.
+#[derive(Debug, Clone, Default)]
+pub struct RpCreateSymlink {}
+
+impl RpCreateSymlink {
+    /// Create a new reply for `create_symlink`.
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+/// Reply for `read_link` operation.
+#[derive(Debug, Clone, Default)]
+pub struct RpReadLink {
+    target: String,
+}
+
+impl RpReadLink {
+    /// Create a new reply for `read_link`.
+    pub fn new(target: impl Into<String>) -> Self {
+        Self {
+            target: target.into(),
+        }
+    }
+
+    /// Get the target of the symlink.
+    pub fn target(&self) -> &str {
+        &self.target
+    }
+
+    /// Consume reply to get the target of the symlink.
+    pub fn into_target(self) -> String {
+        self.target
+    }
+}
+
+/// Reply for `list_pending_uploads` operation.
+///
+/// Every reply carries one page of pending uploads.
+#[derive(Debug, Clone, Default)]
+pub struct RpListPendingUploads {
+    uploads: Vec<PendingUpload>,
+    next_page_token: Option<String>,
+}
+
+impl RpListPendingUploads {
+    /// Create a new reply for `list_pending_uploads`.
+    pub fn new(uploads: Vec<PendingUpload>) -> Self {
+        Self {
+            uploads,
+            next_page_token: None,
+        }
+    }
+
+    /// Set the token of the next page, `None` means this is the last page.
+    pub fn with_next_page_token(mut self, token: Option<String>) -> Self {
+        self.next_page_token = token;
+        self
+    }
+
+    /// Get the token of the next page.
+    pub fn next_page_token(&self) -> Option<&str> {
+        self.next_page_token.as_deref()
+    }
+
+    /// Get the pending uploads.
+    pub fn uploads(&self) -> &[PendingUpload] {
+        &self.uploads
+    }
+
+    /// Consume reply to get the pending uploads.
+    pub fn into_uploads(self) -> Vec<PendingUpload> {
+        self.uploads
+    }
+
+    /// Consume reply to get the pending uploads and the token of the next page.
+    pub fn into_page(self) -> (Vec<PendingUpload>, Option<String>) {
+        (self.uploads, self.next_page_token)
+    }
+}
+
+/// Reply for `abort_pending_upload` operation.
+#[derive(Debug, Clone, Default)]
+pub struct RpAbortPendingUpload {}
+
+impl RpAbortPendingUpload {
+    /// Create a new reply for `abort_pending_upload`.
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
 /// Reply for `rename` operation.
 #[derive(Debug, Clone, Default)]
 pub struct RpRename {}
@@ -402,6 +402,7 @@ impl Builder for AzblobBuilder {
                             write_can_append: true,
                             write_can_empty: true,
                             write_can_multi: true,
+                            write_can_resume: true,
                             write_with_cache_control: true,
                             write_with_content_type: true,
                             write_with_if_not_exists: true,
@@ -416,6 +417,7 @@ impl Builder for AzblobBuilder {
 
                             list: true,
                             list_with_recursive: true,
+                            list_with_continuation: true,
 
                             presign: self.config.sas_token.is_some(),
                             presign_stat: self.config.sas_token.is_some(),
@@ -508,11 +510,10 @@ impl Access for AzblobBackend {
         let w = if args.append() {
             AzblobWriters::Two(oio::AppendWriter::new(w))
         } else {
-            AzblobWriters::One(oio::BlockWriter::new(
-                self.core.info.clone(),
-                w,
-                args.concurrent(),
-            ))
+            AzblobWriters::One(
+                oio::BlockWriter::new(self.core.info.clone(), w, args.concurrent())
+                    .with_checkpoint(&args)?,
+            )
         };
 
         Ok((RpWrite::default(), w))
@@ -533,7 +534,10 @@ impl Access for AzblobBackend {
             args.limit(),
         );
 
-        Ok((RpList::default(), oio::PageLister::new(l)))
+        Ok((
+            RpList::default(),
+            oio::PageLister::new(l).with_continuation(&args),
+        ))
     }
 
     async fn copy(&self, from: &str, to: &str, args: OpCopy) -> Result<RpCopy> {
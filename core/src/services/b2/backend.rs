@@ -188,6 +188,7 @@ impl Builder for B2Builder {
                             write: true,
                             write_can_empty: true,
                             write_can_multi: true,
+                            write_can_resume: true,
                             write_with_content_type: true,
                             // The min multipart size of b2 is 5 MiB.
                             //
@@ -290,9 +291,10 @@ impl Access for B2Backend {
 
     async fn write(&self, path: &str, args: OpWrite) -> Result<(RpWrite, Self::Writer)> {
         let concurrent = args.concurrent();
-        let writer = B2Writer::new(self.core.clone(), path, args);
+        let writer = B2Writer::new(self.core.clone(), path, args.clone());
 
-        let w = oio::MultipartWriter::new(self.core.info.clone(), writer, concurrent);
+        let w = oio::MultipartWriter::new(self.core.info.clone(), writer, concurrent)
+            .with_checkpoint(&args)?;
 
         Ok((RpWrite::default(), w))
     }
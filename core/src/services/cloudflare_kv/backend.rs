@@ -169,6 +169,7 @@ impl Builder for CloudflareKvBuilder {
 
                             write: true,
                             write_can_empty: true,
+                            write_with_ttl: true,
                             write_total_max_size: Some(25 * 1024 * 1024),
 
                             list: true,
@@ -241,11 +242,12 @@ impl Access for CloudflareKvBackend {
                 last_modified: Timestamp::now().to_string(),
                 content_length: 0,
                 is_dir: true,
+                expires_at: None,
             };
 
             // Set the directory entry
             self.core
-                .set(&current_path, Buffer::new(), cf_kv_metadata)
+                .set(&current_path, Buffer::new(), cf_kv_metadata, None)
                 .await?;
         }
 
@@ -362,7 +364,7 @@ impl Access for CloudflareKvBackend {
             }
         }
 
-        let meta = Metadata::new(if metadata.is_dir {
+        let mut meta = Metadata::new(if metadata.is_dir {
             EntryMode::DIR
         } else {
             EntryMode::FILE
@@ -370,6 +372,9 @@ impl Access for CloudflareKvBackend {
         .with_etag(metadata.etag)
         .with_content_length(metadata.content_length as u64)
         .with_last_modified(metadata.last_modified.parse::<Timestamp>()?);
+        if let Some(v) = metadata.expires_at {
+            meta.set_expires_at(v.parse()?);
+        }
 
         Ok(RpStat::new(meta))
     }
@@ -468,9 +473,19 @@ impl Access for CloudflareKvBackend {
         Ok((RpRead::new(), buffer))
     }
 
-    async fn write(&self, path: &str, _: OpWrite) -> Result<(RpWrite, Self::Writer)> {
+    async fn write(&self, path: &str, args: OpWrite) -> Result<(RpWrite, Self::Writer)> {
+        if let Some(ttl) = args.expires_in() {
+            if ttl < Duration::from_secs(60) {
+                return Err(Error::new(
+                    ErrorKind::Unsupported,
+                    "cloudflare kv requires expires_in to be at least 60 seconds",
+                )
+                .with_context("expires_in", format!("{ttl:?}")));
+            }
+        }
+
         let path = build_abs_path(&self.core.info.root(), path);
-        let writer = CloudflareWriter::new(self.core.clone(), path);
+        let writer = CloudflareWriter::new(self.core.clone(), path, args.expires_in());
 
         let w = oio::OneShotWriter::new(writer);
 
@@ -69,10 +69,14 @@ impl CloudflareKvLister {
                 .with_etag(build_tmp_path_of(&name))
                 .with_content_length(0)
         } else {
-            Metadata::new(EntryMode::FILE)
+            let mut meta = Metadata::new(EntryMode::FILE)
                 .with_etag(metadata.etag)
                 .with_content_length(metadata.content_length as u64)
-                .with_last_modified(metadata.last_modified.parse::<Timestamp>()?)
+                .with_last_modified(metadata.last_modified.parse::<Timestamp>()?);
+            if let Some(v) = metadata.expires_at {
+                meta.set_expires_at(v.parse()?);
+            }
+            meta
         };
 
         Ok(oio::Entry::new(&name, entry_metadata))
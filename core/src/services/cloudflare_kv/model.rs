@@ -30,6 +30,9 @@ pub struct CfKvMetadata {
     pub last_modified: String,
     pub content_length: usize,
     pub is_dir: bool,
+    /// Expiry of the key, kept here since the metadata API doesn't report it.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expires_at: Option<String>,
 }
 
 #[derive(Debug, Deserialize)]
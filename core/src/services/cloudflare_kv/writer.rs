@@ -16,6 +16,7 @@
 // under the License.
 
 use std::sync::Arc;
+use std::time::Duration;
 
 use super::core::CloudflareKvCore;
 use super::error::parse_error;
@@ -27,26 +28,30 @@ use http::StatusCode;
 pub struct CloudflareWriter {
     core: Arc<CloudflareKvCore>,
     path: String,
+    ttl: Option<Duration>,
 }
 
 impl CloudflareWriter {
-    pub fn new(core: Arc<CloudflareKvCore>, path: String) -> Self {
-        CloudflareWriter { core, path }
+    pub fn new(core: Arc<CloudflareKvCore>, path: String, ttl: Option<Duration>) -> Self {
+        CloudflareWriter { core, path, ttl }
     }
 }
 
 impl oio::OneShotWrite for CloudflareWriter {
     async fn write_once(&self, bs: Buffer) -> Result<Metadata> {
+        let now = Timestamp::now();
+        let ttl = self.ttl.or(self.core.expiration_ttl);
         let cf_kv_metadata = CfKvMetadata {
             etag: build_tmp_path_of(&self.path),
-            last_modified: Timestamp::now().to_string(),
+            last_modified: now.to_string(),
             content_length: bs.len(),
             is_dir: self.path.ends_with('/'),
+            expires_at: ttl.map(|ttl| (now + ttl).to_string()),
         };
 
         let resp = self
             .core
-            .set(&self.path, bs, cf_kv_metadata.clone())
+            .set(&self.path, bs, cf_kv_metadata.clone(), self.ttl)
             .await?;
 
         let status = resp.status();
@@ -57,6 +62,9 @@ impl oio::OneShotWrite for CloudflareWriter {
                 metadata.set_etag(&cf_kv_metadata.etag);
                 metadata.set_last_modified(cf_kv_metadata.last_modified.parse::<Timestamp>()?);
                 metadata.set_content_length(cf_kv_metadata.content_length as u64);
+                if let Some(v) = &cf_kv_metadata.expires_at {
+                    metadata.set_expires_at(v.parse()?);
+                }
 
                 Ok(metadata)
             }
@@ -32,6 +32,9 @@ use crate::raw::*;
 use crate::*;
 
 /// [`compio`]-based file system support.
+///
+/// `content_type`, `cache_control` and `user_metadata` are stored in the same
+/// way as the `fs` service.
 #[derive(Debug, Default)]
 pub struct CompfsBuilder {
     pub(super) config: CompfsConfig,
@@ -93,6 +96,10 @@ impl Builder for CompfsBuilder {
                         write: true,
                         write_can_empty: true,
                         write_can_multi: true,
+                        write_with_content_type: true,
+                        write_with_cache_control: true,
+                        write_with_user_metadata: true,
+
                         create_dir: true,
                         delete: true,
 
@@ -145,6 +152,7 @@ impl Access for CompfsBackend {
 
     async fn stat(&self, path: &str, _: OpStat) -> Result<RpStat> {
         let path = self.core.prepare_path(path);
+        let attrs_path = path.clone();
         let meta = self
             .core
             .exec(move || async move { compio::fs::metadata(path).await })
@@ -158,9 +166,12 @@ impl Access for CompfsBackend {
             EntryMode::Unknown
         };
         let last_mod = Timestamp::try_from(meta.modified().map_err(new_std_io_error)?)?;
-        let ret = Metadata::new(mode)
+        let mut ret = Metadata::new(mode)
             .with_last_modified(last_mod)
             .with_content_length(meta.len());
+        if ty.is_file() {
+            ret = self.core.load_attrs(attrs_path).await?.into_metadata(ret);
+        }
         Ok(RpStat::new(ret))
     }
 
@@ -174,6 +185,7 @@ impl Access for CompfsBackend {
     async fn copy(&self, from: &str, to: &str, _: OpCopy) -> Result<RpCopy> {
         let from = self.core.prepare_path(from);
         let to = self.core.prepare_path(to);
+        let attrs_paths = (from.clone(), to.clone());
 
         self.core
             .exec(move || async move {
@@ -194,6 +206,9 @@ impl Access for CompfsBackend {
                 Ok(())
             })
             .await?;
+        self.core
+            .exec_blocking(move || copy_file_attrs(&attrs_paths.0, &attrs_paths.1))
+            .await??;
 
         Ok(RpCopy::default())
     }
@@ -201,6 +216,7 @@ impl Access for CompfsBackend {
     async fn rename(&self, from: &str, to: &str, _: OpRename) -> Result<RpRename> {
         let from = self.core.prepare_path(from);
         let to = self.core.prepare_path(to);
+        let attrs_paths = (from.clone(), to.clone());
 
         self.core
             .exec(move || async move {
@@ -210,6 +226,9 @@ impl Access for CompfsBackend {
                 compio::fs::rename(from, to).await
             })
             .await?;
+        self.core
+            .exec_blocking(move || rename_file_attrs(&attrs_paths.0, &attrs_paths.1))
+            .await??;
 
         Ok(RpRename::default())
     }
@@ -228,6 +247,7 @@ impl Access for CompfsBackend {
 
     async fn write(&self, path: &str, args: OpWrite) -> Result<(RpWrite, Self::Writer)> {
         let path = self.core.prepare_path(path);
+        let target_path = path.clone();
         let append = args.append();
         let file = self
             .core
@@ -250,7 +270,11 @@ impl Access for CompfsBackend {
             })
             .await?;
 
-        let w = CompfsWriter::new(self.core.clone(), file);
+        // Appending without any attributes keeps the existing ones.
+        let attrs = FileAttrs::from_op(&args);
+        let attrs = (!append || !attrs.is_empty()).then_some(attrs);
+
+        let w = CompfsWriter::new(self.core.clone(), target_path, file, attrs);
         Ok((RpWrite::new(), w))
     }
 
@@ -79,6 +79,11 @@ impl CompfsCore {
             .await
             .map_err(|_| Error::new(ErrorKind::Unexpected, "compio task cancelled"))
     }
+
+    /// Load the content type, cache control and user metadata of the file.
+    pub async fn load_attrs(&self, path: PathBuf) -> Result<FileAttrs> {
+        self.exec_blocking(move || FileAttrs::load(&path)).await?
+    }
 }
 
 impl IoVectoredBuf for Buffer {
@@ -40,9 +40,19 @@ impl oio::OneShotDelete for CompfsDeleter {
                 .await
         } else {
             let path = self.core.prepare_path(&path);
-            self.core
+            let attrs_path = path.clone();
+            match self
+                .core
                 .exec(move || async move { compio::fs::remove_file(path).await })
                 .await
+            {
+                Ok(()) => {
+                    self.core
+                        .exec_blocking(move || remove_file_attrs(&attrs_path))
+                        .await?
+                }
+                Err(e) => Err(e),
+            }
         };
         match res {
             Ok(()) => Ok(()),
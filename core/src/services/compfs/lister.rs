@@ -51,14 +51,20 @@ fn normalize(path: &Path, root: &Path) -> String {
     )
 }
 
-fn next_entry(read_dir: &mut ReadDir, root: &Path) -> std::io::Result<Option<oio::Entry>> {
-    let Some(entry) = read_dir.next().transpose()? else {
-        return Ok(None);
+fn next_entry(read_dir: &mut ReadDir, root: &Path) -> Result<Option<oio::Entry>> {
+    let entry = loop {
+        let Some(entry) = read_dir.next().transpose().map_err(new_std_io_error)? else {
+            return Ok(None);
+        };
+        // Skip the sidecar files used to store file attributes.
+        if !is_sidecar_file(&entry.file_name().to_string_lossy()) {
+            break entry;
+        }
     };
     let path = entry.path();
     let rel_path = normalize(&path, root);
 
-    let file_type = entry.file_type()?;
+    let file_type = entry.file_type().map_err(new_std_io_error)?;
 
     let entry = if file_type.is_file() {
         oio::Entry::new(&rel_path, Metadata::new(EntryMode::FILE))
@@ -86,7 +92,7 @@ impl oio::List for CompfsLister {
         let (entry, read_dir) = self
             .core
             .exec_blocking(move || {
-                let entry = next_entry(&mut read_dir, &root).map_err(new_std_io_error);
+                let entry = next_entry(&mut read_dir, &root);
                 (entry, read_dir)
             })
             .await?;
@@ -16,6 +16,7 @@
 // under the License.
 
 use std::io::Cursor;
+use std::path::PathBuf;
 use std::sync::Arc;
 
 use compio::buf::buf_try;
@@ -29,14 +30,23 @@ use crate::*;
 #[derive(Debug)]
 pub struct CompfsWriter {
     core: Arc<CompfsCore>,
+    path: PathBuf,
     file: Option<Cursor<File>>,
+    attrs: Option<FileAttrs>,
 }
 
 impl CompfsWriter {
-    pub(super) fn new(core: Arc<CompfsCore>, file: Cursor<File>) -> Self {
+    pub(super) fn new(
+        core: Arc<CompfsCore>,
+        path: PathBuf,
+        file: Cursor<File>,
+        attrs: Option<FileAttrs>,
+    ) -> Self {
         Self {
             core,
+            path,
             file: Some(file),
+            attrs,
         }
     }
 }
@@ -71,7 +81,15 @@ impl oio::Write for CompfsWriter {
             })
             .await?;
 
-        Ok(Metadata::default())
+        let Some(attrs) = self.attrs.take() else {
+            return Ok(Metadata::default());
+        };
+        let path = self.path.clone();
+        let attrs = self
+            .core
+            .exec_blocking(move || attrs.store(&path).map(|_| attrs))
+            .await??;
+        Ok(attrs.into_metadata(Metadata::default()))
     }
 
     async fn abort(&mut self) -> Result<()> {
@@ -254,9 +254,12 @@ impl Builder for CosBuilder {
 
                             list: true,
                             list_with_recursive: true,
+                            list_with_continuation: true,
                             list_with_versions: self.config.enable_versioning,
                             list_with_deleted: self.config.enable_versioning,
 
+                            pending_upload: true,
+
                             presign: true,
                             presign_stat: true,
                             presign_read: true,
@@ -370,23 +373,62 @@ impl Access for CosBackend {
 
     async fn list(&self, path: &str, args: OpList) -> Result<(RpList, Self::Lister)> {
         let l = if args.versions() || args.deleted() {
-            TwoWays::Two(oio::PageLister::new(CosObjectVersionsLister::new(
-                self.core.clone(),
-                path,
-                args,
-            )))
+            TwoWays::Two(
+                oio::PageLister::new(CosObjectVersionsLister::new(
+                    self.core.clone(),
+                    path,
+                    args.clone(),
+                ))
+                .with_continuation(&args),
+            )
         } else {
-            TwoWays::One(oio::PageLister::new(CosLister::new(
-                self.core.clone(),
-                path,
-                args.recursive(),
-                args.limit(),
-            )))
+            TwoWays::One(
+                oio::PageLister::new(CosLister::new(
+                    self.core.clone(),
+                    path,
+                    args.recursive(),
+                    args.limit(),
+                ))
+                .with_continuation(&args),
+            )
         };
 
         Ok((RpList::default(), l))
     }
 
+    async fn list_pending_uploads(
+        &self,
+        path: &str,
+        args: OpListPendingUploads,
+    ) -> Result<RpListPendingUploads> {
+        let (key_marker, upload_id_marker) = parse_multipart_uploads_token(&args);
+        let resp = self
+            .core
+            .cos_list_multipart_uploads(path, key_marker, upload_id_marker)
+            .await?;
+        match resp.status() {
+            StatusCode::OK => parse_list_multipart_uploads(&self.core.root, resp.into_body()),
+            _ => Err(parse_error(resp)),
+        }
+    }
+
+    async fn abort_pending_upload(
+        &self,
+        path: &str,
+        upload_id: &str,
+        _: OpAbortPendingUpload,
+    ) -> Result<RpAbortPendingUpload> {
+        let resp = self
+            .core
+            .cos_abort_multipart_upload(path, upload_id)
+            .await?;
+        match resp.status() {
+            // cos returns code 204 if abort succeeds.
+            StatusCode::NO_CONTENT => Ok(RpAbortPendingUpload::default()),
+            _ => Err(parse_error(resp)),
+        }
+    }
+
     async fn copy(&self, from: &str, to: &str, _args: OpCopy) -> Result<RpCopy> {
         let resp = self.core.cos_copy_object(from, to).await?;
 
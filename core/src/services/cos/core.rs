@@ -493,6 +493,37 @@ impl CosCore {
         self.send(req).await
     }
 
+    /// List on-going multipart uploads whose key starts with path.
+    pub async fn cos_list_multipart_uploads(
+        &self,
+        path: &str,
+        key_marker: &str,
+        upload_id_marker: &str,
+    ) -> Result<Response<Buffer>> {
+        let p = build_abs_path(&self.root, path);
+
+        let mut url = QueryPairsWriter::new(&self.endpoint);
+        url = url.push("uploads", "");
+
+        if !p.is_empty() {
+            url = url.push("prefix", &percent_encode_path(&p));
+        }
+        if !key_marker.is_empty() {
+            url = url.push("key-marker", &percent_encode_path(key_marker));
+        }
+        if !upload_id_marker.is_empty() {
+            url = url.push("upload-id-marker", &percent_encode_path(upload_id_marker));
+        }
+
+        let mut req = Request::get(url.finish())
+            .extension(Operation::ListPendingUploads)
+            .body(Buffer::new())
+            .map_err(new_request_build_error)?;
+
+        self.sign(&mut req).await?;
+        self.send(req).await
+    }
+
     /// Abort an on-going multipart upload.
     pub async fn cos_abort_multipart_upload(
         &self,
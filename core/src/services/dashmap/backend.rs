@@ -99,6 +99,9 @@ impl DashmapBackend {
             delete: true,
             stat: true,
             list: true,
+            copy: true,
+            rename: true,
+            rename_dir: true,
             shared: false,
             ..Default::default()
         });
@@ -183,4 +186,27 @@ impl Access for DashmapBackend {
         let lister = oio::HierarchyLister::new(lister, path, args.recursive());
         Ok((RpList::default(), lister))
     }
+
+    async fn copy(&self, from: &str, to: &str, _: OpCopy) -> Result<RpCopy> {
+        let from = build_abs_path(&self.root, from);
+        let to = build_abs_path(&self.root, to);
+        if !self.core.copy(&from, &to)? {
+            return Err(Error::new(ErrorKind::NotFound, "key not found in dashmap"));
+        }
+        Ok(RpCopy::default())
+    }
+
+    async fn rename(&self, from: &str, to: &str, _: OpRename) -> Result<RpRename> {
+        let from = build_abs_path(&self.root, from);
+        let to = build_abs_path(&self.root, to);
+        let renamed = if from.ends_with('/') {
+            self.core.rename_dir(&from, &to)?
+        } else {
+            self.core.rename(&from, &to)?
+        };
+        if !renamed {
+            return Err(Error::new(ErrorKind::NotFound, "key not found in dashmap"));
+        }
+        Ok(RpRename::default())
+    }
 }
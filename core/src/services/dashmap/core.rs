@@ -57,4 +57,47 @@ impl DashmapCore {
         self.cache.remove(key);
         Ok(())
     }
+
+    /// Copy the value of `from` to `to`, returns `false` if `from` not exist.
+    pub fn copy(&self, from: &str, to: &str) -> Result<bool> {
+        let Some(value) = self.get(from)? else {
+            return Ok(false);
+        };
+        self.set(to, value)?;
+        Ok(true)
+    }
+
+    /// Move the value of `from` to `to`, returns `false` if `from` not exist.
+    ///
+    /// Dashmap can't update two keys atomically, so neither of them is
+    /// visible for a short while.
+    pub fn rename(&self, from: &str, to: &str) -> Result<bool> {
+        let Some((_, value)) = self.cache.remove(from) else {
+            return Ok(false);
+        };
+        self.cache.insert(to.to_string(), value);
+        Ok(true)
+    }
+
+    /// Move every key under `from` to the same place under `to` one by one,
+    /// returns `false` if there is nothing under `from`.
+    pub fn rename_dir(&self, from: &str, to: &str) -> Result<bool> {
+        let keys: Vec<String> = self
+            .cache
+            .iter()
+            .filter(|kv| kv.key().starts_with(from))
+            .map(|kv| kv.key().clone())
+            .collect();
+        if keys.is_empty() {
+            return Ok(false);
+        }
+
+        for key in keys {
+            if let Some((key, value)) = self.cache.remove(&key) {
+                self.cache
+                    .insert(format!("{to}{}", &key[from.len()..]), value);
+            }
+        }
+        Ok(true)
+    }
 }
@@ -28,7 +28,6 @@ use super::config::EtcdConfig;
 use super::core::EtcdCore;
 use super::core::constants::DEFAULT_ETCD_ENDPOINTS;
 use super::deleter::EtcdDeleter;
-use super::lister::EtcdLister;
 use super::writer::EtcdWriter;
 use crate::raw::*;
 use crate::*;
@@ -194,10 +193,15 @@ impl EtcdBackend {
 
             write: true,
             write_can_empty: true,
+            write_with_ttl: true,
 
             delete: true,
             stat: true,
             list: true,
+            list_with_start_after: true,
+
+            copy: true,
+            rename: true,
 
             shared: true,
 
@@ -214,7 +218,7 @@ impl EtcdBackend {
 impl Access for EtcdBackend {
     type Reader = Buffer;
     type Writer = EtcdWriter;
-    type Lister = oio::HierarchyLister<EtcdLister>;
+    type Lister = oio::HierarchyLister<oio::KeyScanLister<EtcdCore>>;
     type Deleter = oio::OneShotDeleter<EtcdDeleter>;
 
     fn info(&self) -> Arc<AccessorInfo> {
@@ -233,7 +237,7 @@ impl Access for EtcdBackend {
         };
 
         // Store an empty buffer to represent the directory
-        self.core.set(&dir_path, Buffer::new()).await?;
+        self.core.set(&dir_path, Buffer::new(), None).await?;
 
         Ok(RpCreateDir::default())
     }
@@ -246,6 +250,9 @@ impl Access for EtcdBackend {
             Some(buffer) => {
                 let mut metadata = Metadata::new(EntryMode::from_path(&abs_path));
                 metadata.set_content_length(buffer.len() as u64);
+                if let Some(ttl) = self.core.ttl(&abs_path).await? {
+                    metadata.set_expires_at(Timestamp::now() + ttl);
+                }
                 Ok(RpStat::new(metadata))
             }
             None => {
@@ -300,9 +307,9 @@ impl Access for EtcdBackend {
         }
     }
 
-    async fn write(&self, path: &str, _op: OpWrite) -> Result<(RpWrite, Self::Writer)> {
+    async fn write(&self, path: &str, op: OpWrite) -> Result<(RpWrite, Self::Writer)> {
         let abs_path = build_abs_path(&self.info.root(), path);
-        let writer = EtcdWriter::new(self.core.clone(), abs_path);
+        let writer = EtcdWriter::new(self.core.clone(), abs_path, op.expires_in());
         Ok((RpWrite::new(), writer))
     }
 
@@ -315,13 +322,31 @@ impl Access for EtcdBackend {
     }
 
     async fn list(&self, path: &str, args: OpList) -> Result<(RpList, Self::Lister)> {
-        let lister = EtcdLister::new(
-            self.core.clone(),
-            self.info.root().to_string(),
-            path.to_string(),
-        )
-        .await?;
-        let lister = oio::HierarchyLister::new(lister, path, args.recursive());
+        let root = self.info.root();
+        let p = build_abs_path(&root, path);
+        let lister = oio::KeyScanLister::new(self.core.clone(), &root, p, &args);
+        let lister = oio::HierarchyLister::new(lister, path, args.recursive())
+            .with_start_after(args.start_after());
         Ok((RpList::default(), lister))
     }
+
+    async fn copy(&self, from: &str, to: &str, _: OpCopy) -> Result<RpCopy> {
+        let root = self.info.root();
+        let from = build_abs_path(&root, from);
+        let to = build_abs_path(&root, to);
+        if !self.core.copy(&from, &to).await? {
+            return Err(Error::new(ErrorKind::NotFound, "path not found"));
+        }
+        Ok(RpCopy::default())
+    }
+
+    async fn rename(&self, from: &str, to: &str, _: OpRename) -> Result<RpRename> {
+        let root = self.info.root();
+        let from = build_abs_path(&root, from);
+        let to = build_abs_path(&root, to);
+        if !self.core.rename(&from, &to).await? {
+            return Err(Error::new(ErrorKind::NotFound, "path not found"));
+        }
+        Ok(RpRename::default())
+    }
 }
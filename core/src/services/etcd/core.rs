@@ -16,13 +16,21 @@
 // under the License.
 
 use std::fmt::Debug;
+use std::time::Duration;
 
 use bb8::PooledConnection;
 use bb8::RunError;
 use etcd_client::Client;
+use etcd_client::Compare;
+use etcd_client::CompareOp;
 use etcd_client::ConnectOptions;
+use etcd_client::GetOptions;
+use etcd_client::PutOptions;
+use etcd_client::Txn;
+use etcd_client::TxnOp;
 use tokio::sync::OnceCell;
 
+use crate::raw::oio;
 use crate::services::etcd::error::format_etcd_error;
 use crate::{Buffer, Error, ErrorKind, Result};
 
@@ -125,18 +133,132 @@ impl EtcdCore {
         }
     }
 
-    pub async fn set(&self, key: &str, value: Buffer) -> Result<()> {
+    /// Set the value of given key, the key is attached to a new lease if
+    /// `ttl` is given.
+    pub async fn set(&self, key: &str, value: Buffer, ttl: Option<Duration>) -> Result<()> {
         let mut client = self.conn().await?;
+        let options = match ttl {
+            Some(ttl) => {
+                // Leases have a granularity of seconds, round up so that
+                // sub-second ttls don't expire at once.
+                let secs = ttl.as_millis().div_ceil(1000) as i64;
+                let lease = client
+                    .lease_grant(secs, None)
+                    .await
+                    .map_err(format_etcd_error)?;
+                Some(PutOptions::new().with_lease(lease.id()))
+            }
+            None => None,
+        };
         let _ = client
-            .put(key, value.to_vec(), None)
+            .put(key, value.to_vec(), options)
             .await
             .map_err(format_etcd_error)?;
         Ok(())
     }
 
+    /// Get the remaining time to live of given key, returns `None` if the
+    /// key doesn't exist or isn't attached to a lease.
+    pub async fn ttl(&self, key: &str) -> Result<Option<Duration>> {
+        let mut client = self.conn().await?;
+        let resp = client
+            .get(key, Some(GetOptions::new().with_keys_only()))
+            .await
+            .map_err(format_etcd_error)?;
+        let Some(lease) = resp
+            .kvs()
+            .first()
+            .map(|kv| kv.lease())
+            .filter(|id| *id != 0)
+        else {
+            return Ok(None);
+        };
+
+        let resp = client
+            .lease_time_to_live(lease, None)
+            .await
+            .map_err(format_etcd_error)?;
+        // A negative ttl means the lease has expired or been revoked.
+        Ok((resp.ttl() >= 0).then(|| Duration::from_secs(resp.ttl() as u64)))
+    }
+
     pub async fn delete(&self, key: &str) -> Result<()> {
         let mut client = self.conn().await?;
         let _ = client.delete(key, None).await.map_err(format_etcd_error)?;
         Ok(())
     }
+
+    /// Copy the value of `from` to `to` in a single transaction, returns
+    /// `false` if `from` not exist.
+    pub async fn copy(&self, from: &str, to: &str) -> Result<bool> {
+        self.transfer(from, to, false).await
+    }
+
+    /// Move the value of `from` to `to` in a single transaction, returns
+    /// `false` if `from` not exist.
+    pub async fn rename(&self, from: &str, to: &str) -> Result<bool> {
+        self.transfer(from, to, true).await
+    }
+
+    async fn transfer(&self, from: &str, to: &str, remove: bool) -> Result<bool> {
+        let mut client = self.conn().await?;
+        let resp = client.get(from, None).await.map_err(format_etcd_error)?;
+        let Some(kv) = resp.kvs().first() else {
+            return Ok(false);
+        };
+
+        // Keep `to` attached to the same lease so that its ttl is not lost.
+        let options = (kv.lease() != 0).then(|| PutOptions::new().with_lease(kv.lease()));
+        let mut ops = vec![TxnOp::put(to, kv.value(), options)];
+        if remove {
+            ops.push(TxnOp::delete(from, None));
+        }
+        // Only apply the changes if `from` is not changed since we read it.
+        let txn = Txn::new()
+            .when([Compare::mod_revision(
+                from,
+                CompareOp::Equal,
+                kv.mod_revision(),
+            )])
+            .and_then(ops);
+        let resp = client.txn(txn).await.map_err(format_etcd_error)?;
+        if !resp.succeeded() {
+            return Err(Error::new(
+                ErrorKind::ConditionNotMatch,
+                "source key has been changed during the transaction",
+            )
+            .set_temporary());
+        }
+        Ok(true)
+    }
+}
+
+impl oio::KeyScan for EtcdCore {
+    async fn scan(&self, prefix: &str, after: Option<&str>, limit: usize) -> Result<Vec<String>> {
+        let mut client = self.conn().await?;
+
+        // `\0` is the smallest key greater than `after`.
+        let start = match after {
+            Some(after) => format!("{after}\0"),
+            None => prefix.to_string(),
+        };
+        let options = GetOptions::new()
+            .with_from_key()
+            .with_limit(limit as i64)
+            .with_keys_only();
+        let resp = client
+            .get(start, Some(options))
+            .await
+            .map_err(format_etcd_error)?;
+
+        let mut keys = Vec::with_capacity(resp.kvs().len());
+        for kv in resp.kvs() {
+            let key = kv.key_str().map(String::from).map_err(|err| {
+                Error::new(ErrorKind::Unexpected, "store key is not valid utf-8 string")
+                    .set_source(err)
+            })?;
+            keys.push(key);
+        }
+        Ok(keys)
+    }
 }
@@ -23,7 +23,6 @@ mod config;
 mod core;
 mod deleter;
 mod error;
-mod lister;
 mod writer;
 
 pub use backend::EtcdBuilder as Etcd;
@@ -16,22 +16,25 @@
 // under the License.
 
 use std::sync::Arc;
+use std::time::Duration;
 
 use super::core::EtcdCore;
-use crate::raw::oio;
+use crate::raw::*;
 use crate::*;
 
 pub struct EtcdWriter {
     core: Arc<EtcdCore>,
     path: String,
+    ttl: Option<Duration>,
     buffer: oio::QueueBuf,
 }
 
 impl EtcdWriter {
-    pub fn new(core: Arc<EtcdCore>, path: String) -> Self {
+    pub fn new(core: Arc<EtcdCore>, path: String, ttl: Option<Duration>) -> Self {
         Self {
             core,
             path,
+            ttl,
             buffer: oio::QueueBuf::new(),
         }
     }
@@ -46,10 +49,13 @@ impl oio::Write for EtcdWriter {
     async fn close(&mut self) -> Result<Metadata> {
         let buf = self.buffer.clone().collect();
 
-        self.core.set(&self.path, buf.clone()).await?;
+        self.core.set(&self.path, buf.clone(), self.ttl).await?;
 
         let mut metadata = Metadata::new(EntryMode::from_path(&self.path));
         metadata.set_content_length(buf.len() as u64);
+        if let Some(ttl) = self.ttl {
+            metadata.set_expires_at(Timestamp::now() + ttl);
+        }
 
         Ok(metadata)
     }
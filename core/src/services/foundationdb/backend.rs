@@ -24,10 +24,12 @@ use super::FOUNDATIONDB_SCHEME;
 use super::config::FoundationdbConfig;
 use super::core::*;
 use super::deleter::FoundationdbDeleter;
-use super::writer::FoundationdbWriter;
 use crate::raw::*;
 use crate::*;
 
+/// Default chunk size, below the 100,000 bytes value limit of FoundationDB.
+const DEFAULT_CHUNK_SIZE: usize = 64 * 1024;
+
 #[doc = include_str!("docs.md")]
 #[derive(Debug, Default)]
 pub struct FoundationdbBuilder {
@@ -46,12 +48,31 @@ impl FoundationdbBuilder {
         self.config.config_path = Some(path.into());
         self
     }
+
+    /// Set the size of chunks used to store large values.
+    ///
+    /// Default to 64 KiB.
+    pub fn chunk_size(mut self, chunk_size: usize) -> Self {
+        if chunk_size > 0 {
+            self.config.chunk_size = Some(chunk_size);
+        }
+        self
+    }
 }
 
 impl Builder for FoundationdbBuilder {
     type Config = FoundationdbConfig;
 
     fn build(self) -> Result<impl Access> {
+        let chunk_size = self.config.chunk_size.unwrap_or(DEFAULT_CHUNK_SIZE);
+        if chunk_size == 0 {
+            return Err(Error::new(
+                ErrorKind::ConfigInvalid,
+                "chunk_size must be greater than 0",
+            )
+            .with_context("service", FOUNDATIONDB_SCHEME));
+        }
+
         let _network = Arc::new(unsafe { foundationdb::boot() });
         let db;
         if let Some(cfg_path) = &self.config.config_path {
@@ -78,20 +99,23 @@ impl Builder for FoundationdbBuilder {
                 .as_str(),
         );
 
-        Ok(FoundationdbBackend::new(FoundationdbCore { db, _network }).with_normalized_root(root))
+        Ok(
+            FoundationdbBackend::new(FoundationdbCore { db, _network }, chunk_size)
+                .with_normalized_root(root),
+        )
     }
 }
 
 /// Backend for Foundationdb services.
 #[derive(Clone, Debug)]
 pub struct FoundationdbBackend {
-    core: Arc<FoundationdbCore>,
+    kv: ChunkedKv<FoundationdbCore>,
     root: String,
     info: Arc<AccessorInfo>,
 }
 
 impl FoundationdbBackend {
-    pub fn new(core: FoundationdbCore) -> Self {
+    pub fn new(core: FoundationdbCore, chunk_size: usize) -> Self {
         let info = AccessorInfo::default();
         info.set_scheme(FOUNDATIONDB_SCHEME);
         info.set_name("foundationdb");
@@ -101,13 +125,16 @@ impl FoundationdbBackend {
             stat: true,
             write: true,
             write_can_empty: true,
+            write_can_multi: true,
             delete: true,
+            copy: true,
+            rename: true,
             shared: true,
             ..Default::default()
         });
 
         Self {
-            core: Arc::new(core),
+            kv: ChunkedKv::new(Arc::new(core), chunk_size),
             root: "/".to_string(),
             info: Arc::new(info),
         }
@@ -121,8 +148,8 @@ impl FoundationdbBackend {
 }
 
 impl Access for FoundationdbBackend {
-    type Reader = Buffer;
-    type Writer = FoundationdbWriter;
+    type Reader = ChunkedKvReader<FoundationdbCore>;
+    type Writer = ChunkedKvWriter<FoundationdbCore>;
     type Lister = ();
     type Deleter = oio::OneShotDeleter<FoundationdbDeleter>;
 
@@ -136,10 +163,9 @@ impl Access for FoundationdbBackend {
         if p == build_abs_path(&self.root, "") {
             Ok(RpStat::new(Metadata::new(EntryMode::DIR)))
         } else {
-            let bs = self.core.get(&p).await?;
-            match bs {
-                Some(bs) => Ok(RpStat::new(
-                    Metadata::new(EntryMode::FILE).with_content_length(bs.len() as u64),
+            match self.kv.stat(&p).await? {
+                Some(size) => Ok(RpStat::new(
+                    Metadata::new(EntryMode::FILE).with_content_length(size),
                 )),
                 None => Err(Error::new(
                     ErrorKind::NotFound,
@@ -151,33 +177,48 @@ impl Access for FoundationdbBackend {
 
     async fn read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::Reader)> {
         let p = build_abs_path(&self.root, path);
-        let bs = match self.core.get(&p).await? {
-            Some(bs) => bs,
-            None => {
-                return Err(Error::new(
-                    ErrorKind::NotFound,
-                    "kv not found in foundationdb",
-                ));
-            }
-        };
-        Ok((RpRead::new(), bs.slice(args.range().to_range_as_usize())))
+        match self.kv.read(&p, args.range()).await? {
+            Some(r) => Ok((RpRead::new(), r)),
+            None => Err(Error::new(
+                ErrorKind::NotFound,
+                "kv not found in foundationdb",
+            )),
+        }
     }
 
     async fn write(&self, path: &str, _: OpWrite) -> Result<(RpWrite, Self::Writer)> {
         let p = build_abs_path(&self.root, path);
-        Ok((
-            RpWrite::new(),
-            FoundationdbWriter::new(self.core.clone(), p),
-        ))
+        Ok((RpWrite::new(), self.kv.writer(&p)))
     }
 
     async fn delete(&self) -> Result<(RpDelete, Self::Deleter)> {
         Ok((
             RpDelete::default(),
-            oio::OneShotDeleter::new(FoundationdbDeleter::new(
-                self.core.clone(),
-                self.root.clone(),
-            )),
+            oio::OneShotDeleter::new(FoundationdbDeleter::new(self.kv.clone(), self.root.clone())),
         ))
     }
+
+    async fn copy(&self, from: &str, to: &str, _: OpCopy) -> Result<RpCopy> {
+        let from = build_abs_path(&self.root, from);
+        let to = build_abs_path(&self.root, to);
+        if !self.kv.copy(&from, &to).await? {
+            return Err(Error::new(
+                ErrorKind::NotFound,
+                "kv not found in foundationdb",
+            ));
+        }
+        Ok(RpCopy::default())
+    }
+
+    async fn rename(&self, from: &str, to: &str, _: OpRename) -> Result<RpRename> {
+        let from = build_abs_path(&self.root, from);
+        let to = build_abs_path(&self.root, to);
+        if !self.kv.rename(&from, &to).await? {
+            return Err(Error::new(
+                ErrorKind::NotFound,
+                "kv not found in foundationdb",
+            ));
+        }
+        Ok(RpRename::default())
+    }
 }
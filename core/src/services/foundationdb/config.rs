@@ -30,6 +30,13 @@ pub struct FoundationdbConfig {
     pub root: Option<String>,
     /// config_path for the backend.
     pub config_path: Option<String>,
+    /// The size of chunks used to store large values.
+    ///
+    /// Values larger than this are split into multiple keys, so writes can
+    /// be streamed and values are not limited by FoundationDB's value size.
+    ///
+    /// Default to 64 KiB.
+    pub chunk_size: Option<usize>,
 }
 
 impl crate::Configurator for FoundationdbConfig {
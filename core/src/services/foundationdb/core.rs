@@ -22,6 +22,7 @@ use foundationdb::Database;
 use foundationdb::api::NetworkAutoStop;
 
 use super::FOUNDATIONDB_SCHEME;
+use crate::raw::*;
 use crate::*;
 
 #[derive(Clone)]
@@ -41,13 +42,7 @@ impl FoundationdbCore {
         let transaction = self.db.create_trx().expect("Unable to create transaction");
 
         match transaction.get(path.as_bytes(), false).await {
-            Ok(slice) => match slice {
-                Some(data) => Ok(Some(Buffer::from(data.to_vec()))),
-                None => Err(Error::new(
-                    ErrorKind::NotFound,
-                    "foundationdb: key not found",
-                )),
-            },
+            Ok(slice) => Ok(slice.map(|data| Buffer::from(data.to_vec()))),
             Err(_) => Err(Error::new(
                 ErrorKind::NotFound,
                 "foundationdb: key not found",
@@ -66,6 +61,27 @@ impl FoundationdbCore {
         }
     }
 
+    /// Move the value of `from` to `to` in a single transaction.
+    pub async fn rename(&self, from: &str, to: &str) -> Result<bool> {
+        let transaction = self.db.create_trx().expect("Unable to create transaction");
+
+        let value = match transaction.get(from.as_bytes(), false).await {
+            Ok(Some(value)) => value,
+            Ok(None) => return Ok(false),
+            Err(e) => {
+                return Err(Error::new(ErrorKind::Unexpected, e.to_string().as_str())
+                    .with_context("service", FOUNDATIONDB_SCHEME));
+            }
+        };
+        transaction.set(to.as_bytes(), &value);
+        transaction.clear(from.as_bytes());
+
+        match transaction.commit().await {
+            Ok(_) => Ok(true),
+            Err(e) => Err(parse_transaction_commit_error(e)),
+        }
+    }
+
     pub async fn delete(&self, path: &str) -> Result<()> {
         let transaction = self.db.create_trx().expect("Unable to create transaction");
         transaction.clear(path.as_bytes());
@@ -77,6 +93,24 @@ impl FoundationdbCore {
     }
 }
 
+impl ChunkedKvStore for FoundationdbCore {
+    async fn get(&self, key: &str) -> Result<Option<Buffer>> {
+        FoundationdbCore::get(self, key).await
+    }
+
+    async fn set(&self, key: &str, value: Buffer) -> Result<()> {
+        FoundationdbCore::set(self, key, value).await
+    }
+
+    async fn delete(&self, key: &str) -> Result<()> {
+        FoundationdbCore::delete(self, key).await
+    }
+
+    async fn rename(&self, from: &str, to: &str) -> Result<bool> {
+        FoundationdbCore::rename(self, from, to).await
+    }
+}
+
 fn parse_transaction_commit_error(e: foundationdb::TransactionCommitError) -> Error {
     Error::new(ErrorKind::Unexpected, e.to_string().as_str())
         .with_context("service", FOUNDATIONDB_SCHEME)
@@ -25,6 +25,7 @@ use super::config::FsConfig;
 use super::core::*;
 use super::deleter::FsDeleter;
 use super::lister::FsLister;
+use super::lister::ListFilter;
 use super::reader::FsReader;
 use super::writer::FsWriter;
 use super::writer::FsWriters;
@@ -150,15 +151,25 @@ impl Builder for FsBuilder {
                             write_can_append: true,
                             write_can_multi: true,
                             write_with_if_not_exists: true,
+                            write_with_unix_mode: cfg!(unix),
+                            write_with_mtime: true,
+                            write_with_content_type: true,
+                            write_with_cache_control: true,
+                            write_with_user_metadata: true,
 
                             create_dir: true,
                             delete: true,
 
                             list: true,
+                            list_with_limit: true,
+                            list_with_start_after: true,
+                            list_with_recursive: true,
 
                             copy: true,
                             rename: true,
 
+                            symlink: true,
+
                             shared: true,
 
                             ..Default::default()
@@ -183,7 +194,7 @@ pub struct FsBackend {
 impl Access for FsBackend {
     type Reader = FsReader<tokio::fs::File>;
     type Writer = FsWriters;
-    type Lister = Option<FsLister<tokio::fs::ReadDir>>;
+    type Lister = Option<FsLister>;
     type Deleter = oio::OneShotDeleter<FsDeleter>;
 
     fn info(&self) -> Arc<AccessorInfo> {
@@ -195,8 +206,8 @@ impl Access for FsBackend {
         Ok(RpCreateDir::default())
     }
 
-    async fn stat(&self, path: &str, _: OpStat) -> Result<RpStat> {
-        let m = self.core.fs_stat(path).await?;
+    async fn stat(&self, path: &str, args: OpStat) -> Result<RpStat> {
+        let m = self.core.fs_stat(path, args.no_follow_symlink()).await?;
         Ok(RpStat::new(m))
     }
 
@@ -245,11 +256,19 @@ impl Access for FsBackend {
         ))
     }
 
-    async fn list(&self, path: &str, _: OpList) -> Result<(RpList, Self::Lister)> {
-        match self.core.fs_list(path).await? {
-            Some(f) => {
-                let rd = FsLister::new(&self.core.root, path, f);
-                Ok((RpList::default(), Some(rd)))
+    async fn list(&self, path: &str, args: OpList) -> Result<(RpList, Self::Lister)> {
+        // Path without trailing `/` is a prefix of entries in its parent.
+        let dir = if path.ends_with('/') {
+            path
+        } else {
+            get_parent(path)
+        };
+        let filter = Arc::new(ListFilter::new(path, &args));
+
+        match self.core.fs_list(dir, filter.clone()).await? {
+            Some(page) => {
+                let l = FsLister::new(&self.core.root, dir, page, args.recursive(), filter);
+                Ok((RpList::default(), Some(l)))
             }
             None => Ok((RpList::default(), None)),
         }
@@ -264,4 +283,18 @@ impl Access for FsBackend {
         self.core.fs_rename(from, to).await?;
         Ok(RpRename::default())
     }
+    async fn create_symlink(
+        &self,
+        path: &str,
+        target: &str,
+        _: OpCreateSymlink,
+    ) -> Result<RpCreateSymlink> {
+        self.core.fs_create_symlink(path, target).await?;
+        Ok(RpCreateSymlink::default())
+    }
+
+    async fn read_link(&self, path: &str, _: OpReadLink) -> Result<RpReadLink> {
+        let target = self.core.fs_read_link(path).await?;
+        Ok(RpReadLink::new(target))
+    }
 }
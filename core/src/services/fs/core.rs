@@ -21,6 +21,9 @@ use std::path::PathBuf;
 use std::sync::Arc;
 
 use super::error::*;
+use super::lister::DirPage;
+use super::lister::ListFilter;
+use super::lister::read_dir;
 use crate::raw::*;
 use crate::*;
 
@@ -69,22 +72,32 @@ impl FsCore {
         Ok(())
     }
 
-    pub async fn fs_stat(&self, path: &str) -> Result<Metadata> {
+    pub async fn fs_stat(&self, path: &str, no_follow_symlink: bool) -> Result<Metadata> {
         let p = self.root.join(path.trim_end_matches('/'));
-        let meta = tokio::fs::metadata(&p).await.map_err(new_std_io_error)?;
+        let meta = if no_follow_symlink {
+            tokio::fs::symlink_metadata(&p).await
+        } else {
+            tokio::fs::metadata(&p).await
+        }
+        .map_err(new_std_io_error)?;
         let mode = if meta.is_dir() {
             EntryMode::DIR
         } else if meta.is_file() {
             EntryMode::FILE
+        } else if meta.is_symlink() {
+            EntryMode::Symlink
         } else {
             EntryMode::Unknown
         };
-        let m = Metadata::new(mode)
+        let mut m = Metadata::new(mode)
             .with_content_length(meta.len())
             .with_last_modified(Timestamp::try_from(
                 meta.modified().map_err(new_std_io_error)?,
             )?);
-        Ok(m)
+        if meta.is_file() {
+            m = load_attrs(p).await?.into_metadata(m);
+        }
+        Ok(with_unix_attrs(m, &meta))
     }
 
     pub async fn fs_read(&self, path: &str, args: &OpRead) -> Result<tokio::fs::File> {
@@ -157,42 +170,10 @@ impl FsCore {
         Ok((f, Some(tmp_path)))
     }
 
-    pub async fn fs_list(&self, path: &str) -> Result<Option<tokio::fs::ReadDir>> {
-        let p = self.root.join(path.trim_end_matches('/'));
-
-        match tokio::fs::read_dir(&p).await {
-            Ok(rd) => Ok(Some(rd)),
-            Err(e) => {
-                match e.kind() {
-                    // Return empty list if the directory not found
-                    std::io::ErrorKind::NotFound => Ok(None),
-                    // TODO: enable after our MSRV has been raised to 1.83
-                    //
-                    // If the path is not a directory, return an empty list
-                    //
-                    // The path could be a file or a symbolic link in this case.
-                    // Returning a NotADirectory error to the user isn't helpful; instead,
-                    // providing an empty directory is a more user-friendly. In fact, the dir
-                    // `path/` does not exist.
-                    // std::io::ErrorKind::NotADirectory => Ok((RpList::default(), None)),
-                    _ => {
-                        // TODO: remove this after we have MSRV 1.83
-                        #[cfg(unix)]
-                        if e.raw_os_error() == Some(20) {
-                            // On unix 20: Not a directory
-                            return Ok(None);
-                        }
-                        #[cfg(windows)]
-                        if e.raw_os_error() == Some(267) {
-                            // On windows 267: DIRECTORY
-                            return Ok(None);
-                        }
-
-                        Err(new_std_io_error(e))
-                    }
-                }
-            }
-        }
+    pub async fn fs_list(&self, path: &str, filter: Arc<ListFilter>) -> Result<Option<DirPage>> {
+        let root = self.root.clone();
+        let path = path.to_string();
+        spawn_blocking(move || read_dir(&root, &path, &filter)).await
     }
 
     pub async fn fs_copy(&self, from: &str, to: &str) -> Result<()> {
@@ -204,10 +185,34 @@ impl FsCore {
             .ensure_write_abs_path(&self.root, to.trim_end_matches('/'))
             .await?;
 
-        tokio::fs::copy(from, to).await.map_err(new_std_io_error)?;
+        tokio::fs::copy(&from, &to)
+            .await
+            .map_err(new_std_io_error)?;
+        spawn_blocking(move || copy_file_attrs(&from, &to)).await
+    }
+
+    pub async fn fs_create_symlink(&self, path: &str, target: &str) -> Result<()> {
+        let p = self.ensure_write_abs_path(&self.root, path).await?;
+
+        #[cfg(unix)]
+        tokio::fs::symlink(target, &p)
+            .await
+            .map_err(new_std_io_error)?;
+        // Windows requires to know the kind of target, we only support links to files here.
+        #[cfg(windows)]
+        tokio::fs::symlink_file(target, &p)
+            .await
+            .map_err(new_std_io_error)?;
+
         Ok(())
     }
 
+    pub async fn fs_read_link(&self, path: &str) -> Result<String> {
+        let p = self.root.join(path.trim_end_matches('/'));
+        let target = tokio::fs::read_link(&p).await.map_err(new_std_io_error)?;
+        Ok(target.to_string_lossy().to_string())
+    }
+
     pub async fn fs_rename(&self, from: &str, to: &str) -> Result<()> {
         let from = self.root.join(from.trim_end_matches('/'));
         tokio::fs::metadata(&from).await.map_err(new_std_io_error)?;
@@ -216,9 +221,65 @@ impl FsCore {
             .ensure_write_abs_path(&self.root, to.trim_end_matches('/'))
             .await?;
 
-        tokio::fs::rename(from, to)
+        tokio::fs::rename(&from, &to)
             .await
             .map_err(new_std_io_error)?;
-        Ok(())
+        spawn_blocking(move || rename_file_attrs(&from, &to)).await
+    }
+}
+
+/// Run blocking file attributes operations in the blocking thread pool.
+pub async fn spawn_blocking<T, F>(f: F) -> Result<T>
+where
+    T: Send + 'static,
+    F: FnOnce() -> Result<T> + Send + 'static,
+{
+    tokio::task::spawn_blocking(f)
+        .await
+        .map_err(new_task_join_error)?
+}
+
+/// Load the content type, cache control and user metadata of the file.
+pub async fn load_attrs(p: PathBuf) -> Result<FileAttrs> {
+    spawn_blocking(move || FileAttrs::load(&p)).await
+}
+
+/// Fill POSIX permission bits and ownership from the file metadata.
+#[cfg(unix)]
+pub fn with_unix_attrs(m: Metadata, meta: &std::fs::Metadata) -> Metadata {
+    use std::os::unix::fs::MetadataExt;
+
+    m.with_unix_mode(meta.mode() & 0o7777)
+        .with_uid(meta.uid())
+        .with_gid(meta.gid())
+}
+
+#[cfg(not(unix))]
+pub fn with_unix_attrs(m: Metadata, _: &std::fs::Metadata) -> Metadata {
+    m
+}
+
+/// Apply the permission bits and modification time requested by write.
+///
+/// This must be called after all data has been written, otherwise the
+/// modification time will be overwritten by following writes.
+pub fn set_file_attrs(
+    f: &std::fs::File,
+    unix_mode: Option<u32>,
+    mtime: Option<Timestamp>,
+) -> Result<()> {
+    #[cfg(unix)]
+    if let Some(mode) = unix_mode {
+        use std::os::unix::fs::PermissionsExt;
+
+        f.set_permissions(std::fs::Permissions::from_mode(mode & 0o7777))
+            .map_err(new_std_io_error)?;
+    }
+    #[cfg(not(unix))]
+    let _ = unix_mode;
+
+    if let Some(mtime) = mtime {
+        f.set_modified(mtime.into()).map_err(new_std_io_error)?;
     }
+    Ok(())
 }
@@ -35,7 +35,8 @@ impl oio::OneShotDelete for FsDeleter {
     async fn delete_once(&self, path: String, _: OpDelete) -> Result<()> {
         let p = self.core.root.join(path.trim_end_matches('/'));
 
-        let meta = tokio::fs::metadata(&p).await;
+        // Don't follow symlinks, so that the link itself is removed instead of its target.
+        let meta = tokio::fs::symlink_metadata(&p).await;
 
         match meta {
             Ok(meta) => {
@@ -43,6 +44,7 @@ impl oio::OneShotDelete for FsDeleter {
                     tokio::fs::remove_dir(&p).await.map_err(new_std_io_error)?;
                 } else {
                     tokio::fs::remove_file(&p).await.map_err(new_std_io_error)?;
+                    spawn_blocking(move || remove_file_attrs(&p)).await?;
                 }
                 Ok(())
             }
@@ -15,38 +15,362 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+use std::collections::HashMap;
+use std::collections::VecDeque;
 use std::path::Path;
 use std::path::PathBuf;
+use std::sync::Arc;
+
+use tokio::task::JoinHandle;
 
-use crate::EntryMode;
-use crate::Metadata;
-use crate::Result;
 use crate::raw::*;
+use crate::*;
 
-pub struct FsLister<P> {
-    root: PathBuf,
+/// The max number of dirs that can be read in background while listing recursively.
+const LIST_CONCURRENT: usize = 8;
 
-    current_path: Option<String>,
+/// The max number of entries that will be kept in memory for one dir.
+///
+/// Larger dirs are read in multiple passes, each pass keeps the smallest
+/// entries after the last returned one.
+///
+/// # Cost
+///
+/// Every pass scans the whole dir since `read_dir` returns entries in no
+/// particular order, so listing a dir of `n` entries reads `n * n / 10_000`
+/// entries in total. A dir of 1M entries takes 100 passes, which trades
+/// listing time for bounded memory.
+const READ_DIR_PAGE_SIZE: usize = 10_000;
+
+type ReadDirResult = Result<Option<DirPage>>;
+
+/// A page of sorted entries read from a dir.
+pub struct DirPage {
+    dir: String,
+    entries: VecDeque<oio::Entry>,
+    /// The path of the last entry in this page if there are more entries in the dir.
+    next: Option<String>,
+}
+
+/// Entry that ordered by path so that we can keep the smallest entries in a max-heap.
+struct DirEntry {
+    path: String,
+    mode: EntryMode,
+}
+
+impl PartialEq for DirEntry {
+    fn eq(&self, other: &Self) -> bool {
+        self.path == other.path
+    }
+}
 
-    rd: P,
+impl Eq for DirEntry {}
+
+impl PartialOrd for DirEntry {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for DirEntry {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.path.cmp(&other.path)
+    }
+}
+
+/// Filters that applied while reading dirs, so that entries that will never
+/// be returned can be skipped as early as possible.
+#[derive(Debug)]
+pub struct ListFilter {
+    /// Only entries that start with prefix will be returned.
+    ///
+    /// It's empty while listing a dir.
+    prefix: String,
+    /// Only entries that sorted after start_after will be returned.
+    start_after: Option<String>,
+    /// Dirs that can't contain entries matching the glob will be skipped.
+    glob: Option<GlobMatcher>,
 }
 
-impl<P> FsLister<P> {
-    pub fn new(root: &Path, path: &str, rd: P) -> Self {
+impl ListFilter {
+    pub fn new(path: &str, args: &OpList) -> Self {
+        let prefix = if path.ends_with('/') {
+            String::new()
+        } else {
+            path.to_string()
+        };
+
         Self {
-            root: root.to_owned(),
-            current_path: Some(path.to_string()),
-            rd,
+            prefix,
+            start_after: args.start_after().map(String::from),
+            glob: args.glob().cloned(),
+        }
+    }
+
+    /// Returns `true` if the entry should be returned.
+    fn matches(&self, path: &str) -> bool {
+        path.starts_with(&self.prefix) && self.start_after.as_deref().is_none_or(|v| path > v)
+    }
+
+    /// Returns `true` if there could be entries under dir that should be returned.
+    fn may_contain(&self, dir: &str) -> bool {
+        if !dir.starts_with(&self.prefix) && !self.prefix.starts_with(dir) {
+            return false;
         }
+        if self.glob.as_ref().is_some_and(|g| !g.matches_prefix(dir)) {
+            return false;
+        }
+
+        // All entries under dir are sorted before start_after, we can skip the whole dir.
+        self.start_after
+            .as_deref()
+            .is_none_or(|v| v < dir || v.starts_with(dir))
     }
 }
 
-/// # Safety
+/// Read the first page of entries of the dir in lexicographical order.
+///
+/// Returns `None` if the dir doesn't exist or is not a dir.
+pub fn read_dir(root: &Path, dir: &str, filter: &ListFilter) -> ReadDirResult {
+    read_dir_page(root, dir, filter, None, READ_DIR_PAGE_SIZE)
+}
+
+/// Read at most `limit` entries that sorted after `after` from the dir.
 ///
-/// We will only take `&mut Self` reference for FsLister.
-unsafe impl<P> Sync for FsLister<P> {}
+/// The whole dir is scanned in every call, but only `limit` entries are kept
+/// in memory.
+fn read_dir_page(
+    root: &Path,
+    dir: &str,
+    filter: &ListFilter,
+    after: Option<&str>,
+    limit: usize,
+) -> ReadDirResult {
+    let rd = match std::fs::read_dir(root.join(dir.trim_end_matches('/'))) {
+        Ok(rd) => rd,
+        Err(e) => {
+            match e.kind() {
+                // Return empty list if the directory not found
+                std::io::ErrorKind::NotFound => return Ok(None),
+                // TODO: enable after our MSRV has been raised to 1.83
+                //
+                // If the path is not a directory, return an empty list
+                //
+                // The path could be a file or a symbolic link in this case.
+                // Returning a NotADirectory error to the user isn't helpful; instead,
+                // providing an empty directory is a more user-friendly. In fact, the dir
+                // `path/` does not exist.
+                // std::io::ErrorKind::NotADirectory => return Ok(None),
+                _ => {
+                    // TODO: remove this after we have MSRV 1.83
+                    #[cfg(unix)]
+                    if e.raw_os_error() == Some(20) {
+                        // On unix 20: Not a directory
+                        return Ok(None);
+                    }
+                    #[cfg(windows)]
+                    if e.raw_os_error() == Some(267) {
+                        // On windows 267: DIRECTORY
+                        return Ok(None);
+                    }
+
+                    return Err(new_std_io_error(e));
+                }
+            }
+        }
+    };
+
+    let base = if dir == "/" { "" } else { dir };
+
+    let mut heap = BinaryHeap::with_capacity(limit.min(1024));
+    let mut more = false;
+    for de in rd {
+        let de = de.map_err(new_std_io_error)?;
+        let name = de.file_name().to_string_lossy().to_string();
+        // Skip the sidecar files used to store file attributes.
+        if is_sidecar_file(&name) {
+            continue;
+        }
+
+        let ft = de.file_type().map_err(new_std_io_error)?;
+        let entry = if ft.is_dir() {
+            // Make sure we are returning the correct path.
+            let path = format!("{base}{name}/");
+            if !filter.matches(&path) && !filter.may_contain(&path) {
+                continue;
+            }
+            DirEntry {
+                path,
+                mode: EntryMode::DIR,
+            }
+        } else {
+            let path = format!("{base}{name}");
+            if !filter.matches(&path) {
+                continue;
+            }
+            // File attributes are only loaded while stat to keep listing cheap.
+            let mode = if ft.is_file() {
+                EntryMode::FILE
+            } else if ft.is_symlink() {
+                EntryMode::Symlink
+            } else {
+                EntryMode::Unknown
+            };
+            DirEntry { path, mode }
+        };
+        if after.is_some_and(|v| entry.path.as_str() <= v) {
+            continue;
+        }
+
+        if heap.len() < limit {
+            heap.push(entry);
+        } else {
+            more = true;
+            if heap.peek().is_some_and(|max: &DirEntry| entry < *max) {
+                heap.pop();
+                heap.push(entry);
+            }
+        }
+    }
+
+    // Dirs are ended with `/`, so walking sorted dirs in depth-first order
+    // keeps the whole output sorted.
+    let entries: VecDeque<_> = heap
+        .into_sorted_vec()
+        .into_iter()
+        .map(|e| oio::Entry::new(&e.path, Metadata::new(e.mode)))
+        .collect();
+    let next = if more {
+        entries.back().map(|e| e.path().to_string())
+    } else {
+        None
+    };
+    Ok(Some(DirPage {
+        dir: dir.to_string(),
+        entries,
+        next,
+    }))
+}
 
-impl oio::List for FsLister<tokio::fs::ReadDir> {
+fn spawn_read_dir(
+    root: PathBuf,
+    dir: &str,
+    filter: Arc<ListFilter>,
+    after: Option<String>,
+    limit: usize,
+) -> JoinHandle<ReadDirResult> {
+    let dir = dir.to_string();
+    tokio::task::spawn_blocking(move || {
+        read_dir_page(&root, &dir, &filter, after.as_deref(), limit)
+    })
+}
+
+/// Sorted entries of a dir that is being walked.
+struct Frame {
+    page: DirPage,
+    /// The number of leading entries that have been checked for prefetching.
+    scanned: usize,
+}
+
+impl Frame {
+    fn new(page: DirPage) -> Self {
+        Self { page, scanned: 0 }
+    }
+
+    fn pop_front(&mut self) -> Option<oio::Entry> {
+        self.scanned = self.scanned.saturating_sub(1);
+        self.page.entries.pop_front()
+    }
+}
+
+/// FsLister returns entries in lexicographical order like object storage services.
+///
+/// While listing recursively, dirs are walked in depth-first order and the
+/// next dirs to walk are read in background with bounded concurrency.
+pub struct FsLister {
+    root: PathBuf,
+    recursive: bool,
+    filter: Arc<ListFilter>,
+    page_size: usize,
+
+    current_path: Option<String>,
+
+    /// The dirs that are being walked, the innermost dir is the last one.
+    stack: Vec<Frame>,
+    /// The dirs that are being read in background.
+    pending: HashMap<String, JoinHandle<ReadDirResult>>,
+}
+
+impl FsLister {
+    pub fn new(
+        root: &Path,
+        path: &str,
+        page: DirPage,
+        recursive: bool,
+        filter: Arc<ListFilter>,
+    ) -> Self {
+        let mut lister = Self {
+            root: root.to_owned(),
+            recursive,
+            current_path: filter.matches(path).then(|| path.to_string()),
+            filter,
+            page_size: READ_DIR_PAGE_SIZE,
+            stack: vec![Frame::new(page)],
+            pending: HashMap::new(),
+        };
+        lister.prefetch();
+        lister
+    }
+
+    /// Start reading the dirs that will be walked next in background.
+    fn prefetch(&mut self) {
+        if !self.recursive {
+            return;
+        }
+
+        for frame in self.stack.iter_mut().rev() {
+            while self.pending.len() < LIST_CONCURRENT {
+                let Some(entry) = frame.page.entries.get(frame.scanned) else {
+                    break;
+                };
+                frame.scanned += 1;
+
+                if entry.mode().is_dir() && self.filter.may_contain(entry.path()) {
+                    let handle = spawn_read_dir(
+                        self.root.clone(),
+                        entry.path(),
+                        self.filter.clone(),
+                        None,
+                        self.page_size,
+                    );
+                    self.pending.insert(entry.path().to_string(), handle);
+                }
+            }
+
+            if self.pending.len() >= LIST_CONCURRENT {
+                return;
+            }
+        }
+    }
+
+    async fn read_dir(&mut self, dir: &str) -> ReadDirResult {
+        let handle = match self.pending.remove(dir) {
+            Some(handle) => handle,
+            None => spawn_read_dir(
+                self.root.clone(),
+                dir,
+                self.filter.clone(),
+                None,
+                self.page_size,
+            ),
+        };
+        handle.await.map_err(new_task_join_error)?
+    }
+}
+
+impl oio::List for FsLister {
     async fn next(&mut self) -> Result<Option<oio::Entry>> {
         // since list should return path itself, we return it first
         if let Some(path) = self.current_path.take() {
@@ -54,28 +378,136 @@ impl oio::List for FsLister<tokio::fs::ReadDir> {
             return Ok(Some(e));
         }
 
-        let Some(de) = self.rd.next_entry().await.map_err(new_std_io_error)? else {
-            return Ok(None);
-        };
+        loop {
+            let Some(frame) = self.stack.last_mut() else {
+                return Ok(None);
+            };
+            let Some(entry) = frame.pop_front() else {
+                // Read the next page of the dir if it's too large to be read at once.
+                if let Some(after) = frame.page.next.take() {
+                    let dir = frame.page.dir.clone();
+                    let handle = spawn_read_dir(
+                        self.root.clone(),
+                        &dir,
+                        self.filter.clone(),
+                        Some(after),
+                        self.page_size,
+                    );
+                    if let Some(page) = handle.await.map_err(new_task_join_error)?? {
+                        frame.page = page;
+                        frame.scanned = 0;
+                        self.prefetch();
+                        continue;
+                    }
+                }
+                self.stack.pop();
+                continue;
+            };
+
+            // Entries under the dir must be returned right after the dir itself.
+            if self.recursive && entry.mode().is_dir() && self.filter.may_contain(entry.path()) {
+                if let Some(page) = self.read_dir(entry.path()).await? {
+                    self.stack.push(Frame::new(page));
+                    self.prefetch();
+                }
+            }
+
+            if self.filter.matches(entry.path()) {
+                return Ok(Some(entry));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_list_filter() {
+        let filter = ListFilter::new("dir/x", &OpList::new().with_start_after("dir/x/b"));
+
+        assert!(!filter.matches("dir/"));
+        assert!(!filter.matches("dir/x/a"));
+        assert!(!filter.matches("dir/x/b"));
+        assert!(filter.matches("dir/x/c"));
+        assert!(filter.matches("dir/xy/"));
+        assert!(!filter.matches("dir/y"));
+
+        assert!(filter.may_contain("dir/"));
+        assert!(filter.may_contain("dir/x/"));
+        assert!(filter.may_contain("dir/xy/"));
+        assert!(!filter.may_contain("dir/x/a/"));
+        assert!(filter.may_contain("dir/x/b/"));
+        assert!(!filter.may_contain("dir/y/"));
+    }
+
+    #[test]
+    fn test_list_filter_with_glob() {
+        let glob = GlobMatcher::new("dir/*/2024/*.csv").unwrap();
+        let filter = ListFilter::new("dir/", &OpList::new().with_glob(glob));
+
+        assert!(filter.may_contain("dir/a/"));
+        assert!(filter.may_contain("dir/a/2024/"));
+        assert!(!filter.may_contain("dir/a/2023/"));
+        assert!(!filter.may_contain("other/"));
+    }
 
-        let entry_path = de.path();
-        let rel_path = normalize_path(
-            &entry_path
-                .strip_prefix(&self.root)
-                .expect("cannot fail because the prefix is iterated")
-                .to_string_lossy()
-                .replace('\\', "/"),
+    #[test]
+    fn test_read_dir_in_pages() {
+        let root = std::env::temp_dir().join(format!("opendal-fs-{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(root.join("dir/sub")).unwrap();
+        for name in ["e", "a", "d", "b"] {
+            std::fs::write(root.join("dir").join(name), b"").unwrap();
+        }
+        let filter = ListFilter::new("dir/", &OpList::new());
+
+        let mut paths = vec![];
+        let mut after = None;
+        loop {
+            let page = read_dir_page(&root, "dir/", &filter, after.as_deref(), 2)
+                .unwrap()
+                .unwrap();
+            assert!(page.entries.len() <= 2);
+            paths.extend(page.entries.iter().map(|e| e.path().to_string()));
+            after = page.next;
+            if after.is_none() {
+                break;
+            }
+        }
+        std::fs::remove_dir_all(&root).unwrap();
+
+        assert_eq!(
+            paths,
+            ["dir/a", "dir/b", "dir/d", "dir/e", "dir/sub/"].map(String::from)
         );
+    }
 
-        let ft = de.file_type().await.map_err(new_std_io_error)?;
-        let entry = if ft.is_dir() {
-            // Make sure we are returning the correct path.
-            oio::Entry::new(&format!("{rel_path}/"), Metadata::new(EntryMode::DIR))
-        } else if ft.is_file() {
-            oio::Entry::new(&rel_path, Metadata::new(EntryMode::FILE))
-        } else {
-            oio::Entry::new(&rel_path, Metadata::new(EntryMode::Unknown))
-        };
-        Ok(Some(entry))
+    #[tokio::test]
+    async fn test_list_recursive_in_pages() -> Result<()> {
+        let root = std::env::temp_dir().join(format!("opendal-fs-{}", uuid::Uuid::new_v4()));
+        for dir in ["dir", "dir/b", "dir/b/y"] {
+            std::fs::create_dir_all(root.join(dir)).unwrap();
+            for name in ["e", "a", "d", "c"] {
+                std::fs::write(root.join(dir).join(name), b"").unwrap();
+            }
+        }
+        let filter = Arc::new(ListFilter::new("dir/", &OpList::new()));
+        let page = read_dir_page(&root, "dir/", &filter, None, 2)?.unwrap();
+
+        let mut lister = FsLister::new(&root, "dir/", page, true, filter);
+        lister.page_size = 2;
+        let mut paths = vec![];
+        while let Some(entry) = oio::List::next(&mut lister).await? {
+            paths.push(entry.path().to_string());
+        }
+        std::fs::remove_dir_all(&root).unwrap();
+
+        let mut expected = paths.clone();
+        expected.sort();
+        assert_eq!(paths, expected);
+        assert_eq!(paths.len(), 15);
+        assert_eq!(paths[..4], ["dir/", "dir/a", "dir/b/", "dir/b/a"]);
+        Ok(())
     }
 }
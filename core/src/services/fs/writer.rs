@@ -24,7 +24,7 @@ use bytes::Buf;
 use tokio::io::AsyncWriteExt;
 
 use crate::raw::*;
-use crate::services::fs::core::FsCore;
+use crate::services::fs::core::*;
 use crate::*;
 
 pub type FsWriters = TwoWays<FsWriter, oio::PositionWriter<FsWriter>>;
@@ -34,6 +34,12 @@ pub struct FsWriter {
     /// The temp_path is used to specify whether we should move to target_path after the file has been closed.
     temp_path: Option<PathBuf>,
     f: tokio::fs::File,
+    unix_mode: Option<u32>,
+    mtime: Option<Timestamp>,
+    /// The attributes to store after the file has been closed.
+    ///
+    /// Appending without any attributes keeps the existing ones.
+    attrs: Option<FileAttrs>,
 }
 
 impl FsWriter {
@@ -48,6 +54,9 @@ impl FsWriter {
                 target_path,
                 temp_path: None,
                 f: target_file,
+                unix_mode: op.unix_mode(),
+                mtime: op.mtime(),
+                attrs: write_attrs(&op),
             });
         }
 
@@ -75,8 +84,75 @@ impl FsWriter {
             target_path,
             temp_path,
             f,
+            unix_mode: op.unix_mode(),
+            mtime: op.mtime(),
+            attrs: write_attrs(&op),
         })
     }
+
+    /// Store the attributes into the file being written, which is the temp
+    /// file if any so that they become visible together with the content.
+    ///
+    /// This must happen before `unix_mode` is applied, since setting extended
+    /// attributes requires write permission on the file.
+    async fn store_attrs(&self) -> Result<()> {
+        let Some(attrs) = self.attrs.clone() else {
+            return Ok(());
+        };
+        // A temp file is a new file that has no attributes yet.
+        if attrs.is_empty() && self.temp_path.is_some() {
+            return Ok(());
+        }
+
+        let path = self.temp_path.as_ref().unwrap_or(&self.target_path).clone();
+        spawn_blocking(move || attrs.store(&path)).await
+    }
+
+    /// Move the temp file to the target path together with its attributes.
+    ///
+    /// The sidecar file of the replaced file is dropped if the temp file has none.
+    async fn rename(&self) -> Result<()> {
+        let Some(temp_path) = self.temp_path.clone() else {
+            return Ok(());
+        };
+
+        let target_path = self.target_path.clone();
+        tokio::fs::rename(&temp_path, &target_path)
+            .await
+            .map_err(new_std_io_error)?;
+        spawn_blocking(move || rename_file_attrs(&temp_path, &target_path)).await
+    }
+
+    /// Fill the stored attributes into metadata.
+    fn with_attrs(&self, meta: Metadata) -> Metadata {
+        match self.attrs.clone() {
+            Some(attrs) => attrs.into_metadata(meta),
+            None => meta,
+        }
+    }
+
+    async fn remove_temp_file(&self) -> Result<()> {
+        let Some(temp_path) = self.temp_path.clone() else {
+            return Err(Error::new(
+                ErrorKind::Unsupported,
+                "Fs doesn't support abort if atomic_write_dir is not set",
+            ));
+        };
+
+        tokio::fs::remove_file(&temp_path)
+            .await
+            .map_err(new_std_io_error)?;
+        spawn_blocking(move || remove_file_attrs(&temp_path)).await
+    }
+}
+
+fn write_attrs(op: &OpWrite) -> Option<FileAttrs> {
+    let attrs = FileAttrs::from_op(op);
+    if op.append() && attrs.is_empty() {
+        None
+    } else {
+        Some(attrs)
+    }
 }
 
 /// # Safety
@@ -97,12 +173,19 @@ impl oio::Write for FsWriter {
     async fn close(&mut self) -> Result<Metadata> {
         self.f.flush().await.map_err(new_std_io_error)?;
         self.f.sync_all().await.map_err(new_std_io_error)?;
+        self.store_attrs().await?;
 
-        if let Some(temp_path) = &self.temp_path {
-            tokio::fs::rename(temp_path, &self.target_path)
+        if self.unix_mode.is_some() || self.mtime.is_some() {
+            let f = self
+                .f
+                .try_clone()
                 .await
-                .map_err(new_std_io_error)?;
+                .map_err(new_std_io_error)?
+                .into_std()
+                .await;
+            set_file_attrs(&f, self.unix_mode, self.mtime)?;
         }
+        self.rename().await?;
 
         let file_meta = self.f.metadata().await.map_err(new_std_io_error)?;
         let meta = Metadata::new(EntryMode::FILE)
@@ -110,20 +193,11 @@ impl oio::Write for FsWriter {
             .with_last_modified(Timestamp::try_from(
                 file_meta.modified().map_err(new_std_io_error)?,
             )?);
-        Ok(meta)
+        Ok(with_unix_attrs(self.with_attrs(meta), &file_meta))
     }
 
     async fn abort(&mut self) -> Result<()> {
-        if let Some(temp_path) = &self.temp_path {
-            tokio::fs::remove_file(temp_path)
-                .await
-                .map_err(new_std_io_error)
-        } else {
-            Err(Error::new(
-                ErrorKind::Unsupported,
-                "Fs doesn't support abort if atomic_write_dir is not set",
-            ))
-        }
+        self.remove_temp_file().await
     }
 }
 
@@ -166,12 +240,9 @@ impl oio::PositionWrite for FsWriter {
 
         f.flush().map_err(new_std_io_error)?;
         f.sync_all().map_err(new_std_io_error)?;
-
-        if let Some(temp_path) = &self.temp_path {
-            tokio::fs::rename(temp_path, &self.target_path)
-                .await
-                .map_err(new_std_io_error)?;
-        }
+        self.store_attrs().await?;
+        set_file_attrs(&f, self.unix_mode, self.mtime)?;
+        self.rename().await?;
 
         let file_meta = f.metadata().map_err(new_std_io_error)?;
         let mode = if file_meta.is_file() {
@@ -186,20 +257,11 @@ impl oio::PositionWrite for FsWriter {
             .with_last_modified(Timestamp::try_from(
                 file_meta.modified().map_err(new_std_io_error)?,
             )?);
-        Ok(meta)
+        Ok(with_unix_attrs(self.with_attrs(meta), &file_meta))
     }
 
     async fn abort(&self) -> Result<()> {
-        if let Some(temp_path) = &self.temp_path {
-            tokio::fs::remove_file(temp_path)
-                .await
-                .map_err(new_std_io_error)
-        } else {
-            Err(Error::new(
-                ErrorKind::Unsupported,
-                "Fs doesn't support abort if atomic_write_dir is not set",
-            ))
-        }
+        self.remove_temp_file().await
     }
 }
 
@@ -16,7 +16,6 @@
 // under the License.
 
 use std::fmt::Debug;
-use std::str::FromStr;
 use std::sync::Arc;
 
 use http::Uri;
@@ -24,13 +23,14 @@ use log::debug;
 use services::ftp::core::Manager;
 use suppaftp::FtpError;
 use suppaftp::Status;
-use suppaftp::list::File;
 use suppaftp::types::Response;
 use tokio::sync::OnceCell;
 
 use super::FTP_SCHEME;
 use super::config::FtpConfig;
 use super::core::FtpCore;
+use super::core::FtpListing;
+use super::core::parse_list_entry;
 use super::deleter::FtpDeleter;
 use super::err::parse_error;
 use super::lister::FtpLister;
@@ -230,19 +230,7 @@ impl Access for FtpBackend {
     }
 
     async fn stat(&self, path: &str, _: OpStat) -> Result<RpStat> {
-        let file = self.ftp_stat(path).await?;
-
-        let mode = if file.is_file() {
-            EntryMode::FILE
-        } else if file.is_directory() {
-            EntryMode::DIR
-        } else {
-            EntryMode::Unknown
-        };
-
-        let mut meta = Metadata::new(mode);
-        meta.set_content_length(file.size() as u64);
-        meta.set_last_modified(Timestamp::try_from(file.modified())?);
+        let meta = self.ftp_stat(path).await?;
 
         Ok(RpStat::new(meta))
     }
@@ -299,40 +287,33 @@ impl Access for FtpBackend {
         let mut ftp_stream = self.core.ftp_connect(Operation::List).await?;
 
         let pathname = if path == "/" { None } else { Some(path) };
-        let files = ftp_stream.list(pathname).await.map_err(parse_error)?;
+        let listing = FtpListing::fetch(&mut ftp_stream, pathname).await?;
 
         Ok((
             RpList::default(),
-            FtpLister::new(if path == "/" { "" } else { path }, files),
+            FtpLister::new(if path == "/" { "" } else { path }, listing),
         ))
     }
 }
 
 impl FtpBackend {
-    pub async fn ftp_stat(&self, path: &str) -> Result<File> {
+    pub async fn ftp_stat(&self, path: &str) -> Result<Metadata> {
         let mut ftp_stream = self.core.ftp_connect(Operation::Stat).await?;
 
         let (parent, basename) = (get_parent(path), get_basename(path));
 
         let pathname = if parent == "/" { None } else { Some(parent) };
 
-        let resp = ftp_stream.list(pathname).await.map_err(parse_error)?;
+        let listing = FtpListing::fetch(&mut ftp_stream, pathname).await?;
 
         // Get stat of file.
-        let mut files = resp
-            .into_iter()
-            .filter_map(|file| File::from_str(file.as_str()).ok())
-            .filter(|f| f.name() == basename.trim_end_matches('/'))
-            .collect::<Vec<File>>();
-
-        if files.is_empty() {
-            Err(Error::new(
-                ErrorKind::NotFound,
-                "file is not found during list",
-            ))
-        } else {
-            Ok(files.remove(0))
-        }
+        listing
+            .lines
+            .iter()
+            .filter_map(|line| parse_list_entry(line, listing.mlsd).ok().flatten())
+            .find(|(name, _)| name == basename.trim_end_matches('/'))
+            .map(|(_, meta)| meta)
+            .ok_or_else(|| Error::new(ErrorKind::NotFound, "file is not found during list"))
     }
 }
 
@@ -15,6 +15,7 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use std::str::FromStr;
 use std::sync::Arc;
 
 use bb8::Pool;
@@ -27,12 +28,14 @@ use suppaftp::AsyncRustlsFtpStream;
 use suppaftp::FtpError;
 use suppaftp::ImplAsyncFtpStream;
 use suppaftp::Status;
+use suppaftp::list::File;
 use suppaftp::rustls::ClientConfig;
 use suppaftp::types::FileType;
 use tokio::sync::OnceCell;
 
 use super::err::parse_error;
 use crate::raw::AccessorInfo;
+use crate::raw::Timestamp;
 use crate::*;
 
 pub struct FtpCore {
@@ -63,6 +66,151 @@ impl FtpCore {
     }
 }
 
+/// Lines returned by listing a directory.
+pub struct FtpListing {
+    pub lines: Vec<String>,
+    /// Whether the lines are `MLSD` facts instead of `LIST` output.
+    pub mlsd: bool,
+}
+
+impl FtpListing {
+    /// List the directory via `MLSD`, fall back to `LIST` if the server
+    /// doesn't support it.
+    pub async fn fetch(
+        ftp_stream: &mut AsyncRustlsFtpStream,
+        pathname: Option<&str>,
+    ) -> Result<Self> {
+        match ftp_stream.mlsd(pathname).await {
+            Ok(lines) => Ok(Self { lines, mlsd: true }),
+            Err(FtpError::UnexpectedResponse(resp))
+                if matches!(
+                    resp.status,
+                    Status::BadCommand | Status::NotImplemented | Status::CommandNotImplemented
+                ) =>
+            {
+                let lines = ftp_stream.list(pathname).await.map_err(parse_error)?;
+                Ok(Self { lines, mlsd: false })
+            }
+            Err(err) => Err(parse_error(err)),
+        }
+    }
+}
+
+/// Parse a line of directory listing into the entry name and metadata.
+///
+/// Returns `None` for the current and parent dir returned by `MLSD`.
+pub fn parse_list_entry(line: &str, mlsd: bool) -> Result<Option<(String, Metadata)>> {
+    if mlsd {
+        return parse_mlsx_entry(line);
+    }
+
+    let de = File::from_str(line)
+        .map_err(|e| Error::new(ErrorKind::Unexpected, "parse file from response").set_source(e))?;
+
+    let mut meta = if de.is_file() {
+        Metadata::new(EntryMode::FILE)
+    } else if de.is_directory() {
+        Metadata::new(EntryMode::DIR)
+    } else {
+        Metadata::new(EntryMode::Unknown)
+    };
+    meta.set_content_length(de.size() as u64);
+    meta.set_last_modified(Timestamp::try_from(de.modified())?);
+    if let Some(uid) = de.uid() {
+        meta.set_uid(uid);
+    }
+    if let Some(gid) = de.gid() {
+        meta.set_gid(gid);
+    }
+
+    Ok(Some((de.name().to_string(), meta)))
+}
+
+/// Parse a line of `MLSD` like `type=file;size=3;modify=20240101120000;unix.mode=0644; a.txt`.
+///
+/// Facts are defined by [RFC 3659](https://datatracker.ietf.org/doc/html/rfc3659#section-7),
+/// `unix.*` facts are widely used extensions, unknown facts are ignored.
+fn parse_mlsx_entry(line: &str) -> Result<Option<(String, Metadata)>> {
+    let (facts, name) = line.split_once(' ').ok_or_else(|| {
+        Error::new(ErrorKind::Unexpected, "mlsd entry is malformed").with_context("line", line)
+    })?;
+
+    let mut meta = Metadata::new(EntryMode::Unknown);
+    for fact in facts.split(';') {
+        let Some((key, value)) = fact.split_once('=') else {
+            continue;
+        };
+        match key.to_ascii_lowercase().as_str() {
+            "type" => match value.to_ascii_lowercase().as_str() {
+                "file" => {
+                    meta.set_mode(EntryMode::FILE);
+                }
+                "dir" => {
+                    meta.set_mode(EntryMode::DIR);
+                }
+                "cdir" | "pdir" => return Ok(None),
+                _ => {}
+            },
+            "size" => {
+                if let Ok(v) = value.parse() {
+                    meta.set_content_length(v);
+                }
+            }
+            "modify" => {
+                meta.set_last_modified(parse_mlsx_time(value)?);
+            }
+            "unix.mode" => {
+                if let Ok(v) = u32::from_str_radix(value, 8) {
+                    meta.set_unix_mode(v & 0o7777);
+                }
+            }
+            "unix.uid" | "unix.owner" => {
+                if let Ok(v) = value.parse() {
+                    meta.set_uid(v);
+                }
+            }
+            "unix.gid" | "unix.group" => {
+                if let Ok(v) = value.parse() {
+                    meta.set_gid(v);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    Ok(Some((name.to_string(), meta)))
+}
+
+/// Parse time value like `20240101120000` or `20240101120000.123` in UTC.
+fn parse_mlsx_time(s: &str) -> Result<Timestamp> {
+    let (datetime, fraction) = match s.split_once('.') {
+        Some((dt, frac)) => (dt, frac),
+        None => (s, ""),
+    };
+    if datetime.len() != 14 || !datetime.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(
+            Error::new(ErrorKind::Unexpected, "mlsd time value is malformed")
+                .with_context("value", s),
+        );
+    }
+
+    let mut rfc3339 = format!(
+        "{}-{}-{}T{}:{}:{}",
+        &datetime[0..4],
+        &datetime[4..6],
+        &datetime[6..8],
+        &datetime[8..10],
+        &datetime[10..12],
+        &datetime[12..14]
+    );
+    if !fraction.is_empty() {
+        rfc3339.push('.');
+        rfc3339.push_str(fraction);
+    }
+    rfc3339.push('Z');
+    rfc3339.parse()
+}
+
 #[derive(Clone)]
 pub struct Manager {
     pub endpoint: String,
@@ -134,3 +282,42 @@ impl bb8::ManageConnection for Manager {
         true
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_mlsx_entry() {
+        let (name, meta) = parse_list_entry(
+            "type=file;size=1024;modify=20240102030405.5;UNIX.mode=0755;UNIX.uid=1000;UNIX.gid=100; run.sh",
+            true,
+        )
+        .unwrap()
+        .unwrap();
+        assert_eq!(name, "run.sh");
+        assert_eq!(meta.mode(), EntryMode::FILE);
+        assert_eq!(meta.content_length(), 1024);
+        assert_eq!(
+            meta.last_modified(),
+            Some("2024-01-02T03:04:05.5Z".parse().unwrap())
+        );
+        assert_eq!(meta.unix_mode(), Some(0o755));
+        assert_eq!(meta.uid(), Some(1000));
+        assert_eq!(meta.gid(), Some(100));
+
+        let (name, meta) = parse_list_entry("type=dir;modify=20240102030405; a dir", true)
+            .unwrap()
+            .unwrap();
+        assert_eq!(name, "a dir");
+        assert_eq!(meta.mode(), EntryMode::DIR);
+        assert_eq!(meta.unix_mode(), None);
+
+        assert!(
+            parse_list_entry("type=cdir; /root", true)
+                .unwrap()
+                .is_none()
+        );
+        assert!(parse_list_entry("type=file;modify=2024; x", true).is_err());
+    }
+}
@@ -15,58 +15,47 @@
 // specific language governing permissions and limitations
 // under the License.
 
-use std::str;
-use std::str::FromStr;
 use std::vec::IntoIter;
 
-use suppaftp::list::File;
-
+use super::core::FtpListing;
+use super::core::parse_list_entry;
 use crate::raw::*;
 use crate::*;
 
 pub struct FtpLister {
     path: String,
     file_iter: IntoIter<String>,
+    mlsd: bool,
 }
 
 impl FtpLister {
-    pub fn new(path: &str, files: Vec<String>) -> Self {
+    pub fn new(path: &str, listing: FtpListing) -> Self {
         Self {
             path: path.to_string(),
-            file_iter: files.into_iter(),
+            file_iter: listing.lines.into_iter(),
+            mlsd: listing.mlsd,
         }
     }
 }
 
 impl oio::List for FtpLister {
     async fn next(&mut self) -> Result<Option<oio::Entry>> {
-        let de = match self.file_iter.next() {
-            Some(file_str) => File::from_str(file_str.as_str()).map_err(|e| {
-                Error::new(ErrorKind::Unexpected, "parse file from response").set_source(e)
-            })?,
-            None => return Ok(None),
-        };
-
-        let path = self.path.to_string() + de.name();
-
-        let mut meta = if de.is_file() {
-            Metadata::new(EntryMode::FILE)
-        } else if de.is_directory() {
-            Metadata::new(EntryMode::DIR)
-        } else {
-            Metadata::new(EntryMode::Unknown)
-        };
-        meta.set_content_length(de.size() as u64);
-        meta.set_last_modified(Timestamp::try_from(de.modified())?);
-
-        let entry = if de.is_file() {
-            oio::Entry::new(&path, meta)
-        } else if de.is_directory() {
-            oio::Entry::new(&format!("{}/", &path), meta)
-        } else {
-            oio::Entry::new(&path, meta)
-        };
-
-        Ok(Some(entry))
+        loop {
+            let Some(line) = self.file_iter.next() else {
+                return Ok(None);
+            };
+            let Some((name, meta)) = parse_list_entry(&line, self.mlsd)? else {
+                continue;
+            };
+
+            let path = self.path.to_string() + &name;
+            let entry = if meta.is_dir() {
+                oio::Entry::new(&format!("{}/", &path), meta)
+            } else {
+                oio::Entry::new(&path, meta)
+            };
+
+            return Ok(Some(entry));
+        }
     }
 }
@@ -308,11 +308,14 @@ impl Builder for GcsBuilder {
                             write: true,
                             write_can_empty: true,
                             write_can_multi: true,
+                            write_can_resume: true,
                             write_with_cache_control: true,
                             write_with_content_type: true,
                             write_with_content_encoding: true,
                             write_with_user_metadata: true,
                             write_with_if_not_exists: true,
+                            // GCS can't expire a single object, only lifecycle rules
+                            // on the bucket can, so `write_with_ttl` is left unset.
 
                             // The min multipart size of Gcs is 5 MiB.
                             //
@@ -335,6 +338,7 @@ impl Builder for GcsBuilder {
                             list_with_limit: true,
                             list_with_start_after: true,
                             list_with_recursive: true,
+                            list_with_continuation: true,
 
                             presign: true,
                             presign_stat: true,
@@ -420,8 +424,13 @@ impl Access for GcsBackend {
 
     async fn write(&self, path: &str, args: OpWrite) -> Result<(RpWrite, Self::Writer)> {
         let concurrent = args.concurrent();
-        let w = GcsWriter::new(self.core.clone(), path, args);
-        let w = oio::MultipartWriter::new(self.core.info.clone(), w, concurrent);
+        let w = GcsWriter::new(self.core.clone(), path, args.clone());
+        // Resume is built on the XML multipart upload API instead of the resumable
+        // session URI of JSON API: GcsWriter already uploads parts via XML multipart,
+        // parts can be uploaded concurrently and listed/aborted like other object
+        // storages, while a session only accepts sequential chunks aligned to 256 KiB.
+        let w = oio::MultipartWriter::new(self.core.info.clone(), w, concurrent)
+            .with_checkpoint(&args)?;
 
         Ok((RpWrite::default(), w))
     }
@@ -442,7 +451,10 @@ impl Access for GcsBackend {
             args.start_after(),
         );
 
-        Ok((RpList::default(), oio::PageLister::new(l)))
+        Ok((
+            RpList::default(),
+            oio::PageLister::new(l).with_continuation(&args),
+        ))
     }
 
     async fn copy(&self, from: &str, to: &str, _: OpCopy) -> Result<RpCopy> {
@@ -708,6 +708,9 @@ impl GcsCore {
 
         m.set_etag(&meta.etag);
         m.set_content_md5(&meta.md5_hash);
+        if !meta.crc32c.is_empty() {
+            m.set_checksum(Checksum::new(ChecksumAlgorithm::Crc32c, meta.crc32c));
+        }
 
         let size = meta
             .size
@@ -808,6 +811,7 @@ pub struct ListResponseItem {
     // metadata
     pub etag: String,
     pub md5_hash: String,
+    pub crc32c: String,
     pub updated: String,
     pub content_type: String,
 }
@@ -855,6 +859,10 @@ struct GetObjectJsonResponse {
     ///
     /// For example: `"md5Hash": "fHcEH1vPwA6eTPqxuasXcg=="`
     md5_hash: String,
+    /// Content crc32c checksum
+    ///
+    /// For example: `"crc32c": "j/un9g=="`
+    crc32c: String,
     /// Content type of this object.
     ///
     /// For example: `"contentType": "image/png",`
@@ -920,6 +928,10 @@ mod tests {
             )
         );
         assert_eq!(meta.content_md5(), Some("fHcEH1vPwA6eTPqxuasXcg=="));
+        assert_eq!(
+            meta.checksum(),
+            Some(&Checksum::new(ChecksumAlgorithm::Crc32c, "j/un9g=="))
+        );
         assert_eq!(meta.etag(), Some("CKWasoTgyPkCEAE="));
         assert_eq!(meta.content_type(), Some("image/png"));
         assert_eq!(meta.content_encoding(), Some("br"));
@@ -990,6 +1002,7 @@ mod tests {
         assert_eq!(output.items[0].name, "1.png");
         assert_eq!(output.items[0].size, "56535");
         assert_eq!(output.items[0].md5_hash, "fHcEH1vPwA6eTPqxuasXcg==");
+        assert_eq!(output.items[0].crc32c, "j/un9g==");
         assert_eq!(output.items[0].etag, "CKWasoTgyPkCEAE=");
         assert_eq!(output.items[0].updated, "2022-08-15T11:33:34.866Z");
         assert_eq!(output.items[1].name, "2.png");
@@ -114,6 +114,9 @@ impl oio::PageList for GcsLister {
 
             // set metadata fields
             meta.set_content_md5(object.md5_hash.as_str());
+            if !object.crc32c.is_empty() {
+                meta.set_checksum(Checksum::new(ChecksumAlgorithm::Crc32c, &object.crc32c));
+            }
             meta.set_etag(object.etag.as_str());
 
             let size = object.size.parse().map_err(|e| {
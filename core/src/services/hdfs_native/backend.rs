@@ -112,6 +112,8 @@ impl Builder for HdfsNativeBuilder {
 
                             write: true,
                             write_can_append: self.config.enable_append,
+                            write_with_unix_mode: true,
+                            write_with_mtime: true,
 
                             create_dir: true,
                             delete: true,
@@ -180,7 +182,10 @@ impl Access for HdfsNativeBackend {
     async fn write(&self, path: &str, args: OpWrite) -> Result<(RpWrite, Self::Writer)> {
         let (f, initial_size) = self.core.hdfs_write(path, &args).await?;
 
-        Ok((RpWrite::new(), HdfsNativeWriter::new(f, initial_size)))
+        Ok((
+            RpWrite::new(),
+            HdfsNativeWriter::new(Arc::clone(&self.core), path, &args, f, initial_size),
+        ))
     }
 
     async fn delete(&self) -> Result<(RpDelete, Self::Deleter)> {
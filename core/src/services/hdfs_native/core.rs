@@ -75,7 +75,8 @@ impl HdfsNativeCore {
             .set_last_modified(Timestamp::from_millisecond(
                 status.modification_time as i64,
             )?)
-            .set_content_length(status.length as u64);
+            .set_content_length(status.length as u64)
+            .set_unix_mode(status.permission as u32);
 
         Ok(metadata)
     }
@@ -139,6 +140,39 @@ impl HdfsNativeCore {
         Ok((f, initial_size))
     }
 
+    /// Apply the permission bits and modification time requested by write.
+    pub async fn hdfs_set_attrs(
+        &self,
+        path: &str,
+        unix_mode: Option<u32>,
+        mtime: Option<Timestamp>,
+    ) -> Result<()> {
+        let p = build_rooted_abs_path(&self.root, path);
+
+        if let Some(mode) = unix_mode {
+            self.client
+                .set_permission(&p, mode & 0o7777)
+                .await
+                .map_err(parse_hdfs_error)?;
+        }
+        if let Some(mtime) = mtime {
+            let mtime = u64::try_from(mtime.into_inner().as_millisecond()).map_err(|err| {
+                Error::new(
+                    ErrorKind::Unexpected,
+                    "mtime before unix epoch is not supported",
+                )
+                .set_source(err)
+            })?;
+            // `u64::MAX` is `-1` for namenode which keeps the access time unchanged.
+            self.client
+                .set_times(&p, mtime, u64::MAX)
+                .await
+                .map_err(parse_hdfs_error)?;
+        }
+
+        Ok(())
+    }
+
     pub async fn hdfs_delete(&self, path: &str) -> Result<()> {
         let p = build_rooted_abs_path(&self.root, path);
 
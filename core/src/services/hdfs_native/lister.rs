@@ -63,13 +63,16 @@ impl oio::List for HdfsNativeLister {
                 let path = build_rel_path(&self.root, &status.path);
 
                 let entry = if status.isdir {
-                    oio::Entry::new(&format!("{path}/"), Metadata::new(EntryMode::DIR))
+                    let meta =
+                        Metadata::new(EntryMode::DIR).with_unix_mode(status.permission as u32);
+                    oio::Entry::new(&format!("{path}/"), meta)
                 } else {
                     let meta = Metadata::new(EntryMode::FILE)
                         .with_content_length(status.length as u64)
                         .with_last_modified(Timestamp::from_millisecond(
                             status.modification_time as i64,
-                        )?);
+                        )?)
+                        .with_unix_mode(status.permission as u32);
                     oio::Entry::new(&path, meta)
                 };
 
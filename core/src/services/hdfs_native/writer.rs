@@ -15,21 +15,38 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use std::sync::Arc;
+
 use hdfs_native::file::FileWriter;
 
 use crate::raw::*;
+use crate::services::hdfs_native::core::HdfsNativeCore;
 use crate::services::hdfs_native::error::parse_hdfs_error;
 use crate::*;
 pub struct HdfsNativeWriter {
+    core: Arc<HdfsNativeCore>,
+    path: String,
     f: FileWriter,
     size: u64,
+    unix_mode: Option<u32>,
+    mtime: Option<Timestamp>,
 }
 
 impl HdfsNativeWriter {
-    pub fn new(f: FileWriter, initial_size: u64) -> Self {
+    pub fn new(
+        core: Arc<HdfsNativeCore>,
+        path: &str,
+        op: &OpWrite,
+        f: FileWriter,
+        initial_size: u64,
+    ) -> Self {
         HdfsNativeWriter {
+            core,
+            path: path.to_string(),
             f,
             size: initial_size,
+            unix_mode: op.unix_mode(),
+            mtime: op.mtime(),
         }
     }
 }
@@ -49,6 +66,12 @@ impl oio::Write for HdfsNativeWriter {
     async fn close(&mut self) -> Result<Metadata> {
         self.f.close().await.map_err(parse_hdfs_error)?;
 
+        if self.unix_mode.is_some() || self.mtime.is_some() {
+            self.core
+                .hdfs_set_attrs(&self.path, self.unix_mode, self.mtime)
+                .await?;
+        }
+
         Ok(Metadata::default().with_content_length(self.size))
     }
 
@@ -184,6 +184,7 @@ impl MemcachedBackend {
             stat: true,
             write: true,
             write_can_empty: true,
+            write_with_ttl: true,
             delete: true,
             shared: true,
             ..Default::default()
@@ -238,9 +239,12 @@ impl Access for MemcachedBackend {
         Ok((RpRead::new(), bs.slice(args.range().to_range_as_usize())))
     }
 
-    async fn write(&self, path: &str, _: OpWrite) -> Result<(RpWrite, Self::Writer)> {
+    async fn write(&self, path: &str, args: OpWrite) -> Result<(RpWrite, Self::Writer)> {
         let p = build_abs_path(&self.root, path);
-        Ok((RpWrite::new(), MemcachedWriter::new(self.core.clone(), p)))
+        Ok((
+            RpWrite::new(),
+            MemcachedWriter::new(self.core.clone(), p, args.expires_in()),
+        ))
     }
 
     async fn delete(&self) -> Result<(RpDelete, Self::Deleter)> {
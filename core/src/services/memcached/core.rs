@@ -115,16 +115,15 @@ impl MemcachedCore {
         Ok(result.map(Buffer::from))
     }
 
-    pub async fn set(&self, key: &str, value: Buffer) -> Result<()> {
+    /// Set the value of given key, `ttl` overrides the `default_ttl`.
+    pub async fn set(&self, key: &str, value: Buffer, ttl: Option<Duration>) -> Result<()> {
         let mut conn = self.conn().await?;
 
         conn.set(
             &percent_encode_path(key),
             &value.to_vec(),
             // Set expiration to 0 if ttl not set.
-            self.default_ttl
-                .map(|v| v.as_secs() as u32)
-                .unwrap_or_default(),
+            ttl.or(self.default_ttl).map(expiration).unwrap_or_default(),
         )
         .await
     }
@@ -135,3 +134,36 @@ impl MemcachedCore {
         conn.delete(&percent_encode_path(key)).await
     }
 }
+
+/// Memcached treats expirations longer than 30 days as unix timestamps.
+const MAX_RELATIVE_EXPIRATION: u64 = 60 * 60 * 24 * 30;
+
+/// Convert a ttl into a memcached expiration.
+///
+/// Sub-second ttls are rounded up since an expiration of 0 never expires.
+fn expiration(ttl: Duration) -> u32 {
+    let secs = ttl.as_millis().div_ceil(1000) as u64;
+    if secs <= MAX_RELATIVE_EXPIRATION {
+        return secs as u32;
+    }
+    let now = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs();
+    (now + secs).min(u32::MAX as u64) as u32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_expiration() {
+        assert_eq!(expiration(Duration::from_millis(1)), 1);
+        assert_eq!(expiration(Duration::from_secs(60)), 60);
+
+        // Long ttls must be sent as absolute unix timestamps.
+        let exp = expiration(Duration::from_secs(MAX_RELATIVE_EXPIRATION + 1));
+        assert!(exp as u64 > MAX_RELATIVE_EXPIRATION * 12);
+    }
+}
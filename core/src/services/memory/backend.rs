@@ -22,7 +22,8 @@ use super::MEMORY_SCHEME;
 use super::config::MemoryConfig;
 use super::core::*;
 use super::deleter::MemoryDeleter;
-use super::lister::MemoryLister;
+use super::snapshot::MemoryHandle;
+use super::snapshot::MemorySnapshot;
 use super::writer::MemoryWriter;
 use crate::raw::oio;
 use crate::raw::*;
@@ -33,6 +34,7 @@ use crate::*;
 #[derive(Debug, Default)]
 pub struct MemoryBuilder {
     pub(super) config: MemoryConfig,
+    pub(super) handle: Option<MemoryHandle>,
 }
 
 impl MemoryBuilder {
@@ -41,6 +43,32 @@ impl MemoryBuilder {
         self.config.root = Some(path.into());
         self
     }
+
+    /// Set the snapshot file used to persist data.
+    ///
+    /// Data will be loaded from this file on build and dumped into it when the
+    /// service is dropped.
+    pub fn snapshot_path(mut self, path: &str) -> Self {
+        if !path.is_empty() {
+            self.config.snapshot_path = Some(path.to_string());
+        }
+        self
+    }
+
+    /// Set the interval between two flushes of the snapshot file, for example `60s`.
+    pub fn flush_interval(mut self, interval: &str) -> Self {
+        if !interval.is_empty() {
+            self.config.flush_interval = Some(interval.to_string());
+        }
+        self
+    }
+
+    /// Store data in the given [`MemoryHandle`] so that snapshots can be taken
+    /// and restored after the operator is built.
+    pub fn handle(mut self, handle: &MemoryHandle) -> Self {
+        self.handle = Some(handle.clone());
+        self
+    }
 }
 
 impl Builder for MemoryBuilder {
@@ -49,7 +77,24 @@ impl Builder for MemoryBuilder {
     fn build(self) -> Result<impl Access> {
         let root = normalize_root(self.config.root.as_deref().unwrap_or("/"));
 
-        let core = MemoryCore::new();
+        let handle = self.handle.unwrap_or_default();
+        let mut core = MemoryCore::new(handle.data.clone());
+
+        if let Some(path) = self.config.snapshot_path.as_deref() {
+            let flush_interval = self
+                .config
+                .flush_interval
+                .as_deref()
+                .map(signed_to_duration)
+                .transpose()?;
+
+            let snapshot = MemorySnapshot::load(path)?;
+            if !snapshot.is_empty() {
+                handle.restore(&snapshot);
+            }
+            core = core.with_persistence(path.into(), flush_interval);
+        }
+
         Ok(MemoryBackend::new(core).with_normalized_root(root))
     }
 }
@@ -76,9 +121,11 @@ impl MemoryBackend {
             write_with_content_type: true,
             write_with_content_disposition: true,
             write_with_content_encoding: true,
+            write_with_user_metadata: true,
             delete: true,
             stat: true,
             list: true,
+            list_with_start_after: true,
             list_with_recursive: true,
             shared: false,
             ..Default::default()
@@ -101,7 +148,7 @@ impl MemoryBackend {
 impl Access for MemoryBackend {
     type Reader = Buffer;
     type Writer = MemoryWriter;
-    type Lister = oio::HierarchyLister<MemoryLister>;
+    type Lister = oio::HierarchyLister<oio::KeyScanLister<MemoryCore>>;
     type Deleter = oio::OneShotDeleter<MemoryDeleter>;
 
     fn info(&self) -> Arc<AccessorInfo> {
@@ -160,9 +207,9 @@ impl Access for MemoryBackend {
 
     async fn list(&self, path: &str, args: OpList) -> Result<(RpList, Self::Lister)> {
         let p = build_abs_path(&self.root, path);
-        let keys = self.core.scan(&p)?;
-        let lister = MemoryLister::new(&self.root, keys);
-        let lister = oio::HierarchyLister::new(lister, path, args.recursive());
+        let lister = oio::KeyScanLister::new(self.core.clone(), &self.root, p, &args);
+        let lister = oio::HierarchyLister::new(lister, path, args.recursive())
+            .with_start_after(args.start_after());
 
         Ok((RpList::default(), lister))
     }
@@ -27,6 +27,16 @@ use super::backend::MemoryBuilder;
 pub struct MemoryConfig {
     /// root of the backend.
     pub root: Option<String>,
+    /// Path of the snapshot file used to persist data.
+    ///
+    /// If set, data will be loaded from this file on build and dumped into it
+    /// when the service is dropped.
+    pub snapshot_path: Option<String>,
+    /// Interval between two flushes of the snapshot file, for example `60s`.
+    ///
+    /// The interval is checked after every write and delete. Only takes effect
+    /// when `snapshot_path` is set.
+    pub flush_interval: Option<String>,
 }
 
 impl crate::Configurator for MemoryConfig {
@@ -44,7 +54,10 @@ impl crate::Configurator for MemoryConfig {
     }
 
     fn into_builder(self) -> Self::Builder {
-        MemoryBuilder { config: self }
+        MemoryBuilder {
+            config: self,
+            ..Default::default()
+        }
     }
 }
 
@@ -17,9 +17,19 @@
 
 use std::collections::BTreeMap;
 use std::fmt::Debug;
+use std::ops::Bound;
+use std::path::PathBuf;
 use std::sync::Arc;
 use std::sync::Mutex;
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::Ordering;
+use std::time::Duration;
+use std::time::Instant;
 
+use log::warn;
+
+use super::snapshot::MemorySnapshot;
+use crate::raw::*;
 use crate::*;
 
 /// Value stored in memory containing both metadata and content
@@ -29,9 +39,32 @@ pub struct MemoryValue {
     pub content: Buffer,
 }
 
-#[derive(Clone)]
 pub struct MemoryCore {
     pub data: Arc<Mutex<BTreeMap<String, MemoryValue>>>,
+    pub persistence: Option<Arc<MemoryPersistence>>,
+}
+
+/// Where and how often the data of a memory service is flushed to disk.
+pub struct MemoryPersistence {
+    pub path: PathBuf,
+    pub flush_interval: Option<Duration>,
+    pub last_flush: Mutex<Instant>,
+    /// Whether a flush is running in background.
+    flushing: AtomicBool,
+    /// Held while dumping, so that snapshots are written in the order they are taken.
+    dumping: Mutex<()>,
+}
+
+impl MemoryPersistence {
+    fn flush(&self, data: &Mutex<BTreeMap<String, MemoryValue>>) -> Result<()> {
+        let _guard = self.dumping.lock().unwrap();
+
+        let snapshot = MemorySnapshot {
+            data: data.lock().unwrap().clone(),
+        };
+        *self.last_flush.lock().unwrap() = Instant::now();
+        snapshot.dump(&self.path)
+    }
 }
 
 impl Debug for MemoryCore {
@@ -41,9 +74,67 @@ impl Debug for MemoryCore {
 }
 
 impl MemoryCore {
-    pub fn new() -> Self {
+    pub fn new(data: Arc<Mutex<BTreeMap<String, MemoryValue>>>) -> Self {
         Self {
-            data: Arc::new(Mutex::new(BTreeMap::new())),
+            data,
+            persistence: None,
+        }
+    }
+
+    pub fn with_persistence(mut self, path: PathBuf, flush_interval: Option<Duration>) -> Self {
+        self.persistence = Some(Arc::new(MemoryPersistence {
+            path,
+            flush_interval,
+            last_flush: Mutex::new(Instant::now()),
+            flushing: AtomicBool::new(false),
+            dumping: Mutex::new(()),
+        }));
+        self
+    }
+
+    /// Dump all data into the snapshot file if persistence is enabled.
+    pub fn flush(&self) -> Result<()> {
+        match &self.persistence {
+            Some(persistence) => persistence.flush(&self.data),
+            None => Ok(()),
+        }
+    }
+
+    /// Flush in background if the flush interval has elapsed since the last flush.
+    ///
+    /// Errors of background flushes are logged instead of failing the operation
+    /// that triggered them, since the operation itself has succeeded in memory.
+    fn maybe_flush(&self) {
+        let Some(persistence) = &self.persistence else {
+            return;
+        };
+        let Some(interval) = persistence.flush_interval else {
+            return;
+        };
+        if persistence.last_flush.lock().unwrap().elapsed() < interval {
+            return;
+        }
+        // Only one background flush is allowed at the same time.
+        if persistence.flushing.swap(true, Ordering::AcqRel) {
+            return;
+        }
+
+        let data = self.data.clone();
+        let task = {
+            let persistence = persistence.clone();
+            move || {
+                if let Err(err) = persistence.flush(&data) {
+                    warn!("memory service failed to flush snapshot: {err}");
+                }
+                persistence.flushing.store(false, Ordering::Release);
+            }
+        };
+        if let Err(err) = std::thread::Builder::new()
+            .name("opendal-memory-flush".to_string())
+            .spawn(task)
+        {
+            warn!("memory service failed to spawn snapshot flush: {err}");
+            persistence.flushing.store(false, Ordering::Release);
         }
     }
 
@@ -53,28 +144,37 @@ impl MemoryCore {
 
     pub fn set(&self, key: &str, value: MemoryValue) -> Result<()> {
         self.data.lock().unwrap().insert(key.to_string(), value);
+        self.maybe_flush();
         Ok(())
     }
 
     pub fn delete(&self, key: &str) -> Result<()> {
         self.data.lock().unwrap().remove(key);
+        self.maybe_flush();
         Ok(())
     }
+}
 
-    pub fn scan(&self, prefix: &str) -> Result<Vec<String>> {
+impl oio::KeyScan for MemoryCore {
+    async fn scan(&self, prefix: &str, after: Option<&str>, limit: usize) -> Result<Vec<String>> {
         let data = self.data.lock().unwrap();
 
-        if prefix.is_empty() {
-            return Ok(data.keys().cloned().collect());
-        }
+        let start = match after {
+            Some(after) => Bound::Excluded(after),
+            None => Bound::Included(prefix),
+        };
+        Ok(data
+            .range::<str, _>((start, Bound::Unbounded))
+            .take(limit)
+            .map(|(key, _)| key.clone())
+            .collect())
+    }
+}
 
-        let mut keys = Vec::new();
-        for (key, _) in data.range(prefix.to_string()..) {
-            if !key.starts_with(prefix) {
-                break;
-            }
-            keys.push(key.clone());
+impl Drop for MemoryCore {
+    fn drop(&mut self) {
+        if let Err(err) = self.flush() {
+            warn!("memory service failed to flush snapshot on drop: {err}");
         }
-        Ok(keys)
     }
 }
@@ -22,8 +22,10 @@ mod backend;
 mod config;
 mod core;
 mod deleter;
-mod lister;
+mod snapshot;
 mod writer;
 
 pub use backend::MemoryBuilder as Memory;
 pub use config::MemoryConfig;
+pub use snapshot::MemoryHandle;
+pub use snapshot::MemorySnapshot;
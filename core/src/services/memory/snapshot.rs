@@ -0,0 +1,470 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::path::Path;
+use std::sync::Arc;
+use std::sync::Mutex;
+
+use bytes::Buf;
+use bytes::BufMut;
+
+use super::core::MemoryValue;
+use crate::raw::*;
+use crate::*;
+
+/// Magic bytes at the start of every snapshot file, the trailing byte is the format version.
+const SNAPSHOT_MAGIC: &[u8; 8] = b"ODMEMSS\x02";
+
+/// Handle to the data of a memory service.
+///
+/// Pass the same handle to [`Memory::handle`](super::Memory::handle) before building the
+/// operator, then use it to take and restore snapshots of everything stored in the service.
+///
+/// # Example
+///
+/// ```no_run
+/// # use anyhow::Result;
+/// # use opendal::services::Memory;
+/// # use opendal::services::MemoryHandle;
+/// # use opendal::Operator;
+/// # async fn test() -> Result<()> {
+/// let handle = MemoryHandle::default();
+/// let op = Operator::new(Memory::default().handle(&handle))?.finish();
+///
+/// op.write("a", "hello").await?;
+/// let checkpoint = handle.snapshot();
+///
+/// op.write("a", "world").await?;
+/// handle.restore(&checkpoint);
+/// assert_eq!(op.read("a").await?.to_vec(), b"hello");
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Default)]
+pub struct MemoryHandle {
+    pub(super) data: Arc<Mutex<BTreeMap<String, MemoryValue>>>,
+}
+
+impl Debug for MemoryHandle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MemoryHandle")
+            .field("entries", &self.data.lock().unwrap().len())
+            .finish()
+    }
+}
+
+impl MemoryHandle {
+    /// Take a snapshot of all data currently stored.
+    ///
+    /// Contents are reference counted, so taking a snapshot doesn't copy them.
+    pub fn snapshot(&self) -> MemorySnapshot {
+        MemorySnapshot {
+            data: self.data.lock().unwrap().clone(),
+        }
+    }
+
+    /// Replace all data currently stored with the given snapshot.
+    pub fn restore(&self, snapshot: &MemorySnapshot) {
+        *self.data.lock().unwrap() = snapshot.data.clone();
+    }
+}
+
+/// A point in time copy of the data of a memory service.
+///
+/// Snapshots can be taken and restored by [`MemoryHandle`], or loaded from and dumped to a file.
+#[derive(Clone, Default)]
+pub struct MemorySnapshot {
+    pub(super) data: BTreeMap<String, MemoryValue>,
+}
+
+impl Debug for MemorySnapshot {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MemorySnapshot")
+            .field("entries", &self.data.len())
+            .finish()
+    }
+}
+
+impl MemorySnapshot {
+    /// Returns the number of entries in this snapshot.
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    /// Returns `true` if this snapshot contains no entries.
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    /// Load a snapshot from the given file.
+    ///
+    /// Returns an empty snapshot if the file doesn't exist.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let bs = match std::fs::read(path.as_ref()) {
+            Ok(bs) => bs,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(Self::default()),
+            Err(err) => return Err(new_std_io_error(err)),
+        };
+
+        Self::decode(&bs).map_err(|err| err.with_context("path", path.as_ref().display()))
+    }
+
+    /// Dump this snapshot to the given file.
+    ///
+    /// The snapshot is written to a temporary file first and then renamed, so the
+    /// file is never left half written.
+    pub fn dump(&self, path: impl AsRef<Path>) -> Result<()> {
+        let path = path.as_ref();
+        let mut tmp = path.as_os_str().to_owned();
+        tmp.push(".tmp");
+
+        std::fs::write(&tmp, self.encode()).map_err(new_std_io_error)?;
+        std::fs::rename(&tmp, path).map_err(new_std_io_error)
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        let mut buf = Vec::new();
+        buf.put_slice(SNAPSHOT_MAGIC);
+        buf.put_u64(self.data.len() as u64);
+
+        for (key, value) in &self.data {
+            let meta = &value.metadata;
+
+            put_bytes(&mut buf, key.as_bytes());
+            buf.put_u8(match meta.mode() {
+                EntryMode::FILE => 1,
+                EntryMode::DIR => 2,
+                EntryMode::Symlink => 3,
+                EntryMode::Unknown => 0,
+            });
+            put_opt_str(&mut buf, meta.content_type());
+            put_opt_str(&mut buf, meta.content_disposition());
+            put_opt_str(&mut buf, meta.content_encoding());
+            put_opt_str(&mut buf, meta.cache_control());
+            put_opt_str(&mut buf, meta.content_md5());
+            put_opt_str(&mut buf, meta.etag());
+            put_opt_str(&mut buf, meta.version());
+            put_opt_timestamp(&mut buf, meta.last_modified());
+            put_opt_timestamp(&mut buf, meta.expires_at());
+
+            match meta.user_metadata() {
+                None => buf.put_u8(0),
+                Some(m) => {
+                    buf.put_u8(1);
+                    buf.put_u32(m.len() as u32);
+                    for (k, v) in m {
+                        put_bytes(&mut buf, k.as_bytes());
+                        put_bytes(&mut buf, v.as_bytes());
+                    }
+                }
+            }
+
+            buf.put_u64(value.content.len() as u64);
+            for bs in value.content.clone() {
+                buf.put_slice(&bs);
+            }
+        }
+
+        buf
+    }
+
+    fn decode(mut bs: &[u8]) -> Result<Self> {
+        if bs.len() < SNAPSHOT_MAGIC.len() || &bs[..SNAPSHOT_MAGIC.len()] != SNAPSHOT_MAGIC {
+            return Err(Error::new(
+                ErrorKind::Unexpected,
+                "memory snapshot has invalid magic or unsupported version",
+            ));
+        }
+        bs.advance(SNAPSHOT_MAGIC.len());
+
+        let mut data = BTreeMap::new();
+        let count = get_u64(&mut bs)?;
+        for _ in 0..count {
+            let key = get_string(&mut bs)?;
+
+            let mode = match get_u8(&mut bs)? {
+                1 => EntryMode::FILE,
+                2 => EntryMode::DIR,
+                3 => EntryMode::Symlink,
+                _ => EntryMode::Unknown,
+            };
+            let mut meta = Metadata::new(mode);
+            if let Some(v) = get_opt_string(&mut bs)? {
+                meta.set_content_type(&v);
+            }
+            if let Some(v) = get_opt_string(&mut bs)? {
+                meta.set_content_disposition(&v);
+            }
+            if let Some(v) = get_opt_string(&mut bs)? {
+                meta.set_content_encoding(&v);
+            }
+            if let Some(v) = get_opt_string(&mut bs)? {
+                meta.set_cache_control(&v);
+            }
+            if let Some(v) = get_opt_string(&mut bs)? {
+                meta.set_content_md5(&v);
+            }
+            if let Some(v) = get_opt_string(&mut bs)? {
+                meta.set_etag(&v);
+            }
+            if let Some(v) = get_opt_string(&mut bs)? {
+                meta.set_version(&v);
+            }
+            if let Some(v) = get_opt_timestamp(&mut bs)? {
+                meta.set_last_modified(v);
+            }
+            if let Some(v) = get_opt_timestamp(&mut bs)? {
+                meta.set_expires_at(v);
+            }
+            if get_u8(&mut bs)? == 1 {
+                let n = get_u32(&mut bs)?;
+                // Every pair takes at least 8 bytes, don't trust the count blindly.
+                let mut m = HashMap::with_capacity((n as usize).min(bs.len() / 8));
+                for _ in 0..n {
+                    let k = get_string(&mut bs)?;
+                    let v = get_string(&mut bs)?;
+                    m.insert(k, v);
+                }
+                meta = meta.with_user_metadata(m);
+            }
+
+            let len = get_u64(&mut bs)? as usize;
+            let content = Buffer::from(get_slice(&mut bs, len)?.to_vec());
+            meta.set_content_length(len as u64);
+
+            data.insert(
+                key,
+                MemoryValue {
+                    metadata: meta,
+                    content,
+                },
+            );
+        }
+
+        Ok(Self { data })
+    }
+}
+
+fn put_bytes(buf: &mut Vec<u8>, bs: &[u8]) {
+    buf.put_u32(bs.len() as u32);
+    buf.put_slice(bs);
+}
+
+fn put_opt_str(buf: &mut Vec<u8>, v: Option<&str>) {
+    match v {
+        None => buf.put_u8(0),
+        Some(v) => {
+            buf.put_u8(1);
+            put_bytes(buf, v.as_bytes());
+        }
+    }
+}
+
+fn put_opt_timestamp(buf: &mut Vec<u8>, v: Option<Timestamp>) {
+    match v {
+        None => buf.put_u8(0),
+        Some(v) => {
+            let v = v.into_inner();
+            buf.put_u8(1);
+            buf.put_i64(v.as_second());
+            buf.put_i32(v.subsec_nanosecond());
+        }
+    }
+}
+
+fn new_truncated_error() -> Error {
+    Error::new(ErrorKind::Unexpected, "memory snapshot is truncated")
+}
+
+fn get_slice<'a>(bs: &mut &'a [u8], len: usize) -> Result<&'a [u8]> {
+    if bs.len() < len {
+        return Err(new_truncated_error());
+    }
+    let (head, tail) = bs.split_at(len);
+    *bs = tail;
+    Ok(head)
+}
+
+fn get_u8(bs: &mut &[u8]) -> Result<u8> {
+    Ok(get_slice(bs, 1)?[0])
+}
+
+fn get_u32(bs: &mut &[u8]) -> Result<u32> {
+    Ok(get_slice(bs, 4)?.get_u32())
+}
+
+fn get_u64(bs: &mut &[u8]) -> Result<u64> {
+    Ok(get_slice(bs, 8)?.get_u64())
+}
+
+fn get_string(bs: &mut &[u8]) -> Result<String> {
+    let len = get_u32(bs)? as usize;
+    String::from_utf8(get_slice(bs, len)?.to_vec()).map_err(|err| {
+        Error::new(
+            ErrorKind::Unexpected,
+            "memory snapshot contains invalid utf-8",
+        )
+        .set_source(err)
+    })
+}
+
+fn get_opt_string(bs: &mut &[u8]) -> Result<Option<String>> {
+    match get_u8(bs)? {
+        0 => Ok(None),
+        _ => get_string(bs).map(Some),
+    }
+}
+
+fn get_opt_timestamp(bs: &mut &[u8]) -> Result<Option<Timestamp>> {
+    match get_u8(bs)? {
+        0 => Ok(None),
+        _ => {
+            let second = get_slice(bs, 8)?.get_i64();
+            let nanosecond = get_slice(bs, 4)?.get_i32();
+            Timestamp::new(second, nanosecond).map(Some)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::services::Memory;
+
+    #[test]
+    fn test_snapshot_roundtrip() {
+        let mut data = BTreeMap::new();
+        data.insert(
+            "a".to_string(),
+            MemoryValue {
+                metadata: Metadata::new(EntryMode::FILE)
+                    .with_content_length(5)
+                    .with_content_type("text/plain".to_string())
+                    .with_etag("\"etag\"".to_string())
+                    .with_version("v1".to_string())
+                    .with_last_modified(Timestamp::new(1700000000, 123).unwrap())
+                    .with_user_metadata(HashMap::from([("k".to_string(), "v".to_string())])),
+                content: Buffer::from("hello"),
+            },
+        );
+        data.insert(
+            "dir/".to_string(),
+            MemoryValue {
+                metadata: Metadata::new(EntryMode::DIR),
+                content: Buffer::new(),
+            },
+        );
+        data.insert(
+            "link".to_string(),
+            MemoryValue {
+                metadata: Metadata::new(EntryMode::Symlink),
+                content: Buffer::from("a"),
+            },
+        );
+        let snapshot = MemorySnapshot { data };
+
+        let decoded = MemorySnapshot::decode(&snapshot.encode()).unwrap();
+        assert_eq!(decoded.len(), 3);
+
+        let a = &decoded.data["a"];
+        assert_eq!(a.content.to_vec(), b"hello");
+        assert_eq!(a.metadata.content_length(), 5);
+        assert_eq!(a.metadata.content_type(), Some("text/plain"));
+        assert_eq!(a.metadata.etag(), Some("\"etag\""));
+        assert_eq!(a.metadata.version(), Some("v1"));
+        assert_eq!(
+            a.metadata.last_modified(),
+            Some(Timestamp::new(1700000000, 123).unwrap())
+        );
+        assert_eq!(
+            a.metadata.user_metadata().and_then(|m| m.get("k")),
+            Some(&"v".to_string())
+        );
+        assert_eq!(decoded.data["dir/"].metadata.mode(), EntryMode::DIR);
+        assert_eq!(decoded.data["link"].metadata.mode(), EntryMode::Symlink);
+
+        let bs = snapshot.encode();
+        assert!(MemorySnapshot::decode(&bs[..bs.len() - 1]).is_err());
+        assert!(MemorySnapshot::decode(b"invalid").is_err());
+
+        // A huge user metadata count must not be trusted for allocation.
+        let mut bs = SNAPSHOT_MAGIC.to_vec();
+        bs.put_u64(1);
+        put_bytes(&mut bs, b"a");
+        bs.put_u8(1);
+        bs.put_slice(&[0; 9]);
+        bs.put_u8(1);
+        bs.put_u32(u32::MAX);
+        assert!(MemorySnapshot::decode(&bs).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_persist_and_restore() {
+        let path = std::env::temp_dir().join(format!("opendal-memory-{}", uuid::Uuid::new_v4()));
+        let path = path.to_str().unwrap();
+
+        let op = Operator::new(Memory::default().snapshot_path(path))
+            .unwrap()
+            .finish();
+        op.write("a", "hello").await.unwrap();
+        drop(op);
+
+        let handle = MemoryHandle::default();
+        let op = Operator::new(Memory::default().snapshot_path(path).handle(&handle))
+            .unwrap()
+            .finish();
+        assert_eq!(op.read("a").await.unwrap().to_vec(), b"hello");
+
+        let checkpoint = handle.snapshot();
+        op.write("a", "world").await.unwrap();
+        op.write("b", "new").await.unwrap();
+        handle.restore(&checkpoint);
+        assert_eq!(op.read("a").await.unwrap().to_vec(), b"hello");
+        assert!(!op.exists("b").await.unwrap());
+
+        drop(op);
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_flush_in_background() {
+        let path = std::env::temp_dir().join(format!("opendal-memory-{}", uuid::Uuid::new_v4()));
+        let path = path.to_str().unwrap();
+
+        let op = Operator::new(Memory::default().snapshot_path(path).flush_interval("1ms"))
+            .unwrap()
+            .finish();
+        tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        op.write("a", "hello").await.unwrap();
+
+        let mut snapshot = MemorySnapshot::default();
+        for _ in 0..100 {
+            snapshot = MemorySnapshot::load(path).unwrap();
+            if !snapshot.is_empty() {
+                break;
+            }
+            tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        }
+        assert_eq!(snapshot.data["a"].content.to_vec(), b"hello");
+
+        drop(op);
+        std::fs::remove_file(path).unwrap();
+    }
+}
@@ -54,6 +54,7 @@ impl oio::Write for MemoryWriter {
 
         let mut metadata = Metadata::new(EntryMode::FILE);
         metadata.set_content_length(content.len() as u64);
+        metadata.set_last_modified(Timestamp::now());
 
         if let Some(v) = self.op.cache_control() {
             metadata.set_cache_control(v);
@@ -67,6 +68,9 @@ impl oio::Write for MemoryWriter {
         if let Some(v) = self.op.content_encoding() {
             metadata.set_content_encoding(v);
         }
+        if let Some(v) = self.op.user_metadata() {
+            metadata = metadata.with_user_metadata(v.clone());
+        }
 
         let value = MemoryValue {
             metadata: metadata.clone(),
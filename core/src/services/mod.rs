@@ -254,6 +254,11 @@ mod rocksdb;
 #[cfg(feature = "services-rocksdb")]
 pub use self::rocksdb::*;
 
+#[cfg(feature = "services-route")]
+mod route;
+#[cfg(feature = "services-route")]
+pub use route::*;
+
 #[cfg(feature = "services-s3")]
 mod s3;
 #[cfg(feature = "services-s3")]
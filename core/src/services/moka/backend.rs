@@ -43,6 +43,7 @@ pub type MokaCacheBuilder<K, V> = moka::future::CacheBuilder<K, V, MokaCache<K,
 pub struct MokaBuilder {
     pub(super) config: MokaConfig,
     pub(super) builder: MokaCacheBuilder<String, MokaValue>,
+    pub(super) expiry: Option<MokaExpiryPolicy>,
 }
 
 impl Debug for MokaBuilder {
@@ -58,6 +59,10 @@ impl MokaBuilder {
     ///
     /// Refer to [`moka::future::CacheBuilder`](https://docs.rs/moka/latest/moka/future/struct.CacheBuilder.html)
     ///
+    /// NOTE: the `expire_after` policy of the given builder will be replaced by
+    /// OpenDAL's own policy which honours `expires_in` set on writes. Use
+    /// [`MokaBuilder::expire_after`] to set a custom policy instead.
+    ///
     /// # Example
     ///
     /// ```no_run
@@ -130,6 +135,20 @@ impl MokaBuilder {
         self
     }
 
+    /// Sets the expiry policy of the cache.
+    ///
+    /// Entries written with `expires_in` expire at the earliest of their own
+    /// expiry and the one returned by this policy.
+    ///
+    /// Refer to [`moka::future::CacheBuilder::expire_after`](https://docs.rs/moka/latest/moka/future/struct.CacheBuilder.html#method.expire_after)
+    pub fn expire_after(
+        mut self,
+        expiry: impl moka::Expiry<String, MokaValue> + Send + Sync + 'static,
+    ) -> Self {
+        self.expiry = Some(Arc::new(expiry));
+        self
+    }
+
     /// Set the root path of this backend
     pub fn root(mut self, path: &str) -> Self {
         self.config.root = if path.is_empty() {
@@ -171,6 +190,7 @@ impl Builder for MokaBuilder {
             let duration = signed_to_duration(value)?;
             builder = builder.time_to_idle(duration);
         }
+        builder = builder.expire_after(MokaExpiry { inner: self.expiry });
 
         debug!("backend build finished: {:?}", self.config);
 
@@ -204,9 +224,13 @@ impl MokaBackend {
             write_with_content_type: true,
             write_with_content_disposition: true,
             write_with_content_encoding: true,
+            write_with_ttl: true,
             delete: true,
             stat: true,
             list: true,
+            copy: true,
+            rename: true,
+            rename_dir: true,
             shared: false,
             ..Default::default()
         });
@@ -316,4 +340,27 @@ impl Access for MokaBackend {
         let lister = oio::HierarchyLister::new(lister, path, args.recursive());
         Ok((RpList::default(), lister))
     }
+
+    async fn copy(&self, from: &str, to: &str, _: OpCopy) -> Result<RpCopy> {
+        let from = build_abs_path(&self.root, from);
+        let to = build_abs_path(&self.root, to);
+        if !self.core.copy(&from, &to).await? {
+            return Err(Error::new(ErrorKind::NotFound, "key not found in moka"));
+        }
+        Ok(RpCopy::default())
+    }
+
+    async fn rename(&self, from: &str, to: &str, _: OpRename) -> Result<RpRename> {
+        let from = build_abs_path(&self.root, from);
+        let to = build_abs_path(&self.root, to);
+        let renamed = if from.ends_with('/') {
+            self.core.rename_dir(&from, &to).await?
+        } else {
+            self.core.rename(&from, &to).await?
+        };
+        if !renamed {
+            return Err(Error::new(ErrorKind::NotFound, "key not found in moka"));
+        }
+        Ok(RpRename::default())
+    }
 }
@@ -16,9 +16,14 @@
 // under the License.
 
 use std::fmt::Debug;
+use std::sync::Arc;
+use std::time::Duration;
+use std::time::Instant;
 
+use moka::Expiry;
 use moka::future::Cache;
 
+use crate::raw::*;
 use crate::*;
 
 /// Value stored in moka cache containing both metadata and content
@@ -30,6 +35,87 @@ pub struct MokaValue {
     pub content: Buffer,
 }
 
+/// Expiry policy that users set via [`MokaBuilder::expire_after`](super::MokaBuilder::expire_after).
+pub type MokaExpiryPolicy = Arc<dyn Expiry<String, MokaValue> + Send + Sync + 'static>;
+
+/// Expire entries at the `expires_at` recorded in their metadata.
+///
+/// Entries without `expires_at` never expire on their own, but are still
+/// subject to the cache wide `time_to_live`, `time_to_idle` and the expiry
+/// policy set by users. Entries with both expire at the earliest one.
+pub struct MokaExpiry {
+    pub inner: Option<MokaExpiryPolicy>,
+}
+
+impl MokaExpiry {
+    fn remaining(value: &MokaValue) -> Option<Duration> {
+        let expires_at = value.metadata.expires_at()?;
+        let remaining = expires_at
+            .into_inner()
+            .duration_since(Timestamp::now().into_inner());
+        Some(remaining.try_into().unwrap_or(Duration::ZERO))
+    }
+
+    fn earliest(value: &MokaValue, duration: Option<Duration>) -> Option<Duration> {
+        match (Self::remaining(value), duration) {
+            (Some(a), Some(b)) => Some(a.min(b)),
+            (a, b) => a.or(b),
+        }
+    }
+}
+
+impl Expiry<String, MokaValue> for MokaExpiry {
+    fn expire_after_create(
+        &self,
+        key: &String,
+        value: &MokaValue,
+        created_at: Instant,
+    ) -> Option<Duration> {
+        let duration = self
+            .inner
+            .as_ref()
+            .and_then(|v| v.expire_after_create(key, value, created_at));
+        Self::earliest(value, duration)
+    }
+
+    fn expire_after_read(
+        &self,
+        key: &String,
+        value: &MokaValue,
+        read_at: Instant,
+        duration_until_expiry: Option<Duration>,
+        last_modified_at: Instant,
+    ) -> Option<Duration> {
+        match &self.inner {
+            Some(v) => {
+                let duration = v.expire_after_read(
+                    key,
+                    value,
+                    read_at,
+                    duration_until_expiry,
+                    last_modified_at,
+                );
+                Self::earliest(value, duration)
+            }
+            None => duration_until_expiry,
+        }
+    }
+
+    fn expire_after_update(
+        &self,
+        key: &String,
+        value: &MokaValue,
+        updated_at: Instant,
+        duration_until_expiry: Option<Duration>,
+    ) -> Option<Duration> {
+        let duration = self
+            .inner
+            .as_ref()
+            .and_then(|v| v.expire_after_update(key, value, updated_at, duration_until_expiry));
+        Self::earliest(value, duration)
+    }
+}
+
 #[derive(Clone)]
 pub struct MokaCore {
     pub cache: Cache<String, MokaValue>,
@@ -58,4 +144,47 @@ impl MokaCore {
         self.cache.invalidate(key).await;
         Ok(())
     }
+
+    /// Copy the value of `from` to `to`, returns `false` if `from` not exist.
+    pub async fn copy(&self, from: &str, to: &str) -> Result<bool> {
+        let Some(value) = self.get(from).await? else {
+            return Ok(false);
+        };
+        self.set(to, value).await?;
+        Ok(true)
+    }
+
+    /// Move the value of `from` to `to`, returns `false` if `from` not exist.
+    ///
+    /// Moka can't update two keys atomically, so neither of them is
+    /// visible for a short while.
+    pub async fn rename(&self, from: &str, to: &str) -> Result<bool> {
+        let Some(value) = self.cache.remove(from).await else {
+            return Ok(false);
+        };
+        self.set(to, value).await?;
+        Ok(true)
+    }
+
+    /// Move every key under `from` to the same place under `to` one by one,
+    /// returns `false` if there is nothing under `from`.
+    pub async fn rename_dir(&self, from: &str, to: &str) -> Result<bool> {
+        let keys: Vec<String> = self
+            .cache
+            .iter()
+            .filter(|(key, _)| key.starts_with(from))
+            .map(|(key, _)| key.to_string())
+            .collect();
+        if keys.is_empty() {
+            return Ok(false);
+        }
+
+        for key in keys {
+            if let Some(value) = self.cache.remove(&key).await {
+                self.set(&format!("{to}{}", &key[from.len()..]), value)
+                    .await?;
+            }
+        }
+        Ok(true)
+    }
 }
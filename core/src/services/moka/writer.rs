@@ -66,6 +66,9 @@ impl oio::Write for MokaWriter {
         if let Some(content_encoding) = self.op.content_encoding() {
             metadata.set_content_encoding(content_encoding);
         }
+        if let Some(ttl) = self.op.expires_in() {
+            metadata.set_expires_at(Timestamp::now() + ttl);
+        }
 
         let value = MokaValue {
             metadata: metadata.clone(),
@@ -186,6 +186,8 @@ impl MongodbBackend {
             write: true,
             write_can_empty: true,
             delete: true,
+            copy: true,
+            rename: true,
             shared: true,
             ..Default::default()
         });
@@ -252,4 +254,22 @@ impl Access for MongodbBackend {
             oio::OneShotDeleter::new(MongodbDeleter::new(self.core.clone(), self.root.clone())),
         ))
     }
+
+    async fn copy(&self, from: &str, to: &str, _: OpCopy) -> Result<RpCopy> {
+        let from = build_abs_path(&self.root, from);
+        let to = build_abs_path(&self.root, to);
+        if !self.core.copy(&from, &to).await? {
+            return Err(Error::new(ErrorKind::NotFound, "kv not found in mongodb"));
+        }
+        Ok(RpCopy::default())
+    }
+
+    async fn rename(&self, from: &str, to: &str, _: OpRename) -> Result<RpRename> {
+        let from = build_abs_path(&self.root, from);
+        let to = build_abs_path(&self.root, to);
+        if !self.core.rename(&from, &to).await? {
+            return Err(Error::new(ErrorKind::NotFound, "kv not found in mongodb"));
+        }
+        Ok(RpRename::default())
+    }
 }
@@ -102,6 +102,27 @@ impl MongodbCore {
             .map_err(parse_mongodb_error)?;
         Ok(())
     }
+
+    /// Copy the value of `from` to `to`, returns `false` if `from` not exist.
+    pub async fn copy(&self, from: &str, to: &str) -> Result<bool> {
+        let Some(value) = self.get(from).await? else {
+            return Ok(false);
+        };
+        self.set(to, value).await?;
+        Ok(true)
+    }
+
+    /// Copy the value of `from` to `to` and then delete `from`, returns
+    /// `false` if `from` not exist.
+    ///
+    /// This is not atomic, both keys exist for a short while.
+    pub async fn rename(&self, from: &str, to: &str) -> Result<bool> {
+        if !self.copy(from, to).await? {
+            return Ok(false);
+        }
+        self.delete(from).await?;
+        Ok(true)
+    }
 }
 
 fn parse_mongodb_error(err: mongodb::error::Error) -> Error {
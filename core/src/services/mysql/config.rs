@@ -46,6 +46,25 @@ pub struct MysqlConfig {
     pub key_field: Option<String>,
     /// The value field name for mysql.
     pub value_field: Option<String>,
+    /// The column to store the content type of each value.
+    ///
+    /// The column will be added to the table if it doesn't exist.
+    /// Setting it enables `write_with_content_type`.
+    pub content_type_field: Option<String>,
+    /// The column to store the last modified time of each value.
+    ///
+    /// The column will be added to the table if it doesn't exist.
+    pub last_modified_field: Option<String>,
+    /// The column to store the etag of each value.
+    ///
+    /// The column will be added to the table if it doesn't exist.
+    /// Setting it enables conditional writes with `if_match`.
+    pub etag_field: Option<String>,
+    /// The column to store the user metadata (as JSON) of each value.
+    ///
+    /// The column will be added to the table if it doesn't exist.
+    /// Setting it enables `write_with_user_metadata`.
+    pub user_metadata_field: Option<String>,
     /// The root for mysql.
     pub root: Option<String>,
 }
@@ -57,6 +76,10 @@ impl Debug for MysqlConfig {
             .field("table", &self.table)
             .field("key_field", &self.key_field)
             .field("value_field", &self.value_field)
+            .field("content_type_field", &self.content_type_field)
+            .field("last_modified_field", &self.last_modified_field)
+            .field("etag_field", &self.etag_field)
+            .field("user_metadata_field", &self.user_metadata_field)
             .finish_non_exhaustive()
     }
 }
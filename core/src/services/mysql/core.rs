@@ -15,10 +15,14 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use std::collections::HashMap;
+
 use sqlx::MySqlPool;
+use sqlx::Row;
 use sqlx::mysql::MySqlConnectOptions;
 use tokio::sync::OnceCell;
 
+use crate::raw::*;
 use crate::*;
 
 #[derive(Clone, Debug)]
@@ -29,6 +33,11 @@ pub struct MysqlCore {
     pub table: String,
     pub key_field: String,
     pub value_field: String,
+
+    pub content_type_field: Option<String>,
+    pub last_modified_field: Option<String>,
+    pub etag_field: Option<String>,
+    pub user_metadata_field: Option<String>,
 }
 
 impl MysqlCore {
@@ -38,11 +47,55 @@ impl MysqlCore {
                 let pool = MySqlPool::connect_with(self.config.clone())
                     .await
                     .map_err(parse_mysql_error)?;
+                self.migrate(&pool).await?;
                 Ok(pool)
             })
             .await
     }
 
+    /// All configured metadata columns in a stable order.
+    fn metadata_fields(&self) -> impl Iterator<Item = &str> {
+        [
+            &self.content_type_field,
+            &self.last_modified_field,
+            &self.etag_field,
+            &self.user_metadata_field,
+        ]
+        .into_iter()
+        .flatten()
+        .map(|v| v.as_str())
+    }
+
+    /// Add configured metadata columns that are missing from the table.
+    async fn migrate(&self, pool: &MySqlPool) -> Result<()> {
+        if self.metadata_fields().next().is_none() {
+            return Ok(());
+        }
+
+        let columns: Vec<String> = sqlx::query_scalar(
+            "SELECT CAST(column_name AS CHAR) FROM information_schema.columns WHERE table_schema = DATABASE() AND table_name = ?",
+        )
+        .bind(&self.table)
+        .fetch_all(pool)
+        .await
+        .map_err(parse_mysql_error)?;
+
+        for field in self.metadata_fields() {
+            if columns.iter().any(|c| c == field) {
+                continue;
+            }
+            sqlx::query(&format!(
+                "ALTER TABLE `{}` ADD COLUMN `{}` TEXT",
+                self.table, field
+            ))
+            .execute(pool)
+            .await
+            .map_err(parse_mysql_error)?;
+        }
+
+        Ok(())
+    }
+
     pub async fn get(&self, path: &str) -> Result<Option<Buffer>> {
         let pool = self.get_client().await?;
 
@@ -58,21 +111,161 @@ impl MysqlCore {
         Ok(value.map(Buffer::from))
     }
 
-    pub async fn set(&self, path: &str, value: Buffer) -> Result<()> {
+    /// Fetch the metadata of given path without loading the value.
+    pub async fn stat(&self, path: &str) -> Result<Option<Metadata>> {
         let pool = self.get_client().await?;
 
-        sqlx::query(&format!(
-            r#"INSERT INTO `{}` (`{}`, `{}`) VALUES (?, ?)
-            ON DUPLICATE KEY UPDATE `{}` = VALUES({})"#,
-            self.table, self.key_field, self.value_field, self.value_field, self.value_field
+        let mut columns = vec![format!("CAST(LENGTH(`{}`) AS SIGNED)", self.value_field)];
+        columns.extend(self.metadata_fields().map(|f| format!("`{f}`")));
+        let row = sqlx::query(&format!(
+            "SELECT {} FROM `{}` WHERE `{}` = ? LIMIT 1",
+            columns.join(", "),
+            self.table,
+            self.key_field
         ))
         .bind(path)
-        .bind(value.to_vec())
-        .execute(pool)
+        .fetch_optional(pool)
         .await
         .map_err(parse_mysql_error)?;
+        let Some(row) = row else {
+            return Ok(None);
+        };
 
-        Ok(())
+        let length: Option<i64> = row.try_get(0).map_err(parse_mysql_error)?;
+        let mut meta = Metadata::new(EntryMode::from_path(path))
+            .with_content_length(length.unwrap_or_default() as u64);
+
+        let mut idx = 1;
+        let mut next = || -> Result<Option<String>> {
+            let v = row.try_get(idx).map_err(parse_mysql_error)?;
+            idx += 1;
+            Ok(v)
+        };
+        if self.content_type_field.is_some() {
+            if let Some(v) = next()? {
+                meta.set_content_type(&v);
+            }
+        }
+        if self.last_modified_field.is_some() {
+            if let Some(v) = next()? {
+                meta.set_last_modified(v.parse()?);
+            }
+        }
+        if self.etag_field.is_some() {
+            if let Some(v) = next()? {
+                meta.set_etag(&v);
+            }
+        }
+        if self.user_metadata_field.is_some() {
+            if let Some(v) = next()? {
+                meta = meta.with_user_metadata(parse_user_metadata(&v)?);
+            }
+        }
+
+        Ok(Some(meta))
+    }
+
+    /// Write the value and its metadata.
+    ///
+    /// - `if_not_exists` inserts the row and fails if the key is taken.
+    /// - `if_match` only updates the row if its etag still matches, which is
+    ///   a compare-and-set done in a single statement.
+    pub async fn set(&self, path: &str, value: Buffer, args: &OpWrite) -> Result<Metadata> {
+        let pool = self.get_client().await?;
+
+        let mut meta =
+            Metadata::new(EntryMode::from_path(path)).with_content_length(value.len() as u64);
+        let mut fields = vec![self.value_field.as_str()];
+        let mut values = vec![];
+        if let Some(f) = &self.content_type_field {
+            fields.push(f);
+            values.push(args.content_type().map(|v| v.to_string()));
+            if let Some(v) = args.content_type() {
+                meta.set_content_type(v);
+            }
+        }
+        if let Some(f) = &self.last_modified_field {
+            let now = Timestamp::now();
+            fields.push(f);
+            values.push(Some(now.to_string()));
+            meta.set_last_modified(now);
+        }
+        if let Some(f) = &self.etag_field {
+            let etag = uuid::Uuid::new_v4().to_string();
+            fields.push(f);
+            values.push(Some(etag.clone()));
+            meta.set_etag(&etag);
+        }
+        if let Some(f) = &self.user_metadata_field {
+            fields.push(f);
+            match args.user_metadata() {
+                Some(v) => {
+                    values.push(Some(format_user_metadata(v)?));
+                    meta = meta.with_user_metadata(v.clone());
+                }
+                None => values.push(None),
+            }
+        }
+
+        // Conditions on etag can only be checked if we store it.
+        let if_match = args.if_match().filter(|_| self.etag_field.is_some());
+        let sql = if let Some(etag_field) = if_match.and(self.etag_field.as_ref()) {
+            let sets = fields
+                .iter()
+                .map(|f| format!("`{f}` = ?"))
+                .collect::<Vec<_>>()
+                .join(", ");
+            format!(
+                "UPDATE `{}` SET {sets} WHERE `{}` = ? AND `{etag_field}` = ?",
+                self.table, self.key_field,
+            )
+        } else {
+            let columns = fields
+                .iter()
+                .copied()
+                .chain(std::iter::once(self.key_field.as_str()))
+                .map(|f| format!("`{f}`"))
+                .collect::<Vec<_>>()
+                .join(", ");
+            let placeholders = vec!["?"; fields.len() + 1].join(", ");
+            let mut sql = format!(
+                "INSERT INTO `{}` ({columns}) VALUES ({placeholders})",
+                self.table
+            );
+            if !args.if_not_exists() {
+                let updates = fields
+                    .iter()
+                    .map(|f| format!("`{f}` = VALUES(`{f}`)"))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                sql.push_str(&format!(" ON DUPLICATE KEY UPDATE {updates}"));
+            }
+            sql
+        };
+
+        let mut query = sqlx::query(&sql).bind(value.to_vec());
+        for v in values {
+            query = query.bind(v);
+        }
+        query = query.bind(path);
+        if let Some(etag) = if_match {
+            query = query.bind(etag);
+        }
+
+        let result = query.execute(pool).await.map_err(|err| match &err {
+            sqlx::Error::Database(db_err) if db_err.is_unique_violation() => {
+                Error::new(ErrorKind::ConditionNotMatch, "key already exists").set_source(err)
+            }
+            _ => parse_mysql_error(err),
+        })?;
+        if if_match.is_some() && result.rows_affected() == 0 {
+            return Err(Error::new(
+                ErrorKind::ConditionNotMatch,
+                "etag doesn't match or key not found",
+            ));
+        }
+
+        Ok(meta)
     }
 
     pub async fn delete(&self, path: &str) -> Result<()> {
@@ -89,8 +282,172 @@ impl MysqlCore {
 
         Ok(())
     }
+
+    /// Copy the row of `from` to `to` in a single transaction, returns
+    /// `false` if `from` not exist.
+    ///
+    /// The value and all metadata columns are copied as is.
+    pub async fn copy(&self, from: &str, to: &str) -> Result<bool> {
+        let pool = self.get_client().await?;
+        let mut tx = pool.begin().await.map_err(parse_mysql_error)?;
+
+        sqlx::query(&format!(
+            "DELETE FROM `{table}` WHERE `{key}` = ?",
+            table = self.table,
+            key = self.key_field
+        ))
+        .bind(to)
+        .execute(&mut *tx)
+        .await
+        .map_err(parse_mysql_error)?;
+
+        let columns = std::iter::once(self.value_field.as_str())
+            .chain(self.metadata_fields())
+            .map(|f| format!("`{f}`"))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let result = sqlx::query(&format!(
+            "INSERT INTO `{table}` (`{key}`, {columns}) SELECT ?, {columns} FROM `{table}` WHERE `{key}` = ?",
+            table = self.table,
+            key = self.key_field
+        ))
+        .bind(to)
+        .bind(from)
+        .execute(&mut *tx)
+        .await
+        .map_err(parse_mysql_error)?;
+        if result.rows_affected() == 0 {
+            return Ok(false);
+        }
+
+        tx.commit().await.map_err(parse_mysql_error)?;
+        Ok(true)
+    }
+
+    /// Move the row of `from` to `to` in a single transaction, returns
+    /// `false` if `from` not exist.
+    pub async fn rename(&self, from: &str, to: &str) -> Result<bool> {
+        let pool = self.get_client().await?;
+        let mut tx = pool.begin().await.map_err(parse_mysql_error)?;
+
+        sqlx::query(&format!(
+            "DELETE FROM `{table}` WHERE `{key}` = ?",
+            table = self.table,
+            key = self.key_field
+        ))
+        .bind(to)
+        .execute(&mut *tx)
+        .await
+        .map_err(parse_mysql_error)?;
+
+        let result = sqlx::query(&format!(
+            "UPDATE `{table}` SET `{key}` = ? WHERE `{key}` = ?",
+            table = self.table,
+            key = self.key_field
+        ))
+        .bind(to)
+        .bind(from)
+        .execute(&mut *tx)
+        .await
+        .map_err(parse_mysql_error)?;
+        if result.rows_affected() == 0 {
+            return Ok(false);
+        }
+
+        tx.commit().await.map_err(parse_mysql_error)?;
+        Ok(true)
+    }
+
+    /// Move every row under `from` to the same place under `to` in a single
+    /// transaction, returns `false` if there is nothing under `from`.
+    pub async fn rename_dir(&self, from: &str, to: &str) -> Result<bool> {
+        let pool = self.get_client().await?;
+        let mut tx = pool.begin().await.map_err(parse_mysql_error)?;
+
+        // SUBSTR counts characters instead of bytes.
+        let n = from.chars().count();
+        let keys: Vec<String> = sqlx::query_scalar(&format!(
+            "SELECT `{key}` FROM `{table}` WHERE SUBSTR(`{key}`, 1, {n}) = ?",
+            table = self.table,
+            key = self.key_field
+        ))
+        .bind(from)
+        .fetch_all(&mut *tx)
+        .await
+        .map_err(parse_mysql_error)?;
+        if keys.is_empty() {
+            return Ok(false);
+        }
+
+        // Remove rows that would be overwritten, rows under `from` are moved
+        // away by the update below.
+        for key in &keys {
+            let target = format!("{to}{}", &key[from.len()..]);
+            if target.starts_with(from) {
+                continue;
+            }
+            sqlx::query(&format!(
+                "DELETE FROM `{table}` WHERE `{key}` = ?",
+                table = self.table,
+                key = self.key_field
+            ))
+            .bind(target)
+            .execute(&mut *tx)
+            .await
+            .map_err(parse_mysql_error)?;
+        }
+
+        sqlx::query(&format!(
+            "UPDATE `{table}` SET `{key}` = CONCAT(?, SUBSTR(`{key}`, {start})) WHERE SUBSTR(`{key}`, 1, {n}) = ?",
+            table = self.table,
+            key = self.key_field,
+            start = n + 1
+        ))
+        .bind(to)
+        .bind(from)
+        .execute(&mut *tx)
+        .await
+        .map_err(parse_mysql_error)?;
+
+        tx.commit().await.map_err(parse_mysql_error)?;
+        Ok(true)
+    }
+}
+
+impl oio::KeyScan for MysqlCore {
+    async fn scan(&self, prefix: &str, after: Option<&str>, limit: usize) -> Result<Vec<String>> {
+        let pool = self.get_client().await?;
+
+        let (op, start) = match after {
+            Some(after) => (">", after),
+            None => (">=", prefix),
+        };
+        // Compare and sort as binary strings so keys are scanned in byte
+        // order no matter which collation the column uses, otherwise the
+        // lister could stop before reaching all keys under the prefix.
+        let keys: Vec<String> = sqlx::query_scalar(&format!(
+            r#"SELECT `{key}` FROM `{table}` WHERE CAST(`{key}` AS BINARY) {op} ? ORDER BY CAST(`{key}` AS BINARY) LIMIT ?"#,
+            key = self.key_field,
+            table = self.table,
+        ))
+        .bind(start)
+        .bind(limit as i64)
+        .fetch_all(pool)
+        .await
+        .map_err(parse_mysql_error)?;
+
+        Ok(keys)
+    }
 }
 
 fn parse_mysql_error(err: sqlx::Error) -> Error {
     Error::new(ErrorKind::Unexpected, "unhandled error from mysql").set_source(err)
 }
+
+fn format_user_metadata(v: &HashMap<String, String>) -> Result<String> {
+    serde_json::to_string(v).map_err(new_json_serialize_error)
+}
+
+fn parse_user_metadata(v: &str) -> Result<HashMap<String, String>> {
+    serde_json::from_str(v).map_err(new_json_deserialize_error)
+}
@@ -251,6 +251,9 @@ impl Builder for ObsBuilder {
 
                             list: true,
                             list_with_recursive: true,
+                            list_with_continuation: true,
+
+                            pending_upload: true,
 
                             presign: true,
                             presign_stat: true,
@@ -378,7 +381,43 @@ impl Access for ObsBackend {
 
     async fn list(&self, path: &str, args: OpList) -> Result<(RpList, Self::Lister)> {
         let l = ObsLister::new(self.core.clone(), path, args.recursive(), args.limit());
-        Ok((RpList::default(), oio::PageLister::new(l)))
+        Ok((
+            RpList::default(),
+            oio::PageLister::new(l).with_continuation(&args),
+        ))
+    }
+
+    async fn list_pending_uploads(
+        &self,
+        path: &str,
+        args: OpListPendingUploads,
+    ) -> Result<RpListPendingUploads> {
+        let (key_marker, upload_id_marker) = parse_multipart_uploads_token(&args);
+        let resp = self
+            .core
+            .obs_list_multipart_uploads(path, key_marker, upload_id_marker)
+            .await?;
+        match resp.status() {
+            StatusCode::OK => parse_list_multipart_uploads(&self.core.root, resp.into_body()),
+            _ => Err(parse_error(resp)),
+        }
+    }
+
+    async fn abort_pending_upload(
+        &self,
+        path: &str,
+        upload_id: &str,
+        _: OpAbortPendingUpload,
+    ) -> Result<RpAbortPendingUpload> {
+        let resp = self
+            .core
+            .obs_abort_multipart_upload(path, upload_id)
+            .await?;
+        match resp.status() {
+            // Obs returns code 204 No Content if abort succeeds.
+            StatusCode::NO_CONTENT => Ok(RpAbortPendingUpload::default()),
+            _ => Err(parse_error(resp)),
+        }
     }
 
     async fn copy(&self, from: &str, to: &str, _args: OpCopy) -> Result<RpCopy> {
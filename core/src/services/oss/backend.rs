@@ -551,6 +551,8 @@ impl Builder for OssBuilder {
                             list_with_versions: self.config.enable_versioning,
                             list_with_deleted: self.config.enable_versioning,
 
+                            pending_upload: true,
+
                             presign: true,
                             presign_stat: true,
                             presign_read: true,
@@ -680,6 +682,39 @@ impl Access for OssBackend {
         Ok((RpList::default(), l))
     }
 
+    async fn list_pending_uploads(
+        &self,
+        path: &str,
+        args: OpListPendingUploads,
+    ) -> Result<RpListPendingUploads> {
+        let (key_marker, upload_id_marker) = parse_multipart_uploads_token(&args);
+        let resp = self
+            .core
+            .oss_list_multipart_uploads(path, key_marker, upload_id_marker)
+            .await?;
+        match resp.status() {
+            StatusCode::OK => parse_list_multipart_uploads(&self.core.root, resp.into_body()),
+            _ => Err(parse_error(resp)),
+        }
+    }
+
+    async fn abort_pending_upload(
+        &self,
+        path: &str,
+        upload_id: &str,
+        _: OpAbortPendingUpload,
+    ) -> Result<RpAbortPendingUpload> {
+        let resp = self
+            .core
+            .oss_abort_multipart_upload(path, upload_id)
+            .await?;
+        match resp.status() {
+            // OSS returns code 204 if abort succeeds.
+            StatusCode::NO_CONTENT => Ok(RpAbortPendingUpload::default()),
+            _ => Err(parse_error(resp)),
+        }
+    }
+
     async fn copy(&self, from: &str, to: &str, _args: OpCopy) -> Result<RpCopy> {
         let resp = self.core.oss_copy_object(from, to).await?;
         let status = resp.status();
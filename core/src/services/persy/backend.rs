@@ -137,6 +137,8 @@ impl PersyBackend {
             write: true,
             write_can_empty: true,
             delete: true,
+            copy: true,
+            rename: true,
             shared: false,
             ..Default::default()
         });
@@ -197,4 +199,22 @@ impl Access for PersyBackend {
             oio::OneShotDeleter::new(PersyDeleter::new(self.core.clone(), self.root.clone())),
         ))
     }
+
+    async fn copy(&self, from: &str, to: &str, _: OpCopy) -> Result<RpCopy> {
+        let from = build_abs_path(&self.root, from);
+        let to = build_abs_path(&self.root, to);
+        if !self.core.copy(&from, &to)? {
+            return Err(Error::new(ErrorKind::NotFound, "kv not found in persy"));
+        }
+        Ok(RpCopy::default())
+    }
+
+    async fn rename(&self, from: &str, to: &str, _: OpRename) -> Result<RpRename> {
+        let from = build_abs_path(&self.root, from);
+        let to = build_abs_path(&self.root, to);
+        if !self.core.rename(&from, &to)? {
+            return Err(Error::new(ErrorKind::NotFound, "kv not found in persy"));
+        }
+        Ok(RpRename::default())
+    }
 }
@@ -85,6 +85,67 @@ impl PersyCore {
 
         Ok(())
     }
+
+    /// Copy the value of `from` to `to` in a single transaction, returns
+    /// `false` if `from` not exist.
+    pub fn copy(&self, from: &str, to: &str) -> Result<bool> {
+        let mut tx = self.persy.begin().map_err(parse_error)?;
+        let value = match tx
+            .one::<String, persy::PersyId>(&self.index, &from.to_string())
+            .map_err(parse_error)?
+        {
+            Some(id) => tx.read(&self.segment, &id).map_err(parse_error)?,
+            None => None,
+        };
+        let Some(value) = value else {
+            tx.rollback().map_err(parse_error)?;
+            return Ok(false);
+        };
+
+        let id = tx.insert(&self.segment, &value).map_err(parse_error)?;
+        self.replace_index(&mut tx, to, id)?;
+        let prepared = tx.prepare().map_err(parse_error)?;
+        prepared.commit().map_err(parse_error)?;
+        Ok(true)
+    }
+
+    /// Point `to` at the record of `from` in a single transaction, returns
+    /// `false` if `from` not exist.
+    pub fn rename(&self, from: &str, to: &str) -> Result<bool> {
+        let mut tx = self.persy.begin().map_err(parse_error)?;
+        let Some(id) = tx
+            .one::<String, persy::PersyId>(&self.index, &from.to_string())
+            .map_err(parse_error)?
+        else {
+            tx.rollback().map_err(parse_error)?;
+            return Ok(false);
+        };
+
+        tx.remove::<String, persy::PersyId>(&self.index, from.to_string(), Some(id))
+            .map_err(parse_error)?;
+        self.replace_index(&mut tx, to, id)?;
+        let prepared = tx.prepare().map_err(parse_error)?;
+        prepared.commit().map_err(parse_error)?;
+        Ok(true)
+    }
+
+    /// Point `path` at the record `id`, the record it pointed to before is deleted.
+    fn replace_index(
+        &self,
+        tx: &mut persy::Transaction,
+        path: &str,
+        id: persy::PersyId,
+    ) -> Result<()> {
+        if let Some(old) = tx
+            .one::<String, persy::PersyId>(&self.index, &path.to_string())
+            .map_err(parse_error)?
+        {
+            tx.delete(&self.segment, &old).map_err(parse_error)?;
+        }
+        tx.put::<String, persy::PersyId>(&self.index, path.to_string(), id)
+            .map_err(parse_error)?;
+        Ok(())
+    }
 }
 
 fn parse_error<T: Into<persy::PersyError>>(err: persy::PE<T>) -> Error {
@@ -93,6 +93,46 @@ impl PostgresqlBuilder {
         }
         self
     }
+
+    /// Set the column name to store the content type of each value.
+    ///
+    /// The column will be added to the table if it doesn't exist.
+    pub fn content_type_field(mut self, content_type_field: &str) -> Self {
+        if !content_type_field.is_empty() {
+            self.config.content_type_field = Some(content_type_field.to_string());
+        }
+        self
+    }
+
+    /// Set the column name to store the last modified time of each value.
+    ///
+    /// The column will be added to the table if it doesn't exist.
+    pub fn last_modified_field(mut self, last_modified_field: &str) -> Self {
+        if !last_modified_field.is_empty() {
+            self.config.last_modified_field = Some(last_modified_field.to_string());
+        }
+        self
+    }
+
+    /// Set the column name to store the etag of each value.
+    ///
+    /// The column will be added to the table if it doesn't exist.
+    pub fn etag_field(mut self, etag_field: &str) -> Self {
+        if !etag_field.is_empty() {
+            self.config.etag_field = Some(etag_field.to_string());
+        }
+        self
+    }
+
+    /// Set the column name to store the user metadata (as JSON) of each value.
+    ///
+    /// The column will be added to the table if it doesn't exist.
+    pub fn user_metadata_field(mut self, user_metadata_field: &str) -> Self {
+        if !user_metadata_field.is_empty() {
+            self.config.user_metadata_field = Some(user_metadata_field.to_string());
+        }
+        self
+    }
 }
 
 impl Builder for PostgresqlBuilder {
@@ -138,6 +178,10 @@ impl Builder for PostgresqlBuilder {
             table,
             key_field,
             value_field,
+            content_type_field: self.config.content_type_field,
+            last_modified_field: self.config.last_modified_field,
+            etag_field: self.config.etag_field,
+            user_metadata_field: self.config.user_metadata_field,
         })
         .with_normalized_root(root))
     }
@@ -162,7 +206,18 @@ impl PostgresqlBackend {
             stat: true,
             write: true,
             write_can_empty: true,
+            write_with_content_type: core.content_type_field.is_some(),
+            write_with_user_metadata: core.user_metadata_field.is_some(),
+            write_with_if_match: core.etag_field.is_some(),
+            write_with_if_not_exists: true,
             delete: true,
+            list: true,
+            list_with_limit: true,
+            list_with_start_after: true,
+            list_with_recursive: true,
+            copy: true,
+            rename: true,
+            rename_dir: true,
             shared: true,
             ..Default::default()
         });
@@ -184,7 +239,7 @@ impl PostgresqlBackend {
 impl Access for PostgresqlBackend {
     type Reader = Buffer;
     type Writer = PostgresqlWriter;
-    type Lister = ();
+    type Lister = oio::HierarchyLister<oio::KeyScanLister<PostgresqlCore>>;
     type Deleter = oio::OneShotDeleter<PostgresqlDeleter>;
 
     fn info(&self) -> Arc<AccessorInfo> {
@@ -197,11 +252,8 @@ impl Access for PostgresqlBackend {
         if p == build_abs_path(&self.root, "") {
             Ok(RpStat::new(Metadata::new(EntryMode::DIR)))
         } else {
-            let bs = self.core.get(&p).await?;
-            match bs {
-                Some(bs) => Ok(RpStat::new(
-                    Metadata::new(EntryMode::FILE).with_content_length(bs.len() as u64),
-                )),
+            match self.core.stat(&p).await? {
+                Some(meta) => Ok(RpStat::new(meta)),
                 None => Err(Error::new(
                     ErrorKind::NotFound,
                     "kv not found in postgresql",
@@ -224,9 +276,12 @@ impl Access for PostgresqlBackend {
         Ok((RpRead::new(), bs.slice(args.range().to_range_as_usize())))
     }
 
-    async fn write(&self, path: &str, _: OpWrite) -> Result<(RpWrite, Self::Writer)> {
+    async fn write(&self, path: &str, args: OpWrite) -> Result<(RpWrite, Self::Writer)> {
         let p = build_abs_path(&self.root, path);
-        Ok((RpWrite::new(), PostgresqlWriter::new(self.core.clone(), p)))
+        Ok((
+            RpWrite::new(),
+            PostgresqlWriter::new(self.core.clone(), p, args),
+        ))
     }
 
     async fn delete(&self) -> Result<(RpDelete, Self::Deleter)> {
@@ -235,4 +290,42 @@ impl Access for PostgresqlBackend {
             oio::OneShotDeleter::new(PostgresqlDeleter::new(self.core.clone(), self.root.clone())),
         ))
     }
+
+    async fn list(&self, path: &str, args: OpList) -> Result<(RpList, Self::Lister)> {
+        let p = build_abs_path(&self.root, path);
+        let lister = oio::KeyScanLister::new(self.core.clone(), &self.root, p, &args);
+        let lister = oio::HierarchyLister::new(lister, path, args.recursive())
+            .with_start_after(args.start_after());
+
+        Ok((RpList::default(), lister))
+    }
+
+    async fn copy(&self, from: &str, to: &str, _: OpCopy) -> Result<RpCopy> {
+        let from = build_abs_path(&self.root, from);
+        let to = build_abs_path(&self.root, to);
+        if !self.core.copy(&from, &to).await? {
+            return Err(Error::new(
+                ErrorKind::NotFound,
+                "kv not found in postgresql",
+            ));
+        }
+        Ok(RpCopy::default())
+    }
+
+    async fn rename(&self, from: &str, to: &str, _: OpRename) -> Result<RpRename> {
+        let from = build_abs_path(&self.root, from);
+        let to = build_abs_path(&self.root, to);
+        let renamed = if from.ends_with('/') {
+            self.core.rename_dir(&from, &to).await?
+        } else {
+            self.core.rename(&from, &to).await?
+        };
+        if !renamed {
+            return Err(Error::new(
+                ErrorKind::NotFound,
+                "kv not found in postgresql",
+            ));
+        }
+        Ok(RpRename::default())
+    }
 }
@@ -18,20 +18,22 @@
 use std::sync::Arc;
 
 use super::core::*;
-use crate::raw::oio;
+use crate::raw::*;
 use crate::*;
 
 pub struct PostgresqlWriter {
     core: Arc<PostgresqlCore>,
     path: String,
+    args: OpWrite,
     buffer: oio::QueueBuf,
 }
 
 impl PostgresqlWriter {
-    pub fn new(core: Arc<PostgresqlCore>, path: String) -> Self {
+    pub fn new(core: Arc<PostgresqlCore>, path: String, args: OpWrite) -> Self {
         Self {
             core,
             path,
+            args,
             buffer: oio::QueueBuf::new(),
         }
     }
@@ -45,11 +47,7 @@ impl oio::Write for PostgresqlWriter {
 
     async fn close(&mut self) -> Result<Metadata> {
         let buf = self.buffer.clone().collect();
-        let length = buf.len() as u64;
-        self.core.set(&self.path, buf).await?;
-
-        let meta = Metadata::new(EntryMode::from_path(&self.path)).with_content_length(length);
-        Ok(meta)
+        self.core.set(&self.path, buf, &self.args).await
     }
 
     async fn abort(&mut self) -> Result<()> {
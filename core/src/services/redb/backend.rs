@@ -149,6 +149,8 @@ impl RedbBackend {
             write: true,
             write_can_empty: true,
             delete: true,
+            copy: true,
+            rename: true,
             shared: false,
             ..Default::default()
         });
@@ -215,4 +217,22 @@ impl Access for RedbBackend {
             oio::OneShotDeleter::new(RedbDeleter::new(self.core.clone(), self.root.clone())),
         ))
     }
+
+    async fn copy(&self, from: &str, to: &str, _: OpCopy) -> Result<RpCopy> {
+        let from = build_abs_path(&self.root, from);
+        let to = build_abs_path(&self.root, to);
+        if !self.core.copy(&from, &to)? {
+            return Err(Error::new(ErrorKind::NotFound, "kv not found in redb"));
+        }
+        Ok(RpCopy::default())
+    }
+
+    async fn rename(&self, from: &str, to: &str, _: OpRename) -> Result<RpRename> {
+        let from = build_abs_path(&self.root, from);
+        let to = build_abs_path(&self.root, to);
+        if !self.core.rename(&from, &to)? {
+            return Err(Error::new(ErrorKind::NotFound, "kv not found in redb"));
+        }
+        Ok(RpRename::default())
+    }
 }
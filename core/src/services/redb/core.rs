@@ -18,6 +18,8 @@
 use std::fmt::Debug;
 use std::sync::Arc;
 
+use redb::ReadableTable;
+
 use crate::*;
 
 #[derive(Clone)]
@@ -92,6 +94,44 @@ impl RedbCore {
         write_txn.commit().map_err(parse_commit_error)?;
         Ok(())
     }
+
+    /// Copy the value of `from` to `to`, returns `false` if `from` not exist.
+    pub fn copy(&self, from: &str, to: &str) -> Result<bool> {
+        self.transfer(from, to, false)
+    }
+
+    /// Move the value of `from` to `to` in a single transaction, returns
+    /// `false` if `from` not exist.
+    pub fn rename(&self, from: &str, to: &str) -> Result<bool> {
+        self.transfer(from, to, true)
+    }
+
+    fn transfer(&self, from: &str, to: &str, remove: bool) -> Result<bool> {
+        let write_txn = self.db.begin_write().map_err(parse_transaction_error)?;
+
+        let table_define: redb::TableDefinition<&str, &[u8]> =
+            redb::TableDefinition::new(&self.table);
+
+        {
+            let mut table = write_txn
+                .open_table(table_define)
+                .map_err(parse_table_error)?;
+
+            let value = if remove {
+                table.remove(from).map_err(parse_storage_error)?
+            } else {
+                table.get(from).map_err(parse_storage_error)?
+            };
+            let Some(value) = value.map(|v| v.value().to_vec()) else {
+                return Ok(false);
+            };
+
+            table.insert(to, &*value).map_err(parse_storage_error)?;
+        }
+
+        write_txn.commit().map_err(parse_commit_error)?;
+        Ok(true)
+    }
 }
 
 fn parse_transaction_error(e: redb::TransactionError) -> Error {
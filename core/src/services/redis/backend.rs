@@ -285,6 +285,9 @@ impl RedisBackend {
             delete: true,
             stat: true,
             write_can_empty: true,
+            write_with_ttl: true,
+            copy: true,
+            rename: true,
             shared: true,
             ..Default::default()
         });
@@ -321,9 +324,14 @@ impl Access for RedisBackend {
         } else {
             let bs = self.core.get(&p).await?;
             match bs {
-                Some(bs) => Ok(RpStat::new(
-                    Metadata::new(EntryMode::FILE).with_content_length(bs.len() as u64),
-                )),
+                Some(bs) => {
+                    let mut meta =
+                        Metadata::new(EntryMode::FILE).with_content_length(bs.len() as u64);
+                    if let Some(ttl) = self.core.ttl(&p).await? {
+                        meta.set_expires_at(Timestamp::now() + ttl);
+                    }
+                    Ok(RpStat::new(meta))
+                }
                 None => Err(Error::new(ErrorKind::NotFound, "key not found in redis")),
             }
         }
@@ -356,9 +364,12 @@ impl Access for RedisBackend {
         Ok((RpRead::new(), buffer))
     }
 
-    async fn write(&self, path: &str, _: OpWrite) -> Result<(RpWrite, Self::Writer)> {
+    async fn write(&self, path: &str, args: OpWrite) -> Result<(RpWrite, Self::Writer)> {
         let p = build_abs_path(&self.root, path);
-        Ok((RpWrite::new(), RedisWriter::new(self.core.clone(), p)))
+        Ok((
+            RpWrite::new(),
+            RedisWriter::new(self.core.clone(), p, args.expires_in()),
+        ))
     }
 
     async fn delete(&self) -> Result<(RpDelete, Self::Deleter)> {
@@ -367,4 +378,22 @@ impl Access for RedisBackend {
             oio::OneShotDeleter::new(RedisDeleter::new(self.core.clone(), self.root.clone())),
         ))
     }
+
+    async fn copy(&self, from: &str, to: &str, _: OpCopy) -> Result<RpCopy> {
+        let from = build_abs_path(&self.root, from);
+        let to = build_abs_path(&self.root, to);
+        if !self.core.copy(&from, &to).await? {
+            return Err(Error::new(ErrorKind::NotFound, "key not found in redis"));
+        }
+        Ok(RpCopy::default())
+    }
+
+    async fn rename(&self, from: &str, to: &str, _: OpRename) -> Result<RpRename> {
+        let from = build_abs_path(&self.root, from);
+        let to = build_abs_path(&self.root, to);
+        if !self.core.rename(&from, &to).await? {
+            return Err(Error::new(ErrorKind::NotFound, "key not found in redis"));
+        }
+        Ok(RpRename::default())
+    }
 }
@@ -23,6 +23,7 @@ use bytes::Bytes;
 use redis::AsyncCommands;
 use redis::Client;
 use redis::Cmd;
+use redis::CopyOptions;
 use redis::Pipeline;
 use redis::RedisError;
 use redis::RedisFuture;
@@ -182,12 +183,16 @@ impl RedisCore {
         Ok(result.map(Buffer::from))
     }
 
-    pub async fn set(&self, key: &str, value: Buffer) -> Result<()> {
+    /// Set the value of given key, `ttl` overrides the `default_ttl`.
+    ///
+    /// `ttl` is rounded up to whole milliseconds, since redis rejects `PX 0`.
+    pub async fn set(&self, key: &str, value: Buffer, ttl: Option<Duration>) -> Result<()> {
         let mut conn = self.conn().await?;
         let value = value.to_vec();
-        if let Some(dur) = self.default_ttl {
+        if let Some(dur) = ttl.or(self.default_ttl) {
+            let millis = dur.as_nanos().div_ceil(1_000_000).max(1) as u64;
             let _: () = conn
-                .set_ex(key, value, dur.as_secs())
+                .pset_ex(key, value, millis)
                 .await
                 .map_err(format_redis_error)?;
         } else {
@@ -196,11 +201,44 @@ impl RedisCore {
         Ok(())
     }
 
+    /// Get the remaining time to live of given key, returns `None` if the
+    /// key doesn't exist or has no expiry.
+    pub async fn ttl(&self, key: &str) -> Result<Option<Duration>> {
+        let mut conn = self.conn().await?;
+        let millis: i64 = conn.pttl(key).await.map_err(format_redis_error)?;
+        // PTTL returns -2 for missing keys and -1 for keys without expiry.
+        Ok((millis >= 0).then(|| Duration::from_millis(millis as u64)))
+    }
+
     pub async fn delete(&self, key: &str) -> Result<()> {
         let mut conn = self.conn().await?;
         let _: () = conn.del(key).await.map_err(format_redis_error)?;
         Ok(())
     }
+
+    /// Copy `from` to `to` with `COPY ... REPLACE`, returns `false` if `from`
+    /// not exist.
+    pub async fn copy(&self, from: &str, to: &str) -> Result<bool> {
+        let mut conn = self.conn().await?;
+        conn.copy(from, to, CopyOptions::default().replace(true))
+            .await
+            .map_err(format_redis_error)
+    }
+
+    /// Move `from` to `to` with `RENAME`, returns `false` if `from` not exist.
+    pub async fn rename(&self, from: &str, to: &str) -> Result<bool> {
+        let mut conn = self.conn().await?;
+        match conn.rename::<_, _, ()>(from, to).await {
+            Ok(()) => Ok(true),
+            Err(e)
+                if e.kind() == redis::ErrorKind::ResponseError
+                    && e.detail() == Some("no such key") =>
+            {
+                Ok(false)
+            }
+            Err(e) => Err(format_redis_error(e)),
+        }
+    }
 }
 
 pub fn format_redis_error(e: RedisError) -> Error {
@@ -15,21 +15,25 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use std::time::Duration;
+
 use super::core::RedisCore;
-use crate::raw::oio;
+use crate::raw::*;
 use crate::*;
 
 pub struct RedisWriter {
     core: std::sync::Arc<RedisCore>,
     path: String,
+    ttl: Option<Duration>,
     buffer: oio::QueueBuf,
 }
 
 impl RedisWriter {
-    pub fn new(core: std::sync::Arc<RedisCore>, path: String) -> Self {
+    pub fn new(core: std::sync::Arc<RedisCore>, path: String, ttl: Option<Duration>) -> Self {
         Self {
             core,
             path,
+            ttl,
             buffer: oio::QueueBuf::new(),
         }
     }
@@ -44,9 +48,12 @@ impl oio::Write for RedisWriter {
     async fn close(&mut self) -> Result<Metadata> {
         let buf = self.buffer.clone().collect();
         let length = buf.len() as u64;
-        self.core.set(&self.path, buf).await?;
+        self.core.set(&self.path, buf, self.ttl).await?;
 
-        let meta = Metadata::new(EntryMode::from_path(&self.path)).with_content_length(length);
+        let mut meta = Metadata::new(EntryMode::from_path(&self.path)).with_content_length(length);
+        if let Some(ttl) = self.ttl.or(self.core.default_ttl) {
+            meta.set_expires_at(Timestamp::now() + ttl);
+        }
         Ok(meta)
     }
 
@@ -23,7 +23,6 @@ use super::ROCKSDB_SCHEME;
 use super::config::RocksdbConfig;
 use super::core::*;
 use super::deleter::RocksdbDeleter;
-use super::lister::RocksdbLister;
 use super::writer::RocksdbWriter;
 use crate::raw::*;
 use crate::*;
@@ -98,7 +97,11 @@ impl RocksdbBackend {
                 write_can_empty: true,
                 delete: true,
                 list: true,
+                list_with_start_after: true,
                 list_with_recursive: true,
+                copy: true,
+                rename: true,
+                rename_dir: true,
                 shared: false,
                 ..Default::default()
             });
@@ -120,7 +123,7 @@ impl RocksdbBackend {
 impl Access for RocksdbBackend {
     type Reader = Buffer;
     type Writer = RocksdbWriter;
-    type Lister = oio::HierarchyLister<RocksdbLister>;
+    type Lister = oio::HierarchyLister<oio::KeyScanLister<RocksdbCore>>;
     type Deleter = oio::OneShotDeleter<RocksdbDeleter>;
 
     fn info(&self) -> Arc<AccessorInfo> {
@@ -167,10 +170,32 @@ impl Access for RocksdbBackend {
 
     async fn list(&self, path: &str, args: OpList) -> Result<(RpList, Self::Lister)> {
         let p = build_abs_path(&self.root, path);
-        let lister = RocksdbLister::new(self.core.clone(), self.root.clone(), p)?;
-        Ok((
-            RpList::default(),
-            oio::HierarchyLister::new(lister, path, args.recursive()),
-        ))
+        let lister = oio::KeyScanLister::new(self.core.clone(), &self.root, p, &args);
+        let lister = oio::HierarchyLister::new(lister, path, args.recursive())
+            .with_start_after(args.start_after());
+        Ok((RpList::default(), lister))
+    }
+
+    async fn copy(&self, from: &str, to: &str, _: OpCopy) -> Result<RpCopy> {
+        let from = build_abs_path(&self.root, from);
+        let to = build_abs_path(&self.root, to);
+        if !self.core.copy(&from, &to)? {
+            return Err(Error::new(ErrorKind::NotFound, "kv not found in rocksdb"));
+        }
+        Ok(RpCopy::default())
+    }
+
+    async fn rename(&self, from: &str, to: &str, _: OpRename) -> Result<RpRename> {
+        let from = build_abs_path(&self.root, from);
+        let to = build_abs_path(&self.root, to);
+        let renamed = if from.ends_with('/') {
+            self.core.rename_dir(&from, &to)?
+        } else {
+            self.core.rename(&from, &to)?
+        };
+        if !renamed {
+            return Err(Error::new(ErrorKind::NotFound, "kv not found in rocksdb"));
+        }
+        Ok(RpRename::default())
     }
 }
@@ -19,7 +19,11 @@ use std::fmt::Debug;
 use std::sync::Arc;
 
 use rocksdb::DB;
+use rocksdb::Direction;
+use rocksdb::IteratorMode;
+use rocksdb::WriteBatch;
 
+use crate::raw::*;
 use crate::*;
 
 #[derive(Clone)]
@@ -51,20 +55,81 @@ impl RocksdbCore {
         self.db.delete(path).map_err(parse_rocksdb_error)
     }
 
-    pub fn list(&self, path: &str) -> Result<Vec<String>> {
-        let it = self.db.prefix_iterator(path).map(|r| r.map(|(k, _)| k));
-        let mut res = Vec::default();
+    /// Copy the value of `from` to `to`, returns `false` if `from` not exist.
+    pub fn copy(&self, from: &str, to: &str) -> Result<bool> {
+        let Some(value) = self.db.get(from).map_err(parse_rocksdb_error)? else {
+            return Ok(false);
+        };
+        self.db.put(to, value).map_err(parse_rocksdb_error)?;
+        Ok(true)
+    }
 
-        for key in it {
-            let key = key.map_err(parse_rocksdb_error)?;
-            let key = String::from_utf8_lossy(&key);
-            if !key.starts_with(path) {
+    /// Move the value of `from` to `to` in a single write batch, returns
+    /// `false` if `from` not exist.
+    pub fn rename(&self, from: &str, to: &str) -> Result<bool> {
+        let Some(value) = self.db.get(from).map_err(parse_rocksdb_error)? else {
+            return Ok(false);
+        };
+
+        let mut batch = WriteBatch::default();
+        batch.delete(from);
+        batch.put(to, value);
+        self.db.write(batch).map_err(parse_rocksdb_error)?;
+        Ok(true)
+    }
+
+    /// Move every key under `from` to the same place under `to` in a single
+    /// write batch, returns `false` if there is nothing under `from`.
+    pub fn rename_dir(&self, from: &str, to: &str) -> Result<bool> {
+        let mut entries = Vec::new();
+        for kv in self.db.prefix_iterator(from) {
+            let (key, value) = kv.map_err(parse_rocksdb_error)?;
+            if !key.starts_with(from.as_bytes()) {
                 break;
             }
-            res.push(key.to_string());
+            entries.push((key, value));
+        }
+        if entries.is_empty() {
+            return Ok(false);
         }
 
-        Ok(res)
+        // Batches are applied in order, removals go first so that moved
+        // keys win over removed ones.
+        let mut batch = WriteBatch::default();
+        for (key, _) in &entries {
+            batch.delete(key);
+        }
+        for (key, value) in entries {
+            let mut new_key = to.as_bytes().to_vec();
+            new_key.extend_from_slice(&key[from.len()..]);
+            batch.put(new_key, value);
+        }
+        self.db.write(batch).map_err(parse_rocksdb_error)?;
+        Ok(true)
+    }
+}
+
+impl oio::KeyScan for RocksdbCore {
+    async fn scan(&self, prefix: &str, after: Option<&str>, limit: usize) -> Result<Vec<String>> {
+        let start = after.unwrap_or(prefix);
+        let it = self
+            .db
+            .iterator(IteratorMode::From(start.as_bytes(), Direction::Forward));
+
+        let mut keys = Vec::new();
+        for kv in it {
+            let (key, _) = kv.map_err(parse_rocksdb_error)?;
+            let key = String::from_utf8_lossy(&key);
+            // `IteratorMode::From` starts at `after` itself if it exists.
+            if after == Some(key.as_ref()) {
+                continue;
+            }
+            keys.push(key.to_string());
+            if keys.len() >= limit {
+                break;
+            }
+        }
+        Ok(keys)
     }
 }
 
@@ -22,7 +22,6 @@ mod backend;
 mod config;
 mod core;
 mod deleter;
-mod lister;
 mod writer;
 
 pub use backend::RocksdbBuilder as Rocksdb;
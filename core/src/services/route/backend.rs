@@ -0,0 +1,469 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::sync::Arc;
+
+use super::ROUTE_SCHEME;
+use super::config::RouteConfig;
+use super::core::*;
+use super::deleter::RouteDeleter;
+use super::lister::RouteLister;
+use crate::raw::oio::Delete;
+use crate::raw::*;
+use crate::*;
+
+/// Route service support, dispatches paths to different operators.
+#[doc = include_str!("docs.md")]
+#[derive(Debug, Default)]
+pub struct RouteBuilder {
+    pub(super) config: RouteConfig,
+    pub(super) routes: Vec<(String, Operator)>,
+    pub(super) default: Option<Operator>,
+}
+
+impl RouteBuilder {
+    /// Route paths matching `pattern` to the given operator.
+    ///
+    /// `pattern` is either a path prefix like `logs/` or a glob pattern
+    /// like `**/*.parquet`. Routes added here are evaluated in order before
+    /// routes from [`RouteConfig::routes`], the first matched route wins.
+    pub fn route(mut self, pattern: &str, op: Operator) -> Self {
+        self.routes.push((pattern.to_string(), op));
+        self
+    }
+
+    /// Set the operator that serves paths not matched by any route.
+    pub fn default_operator(mut self, op: Operator) -> Self {
+        self.default = Some(op);
+        self
+    }
+}
+
+impl Builder for RouteBuilder {
+    type Config = RouteConfig;
+
+    fn build(self) -> Result<impl Access> {
+        let load = |uri: &str| {
+            Operator::from_uri(uri).map_err(|err| {
+                err.with_operation("Builder::build")
+                    .with_context("service", ROUTE_SCHEME)
+                    .with_context("uri", uri)
+            })
+        };
+
+        let mut children = vec![];
+        let mut routes = vec![];
+        for (pattern, op) in self.routes {
+            let idx = RouteCore::push_child(&mut children, op.into_inner());
+            routes.push((RoutePattern::parse(&pattern)?, idx));
+        }
+        for (pattern, uri) in &self.config.routes {
+            let idx = RouteCore::push_child(&mut children, load(uri)?.into_inner());
+            routes.push((RoutePattern::parse(pattern)?, idx));
+        }
+
+        let default = match (self.default, &self.config.default) {
+            (Some(op), _) => Some(op),
+            (None, Some(uri)) => Some(load(uri)?),
+            (None, None) => None,
+        }
+        .map(|op| RouteCore::push_child(&mut children, op.into_inner()));
+
+        if routes.is_empty() && default.is_none() {
+            return Err(Error::new(
+                ErrorKind::ConfigInvalid,
+                "route requires at least one route or a default operator",
+            )
+            .with_operation("Builder::build")
+            .with_context("service", ROUTE_SCHEME));
+        }
+
+        // Only report what every child supports, since any path could be
+        // routed to any of them.
+        let mut cap = children
+            .iter()
+            .map(|v| v.info().full_capability())
+            .reduce(intersect_capability)
+            .expect("route must have at least one child");
+        cap.delete_max_size = children
+            .iter()
+            .map(|v| v.info().full_capability().delete_max_size.unwrap_or(1))
+            .min();
+        // Copy and rename across children fall back to streaming the content,
+        // a dir can't be moved across children.
+        let single = children.len() == 1;
+        cap.copy = (single && cap.copy) || (cap.read && cap.write);
+        cap.copy_with_if_not_exists &= cap.write_with_if_not_exists;
+        cap.rename = (single && cap.rename) || (cap.read && cap.write && cap.delete);
+        cap.rename_dir &= single;
+        // Continuation tokens can't tell which child the listing stopped at.
+        cap.list_with_continuation &= single;
+
+        let info = AccessorInfo::default();
+        info.set_scheme(ROUTE_SCHEME)
+            .set_root("/")
+            .set_native_capability(cap);
+
+        Ok(RouteBackend {
+            core: Arc::new(RouteCore {
+                info: Arc::new(info),
+                children,
+                routes,
+                default,
+            }),
+        })
+    }
+}
+
+/// Returns the capability supported by both `a` and `b`.
+#[allow(deprecated)]
+fn intersect_capability(a: Capability, b: Capability) -> Capability {
+    // `None` means there is no limit.
+    let min = |x: Option<usize>, y: Option<usize>| match (x, y) {
+        (Some(x), Some(y)) => Some(x.min(y)),
+        (x, y) => x.or(y),
+    };
+
+    Capability {
+        stat: a.stat && b.stat,
+        stat_with_if_match: a.stat_with_if_match && b.stat_with_if_match,
+        stat_with_if_none_match: a.stat_with_if_none_match && b.stat_with_if_none_match,
+        stat_with_if_modified_since: a.stat_with_if_modified_since && b.stat_with_if_modified_since,
+        stat_with_if_unmodified_since: a.stat_with_if_unmodified_since
+            && b.stat_with_if_unmodified_since,
+        stat_with_override_cache_control: a.stat_with_override_cache_control
+            && b.stat_with_override_cache_control,
+        stat_with_override_content_disposition: a.stat_with_override_content_disposition
+            && b.stat_with_override_content_disposition,
+        stat_with_override_content_type: a.stat_with_override_content_type
+            && b.stat_with_override_content_type,
+        stat_with_version: a.stat_with_version && b.stat_with_version,
+        read: a.read && b.read,
+        read_with_if_match: a.read_with_if_match && b.read_with_if_match,
+        read_with_if_none_match: a.read_with_if_none_match && b.read_with_if_none_match,
+        read_with_if_modified_since: a.read_with_if_modified_since && b.read_with_if_modified_since,
+        read_with_if_unmodified_since: a.read_with_if_unmodified_since
+            && b.read_with_if_unmodified_since,
+        read_with_override_cache_control: a.read_with_override_cache_control
+            && b.read_with_override_cache_control,
+        read_with_override_content_disposition: a.read_with_override_content_disposition
+            && b.read_with_override_content_disposition,
+        read_with_override_content_type: a.read_with_override_content_type
+            && b.read_with_override_content_type,
+        read_with_version: a.read_with_version && b.read_with_version,
+        write: a.write && b.write,
+        write_can_multi: a.write_can_multi && b.write_can_multi,
+        write_can_empty: a.write_can_empty && b.write_can_empty,
+        write_can_append: a.write_can_append && b.write_can_append,
+        write_can_resume: a.write_can_resume && b.write_can_resume,
+        write_with_content_type: a.write_with_content_type && b.write_with_content_type,
+        write_with_content_disposition: a.write_with_content_disposition
+            && b.write_with_content_disposition,
+        write_with_content_encoding: a.write_with_content_encoding && b.write_with_content_encoding,
+        write_with_cache_control: a.write_with_cache_control && b.write_with_cache_control,
+        write_with_if_match: a.write_with_if_match && b.write_with_if_match,
+        write_with_if_none_match: a.write_with_if_none_match && b.write_with_if_none_match,
+        write_with_if_not_exists: a.write_with_if_not_exists && b.write_with_if_not_exists,
+        write_with_user_metadata: a.write_with_user_metadata && b.write_with_user_metadata,
+        write_with_ttl: a.write_with_ttl && b.write_with_ttl,
+        write_with_unix_mode: a.write_with_unix_mode && b.write_with_unix_mode,
+        write_with_mtime: a.write_with_mtime && b.write_with_mtime,
+        write_multi_max_size: min(a.write_multi_max_size, b.write_multi_max_size),
+        write_multi_min_size: a.write_multi_min_size.max(b.write_multi_min_size),
+        write_total_max_size: min(a.write_total_max_size, b.write_total_max_size),
+        create_dir: a.create_dir && b.create_dir,
+        delete: a.delete && b.delete,
+        delete_with_version: a.delete_with_version && b.delete_with_version,
+        delete_max_size: min(a.delete_max_size, b.delete_max_size),
+        copy: a.copy && b.copy,
+        copy_with_if_not_exists: a.copy_with_if_not_exists && b.copy_with_if_not_exists,
+        rename: a.rename && b.rename,
+        rename_dir: a.rename_dir && b.rename_dir,
+        symlink: a.symlink && b.symlink,
+        pending_upload: a.pending_upload && b.pending_upload,
+        list: a.list && b.list,
+        list_with_limit: a.list_with_limit && b.list_with_limit,
+        list_with_start_after: a.list_with_start_after && b.list_with_start_after,
+        list_with_continuation: a.list_with_continuation && b.list_with_continuation,
+        list_with_recursive: a.list_with_recursive && b.list_with_recursive,
+        list_with_version: a.list_with_version && b.list_with_version,
+        list_with_versions: a.list_with_versions && b.list_with_versions,
+        list_with_deleted: a.list_with_deleted && b.list_with_deleted,
+        presign: a.presign && b.presign,
+        presign_read: a.presign_read && b.presign_read,
+        presign_stat: a.presign_stat && b.presign_stat,
+        presign_write: a.presign_write && b.presign_write,
+        presign_delete: a.presign_delete && b.presign_delete,
+        shared: a.shared && b.shared,
+    }
+}
+
+/// RouteBackend dispatches every operation to the child its path routes to.
+#[derive(Debug, Clone)]
+pub struct RouteBackend {
+    core: Arc<RouteCore>,
+}
+
+impl RouteBackend {
+    fn child(&self, path: &str) -> Result<&Accessor> {
+        Ok(&self.core.children[self.core.route(path)?])
+    }
+}
+
+impl Access for RouteBackend {
+    type Reader = oio::Reader;
+    type Writer = oio::Writer;
+    type Lister = RouteLister;
+    type Deleter = RouteDeleter;
+
+    fn info(&self) -> Arc<AccessorInfo> {
+        self.core.info.clone()
+    }
+
+    async fn create_dir(&self, path: &str, args: OpCreateDir) -> Result<RpCreateDir> {
+        self.child(path)?.create_dir(path, args).await
+    }
+
+    async fn stat(&self, path: &str, args: OpStat) -> Result<RpStat> {
+        if path == "/" {
+            return Ok(RpStat::new(Metadata::new(EntryMode::DIR)));
+        }
+        self.child(path)?.stat(path, args).await
+    }
+
+    async fn read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::Reader)> {
+        self.child(path)?.read(path, args).await
+    }
+
+    async fn write(&self, path: &str, args: OpWrite) -> Result<(RpWrite, Self::Writer)> {
+        self.child(path)?.write(path, args).await
+    }
+
+    async fn delete(&self) -> Result<(RpDelete, Self::Deleter)> {
+        Ok((RpDelete::default(), RouteDeleter::new(self.core.clone())))
+    }
+
+    async fn list(&self, path: &str, args: OpList) -> Result<(RpList, Self::Lister)> {
+        Ok((
+            RpList::default(),
+            RouteLister::new(self.core.clone(), path, args),
+        ))
+    }
+
+    async fn copy(&self, from: &str, to: &str, args: OpCopy) -> Result<RpCopy> {
+        let from_idx = self.core.route(from)?;
+        let to_idx = self.core.route(to)?;
+
+        let child = &self.core.children[from_idx];
+        if from_idx == to_idx && child.info().full_capability().copy {
+            return child.copy(from, to, args).await;
+        }
+
+        self.core
+            .stream_copy(from_idx, from, to_idx, to, args)
+            .await?;
+        Ok(RpCopy::default())
+    }
+
+    async fn rename(&self, from: &str, to: &str, args: OpRename) -> Result<RpRename> {
+        let from_idx = self.core.route(from)?;
+        let to_idx = self.core.route(to)?;
+
+        let child = &self.core.children[from_idx];
+        if from_idx == to_idx && child.info().full_capability().rename {
+            return child.rename(from, to, args).await;
+        }
+
+        // Rename across children is not atomic, `from` is only deleted after
+        // the content has been copied, and is left in place if deleting fails.
+        self.core
+            .stream_copy(from_idx, from, to_idx, to, OpCopy::new())
+            .await?;
+        let (_, mut deleter) = child.delete().await?;
+        deleter.delete(from, OpDelete::new())?;
+        deleter.flush().await?;
+        Ok(RpRename::default())
+    }
+
+    async fn presign(&self, path: &str, args: OpPresign) -> Result<RpPresign> {
+        self.child(path)?.presign(path, args).await
+    }
+    async fn create_symlink(
+        &self,
+        path: &str,
+        target: &str,
+        args: OpCreateSymlink,
+    ) -> Result<RpCreateSymlink> {
+        self.child(path)?.create_symlink(path, target, args).await
+    }
+
+    async fn read_link(&self, path: &str, args: OpReadLink) -> Result<RpReadLink> {
+        self.child(path)?.read_link(path, args).await
+    }
+
+    async fn list_pending_uploads(
+        &self,
+        path: &str,
+        args: OpListPendingUploads,
+    ) -> Result<RpListPendingUploads> {
+        self.child(path)?.list_pending_uploads(path, args).await
+    }
+
+    async fn abort_pending_upload(
+        &self,
+        path: &str,
+        upload_id: &str,
+        args: OpAbortPendingUpload,
+    ) -> Result<RpAbortPendingUpload> {
+        self.child(path)?
+            .abort_pending_upload(path, upload_id, args)
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::services::Memory;
+
+    fn memory() -> Operator {
+        Operator::new(Memory::default()).unwrap().finish()
+    }
+
+    #[tokio::test]
+    async fn test_route_dispatch_and_list() {
+        let (logs, csv, data) = (memory(), memory(), memory());
+        let op = Operator::new(
+            RouteBuilder::default()
+                .route("logs/", logs.clone())
+                .route("**/*.csv", csv.clone())
+                .default_operator(data.clone()),
+        )
+        .unwrap()
+        .finish();
+
+        op.write("logs/a.log", "log").await.unwrap();
+        op.write("logs/b.csv", "logs first").await.unwrap();
+        op.write("dir/c.csv", "csv").await.unwrap();
+        op.write("dir/d.txt", "data").await.unwrap();
+
+        assert!(logs.exists("logs/b.csv").await.unwrap());
+        assert!(csv.exists("dir/c.csv").await.unwrap());
+        assert!(data.exists("dir/d.txt").await.unwrap());
+        assert_eq!(op.read("dir/c.csv").await.unwrap().to_vec(), b"csv");
+
+        // Files only reachable through another route are hidden.
+        data.write("dir/e.csv", "shadowed").await.unwrap();
+
+        let mut paths: Vec<_> = op
+            .list_with("/")
+            .recursive(true)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|v| v.path().to_string())
+            .collect();
+        paths.sort();
+        assert_eq!(
+            paths,
+            ["dir/c.csv", "dir/d.txt", "logs/a.log", "logs/b.csv"]
+        );
+
+        let mut paths: Vec<_> = op
+            .list("logs/")
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|v| v.path().to_string())
+            .collect();
+        paths.sort();
+        assert_eq!(paths, ["logs/a.log", "logs/b.csv"]);
+
+        op.delete_iter(["logs/a.log", "dir/c.csv", "dir/d.txt"])
+            .await
+            .unwrap();
+        assert!(!logs.exists("logs/a.log").await.unwrap());
+        assert!(!csv.exists("dir/c.csv").await.unwrap());
+        assert!(!data.exists("dir/d.txt").await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_route_cross_backend_copy_and_rename() {
+        let (hot, cold) = (memory(), memory());
+        let op = Operator::new(
+            RouteBuilder::default()
+                .route("hot/", hot.clone())
+                .default_operator(cold.clone()),
+        )
+        .unwrap()
+        .finish();
+
+        op.write("hot/a", "hello").await.unwrap();
+        op.copy("hot/a", "archive/a").await.unwrap();
+        assert_eq!(cold.read("archive/a").await.unwrap().to_vec(), b"hello");
+        assert!(hot.exists("hot/a").await.unwrap());
+
+        op.rename("hot/a", "archive/b").await.unwrap();
+        assert_eq!(cold.read("archive/b").await.unwrap().to_vec(), b"hello");
+        assert!(!hot.exists("hot/a").await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_route_capability() {
+        let limited = memory();
+        limited.inner().info().update_full_capability(|mut cap| {
+            cap.write_with_user_metadata = false;
+            cap.rename_dir = true;
+            cap
+        });
+        let op = Operator::new(
+            RouteBuilder::default()
+                .route("a/", limited)
+                .default_operator(memory()),
+        )
+        .unwrap()
+        .finish();
+
+        let cap = op.info().full_capability();
+        assert!(!cap.write_with_user_metadata);
+        assert!(!cap.rename_dir);
+        assert!(cap.copy && cap.rename && cap.list_with_recursive);
+    }
+
+    #[tokio::test]
+    async fn test_route_from_uri() {
+        let op = Operator::from_uri(
+            "route://?route.0.pattern=tmp/&route.0.uri=memory://&default=memory://",
+        )
+        .unwrap();
+        op.write("tmp/a", "a").await.unwrap();
+        op.write("b", "b").await.unwrap();
+        assert_eq!(op.read("tmp/a").await.unwrap().to_vec(), b"a");
+        let mut paths: Vec<_> = op
+            .list("/")
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|v| v.path().to_string())
+            .collect();
+        paths.sort();
+        assert_eq!(paths, ["b", "tmp/"]);
+
+        let err = RouteBuilder::default().build().unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::ConfigInvalid);
+    }
+}
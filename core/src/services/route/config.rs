@@ -0,0 +1,154 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::backend::RouteBuilder;
+use crate::*;
+
+/// Config for route service.
+///
+/// Routes are set by indexed keys, so that patterns and uris can contain any
+/// character:
+///
+/// - `route.<N>.pattern`: either a path prefix like `logs/` or a glob pattern
+///   like `**/*.parquet`.
+/// - `route.<N>.uri`: uri of the operator, loaded via [`crate::Operator::from_uri`].
+/// - `default`: uri of the operator that serves paths not matched by any route.
+///
+/// Routes are evaluated in the order of `N`.
+#[derive(Default, Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[serde(try_from = "HashMap<String, String>", into = "HashMap<String, String>")]
+#[non_exhaustive]
+pub struct RouteConfig {
+    /// Routes in `(pattern, uri)` form, evaluated in order.
+    pub routes: Vec<(String, String)>,
+    /// URI of the operator that serves paths not matched by any route.
+    pub default: Option<String>,
+}
+
+impl TryFrom<HashMap<String, String>> for RouteConfig {
+    type Error = Error;
+
+    fn try_from(map: HashMap<String, String>) -> Result<Self> {
+        let mut cfg = RouteConfig::default();
+        let mut routes: BTreeMap<usize, (Option<String>, Option<String>)> = BTreeMap::new();
+        for (key, value) in map {
+            if key == "default" {
+                cfg.default = Some(value);
+                continue;
+            }
+            // Other keys are ignored like unknown fields of other services.
+            let Some(rest) = key.strip_prefix("route.") else {
+                continue;
+            };
+            let Some((idx, field)) = rest.split_once('.') else {
+                continue;
+            };
+            let idx = idx.parse::<usize>().map_err(|err| {
+                Error::new(ErrorKind::ConfigInvalid, "route index must be a number")
+                    .with_context("key", &key)
+                    .set_source(err)
+            })?;
+            let route = routes.entry(idx).or_default();
+            match field {
+                "pattern" => route.0 = Some(value),
+                "uri" => route.1 = Some(value),
+                _ => {}
+            }
+        }
+
+        for (idx, route) in routes {
+            let (Some(pattern), Some(uri)) = route else {
+                return Err(Error::new(
+                    ErrorKind::ConfigInvalid,
+                    "route requires both pattern and uri",
+                )
+                .with_context("route", idx.to_string()));
+            };
+            cfg.routes.push((pattern, uri));
+        }
+        Ok(cfg)
+    }
+}
+
+impl From<RouteConfig> for HashMap<String, String> {
+    fn from(cfg: RouteConfig) -> Self {
+        let mut map = HashMap::new();
+        for (idx, (pattern, uri)) in cfg.routes.into_iter().enumerate() {
+            map.insert(format!("route.{idx}.pattern"), pattern);
+            map.insert(format!("route.{idx}.uri"), uri);
+        }
+        if let Some(v) = cfg.default {
+            map.insert("default".to_string(), v);
+        }
+        map
+    }
+}
+
+impl Configurator for RouteConfig {
+    type Builder = RouteBuilder;
+
+    fn from_uri(uri: &OperatorUri) -> Result<Self> {
+        Self::from_iter(uri.options().clone())
+    }
+
+    fn into_builder(self) -> Self::Builder {
+        RouteBuilder {
+            config: self,
+            ..Default::default()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_uri_extracts_routes() {
+        let uri = OperatorUri::new(
+            "route://?route.1.pattern=**/*.csv&route.1.uri=memory://&route.0.pattern=logs/&route.0.uri=fs:///tmp%3Fa%3Db%2Cc&default=memory://",
+            Vec::<(String, String)>::new(),
+        )
+        .unwrap();
+        let cfg = RouteConfig::from_uri(&uri).unwrap();
+        assert_eq!(
+            cfg.routes,
+            vec![
+                ("logs/".to_string(), "fs:///tmp?a=b,c".to_string()),
+                ("**/*.csv".to_string(), "memory://".to_string()),
+            ]
+        );
+        assert_eq!(cfg.default.as_deref(), Some("memory://"));
+
+        // Round trip through the serialized map.
+        let map: HashMap<String, String> = cfg.clone().into();
+        assert_eq!(RouteConfig::from_iter(map).unwrap(), cfg);
+    }
+
+    #[test]
+    fn from_iter_rejects_incomplete_route() {
+        let err = RouteConfig::from_iter([("route.0.pattern".to_string(), "logs/".to_string())])
+            .unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::ConfigInvalid);
+    }
+}
@@ -0,0 +1,159 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::sync::Arc;
+
+use crate::raw::oio::Read;
+use crate::raw::oio::Write;
+use crate::raw::*;
+use crate::*;
+
+/// Pattern of a route, matched against the path without leading `/`.
+#[derive(Debug)]
+pub enum RoutePattern {
+    Prefix(String),
+    Glob(GlobMatcher),
+}
+
+impl RoutePattern {
+    pub fn parse(pattern: &str) -> Result<Self> {
+        let pattern = pattern.trim_start_matches('/');
+        if GlobMatcher::is_glob(pattern) {
+            Ok(Self::Glob(GlobMatcher::new(pattern)?))
+        } else {
+            Ok(Self::Prefix(pattern.to_string()))
+        }
+    }
+
+    fn matches(&self, path: &str) -> bool {
+        match self {
+            Self::Prefix(prefix) => path.starts_with(prefix.as_str()),
+            Self::Glob(matcher) => matcher.matches(path),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct RouteCore {
+    pub info: Arc<AccessorInfo>,
+    /// Child accessors, the same operator used by multiple routes is only
+    /// stored once.
+    pub children: Vec<Accessor>,
+    /// Routes in order with the index of their child.
+    pub routes: Vec<(RoutePattern, usize)>,
+    /// Index of the child that serves unmatched paths.
+    pub default: Option<usize>,
+}
+
+impl RouteCore {
+    /// Add a child and return its index, reusing the existing one if the
+    /// same operator has been added before.
+    pub fn push_child(children: &mut Vec<Accessor>, child: Accessor) -> usize {
+        let ptr = Arc::as_ptr(&child) as *const ();
+        match children
+            .iter()
+            .position(|v| Arc::as_ptr(v) as *const () == ptr)
+        {
+            Some(idx) => idx,
+            None => {
+                children.push(child);
+                children.len() - 1
+            }
+        }
+    }
+
+    /// Find the child that serves the given path.
+    pub fn route(&self, path: &str) -> Result<usize> {
+        let path = path.trim_start_matches('/');
+        self.routes
+            .iter()
+            .find(|(pattern, _)| pattern.matches(path))
+            .map(|(_, idx)| *idx)
+            .or(self.default)
+            .ok_or_else(|| {
+                Error::new(ErrorKind::NotFound, "no route matches this path")
+                    .with_context("path", path)
+            })
+    }
+
+    /// Find the children that may hold entries under the given dir.
+    pub fn list_candidates(&self, path: &str) -> Vec<usize> {
+        let path = path.trim_start_matches('/');
+        let mut candidates = vec![];
+        let push = |candidates: &mut Vec<usize>, idx: usize| {
+            if !candidates.contains(&idx) {
+                candidates.push(idx);
+            }
+        };
+
+        for (pattern, idx) in &self.routes {
+            match pattern {
+                // The whole dir is covered by this route, no later route
+                // or the default one could serve anything inside it.
+                RoutePattern::Prefix(prefix) if path.starts_with(prefix.as_str()) => {
+                    push(&mut candidates, *idx);
+                    return candidates;
+                }
+                RoutePattern::Prefix(prefix) if prefix.starts_with(path) => {
+                    push(&mut candidates, *idx)
+                }
+                RoutePattern::Prefix(_) => {}
+                RoutePattern::Glob(_) => push(&mut candidates, *idx),
+            }
+        }
+        if let Some(idx) = self.default {
+            push(&mut candidates, idx);
+        }
+        candidates
+    }
+
+    /// Copy the content of `from` in one child to `to` in another child
+    /// by streaming read and write.
+    pub async fn stream_copy(
+        &self,
+        from_idx: usize,
+        from: &str,
+        to_idx: usize,
+        to: &str,
+        args: OpCopy,
+    ) -> Result<()> {
+        let (_, mut r) = self.children[from_idx].read(from, OpRead::new()).await?;
+        let (_, mut w) = self.children[to_idx]
+            .write(to, OpWrite::new().with_if_not_exists(args.if_not_exists()))
+            .await?;
+
+        loop {
+            let bs = match r.read().await {
+                Ok(bs) => bs,
+                Err(err) => {
+                    let _ = w.abort().await;
+                    return Err(err);
+                }
+            };
+            if bs.is_empty() {
+                break;
+            }
+            if let Err(err) = w.write(bs).await {
+                let _ = w.abort().await;
+                return Err(err);
+            }
+        }
+
+        w.close().await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,76 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::sync::Arc;
+
+use super::core::RouteCore;
+use crate::raw::*;
+use crate::*;
+
+/// RouteDeleter queues deletes per child and creates the child deleters
+/// on first flush.
+pub struct RouteDeleter {
+    core: Arc<RouteCore>,
+
+    queued: Vec<Vec<(String, OpDelete)>>,
+    pending: Vec<usize>,
+    deleters: Vec<Option<oio::Deleter>>,
+}
+
+impl RouteDeleter {
+    pub fn new(core: Arc<RouteCore>) -> Self {
+        let size = core.children.len();
+        Self {
+            core,
+
+            queued: vec![vec![]; size],
+            pending: vec![0; size],
+            deleters: (0..size).map(|_| None).collect(),
+        }
+    }
+}
+
+impl oio::Delete for RouteDeleter {
+    fn delete(&mut self, path: &str, args: OpDelete) -> Result<()> {
+        let idx = self.core.route(path)?;
+        self.queued[idx].push((path.to_string(), args));
+        Ok(())
+    }
+
+    async fn flush(&mut self) -> Result<usize> {
+        let mut deleted = 0;
+        for idx in 0..self.core.children.len() {
+            if self.queued[idx].is_empty() && self.pending[idx] == 0 {
+                continue;
+            }
+
+            let deleter = match &mut self.deleters[idx] {
+                Some(deleter) => deleter,
+                slot => slot.insert(self.core.children[idx].delete().await?.1),
+            };
+            for (path, args) in self.queued[idx].drain(..) {
+                deleter.delete(&path, args)?;
+                self.pending[idx] += 1;
+            }
+
+            let n = deleter.flush().await?;
+            self.pending[idx] -= n.min(self.pending[idx]);
+            deleted += n;
+        }
+        Ok(deleted)
+    }
+}
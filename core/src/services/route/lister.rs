@@ -0,0 +1,88 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::collections::HashSet;
+use std::collections::VecDeque;
+use std::sync::Arc;
+
+use super::core::RouteCore;
+use crate::raw::*;
+use crate::*;
+
+/// RouteLister merges listings of all children that may hold entries under
+/// the listed dir.
+///
+/// Files are only yielded by the child they are routed to, so that every
+/// listed file can be read back. Dirs are yielded once.
+pub struct RouteLister {
+    core: Arc<RouteCore>,
+    path: String,
+    args: OpList,
+
+    candidates: VecDeque<usize>,
+    current: Option<(usize, oio::Lister)>,
+    seen_dirs: HashSet<String>,
+}
+
+impl RouteLister {
+    pub fn new(core: Arc<RouteCore>, path: &str, args: OpList) -> Self {
+        let candidates = core.list_candidates(path).into();
+        Self {
+            core,
+            path: path.to_string(),
+            args,
+
+            candidates,
+            current: None,
+            seen_dirs: HashSet::new(),
+        }
+    }
+}
+
+impl oio::List for RouteLister {
+    async fn next(&mut self) -> Result<Option<oio::Entry>> {
+        loop {
+            let Some((idx, lister)) = self.current.as_mut() else {
+                let Some(idx) = self.candidates.pop_front() else {
+                    return Ok(None);
+                };
+                match self.core.children[idx]
+                    .list(&self.path, self.args.clone())
+                    .await
+                {
+                    Ok((_, lister)) => self.current = Some((idx, lister)),
+                    Err(err) if err.kind() == ErrorKind::NotFound => {}
+                    Err(err) => return Err(err),
+                }
+                continue;
+            };
+
+            let Some(entry) = lister.next().await? else {
+                self.current = None;
+                continue;
+            };
+
+            if entry.mode().is_dir() {
+                if self.seen_dirs.insert(entry.path().to_string()) {
+                    return Ok(Some(entry));
+                }
+            } else if self.core.route(entry.path()).ok() == Some(*idx) {
+                return Ok(Some(entry));
+            }
+        }
+    }
+}
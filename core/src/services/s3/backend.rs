@@ -42,6 +42,7 @@ use reqwest::Url;
 
 use super::S3_SCHEME;
 use super::config::S3Config;
+use super::core::ChecksumAlgorithm;
 use super::core::*;
 use super::deleter::S3Deleter;
 use super::error::parse_error;
@@ -563,6 +564,9 @@ impl S3Builder {
     /// Set checksum algorithm of this backend.
     /// This is necessary when writing to AWS S3 Buckets with Object Lock enabled for example.
     ///
+    /// Once set, `stat` will also ask s3 to return the checksum of the object
+    /// in [`Metadata::checksum`].
+    ///
     /// Available options:
     /// - "crc32c"
     pub fn checksum_algorithm(mut self, checksum_algorithm: &str) -> Self {
@@ -922,6 +926,7 @@ impl Builder for S3Builder {
                             write_can_empty: true,
                             write_can_multi: true,
                             write_can_append: self.config.enable_write_with_append,
+                            write_can_resume: true,
 
                             write_with_cache_control: true,
                             write_with_content_type: true,
@@ -953,9 +958,12 @@ impl Builder for S3Builder {
                             list_with_limit: true,
                             list_with_start_after: true,
                             list_with_recursive: true,
+                            list_with_continuation: true,
                             list_with_versions: self.config.enable_versioning,
                             list_with_deleted: self.config.enable_versioning,
 
+                            pending_upload: true,
+
                             presign: true,
                             presign_stat: true,
                             presign_read: true,
@@ -1030,6 +1038,14 @@ impl Access for S3Backend {
                     meta.set_version(v);
                 }
 
+                if let Some(v) = parse_checksum(headers)? {
+                    meta.set_checksum(v);
+                }
+
+                if let Some(v) = parse_expiration(headers)? {
+                    meta.set_expires_at(v);
+                }
+
                 Ok(RpStat::new(meta))
             }
             _ => Err(parse_error(resp)),
@@ -1058,11 +1074,10 @@ impl Access for S3Backend {
         let w = if args.append() {
             S3Writers::Two(oio::AppendWriter::new(writer))
         } else {
-            S3Writers::One(oio::MultipartWriter::new(
-                self.core.info.clone(),
-                writer,
-                args.concurrent(),
-            ))
+            S3Writers::One(
+                oio::MultipartWriter::new(self.core.info.clone(), writer, args.concurrent())
+                    .with_checkpoint(&args)?,
+            )
         };
 
         Ok((RpWrite::default(), w))
@@ -1077,28 +1092,59 @@ impl Access for S3Backend {
 
     async fn list(&self, path: &str, args: OpList) -> Result<(RpList, Self::Lister)> {
         let l = if args.versions() || args.deleted() {
-            ThreeWays::Three(oio::PageLister::new(S3ObjectVersionsLister::new(
-                self.core.clone(),
-                path,
-                args,
-            )))
+            ThreeWays::Three(
+                oio::PageLister::new(S3ObjectVersionsLister::new(
+                    self.core.clone(),
+                    path,
+                    args.clone(),
+                ))
+                .with_continuation(&args),
+            )
         } else if self.core.disable_list_objects_v2 {
-            ThreeWays::One(oio::PageLister::new(S3ListerV1::new(
-                self.core.clone(),
-                path,
-                args,
-            )))
+            ThreeWays::One(
+                oio::PageLister::new(S3ListerV1::new(self.core.clone(), path, args.clone()))
+                    .with_continuation(&args),
+            )
         } else {
-            ThreeWays::Two(oio::PageLister::new(S3ListerV2::new(
-                self.core.clone(),
-                path,
-                args,
-            )))
+            ThreeWays::Two(
+                oio::PageLister::new(S3ListerV2::new(self.core.clone(), path, args.clone()))
+                    .with_continuation(&args),
+            )
         };
 
         Ok((RpList::default(), l))
     }
 
+    async fn list_pending_uploads(
+        &self,
+        path: &str,
+        args: OpListPendingUploads,
+    ) -> Result<RpListPendingUploads> {
+        let (key_marker, upload_id_marker) = parse_multipart_uploads_token(&args);
+        let resp = self
+            .core
+            .s3_list_multipart_uploads(path, key_marker, upload_id_marker)
+            .await?;
+        match resp.status() {
+            StatusCode::OK => parse_list_multipart_uploads(&self.core.root, resp.into_body()),
+            _ => Err(parse_error(resp)),
+        }
+    }
+
+    async fn abort_pending_upload(
+        &self,
+        path: &str,
+        upload_id: &str,
+        _: OpAbortPendingUpload,
+    ) -> Result<RpAbortPendingUpload> {
+        let resp = self.core.s3_abort_multipart_upload(path, upload_id).await?;
+        match resp.status() {
+            // s3 returns code 204 if abort succeeds.
+            StatusCode::NO_CONTENT => Ok(RpAbortPendingUpload::default()),
+            _ => Err(parse_error(resp)),
+        }
+    }
+
     async fn copy(&self, from: &str, to: &str, _args: OpCopy) -> Result<RpCopy> {
         let resp = self.core.s3_copy_object(from, to).await?;
 
@@ -196,6 +196,9 @@ pub struct S3Config {
     /// Checksum Algorithm to use when sending checksums in HTTP headers.
     /// This is necessary when writing to AWS S3 Buckets with Object Lock enabled for example.
     ///
+    /// Once set, `stat` will also ask s3 to return the checksum of the object
+    /// in [`Metadata::checksum`].
+    ///
     /// Available options:
     /// - "crc32c"
     #[serde(alias = "aws_checksum_algorithm")]
@@ -79,6 +79,11 @@ pub mod constants {
     pub const X_AMZ_VERSION_ID: &str = "x-amz-version-id";
     pub const X_AMZ_OBJECT_SIZE: &str = "x-amz-object-size";
 
+    pub const X_AMZ_CHECKSUM_MODE: &str = "x-amz-checksum-mode";
+    pub const X_AMZ_CHECKSUM_TYPE: &str = "x-amz-checksum-type";
+
+    pub const X_AMZ_EXPIRATION: &str = "x-amz-expiration";
+
     pub const RESPONSE_CONTENT_DISPOSITION: &str = "response-content-disposition";
     pub const RESPONSE_CONTENT_TYPE: &str = "response-content-type";
     pub const RESPONSE_CACHE_CONTROL: &str = "response-cache-control";
@@ -403,6 +408,11 @@ impl S3Core {
 
         req = self.insert_sse_headers(req, false);
 
+        // Ask s3 to return the checksum of the object if checksum is enabled.
+        if self.checksum_algorithm.is_some() {
+            req = req.header(constants::X_AMZ_CHECKSUM_MODE, "ENABLED");
+        }
+
         if let Some(if_none_match) = args.if_none_match() {
             req = req.header(IF_NONE_MATCH, if_none_match);
         }
@@ -939,6 +949,44 @@ impl S3Core {
         self.send(req).await
     }
 
+    /// List on-going multipart uploads whose key starts with path.
+    pub async fn s3_list_multipart_uploads(
+        &self,
+        path: &str,
+        key_marker: &str,
+        upload_id_marker: &str,
+    ) -> Result<Response<Buffer>> {
+        let p = build_abs_path(&self.root, path);
+
+        let mut url = QueryPairsWriter::new(&self.endpoint);
+        url = url.push("uploads", "");
+
+        if !p.is_empty() {
+            url = url.push("prefix", &percent_encode_path(&p));
+        }
+        if !key_marker.is_empty() {
+            url = url.push("key-marker", &percent_encode_path(key_marker));
+        }
+        if !upload_id_marker.is_empty() {
+            url = url.push("upload-id-marker", &percent_encode_path(upload_id_marker));
+        }
+
+        let mut req = Request::get(url.finish());
+
+        // Set request payer header if enabled.
+        req = self.insert_request_payer_header(req);
+
+        let mut req = req
+            // Inject operation to the request.
+            .extension(Operation::ListPendingUploads)
+            .body(Buffer::new())
+            .map_err(new_request_build_error)?;
+
+        self.sign(&mut req).await?;
+
+        self.send(req).await
+    }
+
     /// Abort an on-going multipart upload.
     pub async fn s3_abort_multipart_upload(
         &self,
@@ -1254,6 +1302,54 @@ pub struct ListObjectVersionsOutputDeleteMarker {
     pub last_modified: String,
 }
 
+/// Parse the checksum of the whole object from s3 response headers.
+///
+/// Composite checksums of multipart uploads are ignored since they can't be
+/// compared with the checksum of the content.
+pub fn parse_checksum(headers: &http::HeaderMap) -> Result<Option<Checksum>> {
+    if parse_header_to_str(headers, constants::X_AMZ_CHECKSUM_TYPE)? == Some("COMPOSITE") {
+        return Ok(None);
+    }
+
+    for (name, algorithm) in [
+        ("x-amz-checksum-crc32", crate::ChecksumAlgorithm::Crc32),
+        ("x-amz-checksum-crc32c", crate::ChecksumAlgorithm::Crc32c),
+        (
+            "x-amz-checksum-crc64nvme",
+            crate::ChecksumAlgorithm::Crc64Nvme,
+        ),
+        ("x-amz-checksum-sha1", crate::ChecksumAlgorithm::Sha1),
+        ("x-amz-checksum-sha256", crate::ChecksumAlgorithm::Sha256),
+    ] {
+        if let Some(v) = parse_header_to_str(headers, name)? {
+            if v.contains('-') {
+                return Ok(None);
+            }
+            return Ok(Some(Checksum::new(algorithm, v)));
+        }
+    }
+    Ok(None)
+}
+
+/// Parse the expiry time set by lifecycle rules from s3 response headers.
+///
+/// The header looks like `expiry-date="Sun, 23 Dec 2012 00:00:00 GMT", rule-id="rule"`.
+pub fn parse_expiration(headers: &http::HeaderMap) -> Result<Option<Timestamp>> {
+    let Some(v) = parse_header_to_str(headers, constants::X_AMZ_EXPIRATION)? else {
+        return Ok(None);
+    };
+
+    let Some(date) = v
+        .split_once("expiry-date=\"")
+        .and_then(|(_, rest)| rest.split_once('"'))
+        .map(|(date, _)| date)
+    else {
+        return Ok(None);
+    };
+
+    Timestamp::parse_rfc2822(date).map(Some)
+}
+
 pub enum ChecksumAlgorithm {
     Crc32c,
     /// Mapping to the `Content-MD5` header from S3.
@@ -1735,4 +1831,21 @@ mod tests {
             },]
         );
     }
+
+    #[test]
+    fn test_parse_expiration() {
+        let mut headers = http::HeaderMap::new();
+        assert_eq!(parse_expiration(&headers).unwrap(), None);
+
+        headers.insert(
+            constants::X_AMZ_EXPIRATION,
+            HeaderValue::from_static(
+                r#"expiry-date="Sun, 23 Dec 2012 00:00:00 GMT", rule-id="picture-deletion-rule""#,
+            ),
+        );
+        assert_eq!(
+            parse_expiration(&headers).unwrap(),
+            Some(Timestamp::from_second(1356220800).unwrap())
+        );
+    }
 }
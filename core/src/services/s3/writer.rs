@@ -22,6 +22,7 @@ use constants::X_AMZ_OBJECT_SIZE;
 use constants::X_AMZ_VERSION_ID;
 use http::StatusCode;
 
+use super::core::ChecksumAlgorithm;
 use super::core::*;
 use super::error::S3Error;
 use super::error::from_s3_error;
@@ -19,9 +19,12 @@ use std::io::SeekFrom;
 use std::path::Path;
 use std::path::PathBuf;
 use std::sync::Arc;
+use std::time::SystemTime;
 
 use log::debug;
 use openssh::KnownHosts;
+use openssh_sftp_client::UnixTimeStamp;
+use openssh_sftp_client::metadata::MetaDataBuilder;
 use tokio::io::AsyncSeekExt;
 use tokio::sync::OnceCell;
 
@@ -34,6 +37,7 @@ use super::error::is_sftp_protocol_error;
 use super::error::parse_sftp_error;
 use super::lister::SftpLister;
 use super::reader::SftpReader;
+use super::utils::mode_to_permissions;
 use super::writer::SftpWriter;
 use crate::raw::*;
 use crate::*;
@@ -172,6 +176,8 @@ impl Builder for SftpBuilder {
 
                 write: true,
                 write_can_multi: true,
+                write_with_unix_mode: true,
+                write_with_mtime: true,
 
                 create_dir: true,
                 delete: true,
@@ -182,6 +188,8 @@ impl Builder for SftpBuilder {
                 copy: self.config.enable_copy,
                 rename: true,
 
+                symlink: true,
+
                 shared: true,
 
                 ..Default::default()
@@ -243,12 +251,17 @@ impl Access for SftpBackend {
         Ok(RpCreateDir::default())
     }
 
-    async fn stat(&self, path: &str, _: OpStat) -> Result<RpStat> {
+    async fn stat(&self, path: &str, args: OpStat) -> Result<RpStat> {
         let client = self.core.connect().await?;
         let mut fs = client.fs();
         fs.set_cwd(&self.core.root);
 
-        let meta: Metadata = fs.metadata(path).await.map_err(parse_sftp_error)?.into();
+        let meta = if args.no_follow_symlink() {
+            fs.symlink_metadata(path).await
+        } else {
+            fs.metadata(path).await
+        };
+        let meta: Metadata = meta.map_err(parse_sftp_error)?.into();
 
         Ok(RpStat::new(meta))
     }
@@ -299,7 +312,27 @@ impl Access for SftpBackend {
 
         let file = option.open(path).await.map_err(parse_sftp_error)?;
 
-        Ok((RpWrite::new(), SftpWriter::new(file)))
+        let attrs = if op.unix_mode().is_some() || op.mtime().is_some() {
+            let mut builder = MetaDataBuilder::new();
+            if let Some(mode) = op.unix_mode() {
+                builder.permissions(mode_to_permissions(mode));
+            }
+            if let Some(mtime) = op.mtime() {
+                // SFTP v3 can only set access and modification time together.
+                let mtime = UnixTimeStamp::new(mtime.into()).map_err(|err| {
+                    Error::new(ErrorKind::Unexpected, "mtime out of sftp range").set_source(err)
+                })?;
+                let atime = UnixTimeStamp::new(SystemTime::now()).map_err(|err| {
+                    Error::new(ErrorKind::Unexpected, "atime out of sftp range").set_source(err)
+                })?;
+                builder.time(atime, mtime);
+            }
+            Some(builder.create())
+        } else {
+            None
+        };
+
+        Ok((RpWrite::new(), SftpWriter::new(file, attrs)))
     }
 
     async fn delete(&self) -> Result<(RpDelete, Self::Deleter)> {
@@ -370,4 +403,36 @@ impl Access for SftpBackend {
 
         Ok(RpRename::default())
     }
+    async fn create_symlink(
+        &self,
+        path: &str,
+        target: &str,
+        _: OpCreateSymlink,
+    ) -> Result<RpCreateSymlink> {
+        let client = self.core.connect().await?;
+
+        // Don't set cwd here, otherwise relative targets will be joined with it.
+        // The target is sent as is, sftp servers resolve relative targets
+        // against the directory of the link.
+        let mut fs = client.fs();
+        let link = Path::new(&self.core.root).join(path);
+
+        if let Some((dir, _)) = path.rsplit_once('/') {
+            self.create_dir(dir, OpCreateDir::default()).await?;
+        }
+        fs.symlink(target, link).await.map_err(parse_sftp_error)?;
+
+        Ok(RpCreateSymlink::default())
+    }
+
+    async fn read_link(&self, path: &str, _: OpReadLink) -> Result<RpReadLink> {
+        let client = self.core.connect().await?;
+
+        let mut fs = client.fs();
+        fs.set_cwd(&self.core.root);
+
+        let target = fs.read_link(path).await.map_err(parse_sftp_error)?;
+
+        Ok(RpReadLink::new(target.to_string_lossy()))
+    }
 }
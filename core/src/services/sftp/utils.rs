@@ -16,6 +16,7 @@
 // under the License.
 
 use openssh_sftp_client::metadata::MetaData as SftpMeta;
+use openssh_sftp_client::metadata::Permissions;
 
 use crate::EntryMode;
 use crate::Metadata;
@@ -31,6 +32,8 @@ impl From<SftpMeta> for Metadata {
                     EntryMode::FILE
                 } else if filetype.is_dir() {
                     EntryMode::DIR
+                } else if filetype.is_symlink() {
+                    EntryMode::Symlink
                 } else {
                     EntryMode::Unknown
                 }
@@ -49,6 +52,107 @@ impl From<SftpMeta> for Metadata {
             }
         }
 
+        if let Some(perm) = meta.permissions() {
+            metadata.set_unix_mode(permissions_to_mode(perm));
+        }
+        if let Some(uid) = meta.uid() {
+            metadata.set_uid(uid);
+        }
+        if let Some(gid) = meta.gid() {
+            metadata.set_gid(gid);
+        }
+
         metadata
     }
 }
+
+type PermissionBit = (
+    u32,
+    fn(&Permissions) -> bool,
+    fn(&mut Permissions, bool) -> &mut Permissions,
+);
+
+/// POSIX permission bits and their accessors in sftp permissions.
+///
+/// `Permissions::from(u16)` is not used since it maps `0o4000` to setgid.
+const PERMISSION_BITS: [PermissionBit; 12] = [
+    (0o4000, Permissions::suid, Permissions::set_suid),
+    (0o2000, Permissions::sgid, Permissions::set_sgid),
+    (0o1000, Permissions::svtx, Permissions::set_vtx),
+    (
+        0o400,
+        Permissions::read_by_owner,
+        Permissions::set_read_by_owner,
+    ),
+    (
+        0o200,
+        Permissions::write_by_owner,
+        Permissions::set_write_by_owner,
+    ),
+    (
+        0o100,
+        Permissions::execute_by_owner,
+        Permissions::set_execute_by_owner,
+    ),
+    (
+        0o40,
+        Permissions::read_by_group,
+        Permissions::set_read_by_group,
+    ),
+    (
+        0o20,
+        Permissions::write_by_group,
+        Permissions::set_write_by_group,
+    ),
+    (
+        0o10,
+        Permissions::execute_by_group,
+        Permissions::set_execute_by_group,
+    ),
+    (
+        0o4,
+        Permissions::read_by_other,
+        Permissions::set_read_by_other,
+    ),
+    (
+        0o2,
+        Permissions::write_by_other,
+        Permissions::set_write_by_other,
+    ),
+    (
+        0o1,
+        Permissions::execute_by_other,
+        Permissions::set_execute_by_other,
+    ),
+];
+
+/// Convert sftp permissions into POSIX permission bits.
+pub fn permissions_to_mode(perm: Permissions) -> u32 {
+    PERMISSION_BITS
+        .iter()
+        .filter(|(_, get, _)| get(&perm))
+        .fold(0, |mode, (bit, _, _)| mode | bit)
+}
+
+/// Convert POSIX permission bits into sftp permissions.
+pub fn mode_to_permissions(mode: u32) -> Permissions {
+    let mut perm = Permissions::new();
+    for (bit, _, set) in PERMISSION_BITS {
+        set(&mut perm, mode & bit != 0);
+    }
+    perm
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mode_permissions_roundtrip() {
+        for mode in [0o644, 0o755, 0o600, 0o4755, 0o2750, 0o1777, 0o7777, 0] {
+            assert_eq!(permissions_to_mode(mode_to_permissions(mode)), mode);
+        }
+        assert!(mode_to_permissions(0o4000).suid());
+        assert!(!mode_to_permissions(0o4000).sgid());
+    }
+}
@@ -20,20 +20,25 @@ use std::pin::Pin;
 use bytes::Buf;
 use openssh_sftp_client::file::File;
 use openssh_sftp_client::file::TokioCompatFile;
+use openssh_sftp_client::metadata::MetaData as SftpMeta;
 use tokio::io::AsyncWriteExt;
 
+use super::error::parse_sftp_error;
 use crate::raw::*;
 use crate::*;
 
 pub struct SftpWriter {
     /// TODO: maybe we can use `File` directly?
     file: Pin<Box<TokioCompatFile>>,
+    /// The attributes to set after all data has been written.
+    attrs: Option<SftpMeta>,
 }
 
 impl SftpWriter {
-    pub fn new(file: File) -> Self {
+    pub fn new(file: File, attrs: Option<SftpMeta>) -> Self {
         SftpWriter {
             file: Box::pin(TokioCompatFile::new(file)),
+            attrs,
         }
     }
 }
@@ -55,6 +60,11 @@ impl oio::Write for SftpWriter {
     async fn close(&mut self) -> Result<Metadata> {
         self.file.shutdown().await.map_err(new_std_io_error)?;
 
+        if let Some(attrs) = self.attrs.take() {
+            let mut f = File::clone(&self.file);
+            f.set_metadata(attrs).await.map_err(parse_sftp_error)?;
+        }
+
         Ok(Metadata::default())
     }
 
@@ -21,7 +21,6 @@ use super::SLED_SCHEME;
 use super::config::SledConfig;
 use super::core::*;
 use super::deleter::SledDeleter;
-use super::lister::SledLister;
 use super::writer::SledWriter;
 use crate::raw::*;
 use crate::*;
@@ -122,7 +121,11 @@ impl SledBackend {
                 write_can_empty: true,
                 delete: true,
                 list: true,
+                list_with_start_after: true,
                 list_with_recursive: true,
+                copy: true,
+                rename: true,
+                rename_dir: true,
                 shared: false,
                 ..Default::default()
             });
@@ -144,7 +147,7 @@ impl SledBackend {
 impl Access for SledBackend {
     type Reader = Buffer;
     type Writer = SledWriter;
-    type Lister = oio::HierarchyLister<SledLister>;
+    type Lister = oio::HierarchyLister<oio::KeyScanLister<SledCore>>;
     type Deleter = oio::OneShotDeleter<SledDeleter>;
 
     fn info(&self) -> Arc<AccessorInfo> {
@@ -191,10 +194,32 @@ impl Access for SledBackend {
 
     async fn list(&self, path: &str, args: OpList) -> Result<(RpList, Self::Lister)> {
         let p = build_abs_path(&self.root, path);
-        let lister = SledLister::new(self.core.clone(), self.root.clone(), p)?;
-        Ok((
-            RpList::default(),
-            oio::HierarchyLister::new(lister, path, args.recursive()),
-        ))
+        let lister = oio::KeyScanLister::new(self.core.clone(), &self.root, p, &args);
+        let lister = oio::HierarchyLister::new(lister, path, args.recursive())
+            .with_start_after(args.start_after());
+        Ok((RpList::default(), lister))
+    }
+
+    async fn copy(&self, from: &str, to: &str, _: OpCopy) -> Result<RpCopy> {
+        let from = build_abs_path(&self.root, from);
+        let to = build_abs_path(&self.root, to);
+        if !self.core.copy(&from, &to)? {
+            return Err(Error::new(ErrorKind::NotFound, "kv not found in sled"));
+        }
+        Ok(RpCopy::default())
+    }
+
+    async fn rename(&self, from: &str, to: &str, _: OpRename) -> Result<RpRename> {
+        let from = build_abs_path(&self.root, from);
+        let to = build_abs_path(&self.root, to);
+        let renamed = if from.ends_with('/') {
+            self.core.rename_dir(&from, &to)?
+        } else {
+            self.core.rename(&from, &to)?
+        };
+        if !renamed {
+            return Err(Error::new(ErrorKind::NotFound, "kv not found in sled"));
+        }
+        Ok(RpRename::default())
     }
 }
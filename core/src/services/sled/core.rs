@@ -15,8 +15,13 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use std::convert::Infallible;
 use std::fmt::Debug;
+use std::ops::Bound;
 
+use sled::transaction::TransactionError;
+
+use crate::raw::*;
 use crate::*;
 
 #[derive(Clone)]
@@ -51,24 +56,84 @@ impl SledCore {
         Ok(())
     }
 
-    pub fn list(&self, path: &str) -> Result<Vec<String>> {
-        let it = self.tree.scan_prefix(path).keys();
-        let mut res = Vec::default();
+    /// Copy the value of `from` to `to`, returns `false` if `from` not exist.
+    pub fn copy(&self, from: &str, to: &str) -> Result<bool> {
+        let Some(value) = self.tree.get(from).map_err(parse_error)? else {
+            return Ok(false);
+        };
+        self.tree.insert(to, value).map_err(parse_error)?;
+        Ok(true)
+    }
+
+    /// Move the value of `from` to `to` in a single transaction, returns
+    /// `false` if `from` not exist.
+    pub fn rename(&self, from: &str, to: &str) -> Result<bool> {
+        self.tree
+            .transaction(|tx| {
+                let Some(value) = tx.remove(from)? else {
+                    return Ok(false);
+                };
+                tx.insert(to, value)?;
+                Ok(true)
+            })
+            .map_err(parse_transaction_error)
+    }
+
+    /// Move every key under `from` to the same place under `to` in a single
+    /// batch, returns `false` if there is nothing under `from`.
+    pub fn rename_dir(&self, from: &str, to: &str) -> Result<bool> {
+        let mut entries = Vec::new();
+        for kv in self.tree.scan_prefix(from) {
+            entries.push(kv.map_err(parse_error)?);
+        }
+        if entries.is_empty() {
+            return Ok(false);
+        }
 
-        for i in it {
-            let bs = i.map_err(parse_error)?.to_vec();
-            let v = String::from_utf8(bs).map_err(|err| {
+        // Removals go first so that moved keys win over removed ones.
+        let mut batch = sled::Batch::default();
+        for (key, _) in &entries {
+            batch.remove(key);
+        }
+        for (key, value) in entries {
+            let mut new_key = to.as_bytes().to_vec();
+            new_key.extend_from_slice(&key[from.len()..]);
+            batch.insert(new_key, value);
+        }
+        self.tree.apply_batch(batch).map_err(parse_error)?;
+        Ok(true)
+    }
+}
+
+impl oio::KeyScan for SledCore {
+    async fn scan(&self, prefix: &str, after: Option<&str>, limit: usize) -> Result<Vec<String>> {
+        let start = match after {
+            Some(after) => Bound::Excluded(after.as_bytes()),
+            None => Bound::Included(prefix.as_bytes()),
+        };
+
+        let mut keys = Vec::new();
+        for key in self
+            .tree
+            .range::<&[u8], _>((start, Bound::Unbounded))
+            .keys()
+            .take(limit)
+        {
+            let bs = key.map_err(parse_error)?.to_vec();
+            let key = String::from_utf8(bs).map_err(|err| {
                 Error::new(ErrorKind::Unexpected, "store key is not valid utf-8 string")
                     .set_source(err)
             })?;
-
-            res.push(v);
+            keys.push(key);
         }
-
-        Ok(res)
+        Ok(keys)
     }
 }
 
 fn parse_error(err: sled::Error) -> Error {
     Error::new(ErrorKind::Unexpected, "error from sled").set_source(err)
 }
+
+fn parse_transaction_error(err: TransactionError<Infallible>) -> Error {
+    Error::new(ErrorKind::Unexpected, "error from sled").set_source(err)
+}
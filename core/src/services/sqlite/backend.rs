@@ -97,6 +97,46 @@ impl SqliteBuilder {
         }
         self
     }
+
+    /// Set the column name to store the content type of each value.
+    ///
+    /// The column will be added to the table if it doesn't exist.
+    pub fn content_type_field(mut self, content_type_field: &str) -> Self {
+        if !content_type_field.is_empty() {
+            self.config.content_type_field = Some(content_type_field.to_string());
+        }
+        self
+    }
+
+    /// Set the column name to store the last modified time of each value.
+    ///
+    /// The column will be added to the table if it doesn't exist.
+    pub fn last_modified_field(mut self, last_modified_field: &str) -> Self {
+        if !last_modified_field.is_empty() {
+            self.config.last_modified_field = Some(last_modified_field.to_string());
+        }
+        self
+    }
+
+    /// Set the column name to store the etag of each value.
+    ///
+    /// The column will be added to the table if it doesn't exist.
+    pub fn etag_field(mut self, etag_field: &str) -> Self {
+        if !etag_field.is_empty() {
+            self.config.etag_field = Some(etag_field.to_string());
+        }
+        self
+    }
+
+    /// Set the column name to store the user metadata (as JSON) of each value.
+    ///
+    /// The column will be added to the table if it doesn't exist.
+    pub fn user_metadata_field(mut self, user_metadata_field: &str) -> Self {
+        if !user_metadata_field.is_empty() {
+            self.config.user_metadata_field = Some(user_metadata_field.to_string());
+        }
+        self
+    }
 }
 
 impl Builder for SqliteBuilder {
@@ -143,6 +183,10 @@ impl Builder for SqliteBuilder {
             table,
             key_field,
             value_field,
+            content_type_field: self.config.content_type_field,
+            last_modified_field: self.config.last_modified_field,
+            etag_field: self.config.etag_field,
+            user_metadata_field: self.config.user_metadata_field,
         })
         .with_normalized_root(root))
     }
@@ -188,7 +232,17 @@ impl SqliteBackend {
             delete: true,
             stat: true,
             write_can_empty: true,
-            list: false,
+            write_with_content_type: core.content_type_field.is_some(),
+            write_with_user_metadata: core.user_metadata_field.is_some(),
+            write_with_if_match: core.etag_field.is_some(),
+            write_with_if_not_exists: true,
+            list: true,
+            list_with_limit: true,
+            list_with_start_after: true,
+            list_with_recursive: true,
+            copy: true,
+            rename: true,
+            rename_dir: true,
             shared: false,
             ..Default::default()
         });
@@ -210,7 +264,7 @@ impl SqliteBackend {
 impl Access for SqliteBackend {
     type Reader = Buffer;
     type Writer = SqliteWriter;
-    type Lister = ();
+    type Lister = oio::HierarchyLister<oio::KeyScanLister<SqliteCore>>;
     type Deleter = oio::OneShotDeleter<SqliteDeleter>;
 
     fn info(&self) -> Arc<AccessorInfo> {
@@ -223,11 +277,8 @@ impl Access for SqliteBackend {
         if p == build_abs_path(&self.root, "") {
             Ok(RpStat::new(Metadata::new(EntryMode::DIR)))
         } else {
-            let bs = self.core.get(&p).await?;
-            match bs {
-                Some(bs) => Ok(RpStat::new(
-                    Metadata::new(EntryMode::from_path(&p)).with_content_length(bs.len() as u64),
-                )),
+            match self.core.stat(&p).await? {
+                Some(meta) => Ok(RpStat::new(meta)),
                 None => {
                     // Check if this might be a directory by looking for keys with this prefix
                     let dir_path = if p.ends_with('/') {
@@ -282,9 +333,12 @@ impl Access for SqliteBackend {
         Ok((RpRead::new(), buffer))
     }
 
-    async fn write(&self, path: &str, _: OpWrite) -> Result<(RpWrite, Self::Writer)> {
+    async fn write(&self, path: &str, args: OpWrite) -> Result<(RpWrite, Self::Writer)> {
         let p = build_abs_path(&self.root, path);
-        Ok((RpWrite::new(), SqliteWriter::new(self.core.clone(), &p)))
+        Ok((
+            RpWrite::new(),
+            SqliteWriter::new(self.core.clone(), &p, args),
+        ))
     }
 
     async fn delete(&self) -> Result<(RpDelete, Self::Deleter)> {
@@ -294,6 +348,15 @@ impl Access for SqliteBackend {
         ))
     }
 
+    async fn list(&self, path: &str, args: OpList) -> Result<(RpList, Self::Lister)> {
+        let p = build_abs_path(&self.root, path);
+        let lister = oio::KeyScanLister::new(self.core.clone(), &self.root, p, &args);
+        let lister = oio::HierarchyLister::new(lister, path, args.recursive())
+            .with_start_after(args.start_after());
+
+        Ok((RpList::default(), lister))
+    }
+
     async fn create_dir(&self, path: &str, _: OpCreateDir) -> Result<RpCreateDir> {
         let p = build_abs_path(&self.root, path);
 
@@ -305,16 +368,44 @@ impl Access for SqliteBackend {
         };
 
         // Store directory marker with empty content
-        self.core.set(&dir_path, Buffer::new()).await?;
+        self.core
+            .set(&dir_path, Buffer::new(), &OpWrite::default())
+            .await?;
 
         Ok(RpCreateDir::default())
     }
+
+    async fn copy(&self, from: &str, to: &str, _: OpCopy) -> Result<RpCopy> {
+        let from = build_abs_path(&self.root, from);
+        let to = build_abs_path(&self.root, to);
+        if !self.core.copy(&from, &to).await? {
+            return Err(Error::new(ErrorKind::NotFound, "key not found in sqlite"));
+        }
+        Ok(RpCopy::default())
+    }
+
+    async fn rename(&self, from: &str, to: &str, _: OpRename) -> Result<RpRename> {
+        let from = build_abs_path(&self.root, from);
+        let to = build_abs_path(&self.root, to);
+        let renamed = if from.ends_with('/') {
+            self.core.rename_dir(&from, &to).await?
+        } else {
+            self.core.rename(&from, &to).await?
+        };
+        if !renamed {
+            return Err(Error::new(ErrorKind::NotFound, "key not found in sqlite"));
+        }
+        Ok(RpRename::default())
+    }
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
+    use std::collections::HashMap;
+
     use sqlx::SqlitePool;
+    use sqlx::sqlite::SqlitePoolOptions;
 
     async fn build_client() -> OnceCell<SqlitePool> {
         let config = SqliteConnectOptions::from_str("sqlite::memory:").unwrap();
@@ -330,6 +421,10 @@ mod test {
             table: "test".to_string(),
             key_field: "key".to_string(),
             value_field: "value".to_string(),
+            content_type_field: None,
+            last_modified_field: None,
+            etag_field: None,
+            user_metadata_field: None,
         };
 
         let accessor = SqliteBackend::new(core);
@@ -351,6 +446,10 @@ mod test {
             table: "test".to_string(),
             key_field: "key".to_string(),
             value_field: "value".to_string(),
+            content_type_field: None,
+            last_modified_field: None,
+            etag_field: None,
+            user_metadata_field: None,
         };
 
         let accessor = SqliteBackend::new(core).with_normalized_root("/test/".to_string());
@@ -358,4 +457,175 @@ mod test {
         assert_eq!(accessor.root, "/test/");
         assert_eq!(accessor.info.root(), "/test/".into());
     }
+
+    #[tokio::test]
+    async fn test_sqlite_list() {
+        let config = SqliteConnectOptions::from_str("sqlite::memory:").unwrap();
+        // Every connection to `sqlite::memory:` opens a new database.
+        let pool = SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect_with(config)
+            .await
+            .unwrap();
+        sqlx::query("CREATE TABLE `test` (`key` TEXT PRIMARY KEY, `value` BLOB)")
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        let core = SqliteCore {
+            pool: OnceCell::new_with(Some(pool)),
+            config: Default::default(),
+            table: "test".to_string(),
+            key_field: "key".to_string(),
+            value_field: "value".to_string(),
+            content_type_field: None,
+            last_modified_field: None,
+            etag_field: None,
+            user_metadata_field: None,
+        };
+        for key in ["a", "dir/", "dir/x", "dir/y", "dir/z", "dir0", "dir/sub/w"] {
+            core.set(key, Buffer::new(), &OpWrite::default())
+                .await
+                .unwrap();
+        }
+
+        let op = OperatorBuilder::new(SqliteBackend::new(core)).finish();
+
+        // A page size smaller than the result forces keyset pagination.
+        let mut paths: Vec<_> = op
+            .list_with("dir/")
+            .recursive(true)
+            .limit(2)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|e| e.path().to_string())
+            .collect();
+        paths.sort();
+        assert_eq!(paths, ["dir/", "dir/sub/w", "dir/x", "dir/y", "dir/z"]);
+
+        let paths: Vec<_> = op
+            .list_with("dir/")
+            .start_after("dir/x")
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|e| e.path().to_string())
+            .collect();
+        assert_eq!(paths, ["dir/y", "dir/z"]);
+    }
+
+    #[tokio::test]
+    async fn test_sqlite_metadata_and_conditional_write() {
+        let config = SqliteConnectOptions::from_str("sqlite::memory:").unwrap();
+        let pool = SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect_with(config)
+            .await
+            .unwrap();
+        sqlx::query("CREATE TABLE `test` (`key` TEXT PRIMARY KEY, `value` BLOB)")
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        // Metadata columns are missing and should be added on first use.
+        let core = SqliteCore {
+            pool: OnceCell::new_with(Some(pool)),
+            config: Default::default(),
+            table: "test".to_string(),
+            key_field: "key".to_string(),
+            value_field: "value".to_string(),
+            content_type_field: Some("content_type".to_string()),
+            last_modified_field: Some("last_modified".to_string()),
+            etag_field: Some("etag".to_string()),
+            user_metadata_field: Some("user_metadata".to_string()),
+        };
+        core.migrate(core.get_client().await.unwrap())
+            .await
+            .unwrap();
+        let op = OperatorBuilder::new(SqliteBackend::new(core)).finish();
+
+        let metadata = HashMap::from([("k".to_string(), "v".to_string())]);
+        op.write_with("file", "hello")
+            .content_type("text/plain")
+            .user_metadata(metadata.clone())
+            .await
+            .unwrap();
+
+        let meta = op.stat("file").await.unwrap();
+        assert_eq!(meta.content_length(), 5);
+        assert_eq!(meta.content_type(), Some("text/plain"));
+        assert_eq!(meta.user_metadata(), Some(&metadata));
+        assert!(meta.last_modified().is_some());
+        let etag = meta.etag().unwrap().to_string();
+
+        let err = op
+            .write_with("file", "world")
+            .if_not_exists(true)
+            .await
+            .unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::ConditionNotMatch);
+
+        let err = op
+            .write_with("file", "world")
+            .if_match("not-the-etag")
+            .await
+            .unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::ConditionNotMatch);
+
+        op.write_with("file", "world")
+            .if_match(&etag)
+            .await
+            .unwrap();
+        assert_eq!(op.read("file").await.unwrap().to_vec(), b"world");
+        assert_ne!(op.stat("file").await.unwrap().etag(), Some(etag.as_str()));
+    }
+
+    #[tokio::test]
+    async fn test_sqlite_copy_and_rename() {
+        let config = SqliteConnectOptions::from_str("sqlite::memory:").unwrap();
+        let pool = SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect_with(config)
+            .await
+            .unwrap();
+        sqlx::query("CREATE TABLE `test` (`key` TEXT PRIMARY KEY, `value` BLOB)")
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        let core = SqliteCore {
+            pool: OnceCell::new_with(Some(pool)),
+            config: Default::default(),
+            table: "test".to_string(),
+            key_field: "key".to_string(),
+            value_field: "value".to_string(),
+            content_type_field: None,
+            last_modified_field: None,
+            etag_field: None,
+            user_metadata_field: None,
+        };
+        let op = OperatorBuilder::new(SqliteBackend::new(core)).finish();
+
+        op.write("a", "hello").await.unwrap();
+        op.write("b", "old").await.unwrap();
+        op.copy("a", "b").await.unwrap();
+        assert_eq!(op.read("b").await.unwrap().to_vec(), b"hello");
+
+        op.rename("a", "c").await.unwrap();
+        assert!(!op.exists("a").await.unwrap());
+        assert_eq!(op.read("c").await.unwrap().to_vec(), b"hello");
+        assert_eq!(
+            op.rename("a", "c").await.unwrap_err().kind(),
+            ErrorKind::NotFound
+        );
+
+        for (path, content) in [("dir/x", "x"), ("dir/sub/y", "y"), ("new/x", "old")] {
+            op.write(path, content).await.unwrap();
+        }
+        op.rename("dir/", "new/").await.unwrap();
+        assert!(!op.exists("dir/x").await.unwrap());
+        assert_eq!(op.read("new/x").await.unwrap().to_vec(), b"x");
+        assert_eq!(op.read("new/sub/y").await.unwrap().to_vec(), b"y");
+    }
 }
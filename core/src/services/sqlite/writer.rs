@@ -15,21 +15,24 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use crate::raw::OpWrite;
 use crate::raw::oio;
 use crate::services::sqlite::core::SqliteCore;
-use crate::{Buffer, EntryMode, Metadata};
+use crate::{Buffer, Metadata};
 
 pub struct SqliteWriter {
     core: std::sync::Arc<SqliteCore>,
     path: String,
+    args: OpWrite,
     buffer: oio::QueueBuf,
 }
 
 impl SqliteWriter {
-    pub fn new(core: std::sync::Arc<SqliteCore>, path: &str) -> Self {
+    pub fn new(core: std::sync::Arc<SqliteCore>, path: &str, args: OpWrite) -> Self {
         Self {
             core,
             path: path.to_string(),
+            args,
             buffer: oio::QueueBuf::new(),
         }
     }
@@ -43,11 +46,7 @@ impl oio::Write for SqliteWriter {
 
     async fn close(&mut self) -> crate::Result<Metadata> {
         let buf = self.buffer.clone().collect();
-        let length = buf.len() as u64;
-        self.core.set(&self.path, buf).await?;
-
-        let meta = Metadata::new(EntryMode::from_path(&self.path)).with_content_length(length);
-        Ok(meta)
+        self.core.set(&self.path, buf, &self.args).await
     }
 
     async fn abort(&mut self) -> crate::Result<()> {
@@ -217,6 +217,8 @@ impl SurrealdbBackend {
             write: true,
             write_can_empty: true,
             delete: true,
+            copy: true,
+            rename: true,
             shared: true,
             ..Default::default()
         });
@@ -283,4 +285,22 @@ impl Access for SurrealdbBackend {
             oio::OneShotDeleter::new(SurrealdbDeleter::new(self.core.clone(), self.root.clone())),
         ))
     }
+
+    async fn copy(&self, from: &str, to: &str, _: OpCopy) -> Result<RpCopy> {
+        let from = build_abs_path(&self.root, from);
+        let to = build_abs_path(&self.root, to);
+        if !self.core.copy(&from, &to).await? {
+            return Err(Error::new(ErrorKind::NotFound, "kv not found in surrealdb"));
+        }
+        Ok(RpCopy::default())
+    }
+
+    async fn rename(&self, from: &str, to: &str, _: OpRename) -> Result<RpRename> {
+        let from = build_abs_path(&self.root, from);
+        let to = build_abs_path(&self.root, to);
+        if !self.core.rename(&from, &to).await? {
+            return Err(Error::new(ErrorKind::NotFound, "kv not found in surrealdb"));
+        }
+        Ok(RpRename::default())
+    }
 }
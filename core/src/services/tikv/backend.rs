@@ -24,10 +24,12 @@ use super::TIKV_SCHEME;
 use super::config::TikvConfig;
 use super::core::*;
 use super::deleter::TikvDeleter;
-use super::writer::TikvWriter;
 use crate::raw::*;
 use crate::*;
 
+/// Default chunk size, well below the default raft entry size limit of TiKV.
+const DEFAULT_CHUNK_SIZE: usize = 1024 * 1024;
+
 /// TiKV backend builder
 #[doc = include_str!("docs.md")]
 #[derive(Debug, Default)]
@@ -73,6 +75,16 @@ impl TikvBuilder {
         }
         self
     }
+
+    /// Set the size of chunks used to store large values.
+    ///
+    /// Default to 1 MiB.
+    pub fn chunk_size(mut self, chunk_size: usize) -> Self {
+        if chunk_size > 0 {
+            self.config.chunk_size = Some(chunk_size)
+        }
+        self
+    }
 }
 
 impl Builder for TikvBuilder {
@@ -96,30 +108,42 @@ impl Builder for TikvBuilder {
                 Error::new(ErrorKind::ConfigInvalid, "invalid tls configuration")
                     .with_context("service", TIKV_SCHEME)
                     .with_context("endpoints", format!("{endpoints:?}")),
-            )?;
+            );
+        }
+
+        let chunk_size = self.config.chunk_size.unwrap_or(DEFAULT_CHUNK_SIZE);
+        if chunk_size == 0 {
+            return Err(Error::new(
+                ErrorKind::ConfigInvalid,
+                "chunk_size must be greater than 0",
+            )
+            .with_context("service", TIKV_SCHEME));
         }
 
-        Ok(TikvBackend::new(TikvCore {
-            client: OnceCell::new(),
-            endpoints,
-            insecure: self.config.insecure,
-            ca_path: self.config.ca_path.clone(),
-            cert_path: self.config.cert_path.clone(),
-            key_path: self.config.key_path.clone(),
-        }))
+        Ok(TikvBackend::new(
+            TikvCore {
+                client: OnceCell::new(),
+                endpoints,
+                insecure: self.config.insecure,
+                ca_path: self.config.ca_path.clone(),
+                cert_path: self.config.cert_path.clone(),
+                key_path: self.config.key_path.clone(),
+            },
+            chunk_size,
+        ))
     }
 }
 
 /// Backend for TiKV service
 #[derive(Clone, Debug)]
 pub struct TikvBackend {
-    core: Arc<TikvCore>,
+    kv: ChunkedKv<TikvCore>,
     root: String,
     info: Arc<AccessorInfo>,
 }
 
 impl TikvBackend {
-    fn new(core: TikvCore) -> Self {
+    fn new(core: TikvCore, chunk_size: usize) -> Self {
         let info = AccessorInfo::default();
         info.set_scheme(TIKV_SCHEME);
         info.set_name("TiKV");
@@ -129,13 +153,16 @@ impl TikvBackend {
             stat: true,
             write: true,
             write_can_empty: true,
+            write_can_multi: true,
             delete: true,
+            copy: true,
+            rename: true,
             shared: true,
             ..Default::default()
         });
 
         Self {
-            core: Arc::new(core),
+            kv: ChunkedKv::new(Arc::new(core), chunk_size),
             root: "/".to_string(),
             info: Arc::new(info),
         }
@@ -143,8 +170,8 @@ impl TikvBackend {
 }
 
 impl Access for TikvBackend {
-    type Reader = Buffer;
-    type Writer = TikvWriter;
+    type Reader = ChunkedKvReader<TikvCore>;
+    type Writer = ChunkedKvWriter<TikvCore>;
     type Lister = ();
     type Deleter = oio::OneShotDeleter<TikvDeleter>;
 
@@ -158,10 +185,9 @@ impl Access for TikvBackend {
         if p == build_abs_path(&self.root, "") {
             Ok(RpStat::new(Metadata::new(EntryMode::DIR)))
         } else {
-            let bs = self.core.get(&p).await?;
-            match bs {
-                Some(bs) => Ok(RpStat::new(
-                    Metadata::new(EntryMode::FILE).with_content_length(bs.len() as u64),
+            match self.kv.stat(&p).await? {
+                Some(size) => Ok(RpStat::new(
+                    Metadata::new(EntryMode::FILE).with_content_length(size),
                 )),
                 None => Err(Error::new(ErrorKind::NotFound, "kv not found in tikv")),
             }
@@ -170,22 +196,39 @@ impl Access for TikvBackend {
 
     async fn read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::Reader)> {
         let p = build_abs_path(&self.root, path);
-        let bs = match self.core.get(&p).await? {
-            Some(bs) => bs,
-            None => return Err(Error::new(ErrorKind::NotFound, "kv not found in tikv")),
-        };
-        Ok((RpRead::new(), bs.slice(args.range().to_range_as_usize())))
+        match self.kv.read(&p, args.range()).await? {
+            Some(r) => Ok((RpRead::new(), r)),
+            None => Err(Error::new(ErrorKind::NotFound, "kv not found in tikv")),
+        }
     }
 
     async fn write(&self, path: &str, _: OpWrite) -> Result<(RpWrite, Self::Writer)> {
         let p = build_abs_path(&self.root, path);
-        Ok((RpWrite::new(), TikvWriter::new(self.core.clone(), p)))
+        Ok((RpWrite::new(), self.kv.writer(&p)))
     }
 
     async fn delete(&self) -> Result<(RpDelete, Self::Deleter)> {
         Ok((
             RpDelete::default(),
-            oio::OneShotDeleter::new(TikvDeleter::new(self.core.clone(), self.root.clone())),
+            oio::OneShotDeleter::new(TikvDeleter::new(self.kv.clone(), self.root.clone())),
         ))
     }
+
+    async fn copy(&self, from: &str, to: &str, _: OpCopy) -> Result<RpCopy> {
+        let from = build_abs_path(&self.root, from);
+        let to = build_abs_path(&self.root, to);
+        if !self.kv.copy(&from, &to).await? {
+            return Err(Error::new(ErrorKind::NotFound, "kv not found in tikv"));
+        }
+        Ok(RpCopy::default())
+    }
+
+    async fn rename(&self, from: &str, to: &str, _: OpRename) -> Result<RpRename> {
+        let from = build_abs_path(&self.root, from);
+        let to = build_abs_path(&self.root, to);
+        if !self.kv.rename(&from, &to).await? {
+            return Err(Error::new(ErrorKind::NotFound, "kv not found in tikv"));
+        }
+        Ok(RpRename::default())
+    }
 }
@@ -37,6 +37,13 @@ pub struct TikvConfig {
     pub cert_path: Option<String>,
     /// key path
     pub key_path: Option<String>,
+    /// The size of chunks used to store large values.
+    ///
+    /// Values larger than this are split into multiple keys, so writes can
+    /// be streamed and values are not limited by TiKV's entry size.
+    ///
+    /// Default to 1 MiB.
+    pub chunk_size: Option<usize>,
 }
 
 impl Debug for TikvConfig {
@@ -47,6 +54,7 @@ impl Debug for TikvConfig {
             .field("ca_path", &self.ca_path)
             .field("cert_path", &self.cert_path)
             .field("key_path", &self.key_path)
+            .field("chunk_size", &self.chunk_size)
             .finish_non_exhaustive()
     }
 }
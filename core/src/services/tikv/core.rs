@@ -22,6 +22,7 @@ use tikv_client::RawClient;
 use tokio::sync::OnceCell;
 
 use super::TIKV_SCHEME;
+use crate::raw::*;
 use crate::*;
 
 /// TikvCore holds the configuration and client for interacting with TiKV.
@@ -101,6 +102,20 @@ impl TikvCore {
     }
 }
 
+impl ChunkedKvStore for TikvCore {
+    async fn get(&self, key: &str) -> Result<Option<Buffer>> {
+        TikvCore::get(self, key).await
+    }
+
+    async fn set(&self, key: &str, value: Buffer) -> Result<()> {
+        TikvCore::set(self, key, value).await
+    }
+
+    async fn delete(&self, key: &str) -> Result<()> {
+        TikvCore::delete(self, key).await
+    }
+}
+
 fn parse_tikv_error(e: tikv_client::Error) -> Error {
     Error::new(ErrorKind::Unexpected, "error from tikv").set_source(e)
 }
@@ -15,28 +15,26 @@
 // specific language governing permissions and limitations
 // under the License.
 
-use std::sync::Arc;
-
 use super::core::*;
 use crate::raw::oio;
 use crate::raw::*;
 use crate::*;
 
 pub struct TikvDeleter {
-    core: Arc<TikvCore>,
+    kv: ChunkedKv<TikvCore>,
     root: String,
 }
 
 impl TikvDeleter {
-    pub fn new(core: Arc<TikvCore>, root: String) -> Self {
-        Self { core, root }
+    pub fn new(kv: ChunkedKv<TikvCore>, root: String) -> Self {
+        Self { kv, root }
     }
 }
 
 impl oio::OneShotDelete for TikvDeleter {
     async fn delete_once(&self, path: String, _: OpDelete) -> Result<()> {
         let p = build_abs_path(&self.root, &path);
-        self.core.delete(&p).await?;
+        self.kv.delete(&p).await?;
         Ok(())
     }
 }
@@ -22,7 +22,6 @@ mod backend;
 mod config;
 mod core;
 mod deleter;
-mod writer;
 
 pub use backend::TikvBuilder as Tikv;
 pub use config::TikvConfig;
@@ -175,12 +175,16 @@ impl Builder for WebhdfsBuilder {
                 write: true,
                 write_can_append: true,
                 write_can_multi: atomic_write_dir.is_some(),
+                write_with_unix_mode: true,
+                write_with_mtime: true,
 
                 create_dir: true,
                 delete: true,
 
                 list: true,
 
+                symlink: true,
+
                 shared: true,
 
                 ..Default::default()
@@ -274,37 +278,39 @@ impl Access for WebhdfsBackend {
         }
     }
 
-    async fn stat(&self, path: &str, _: OpStat) -> Result<RpStat> {
+    async fn stat(&self, path: &str, args: OpStat) -> Result<RpStat> {
         // if root exists and is a directory, stat will be ok
         self.core
             .root_checker
             .get_or_try_init(|| async { self.check_root().await })
             .await?;
 
-        let resp = self.core.webhdfs_get_file_status(path).await?;
-        let status = resp.status();
-        match status {
-            StatusCode::OK => {
-                let bs = resp.into_body();
-
-                let file_status = serde_json::from_reader::<_, FileStatusWrapper>(bs.reader())
-                    .map_err(new_json_deserialize_error)?
-                    .file_status;
-
-                let meta = match file_status.ty {
-                    FileStatusType::Directory => Metadata::new(EntryMode::DIR),
-                    FileStatusType::File => Metadata::new(EntryMode::FILE)
-                        .with_content_length(file_status.length)
-                        .with_last_modified(Timestamp::from_millisecond(
-                            file_status.modification_time,
-                        )?),
-                };
-
-                Ok(RpStat::new(meta))
+        let file_status = if args.no_follow_symlink() {
+            self.core.webhdfs_get_link_status(path).await?
+        } else {
+            let resp = self.core.webhdfs_get_file_status(path).await?;
+            if resp.status() != StatusCode::OK {
+                return Err(parse_error(resp));
             }
 
-            _ => Err(parse_error(resp)),
+            serde_json::from_reader::<_, FileStatusWrapper>(resp.into_body().reader())
+                .map_err(new_json_deserialize_error)?
+                .file_status
+        };
+
+        let mut meta = match file_status.ty {
+            FileStatusType::Directory => Metadata::new(EntryMode::DIR),
+            FileStatusType::File => Metadata::new(EntryMode::FILE)
+                .with_content_length(file_status.length)
+                .with_last_modified(Timestamp::from_millisecond(file_status.modification_time)?),
+            FileStatusType::Symlink => Metadata::new(EntryMode::Symlink)
+                .with_last_modified(Timestamp::from_millisecond(file_status.modification_time)?),
+        };
+        if let Some(mode) = file_status.unix_mode() {
+            meta.set_unix_mode(mode);
         }
+
+        Ok(RpStat::new(meta))
     }
 
     async fn read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::Reader)> {
@@ -358,4 +364,26 @@ impl Access for WebhdfsBackend {
         let l = WebhdfsLister::new(self.core.clone(), path);
         Ok((RpList::default(), oio::PageLister::new(l)))
     }
+    async fn create_symlink(
+        &self,
+        path: &str,
+        target: &str,
+        _: OpCreateSymlink,
+    ) -> Result<RpCreateSymlink> {
+        let resp = self.core.webhdfs_create_symlink(path, target).await?;
+
+        match resp.status() {
+            StatusCode::OK => Ok(RpCreateSymlink::default()),
+            _ => Err(parse_error(resp)),
+        }
+    }
+
+    async fn read_link(&self, path: &str, _: OpReadLink) -> Result<RpReadLink> {
+        let file_status = self.core.webhdfs_get_link_status(path).await?;
+
+        match (file_status.ty, file_status.symlink) {
+            (FileStatusType::Symlink, Some(target)) => Ok(RpReadLink::new(target)),
+            _ => Err(Error::new(ErrorKind::Unexpected, "path is not a symlink")),
+        }
+    }
 }
@@ -28,6 +28,8 @@ use serde::Deserialize;
 use tokio::sync::OnceCell;
 
 use super::error::parse_error;
+use super::message::FileStatus;
+use super::message::FileStatusesWrapper;
 use crate::raw::*;
 use crate::*;
 
@@ -367,6 +369,108 @@ impl WebhdfsCore {
         self.info.http_client().send(req).await
     }
 
+    pub async fn webhdfs_create_symlink(
+        &self,
+        path: &str,
+        target: &str,
+    ) -> Result<Response<Buffer>> {
+        let p = build_abs_path(&self.root, path);
+
+        let mut url = format!(
+            "{}/webhdfs/v1/{}?op=CREATESYMLINK&destination={}&createParent=true",
+            self.endpoint,
+            percent_encode_path(&p),
+            percent_encode_path(target),
+        );
+        if let Some(user) = &self.user_name {
+            url += format!("&user.name={user}").as_str();
+        }
+        if let Some(auth) = &self.auth {
+            url += format!("&{auth}").as_str();
+        }
+
+        let req = Request::put(&url)
+            .extension(Operation::CreateSymlink)
+            .body(Buffer::new())
+            .map_err(new_request_build_error)?;
+
+        self.info.http_client().send(req).await
+    }
+
+    /// Get the status of the given path without following symlinks.
+    ///
+    /// `GETFILESTATUS` always resolves symlinks, so we list the parent dir
+    /// and pick the entry instead.
+    pub async fn webhdfs_get_link_status(&self, path: &str) -> Result<FileStatus> {
+        let resp = self.webhdfs_list_status(get_parent(path)).await?;
+        if resp.status() != StatusCode::OK {
+            return Err(parse_error(resp));
+        }
+
+        let name = get_basename(path).trim_end_matches('/');
+        serde_json::from_reader::<_, FileStatusesWrapper>(resp.into_body().reader())
+            .map_err(new_json_deserialize_error)?
+            .file_statuses
+            .file_status
+            .into_iter()
+            .find(|status| status.path_suffix == name)
+            .ok_or_else(|| Error::new(ErrorKind::NotFound, "path not found in webhdfs"))
+    }
+
+    pub async fn webhdfs_set_permission(&self, path: &str, mode: u32) -> Result<Response<Buffer>> {
+        let p = build_abs_path(&self.root, path);
+
+        let mut url = format!(
+            "{}/webhdfs/v1/{}?op=SETPERMISSION&permission={:o}",
+            self.endpoint,
+            percent_encode_path(&p),
+            mode & 0o7777,
+        );
+        if let Some(user) = &self.user_name {
+            url += format!("&user.name={user}").as_str();
+        }
+        if let Some(auth) = &self.auth {
+            url += format!("&{auth}").as_str();
+        }
+
+        let req = Request::put(&url)
+            .extension(Operation::Write)
+            .body(Buffer::new())
+            .map_err(new_request_build_error)?;
+
+        self.info.http_client().send(req).await
+    }
+
+    /// Set the modification time of the given path, `accesstime=-1` keeps
+    /// the access time unchanged.
+    pub async fn webhdfs_set_times(
+        &self,
+        path: &str,
+        mtime: Timestamp,
+    ) -> Result<Response<Buffer>> {
+        let p = build_abs_path(&self.root, path);
+
+        let mut url = format!(
+            "{}/webhdfs/v1/{}?op=SETTIMES&modificationtime={}&accesstime=-1",
+            self.endpoint,
+            percent_encode_path(&p),
+            mtime.into_inner().as_millisecond(),
+        );
+        if let Some(user) = &self.user_name {
+            url += format!("&user.name={user}").as_str();
+        }
+        if let Some(auth) = &self.auth {
+            url += format!("&{auth}").as_str();
+        }
+
+        let req = Request::put(&url)
+            .extension(Operation::Write)
+            .body(Buffer::new())
+            .map_err(new_request_build_error)?;
+
+        self.info.http_client().send(req).await
+    }
+
     pub async fn webhdfs_delete(&self, path: &str) -> Result<Response<Buffer>> {
         let p = build_abs_path(&self.root, path);
         let mut url = format!(
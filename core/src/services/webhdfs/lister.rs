@@ -106,12 +106,17 @@ impl oio::PageList for WebhdfsLister {
                 format!("{}/{}", self.path, status.path_suffix)
             };
 
-            let meta = match status.ty {
+            let mut meta = match status.ty {
                 FileStatusType::Directory => Metadata::new(EntryMode::DIR),
                 FileStatusType::File => Metadata::new(EntryMode::FILE)
                     .with_content_length(status.length)
                     .with_last_modified(Timestamp::from_millisecond(status.modification_time)?),
+                FileStatusType::Symlink => Metadata::new(EntryMode::Symlink)
+                    .with_last_modified(Timestamp::from_millisecond(status.modification_time)?),
             };
+            if let Some(mode) = status.unix_mode() {
+                meta.set_unix_mode(mode);
+            }
 
             if meta.mode().is_file() {
                 path = path.trim_end_matches('/').to_string();
@@ -70,6 +70,19 @@ pub struct FileStatus {
     pub path_suffix: String,
     #[serde(rename = "type")]
     pub ty: FileStatusType,
+    /// The target of the symlink, only set when `ty` is `Symlink`.
+    pub symlink: Option<String>,
+    /// POSIX permission bits in octal, like `755`.
+    pub permission: Option<String>,
+}
+
+impl FileStatus {
+    /// Parse the octal permission into POSIX permission bits.
+    pub fn unix_mode(&self) -> Option<u32> {
+        self.permission
+            .as_deref()
+            .and_then(|v| u32::from_str_radix(v, 8).ok())
+    }
 }
 
 #[derive(Debug, Default, Deserialize, PartialEq, Eq)]
@@ -78,6 +91,7 @@ pub enum FileStatusType {
     Directory,
     #[default]
     File,
+    Symlink,
 }
 
 #[cfg(test)]
@@ -108,6 +122,35 @@ mod test {
         assert_eq!(status.file_status.modification_time, 1320173277227);
         assert_eq!(status.file_status.path_suffix, "");
         assert_eq!(status.file_status.ty, FileStatusType::Directory);
+        assert_eq!(status.file_status.unix_mode(), Some(0o777));
+    }
+
+    #[test]
+    fn test_symlink_file_status() {
+        let json = r#"
+{
+  "FileStatus":
+  {
+    "accessTime"      : 0,
+    "blockSize"       : 0,
+    "group"           : "supergroup",
+    "length"          : 0,
+    "modificationTime": 1320173277227,
+    "owner"           : "webuser",
+    "pathSuffix"      : "link",
+    "permission"      : "777",
+    "replication"     : 0,
+    "symlink"         : "/path/to/target",
+    "type"            : "SYMLINK"
+  }
+}
+"#;
+        let status: FileStatusWrapper = serde_json::from_str(json).expect("must success");
+        assert_eq!(status.file_status.ty, FileStatusType::Symlink);
+        assert_eq!(
+            status.file_status.symlink.as_deref(),
+            Some("/path/to/target")
+        );
     }
 
     #[tokio::test]
@@ -41,6 +41,26 @@ impl WebhdfsWriter {
     pub fn new(core: Arc<WebhdfsCore>, op: OpWrite, path: String) -> Self {
         WebhdfsWriter { core, op, path }
     }
+
+    /// Apply the permission bits and modification time requested by write.
+    ///
+    /// This must be called after all data has been written, otherwise the
+    /// modification time will be overwritten by following writes.
+    async fn set_attrs(&self) -> Result<()> {
+        if let Some(mode) = self.op.unix_mode() {
+            let resp = self.core.webhdfs_set_permission(&self.path, mode).await?;
+            if resp.status() != StatusCode::OK {
+                return Err(parse_error(resp));
+            }
+        }
+        if let Some(mtime) = self.op.mtime() {
+            let resp = self.core.webhdfs_set_times(&self.path, mtime).await?;
+            if resp.status() != StatusCode::OK {
+                return Err(parse_error(resp));
+            }
+        }
+        Ok(())
+    }
 }
 
 impl oio::BlockWrite for WebhdfsWriter {
@@ -52,7 +72,10 @@ impl oio::BlockWrite for WebhdfsWriter {
 
         let status = resp.status();
         match status {
-            StatusCode::CREATED | StatusCode::OK => Ok(Metadata::default()),
+            StatusCode::CREATED | StatusCode::OK => {
+                self.set_attrs().await?;
+                Ok(Metadata::default())
+            }
             _ => Err(parse_error(resp)),
         }
     }
@@ -118,7 +141,10 @@ impl oio::BlockWrite for WebhdfsWriter {
 
         let status = resp.status();
         match status {
-            StatusCode::OK => Ok(Metadata::default()),
+            StatusCode::OK => {
+                self.set_attrs().await?;
+                Ok(Metadata::default())
+            }
             _ => Err(parse_error(resp)),
         }
     }
@@ -170,7 +196,12 @@ impl oio::AppendWrite for WebhdfsWriter {
 
         let status = resp.status();
         match status {
-            StatusCode::OK => Ok(Metadata::default()),
+            StatusCode::OK => {
+                // There is no close hook for append, so attrs are applied
+                // after every append to keep them for the final state.
+                self.set_attrs().await?;
+                Ok(Metadata::default())
+            }
             _ => Err(parse_error(resp)),
         }
     }
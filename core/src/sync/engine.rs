@@ -0,0 +1,403 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::fmt::Formatter;
+use std::sync::Arc;
+
+use futures::StreamExt;
+use futures::TryStreamExt;
+
+use super::SyncAction;
+use super::SyncPlan;
+use super::plan::diff;
+use super::plan::is_comparable;
+use crate::*;
+
+type ProgressFn = Arc<dyn Fn(&SyncProgress) + Send + std::marker::Sync>;
+
+/// Progress of [`Sync::execute`], reported after every finished action.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct SyncProgress {
+    /// Number of actions in the plan.
+    pub total: usize,
+    /// Number of finished actions.
+    pub done: usize,
+    /// Total bytes to copy in the plan.
+    pub total_bytes: u64,
+    /// Bytes copied so far.
+    pub copied_bytes: u64,
+}
+
+/// Result of [`Sync::execute`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct SyncReport {
+    /// Number of copied entries.
+    pub copied: usize,
+    /// Number of deleted entries.
+    pub deleted: usize,
+    /// Number of entries that are already up to date.
+    pub unchanged: usize,
+    /// Bytes copied.
+    pub copied_bytes: u64,
+}
+
+/// Sync files under a dir from one operator to another.
+///
+/// # Notes
+///
+/// Both sides are walked recursively. A file is copied if it's missing in
+/// the target or if the target differs in size, checksum, etag (only within
+/// the same service) or is older than the source. Files that can't be
+/// compared beyond their size are always copied.
+///
+/// Files in the target that don't exist in the source are only deleted if
+/// [`Sync::with_delete`] is enabled. Dirs are not synced on their own.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use opendal::services;
+/// # use opendal::sync::Sync;
+/// # use opendal::Operator;
+/// # use opendal::Result;
+///
+/// # async fn test() -> Result<()> {
+/// let src = Operator::new(services::Memory::default())?.finish();
+/// let dst = Operator::new(services::Memory::default())?.finish();
+///
+/// let sync = Sync::new(src, dst)
+///     .with_paths("data/", "backup/data/")
+///     .with_delete(true)
+///     .with_concurrent(16);
+///
+/// // Inspect the plan without changing anything.
+/// let plan = sync.plan().await?;
+/// for action in plan.actions() {
+///     println!("{action:?}");
+/// }
+///
+/// let report = sync.execute(&plan).await?;
+/// println!("copied {} files", report.copied);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct Sync {
+    source: Operator,
+    target: Operator,
+    source_path: String,
+    target_path: String,
+    delete: bool,
+    concurrent: usize,
+    progress: Option<ProgressFn>,
+}
+
+impl Debug for Sync {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Sync")
+            .field("source", &self.source)
+            .field("target", &self.target)
+            .field("source_path", &self.source_path)
+            .field("target_path", &self.target_path)
+            .field("delete", &self.delete)
+            .field("concurrent", &self.concurrent)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Sync {
+    /// Create a new `Sync` from `source` to `target`.
+    ///
+    /// By default, the root of both operators is synced with 8 concurrent
+    /// actions and extraneous files are kept.
+    pub fn new(source: Operator, target: Operator) -> Self {
+        Self {
+            source,
+            target,
+            source_path: String::new(),
+            target_path: String::new(),
+            delete: false,
+            concurrent: 8,
+            progress: None,
+        }
+    }
+
+    /// Set the dirs to sync in the source and the target.
+    pub fn with_paths(mut self, source: &str, target: &str) -> Self {
+        self.source_path = normalize_dir(source);
+        self.target_path = normalize_dir(target);
+        self
+    }
+
+    /// Delete files in the target that don't exist in the source.
+    pub fn with_delete(mut self, delete: bool) -> Self {
+        self.delete = delete;
+        self
+    }
+
+    /// Set the max number of actions executed at the same time.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `concurrent` is 0.
+    pub fn with_concurrent(mut self, concurrent: usize) -> Self {
+        assert!(concurrent > 0, "concurrent must be greater than 0");
+        self.concurrent = concurrent;
+        self
+    }
+
+    /// Set the callback to report progress.
+    pub fn with_progress(
+        mut self,
+        f: impl Fn(&SyncProgress) + Send + std::marker::Sync + 'static,
+    ) -> Self {
+        self.progress = Some(Arc::new(f));
+        self
+    }
+
+    /// Compare both sides and build the plan without changing anything.
+    pub async fn plan(&self) -> Result<SyncPlan> {
+        let same_service = self.same_service();
+
+        let mut targets = HashMap::new();
+        let mut lister = self
+            .target
+            .lister_with(&self.target_path)
+            .recursive(true)
+            .await?;
+        while let Some(entry) = lister.try_next().await? {
+            let (path, meta) = entry.into_parts();
+            if meta.is_file() {
+                let rel = path[self.target_path.len()..].to_string();
+                targets.insert(rel, (path, meta));
+            }
+        }
+
+        let mut plan = SyncPlan::default();
+        let mut lister = self
+            .source
+            .lister_with(&self.source_path)
+            .recursive(true)
+            .await?;
+        while let Some(entry) = lister.try_next().await? {
+            let (path, meta) = entry.into_parts();
+            if !meta.is_file() {
+                continue;
+            }
+
+            let rel = &path[self.source_path.len()..];
+            let source_meta = complete(&self.source, &path, meta).await?;
+            let target_meta = match targets.remove(rel) {
+                Some((target, meta)) => Some(complete(&self.target, &target, meta).await?),
+                None => None,
+            };
+
+            match diff(&source_meta, target_meta.as_ref(), same_service) {
+                Some(reason) => plan.actions.push(SyncAction::Copy {
+                    target: format!("{}{rel}", self.target_path),
+                    source: path,
+                    size: source_meta.content_length(),
+                    reason,
+                }),
+                None => plan.unchanged += 1,
+            }
+        }
+
+        if self.delete {
+            let mut extraneous: Vec<_> = targets.into_values().map(|(path, _)| path).collect();
+            extraneous.sort();
+            plan.actions.extend(
+                extraneous
+                    .into_iter()
+                    .map(|target| SyncAction::Delete { target }),
+            );
+        }
+
+        Ok(plan)
+    }
+
+    /// Execute the given plan with bounded concurrency.
+    ///
+    /// Execution stops at the first failed action.
+    pub async fn execute(&self, plan: &SyncPlan) -> Result<SyncReport> {
+        let mut progress = SyncProgress {
+            total: plan.actions.len(),
+            total_bytes: plan.copy_bytes(),
+            ..Default::default()
+        };
+        let mut report = SyncReport {
+            unchanged: plan.unchanged,
+            ..Default::default()
+        };
+
+        let mut tasks = futures::stream::iter(&plan.actions)
+            .map(|action| self.apply(action))
+            .buffer_unordered(self.concurrent);
+        while let Some(action) = tasks.try_next().await? {
+            match action {
+                SyncAction::Copy { size, .. } => {
+                    report.copied += 1;
+                    report.copied_bytes += size;
+                    progress.copied_bytes += size;
+                }
+                SyncAction::Delete { .. } => report.deleted += 1,
+            }
+            progress.done += 1;
+            if let Some(f) = &self.progress {
+                f(&progress);
+            }
+        }
+
+        Ok(report)
+    }
+
+    /// Build the plan and execute it.
+    pub async fn run(&self) -> Result<SyncReport> {
+        let plan = self.plan().await?;
+        self.execute(&plan).await
+    }
+
+    /// Check if both operators point to the same place of the same service.
+    fn same_service(&self) -> bool {
+        let (src, dst) = (self.source.info(), self.target.info());
+        src.scheme() == dst.scheme() && src.name() == dst.name() && src.root() == dst.root()
+    }
+
+    async fn apply<'a>(&self, action: &'a SyncAction) -> Result<&'a SyncAction> {
+        match action {
+            SyncAction::Copy { source, target, .. } => {
+                self.source.copy_to(source, &self.target, target).await?
+            }
+            SyncAction::Delete { target } => self.target.delete(target).await?,
+        }
+        Ok(action)
+    }
+}
+
+/// Stat the entry if the listed metadata has nothing to compare.
+async fn complete(op: &Operator, path: &str, meta: Metadata) -> Result<Metadata> {
+    if is_comparable(&meta) {
+        Ok(meta)
+    } else {
+        op.stat(path).await
+    }
+}
+
+/// Normalize path into a dir without leading `/`, root is represented by `""`.
+fn normalize_dir(path: &str) -> String {
+    let path = path.trim_start_matches('/');
+    if path.is_empty() || path.ends_with('/') {
+        path.to_string()
+    } else {
+        format!("{path}/")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use super::*;
+    use crate::services::Memory;
+    use crate::sync::SyncReason;
+
+    fn memory() -> Operator {
+        Operator::new(Memory::default()).unwrap().finish()
+    }
+
+    #[tokio::test]
+    async fn test_sync_plan_and_execute() {
+        let (src, dst) = (memory(), memory());
+        src.write("data/a", "aaa").await.unwrap();
+        src.write("data/dir/b", "bbb").await.unwrap();
+        src.write("data/c", "ccc").await.unwrap();
+        src.write("other", "skipped").await.unwrap();
+        dst.write("backup/c", "ccc").await.unwrap();
+        dst.write("backup/dir/b", "b").await.unwrap();
+        dst.write("backup/extra", "x").await.unwrap();
+
+        let progress = Arc::new(Mutex::new(vec![]));
+        let sync = Sync::new(src.clone(), dst.clone())
+            .with_paths("data", "/backup/")
+            .with_delete(true)
+            .with_progress({
+                let progress = progress.clone();
+                move |v| progress.lock().unwrap().push(*v)
+            });
+
+        let plan = sync.plan().await.unwrap();
+        let mut actions = plan.actions().to_vec();
+        actions.sort_by_key(|v| format!("{v:?}"));
+        assert_eq!(
+            actions,
+            [
+                SyncAction::Copy {
+                    source: "data/a".to_string(),
+                    target: "backup/a".to_string(),
+                    size: 3,
+                    reason: SyncReason::Missing,
+                },
+                SyncAction::Copy {
+                    source: "data/dir/b".to_string(),
+                    target: "backup/dir/b".to_string(),
+                    size: 3,
+                    reason: SyncReason::SizeChanged,
+                },
+                SyncAction::Delete {
+                    target: "backup/extra".to_string(),
+                },
+            ]
+        );
+        assert_eq!(plan.unchanged(), 1);
+        assert_eq!(plan.copy_bytes(), 6);
+
+        // Plan is a dry run.
+        assert!(!dst.exists("backup/a").await.unwrap());
+
+        let report = sync.execute(&plan).await.unwrap();
+        assert_eq!((report.copied, report.deleted, report.unchanged), (2, 1, 1));
+        assert_eq!(report.copied_bytes, 6);
+        assert_eq!(dst.read("backup/a").await.unwrap().to_vec(), b"aaa");
+        assert_eq!(dst.read("backup/dir/b").await.unwrap().to_vec(), b"bbb");
+        assert!(!dst.exists("backup/extra").await.unwrap());
+        assert!(!dst.exists("backup/other").await.unwrap());
+
+        let progress = progress.lock().unwrap().clone();
+        assert_eq!(progress.len(), 3);
+        assert_eq!(progress.last().unwrap().done, 3);
+        assert_eq!(progress.last().unwrap().copied_bytes, 6);
+
+        // Everything is up to date now.
+        assert!(sync.plan().await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_sync_keeps_extraneous_by_default() {
+        let (src, dst) = (memory(), memory());
+        src.write("a", "a").await.unwrap();
+        dst.write("b", "b").await.unwrap();
+
+        let report = Sync::new(src, dst.clone()).run().await.unwrap();
+        assert_eq!((report.copied, report.deleted), (1, 0));
+        assert!(dst.exists("a").await.unwrap());
+        assert!(dst.exists("b").await.unwrap());
+    }
+}
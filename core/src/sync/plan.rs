@@ -0,0 +1,177 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use crate::*;
+
+/// Reason why an entry needs to be copied.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum SyncReason {
+    /// The entry doesn't exist in the target.
+    Missing,
+    /// The content length differs.
+    SizeChanged,
+    /// Both sides have checksums of the same algorithm and they differ.
+    ChecksumChanged,
+    /// Both sides are on the same service and their etags differ.
+    EtagChanged,
+    /// The source is modified after the target.
+    Newer,
+    /// The entries can't be compared, they are copied to be safe.
+    Unknown,
+}
+
+/// An action in a [`SyncPlan`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum SyncAction {
+    /// Copy `source` from the source operator to `target` in the target operator.
+    Copy {
+        /// Path in the source operator.
+        source: String,
+        /// Path in the target operator.
+        target: String,
+        /// Content length of the source.
+        size: u64,
+        /// Why the entry needs to be copied.
+        reason: SyncReason,
+    },
+    /// Delete `target` that doesn't exist in the source operator.
+    Delete {
+        /// Path in the target operator.
+        target: String,
+    },
+}
+
+/// Plan of a sync, returned by [`Sync::plan`](super::Sync::plan).
+///
+/// A plan can be inspected as a dry run and later passed to
+/// [`Sync::execute`](super::Sync::execute).
+#[derive(Debug, Clone, Default)]
+pub struct SyncPlan {
+    pub(super) actions: Vec<SyncAction>,
+    pub(super) unchanged: usize,
+}
+
+impl SyncPlan {
+    /// Get the actions of this plan.
+    pub fn actions(&self) -> &[SyncAction] {
+        &self.actions
+    }
+
+    /// Check if there is nothing to do.
+    pub fn is_empty(&self) -> bool {
+        self.actions.is_empty()
+    }
+
+    /// Get the number of entries that are already up to date.
+    pub fn unchanged(&self) -> usize {
+        self.unchanged
+    }
+
+    /// Get the total bytes to copy.
+    pub fn copy_bytes(&self) -> u64 {
+        self.actions
+            .iter()
+            .map(|v| match v {
+                SyncAction::Copy { size, .. } => *size,
+                SyncAction::Delete { .. } => 0,
+            })
+            .sum()
+    }
+}
+
+/// Compare the source with the target, returns `None` if the target is up to date.
+pub(super) fn diff(
+    source: &Metadata,
+    target: Option<&Metadata>,
+    same_service: bool,
+) -> Option<SyncReason> {
+    let Some(target) = target else {
+        return Some(SyncReason::Missing);
+    };
+
+    if source.content_length() != target.content_length() {
+        return Some(SyncReason::SizeChanged);
+    }
+
+    if let (Some(src), Some(dst)) = (source.checksum(), target.checksum()) {
+        if src.algorithm() == dst.algorithm() {
+            return (src != dst).then_some(SyncReason::ChecksumChanged);
+        }
+    }
+
+    // Etags are only comparable within the same service.
+    if same_service {
+        if let (Some(src), Some(dst)) = (source.etag(), target.etag()) {
+            return (src != dst).then_some(SyncReason::EtagChanged);
+        }
+    }
+
+    match (source.last_modified(), target.last_modified()) {
+        (Some(src), Some(dst)) => (src > dst).then_some(SyncReason::Newer),
+        _ => Some(SyncReason::Unknown),
+    }
+}
+
+/// Check if the metadata has anything to compare besides the size.
+pub(super) fn is_comparable(meta: &Metadata) -> bool {
+    meta.checksum().is_some() || meta.etag().is_some() || meta.last_modified().is_some()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::raw::Timestamp;
+
+    #[test]
+    fn test_diff() {
+        let ts = |v: &str| v.parse::<Timestamp>().unwrap();
+        let file = |size: u64, modified: &str| {
+            Metadata::new(EntryMode::FILE)
+                .with_content_length(size)
+                .with_last_modified(ts(modified))
+        };
+        let old = file(4, "2024-01-01T00:00:00Z");
+        let new = file(4, "2024-06-01T00:00:00Z");
+        let crc = |v: &str| Checksum::new(ChecksumAlgorithm::Crc32c, v);
+
+        assert_eq!(diff(&old, None, false), Some(SyncReason::Missing));
+        assert_eq!(
+            diff(&file(5, "2024-01-01T00:00:00Z"), Some(&old), false),
+            Some(SyncReason::SizeChanged)
+        );
+        assert_eq!(diff(&new, Some(&old), false), Some(SyncReason::Newer));
+        assert_eq!(diff(&old, Some(&new), false), None);
+
+        // Checksums win over timestamps.
+        let a = new.clone().with_checksum(crc("AAAAAA=="));
+        let b = old.clone().with_checksum(crc("AAAAAA=="));
+        assert_eq!(diff(&a, Some(&b), false), None);
+        let b = old.clone().with_checksum(crc("BBBBBB=="));
+        assert_eq!(diff(&a, Some(&b), false), Some(SyncReason::ChecksumChanged));
+
+        // Etags are ignored across services.
+        let a = old.clone().with_etag("\"a\"".to_string());
+        let b = new.clone().with_etag("\"b\"".to_string());
+        assert_eq!(diff(&a, Some(&b), false), None);
+        assert_eq!(diff(&a, Some(&b), true), Some(SyncReason::EtagChanged));
+
+        let bare = Metadata::new(EntryMode::FILE).with_content_length(4);
+        assert_eq!(diff(&bare, Some(&bare), false), Some(SyncReason::Unknown));
+    }
+}
@@ -110,6 +110,8 @@ pub struct Capability {
     pub write_can_empty: bool,
     /// Indicates if append operations are supported.
     pub write_can_append: bool,
+    /// Indicates if unfinished multipart uploads can be resumed from a checkpoint.
+    pub write_can_resume: bool,
     /// Indicates if Content-Type can be specified during write operations.
     pub write_with_content_type: bool,
     /// Indicates if Content-Disposition can be specified during write operations.
@@ -126,6 +128,12 @@ pub struct Capability {
     pub write_with_if_not_exists: bool,
     /// Indicates if custom user metadata can be attached during write operations.
     pub write_with_user_metadata: bool,
+    /// Indicates if write operations can set an expiry via `expires_in`.
+    pub write_with_ttl: bool,
+    /// Indicates if write operations can set POSIX permission bits via `unix_mode`.
+    pub write_with_unix_mode: bool,
+    /// Indicates if write operations can set the modification time via `mtime`.
+    pub write_with_mtime: bool,
     /// Maximum size supported for multipart uploads.
     /// For example, AWS S3 supports up to 5GiB per part in multipart uploads.
     pub write_multi_max_size: Option<usize>,
@@ -153,6 +161,15 @@ pub struct Capability {
 
     /// Indicates if rename operations are supported.
     pub rename: bool,
+    /// Indicates if rename operations support moving a whole directory.
+    pub rename_dir: bool,
+
+    /// Indicates if symbolic links are supported, including `create_symlink`,
+    /// `read_link` and stat without following links.
+    pub symlink: bool,
+
+    /// Indicates if unfinished multipart uploads can be listed and aborted.
+    pub pending_upload: bool,
 
     /// Indicates if list operations are supported.
     pub list: bool,
@@ -160,6 +177,9 @@ pub struct Capability {
     pub list_with_limit: bool,
     /// Indicates if list operations support continuation from a specific point.
     pub list_with_start_after: bool,
+    /// Indicates if list operations can be resumed from the page that was being
+    /// read, see [`Lister::continuation_token`](crate::Lister::continuation_token).
+    pub list_with_continuation: bool,
     /// Indicates if recursive listing is supported.
     pub list_with_recursive: bool,
     /// Indicates if versions listing is supported.
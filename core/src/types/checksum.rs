@@ -0,0 +1,152 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::str::FromStr;
+
+use crate::*;
+
+/// ChecksumAlgorithm represents the algorithm used to calculate a [`Checksum`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[non_exhaustive]
+pub enum ChecksumAlgorithm {
+    /// CRC32 (IEEE) checksum.
+    Crc32,
+    /// CRC32C (Castagnoli) checksum.
+    Crc32c,
+    /// CRC64NVME checksum.
+    Crc64Nvme,
+    /// MD5 digest.
+    Md5,
+    /// SHA-1 digest.
+    Sha1,
+    /// SHA-256 digest.
+    Sha256,
+}
+
+impl ChecksumAlgorithm {
+    /// Convert self into static str.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ChecksumAlgorithm::Crc32 => "crc32",
+            ChecksumAlgorithm::Crc32c => "crc32c",
+            ChecksumAlgorithm::Crc64Nvme => "crc64nvme",
+            ChecksumAlgorithm::Md5 => "md5",
+            ChecksumAlgorithm::Sha1 => "sha1",
+            ChecksumAlgorithm::Sha256 => "sha256",
+        }
+    }
+}
+
+impl Display for ChecksumAlgorithm {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl FromStr for ChecksumAlgorithm {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_lowercase().as_str() {
+            "crc32" => Ok(ChecksumAlgorithm::Crc32),
+            "crc32c" => Ok(ChecksumAlgorithm::Crc32c),
+            "crc64nvme" => Ok(ChecksumAlgorithm::Crc64Nvme),
+            "md5" => Ok(ChecksumAlgorithm::Md5),
+            "sha1" => Ok(ChecksumAlgorithm::Sha1),
+            "sha256" => Ok(ChecksumAlgorithm::Sha256),
+            _ => Err(Error::new(
+                ErrorKind::Unsupported,
+                "checksum algorithm is not supported",
+            )
+            .with_context("algorithm", s)),
+        }
+    }
+}
+
+/// Checksum is the checksum of an entry's content.
+///
+/// The value is the base64 encoded digest in big-endian, which is the format
+/// used by services like s3 and gcs.
+///
+/// Azblob is not supported: its `Content-MD5` is only computed by the service
+/// for some uploads and `x-ms-content-crc64` is not returned for committed
+/// block blobs, so azblob never fills [`Metadata::checksum`].
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct Checksum {
+    algorithm: ChecksumAlgorithm,
+    value: String,
+}
+
+impl Checksum {
+    /// Create a new checksum with given algorithm and base64 encoded value.
+    pub fn new(algorithm: ChecksumAlgorithm, value: impl Into<String>) -> Self {
+        Self {
+            algorithm,
+            value: value.into(),
+        }
+    }
+
+    /// Algorithm of this checksum.
+    pub fn algorithm(&self) -> ChecksumAlgorithm {
+        self.algorithm
+    }
+
+    /// Base64 encoded value of this checksum.
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+}
+
+impl Display for Checksum {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}:{}", self.algorithm, self.value)
+    }
+}
+
+impl FromStr for Checksum {
+    type Err = Error;
+
+    /// Parse checksum from the `{algorithm}:{value}` format.
+    fn from_str(s: &str) -> Result<Self> {
+        let Some((algorithm, value)) = s.split_once(':') else {
+            return Err(
+                Error::new(ErrorKind::Unexpected, "checksum is in invalid format")
+                    .with_context("checksum", s),
+            );
+        };
+        Ok(Checksum::new(algorithm.parse()?, value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_checksum_parse() {
+        let checksum = Checksum::new(ChecksumAlgorithm::Crc32c, "yZRlqg==");
+        assert_eq!(checksum.to_string(), "crc32c:yZRlqg==");
+        assert_eq!("crc32c:yZRlqg==".parse::<Checksum>().unwrap(), checksum);
+        assert_eq!(
+            "SHA256:abc".parse::<Checksum>().unwrap().algorithm(),
+            ChecksumAlgorithm::Sha256
+        );
+        assert!("yZRlqg==".parse::<Checksum>().is_err());
+        assert!("crc16:abc".parse::<Checksum>().is_err());
+    }
+}
@@ -86,6 +86,12 @@ pub enum ErrorKind {
     ///
     /// OpenDAL returns this error to indicate that the range of the read request is not satisfied.
     RangeNotSatisfied,
+    /// The checksum of the content is not match.
+    ///
+    /// OpenDAL returns this error when the checksum calculated from the data read
+    /// is different from the one recorded while writing, which usually means the
+    /// content is corrupted or truncated.
+    ChecksumMismatch,
 }
 
 impl ErrorKind {
@@ -124,6 +130,7 @@ impl From<ErrorKind> for &'static str {
             ErrorKind::IsSameFile => "IsSameFile",
             ErrorKind::ConditionNotMatch => "ConditionNotMatch",
             ErrorKind::RangeNotSatisfied => "RangeNotSatisfied",
+            ErrorKind::ChecksumMismatch => "ChecksumMismatch",
         }
     }
 }
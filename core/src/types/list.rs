@@ -15,12 +15,18 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use std::ops::Bound;
+use std::ops::RangeBounds;
 use std::pin::Pin;
 use std::task::Context;
 use std::task::Poll;
 use std::task::ready;
 
+use base64::Engine;
+use base64::prelude::BASE64_URL_SAFE_NO_PAD;
 use futures::Stream;
+use serde::Deserialize;
+use serde::Serialize;
 
 use crate::raw::*;
 use crate::*;
@@ -30,11 +36,18 @@ use crate::*;
 ///
 /// - Lister implements `Stream<Item = Result<Entry>>`.
 /// - Lister will return `None` if there is no more entries or error has been returned.
+/// - Lister can be resumed later via [`Lister::continuation_token`].
 pub struct Lister {
     lister: Option<oio::Lister>,
 
     fut: Option<BoxedStaticFuture<(oio::Lister, Result<Option<oio::Entry>>)>>,
     errored: bool,
+
+    continuation: Option<oio::ListContinuation>,
+    /// The position after the last returned entry.
+    token: ListToken,
+    /// Whether entries are returned in a stable order that can be resumed.
+    resumable: bool,
 }
 
 /// # Safety
@@ -44,16 +57,174 @@ unsafe impl Sync for Lister {}
 
 impl Lister {
     /// Create a new lister.
-    pub(crate) async fn create(acc: Accessor, path: &str, args: OpList) -> Result<Self> {
-        let (_, lister) = acc.list(path, args).await?;
+    ///
+    /// If glob is set, the longest literal prefix of the glob will be listed
+    /// instead of the given path.
+    pub(crate) async fn create(
+        acc: Accessor,
+        path: &str,
+        opts: options::ListOptions,
+    ) -> Result<Self> {
+        let mut opts = opts;
+        let token = match opts.continuation_token.take() {
+            Some(v) => ListToken::decode(&v)?,
+            None => ListToken::default(),
+        };
+
+        let mut path = path.to_string();
+        let mut glob = None;
+        let mut recursive = opts.recursive;
+        if let Some(pattern) = &opts.glob {
+            let base = if path == "/" { "" } else { path.as_str() };
+            let matcher = GlobMatcher::new(&format!("{}{pattern}", GlobMatcher::escape(base)))
+                .map_err(|err| err.with_operation(Operation::List))?;
+
+            let prefix = matcher.literal_prefix();
+            if prefix.len() > base.len() {
+                path = prefix;
+            }
+            let dir = match path.rfind('/') {
+                Some(idx) => &path[..=idx],
+                None => "",
+            };
+            recursive = recursive || matcher.matches_nested(dir);
+            glob = Some(matcher);
+        }
+
+        let filter = EntryFilter {
+            acc: acc.clone(),
+            glob: glob.clone(),
+            size_range: opts.size_range,
+            last_modified_range: opts.last_modified_range,
+            mode: opts.mode,
+        };
+
+        let concurrent = opts.concurrent;
+        let unordered = opts.unordered;
+
+        // Resume from the page cursor if the service supports it, or fallback
+        // to start after the last returned key.
+        //
+        // Page cursor is not available while listing partitions concurrently.
+        let cap = acc.info().full_capability();
+        let mut continuation = None;
+        let mut skip_until = None;
+        if cap.list_with_continuation
+            && concurrent <= 1
+            && (token.page.is_some() || token.key.is_none())
+        {
+            let c = oio::ListContinuation::default();
+            c.set(token.page.clone());
+            continuation = Some(c);
+        } else if let Some(key) = &token.key {
+            if cap.list_with_start_after {
+                opts.start_after = Some(key.clone());
+            } else {
+                skip_until = Some(key.clone());
+            }
+        }
+
+        let mut args = OpList::from(opts).with_recursive(recursive);
+        if let Some(glob) = glob {
+            args = args.with_glob(glob);
+        }
+        if let Some(continuation) = &continuation {
+            args = args.with_continuation(continuation.clone());
+        }
+        let partitioned = concurrent > 1 && args.recursive() && args.start_after().is_none();
+        let resumable = !(partitioned && unordered);
+        if !resumable && token != ListToken::default() {
+            return Err(Error::new(
+                ErrorKind::ConfigInvalid,
+                "continuation token can't be used to resume an unordered listing",
+            )
+            .with_operation(Operation::List)
+            .with_context("path", &path));
+        }
+        let lister: oio::Lister = if partitioned {
+            Box::new(oio::PartitionLister::new(
+                acc.clone(),
+                &path,
+                args,
+                concurrent,
+                unordered,
+            ))
+        } else {
+            acc.list(&path, args).await?.1
+        };
+        let lister: oio::Lister = match skip_until {
+            Some(key) => Box::new(SkipLister {
+                inner: lister,
+                key: Some(key),
+            }),
+            None => lister,
+        };
+        let lister: oio::Lister = if filter.is_empty() {
+            lister
+        } else {
+            Box::new(FilterLister {
+                inner: lister,
+                filter,
+            })
+        };
 
         Ok(Self {
             lister: Some(lister),
 
             fut: None,
             errored: false,
+
+            continuation,
+            token,
+            resumable,
         })
     }
+
+    /// Get the continuation token of this lister.
+    ///
+    /// The token is an opaque string that can be persisted and passed to
+    /// [`FutureLister::continuation_token`](crate::operator_futures::FutureLister::continuation_token)
+    /// to resume listing after the last returned entry, for example, after a crash.
+    ///
+    /// Services with [`Capability::list_with_continuation`] resume from the page that was
+    /// being read, services with [`Capability::list_with_start_after`] resume after the last
+    /// returned key. Other services will list from the beginning and skip entries until
+    /// the last returned key, which requires the entries to be returned in a stable order.
+    /// Resuming on these services returns [`ErrorKind::ConditionNotMatch`] if the last
+    /// returned key has been removed in the meantime.
+    ///
+    /// Returns `None` if the entries are returned in an unstable order, which happens while
+    /// listing partitions concurrently with [`options::ListOptions::unordered`]. Partitions
+    /// merged in key order can still be resumed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use futures::TryStreamExt;
+    /// use opendal::Operator;
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// let mut lister = op.lister_with("path/to/dir/").recursive(true).await?;
+    /// if let Some(entry) = lister.try_next().await? {
+    ///     println!("Handling {}", entry.path())
+    /// }
+    /// let token = lister.continuation_token().expect("ordered listing is resumable");
+    ///
+    /// // Resume the listing later.
+    /// let mut lister = op
+    ///     .lister_with("path/to/dir/")
+    ///     .recursive(true)
+    ///     .continuation_token(&token)
+    ///     .await?;
+    /// while let Some(entry) = lister.try_next().await? {
+    ///     println!("Handling {}", entry.path())
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn continuation_token(&self) -> Option<String> {
+        self.resumable.then(|| self.token.encode())
+    }
 }
 
 impl Stream for Lister {
@@ -79,7 +250,15 @@ impl Stream for Lister {
             self.fut = None;
 
             return match entry {
-                Ok(Some(oe)) => Poll::Ready(Some(Ok(oe.into_entry()))),
+                Ok(Some(oe)) => {
+                    // Take a snapshot here since the underlying lister may read ahead.
+                    let page = self.continuation.as_ref().and_then(|c| c.get());
+                    self.token = ListToken {
+                        key: Some(oe.path().to_string()),
+                        page,
+                    };
+                    Poll::Ready(Some(Ok(oe.into_entry())))
+                }
                 Ok(None) => {
                     self.lister = None;
                     Poll::Ready(None)
@@ -95,6 +274,156 @@ impl Stream for Lister {
     }
 }
 
+/// ListToken is the decoded continuation token.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+struct ListToken {
+    /// The path of the last returned entry.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    key: Option<String>,
+    /// The cursor of underlying page lister.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    page: Option<oio::PageCursor>,
+}
+
+impl ListToken {
+    fn encode(&self) -> String {
+        let bs = serde_json::to_vec(self).expect("list token must be serializable");
+        BASE64_URL_SAFE_NO_PAD.encode(bs)
+    }
+
+    fn decode(token: &str) -> Result<Self> {
+        BASE64_URL_SAFE_NO_PAD
+            .decode(token)
+            .ok()
+            .and_then(|bs| serde_json::from_slice(&bs).ok())
+            .ok_or_else(|| {
+                Error::new(ErrorKind::ConfigInvalid, "continuation token is invalid")
+                    .with_operation(Operation::List)
+                    .with_context("token", token)
+            })
+    }
+}
+
+/// SkipLister skips entries until the given key has been returned.
+///
+/// Services without `start_after` support don't promise any order, so we can't
+/// tell where the key would have been if it has been removed. Return an error
+/// instead of silently dropping all entries in this case.
+struct SkipLister {
+    inner: oio::Lister,
+    key: Option<String>,
+}
+
+impl oio::List for SkipLister {
+    async fn next(&mut self) -> Result<Option<oio::Entry>> {
+        while let Some(entry) = self.inner.next().await? {
+            match &self.key {
+                Some(key) if entry.path() == key => self.key = None,
+                Some(_) => {}
+                None => return Ok(Some(entry)),
+            }
+        }
+        match self.key.take() {
+            Some(key) => Err(Error::new(
+                ErrorKind::ConditionNotMatch,
+                "the last key of continuation token is not found, it may have been removed",
+            )
+            .with_operation(Operation::List)
+            .with_context("key", key)),
+            None => Ok(None),
+        }
+    }
+}
+
+/// EntryFilter holds the filters set in [`options::ListOptions`].
+struct EntryFilter {
+    acc: Accessor,
+    glob: Option<GlobMatcher>,
+    size_range: Option<(Bound<u64>, Bound<u64>)>,
+    last_modified_range: Option<(Bound<Timestamp>, Bound<Timestamp>)>,
+    mode: Option<EntryMode>,
+}
+
+impl EntryFilter {
+    fn is_empty(&self) -> bool {
+        self.glob.is_none()
+            && self.size_range.is_none()
+            && self.last_modified_range.is_none()
+            && self.mode.is_none()
+    }
+
+    /// Check the filters that don't need metadata.
+    fn matches_path(&self, entry: &oio::Entry) -> bool {
+        self.glob.as_ref().is_none_or(|g| g.matches(entry.path()))
+            && self.mode.is_none_or(|mode| entry.mode() == mode)
+    }
+
+    /// Check the filters on metadata.
+    ///
+    /// Returns `None` if the metadata returned by list is not enough.
+    fn matches_metadata(&self, meta: &Metadata) -> Option<bool> {
+        if let Some(range) = &self.size_range {
+            if meta.mode().is_dir() {
+                return Some(false);
+            }
+            if !meta.has_content_length() {
+                return None;
+            }
+            if !range.contains(&meta.content_length()) {
+                return Some(false);
+            }
+        }
+        if let Some(range) = &self.last_modified_range {
+            match meta.last_modified() {
+                Some(v) if !range.contains(&v) => return Some(false),
+                Some(_) => {}
+                // Dirs usually don't have last modified, no need to stat them.
+                None if meta.mode().is_dir() => return Some(false),
+                None => return None,
+            }
+        }
+        Some(true)
+    }
+}
+
+/// FilterLister drops entries that don't match the [`EntryFilter`].
+///
+/// Entries without the required metadata will be stat-ed.
+struct FilterLister {
+    inner: oio::Lister,
+    filter: EntryFilter,
+}
+
+impl oio::List for FilterLister {
+    async fn next(&mut self) -> Result<Option<oio::Entry>> {
+        while let Some(entry) = self.inner.next().await? {
+            if !self.filter.matches_path(&entry) {
+                continue;
+            }
+            match self.filter.matches_metadata(entry.metadata()) {
+                Some(true) => return Ok(Some(entry)),
+                Some(false) => continue,
+                None => {
+                    let mut op = OpStat::new();
+                    if let Some(version) = entry.metadata().version() {
+                        op = op.with_version(version);
+                    }
+                    let meta = self
+                        .filter
+                        .acc
+                        .stat(entry.path(), op)
+                        .await?
+                        .into_metadata();
+                    if self.filter.matches_metadata(&meta) == Some(true) {
+                        return Ok(Some(oio::Entry::new(entry.path(), meta)));
+                    }
+                }
+            }
+        }
+        Ok(None)
+    }
+}
+
 #[cfg(test)]
 #[cfg(feature = "services-azblob")]
 mod tests {
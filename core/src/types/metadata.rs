@@ -63,6 +63,18 @@ pub struct Metadata {
     version: Option<String>,
 
     user_metadata: Option<HashMap<String, String>>,
+    extended: Option<Box<ExtendedMetadata>>,
+}
+
+/// Metadata that is rarely set, boxed to keep [`Metadata`] small since every
+/// listed entry carries one.
+#[derive(Debug, Clone, Eq, PartialEq, Default)]
+struct ExtendedMetadata {
+    checksum: Option<Checksum>,
+    expires_at: Option<Timestamp>,
+    unix_mode: Option<u32>,
+    uid: Option<u32>,
+    gid: Option<u32>,
 }
 
 impl Metadata {
@@ -85,9 +97,14 @@ impl Metadata {
             content_disposition: None,
             version: None,
             user_metadata: None,
+            extended: None,
         }
     }
 
+    fn extended_mut(&mut self) -> &mut ExtendedMetadata {
+        self.extended.get_or_insert_with(Default::default)
+    }
+
     /// mode represent this entry's mode.
     pub fn mode(&self) -> EntryMode {
         self.mode
@@ -115,6 +132,11 @@ impl Metadata {
         matches!(self.mode, EntryMode::DIR)
     }
 
+    /// Returns `true` if this metadata is for a symbolic link.
+    pub fn is_symlink(&self) -> bool {
+        matches!(self.mode, EntryMode::Symlink)
+    }
+
     /// Checks whether the metadata corresponds to the most recent version of the file.
     ///
     /// This function is particularly useful when working with versioned objects,
@@ -222,6 +244,18 @@ impl Metadata {
         self.content_length.unwrap_or_default()
     }
 
+    /// Returns `true` if the content length is set by the storage services.
+    pub(crate) fn has_content_length(&self) -> bool {
+        self.content_length.is_some()
+    }
+
+    /// Remove the content length of this entry, so that it's known as not set.
+    #[allow(dead_code)]
+    pub(crate) fn remove_content_length(&mut self) -> &mut Self {
+        self.content_length = None;
+        self
+    }
+
     /// Set content length of this entry.
     pub fn set_content_length(&mut self, v: u64) -> &mut Self {
         self.content_length = Some(v);
@@ -256,6 +290,27 @@ impl Metadata {
         self
     }
 
+    /// Checksum of this entry's content.
+    ///
+    /// Checksum is filled from the native checksum headers of services like s3
+    /// and gcs, and OpenDAL will not calculate it. Headers that are not always
+    /// computed by the service, like the `Content-MD5` of azblob, are not used.
+    pub fn checksum(&self) -> Option<&Checksum> {
+        self.extended.as_ref().and_then(|v| v.checksum.as_ref())
+    }
+
+    /// Set checksum of this entry.
+    pub fn set_checksum(&mut self, v: Checksum) -> &mut Self {
+        self.extended_mut().checksum = Some(v);
+        self
+    }
+
+    /// Set checksum of this entry.
+    pub fn with_checksum(mut self, v: Checksum) -> Self {
+        self.extended_mut().checksum = Some(v);
+        self
+    }
+
     /// Content Type of this entry.
     ///
     /// Content Type is defined by [RFC 9110](https://httpwg.org/specs/rfc9110.html#field.content-type).
@@ -334,6 +389,26 @@ impl Metadata {
         self
     }
 
+    /// The time after which this entry expires and is removed by the service.
+    ///
+    /// `None` means the entry doesn't expire or the service doesn't expose
+    /// its expiry.
+    pub fn expires_at(&self) -> Option<Timestamp> {
+        self.extended.as_ref().and_then(|v| v.expires_at)
+    }
+
+    /// Set the expiry of this entry.
+    pub fn set_expires_at(&mut self, v: Timestamp) -> &mut Self {
+        self.extended_mut().expires_at = Some(v);
+        self
+    }
+
+    /// Set the expiry of this entry.
+    pub fn with_expires_at(mut self, v: Timestamp) -> Self {
+        self.extended_mut().expires_at = Some(v);
+        self
+    }
+
     /// ETag of this entry.
     ///
     /// `ETag` is defined by [RFC 7232](https://httpwg.org/specs/rfc7232.html#header.etag)
@@ -417,6 +492,68 @@ impl Metadata {
         self
     }
 
+    /// POSIX permission bits of this entry, like `0o644`.
+    ///
+    /// Only the permission bits (including setuid, setgid and sticky) are
+    /// returned, the file type bits are exposed via [`Metadata::mode`].
+    /// The modification time of the entry is exposed via
+    /// [`Metadata::last_modified`].
+    ///
+    /// `None` means the service doesn't expose POSIX permissions.
+    pub fn unix_mode(&self) -> Option<u32> {
+        self.extended.as_ref().and_then(|v| v.unix_mode)
+    }
+
+    /// Set POSIX permission bits of this entry.
+    pub fn set_unix_mode(&mut self, v: u32) -> &mut Self {
+        self.extended_mut().unix_mode = Some(v);
+        self
+    }
+
+    /// Set POSIX permission bits of this entry.
+    pub fn with_unix_mode(mut self, v: u32) -> Self {
+        self.extended_mut().unix_mode = Some(v);
+        self
+    }
+
+    /// Numeric user id of the owner of this entry.
+    ///
+    /// `None` means the service doesn't expose numeric ownership.
+    pub fn uid(&self) -> Option<u32> {
+        self.extended.as_ref().and_then(|v| v.uid)
+    }
+
+    /// Set the owner's user id of this entry.
+    pub fn set_uid(&mut self, v: u32) -> &mut Self {
+        self.extended_mut().uid = Some(v);
+        self
+    }
+
+    /// Set the owner's user id of this entry.
+    pub fn with_uid(mut self, v: u32) -> Self {
+        self.extended_mut().uid = Some(v);
+        self
+    }
+
+    /// Numeric group id of the owner of this entry.
+    ///
+    /// `None` means the service doesn't expose numeric ownership.
+    pub fn gid(&self) -> Option<u32> {
+        self.extended.as_ref().and_then(|v| v.gid)
+    }
+
+    /// Set the owner's group id of this entry.
+    pub fn set_gid(&mut self, v: u32) -> &mut Self {
+        self.extended_mut().gid = Some(v);
+        self
+    }
+
+    /// Set the owner's group id of this entry.
+    pub fn with_gid(mut self, v: u32) -> Self {
+        self.extended_mut().gid = Some(v);
+        self
+    }
+
     /// User defined metadata of this entry
     ///
     /// The prefix of the user defined metadata key(for example: in oss, it's x-oss-meta-)
@@ -27,6 +27,10 @@ pub use entry::Entry;
 mod metadata;
 pub use metadata::Metadata;
 
+mod checksum;
+pub use checksum::Checksum;
+pub use checksum::ChecksumAlgorithm;
+
 mod read;
 pub use read::*;
 
@@ -25,6 +25,10 @@ pub enum EntryMode {
     FILE,
     /// DIR means the path can be listed.
     DIR,
+    /// Symlink means the path is a symbolic link to another path.
+    ///
+    /// Only returned by services that support [`Capability::symlink`](crate::Capability::symlink).
+    Symlink,
     /// Unknown means we don't know what we can do on this path.
     #[default]
     Unknown,
@@ -41,6 +45,11 @@ impl EntryMode {
         self == EntryMode::DIR
     }
 
+    /// Check if this mode is Symlink.
+    pub fn is_symlink(self) -> bool {
+        self == EntryMode::Symlink
+    }
+
     /// Create entry mode from given path.
     #[allow(dead_code)]
     pub(crate) fn from_path(path: &str) -> Self {
@@ -57,6 +66,7 @@ impl Display for EntryMode {
         match self {
             EntryMode::FILE => write!(f, "file"),
             EntryMode::DIR => write!(f, "dir"),
+            EntryMode::Symlink => write!(f, "symlink"),
             EntryMode::Unknown => write!(f, "unknown"),
         }
     }
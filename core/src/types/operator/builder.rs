@@ -287,6 +287,8 @@ impl Operator {
             services::REDIS_SCHEME => Self::from_iter::<services::Redis>(iter)?.finish(),
             #[cfg(feature = "services-rocksdb")]
             services::ROCKSDB_SCHEME => Self::from_iter::<services::Rocksdb>(iter)?.finish(),
+            #[cfg(feature = "services-route")]
+            services::ROUTE_SCHEME => Self::from_iter::<services::Route>(iter)?.finish(),
             #[cfg(feature = "services-s3")]
             services::S3_SCHEME => Self::from_iter::<services::S3>(iter)?.finish(),
             #[cfg(feature = "services-seafile")]
@@ -16,6 +16,7 @@
 // under the License.
 
 use std::future::Future;
+use std::sync::Arc;
 use std::time::Duration;
 
 use futures::Stream;
@@ -472,6 +473,203 @@ impl Operator {
         Ok(())
     }
 
+    /// Create a symbolic link at `path` which points to `target`.
+    ///
+    /// # Notes
+    ///
+    /// - `target` is stored as is without normalization, so relative targets are
+    ///   resolved against the directory of `path` by the underlying storage.
+    /// - Creating a symlink on an existing path will fail.
+    ///
+    /// # Capability
+    ///
+    /// Requires [`Capability::symlink`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use opendal::Result;
+    /// # use opendal::Operator;
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// op.create_symlink("path/to/link", "file").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn create_symlink(&self, path: &str, target: &str) -> Result<()> {
+        let path = normalize_path(path);
+
+        if !validate_path(&path, EntryMode::Symlink) {
+            return Err(Error::new(
+                ErrorKind::IsADirectory,
+                "the path trying to create a symlink at should not end with `/`",
+            )
+            .with_operation("create_symlink")
+            .with_context("service", self.inner().info().scheme())
+            .with_context("path", &path));
+        }
+
+        self.inner()
+            .create_symlink(&path, target, OpCreateSymlink::new())
+            .await?;
+
+        Ok(())
+    }
+
+    /// Read the target of the symbolic link at `path`.
+    ///
+    /// The target is returned exactly as it was stored.
+    ///
+    /// # Capability
+    ///
+    /// Requires [`Capability::symlink`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use opendal::Result;
+    /// # use opendal::Operator;
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// let target = op.read_link("path/to/link").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn read_link(&self, path: &str) -> Result<String> {
+        let path = normalize_path(path);
+
+        let rp = self.inner().read_link(&path, OpReadLink::new()).await?;
+
+        Ok(rp.into_target())
+    }
+
+    /// List unfinished multipart uploads whose path starts with `prefix`.
+    ///
+    /// # Notes
+    ///
+    /// Writers that are neither closed nor aborted leave unfinished uploads behind.
+    /// Their parts are still stored by the service and could cost money until they
+    /// are aborted by [`Operator::abort_pending_upload`].
+    ///
+    /// This function fetches all pages before returning, use
+    /// [`Operator::list_pending_uploads_page`] to list page by page.
+    ///
+    /// # Capability
+    ///
+    /// Requires [`Capability::pending_upload`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use opendal::Result;
+    /// # use opendal::Operator;
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// for upload in op.list_pending_uploads("path/to/dir/").await? {
+    ///     println!("{} is uploading as {}", upload.path(), upload.upload_id());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn list_pending_uploads(&self, prefix: &str) -> Result<Vec<PendingUpload>> {
+        let mut uploads = Vec::new();
+        let mut token = None;
+
+        loop {
+            let (page, next) = self
+                .list_pending_uploads_page(prefix, token.as_deref())
+                .await?;
+            uploads.extend(page);
+
+            match next {
+                Some(next) => token = Some(next),
+                None => return Ok(uploads),
+            }
+        }
+    }
+
+    /// List one page of unfinished multipart uploads whose path starts with `prefix`.
+    ///
+    /// Returns the uploads of this page with an opaque token of the next page, pass it
+    /// back as `page_token` to continue listing. `None` means this is the last page.
+    ///
+    /// # Capability
+    ///
+    /// Requires [`Capability::pending_upload`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use opendal::Result;
+    /// # use opendal::Operator;
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// let mut token = None;
+    /// loop {
+    ///     let (uploads, next) = op
+    ///         .list_pending_uploads_page("path/to/dir/", token.as_deref())
+    ///         .await?;
+    ///     for upload in uploads {
+    ///         println!("{} is uploading as {}", upload.path(), upload.upload_id());
+    ///     }
+    ///     match next {
+    ///         Some(next) => token = Some(next),
+    ///         None => break,
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn list_pending_uploads_page(
+        &self,
+        prefix: &str,
+        page_token: Option<&str>,
+    ) -> Result<(Vec<PendingUpload>, Option<String>)> {
+        let prefix = normalize_path(prefix);
+
+        let mut args = OpListPendingUploads::new();
+        if let Some(token) = page_token {
+            args = args.with_page_token(token);
+        }
+        let rp = self.inner().list_pending_uploads(&prefix, args).await?;
+
+        Ok(rp.into_page())
+    }
+
+    /// Abort an unfinished multipart upload and purge all its uploaded parts.
+    ///
+    /// # Capability
+    ///
+    /// Requires [`Capability::pending_upload`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use opendal::Result;
+    /// # use opendal::Operator;
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// for upload in op.list_pending_uploads("path/to/dir/").await? {
+    ///     op.abort_pending_upload(upload.path(), upload.upload_id())
+    ///         .await?;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn abort_pending_upload(&self, path: &str, upload_id: &str) -> Result<()> {
+        let path = normalize_path(path);
+
+        if !validate_path(&path, EntryMode::FILE) {
+            return Err(
+                Error::new(ErrorKind::IsADirectory, "upload path is a directory")
+                    .with_operation("abort_pending_upload")
+                    .with_context("service", self.inner().info().scheme())
+                    .with_context("path", &path),
+            );
+        }
+
+        self.inner()
+            .abort_pending_upload(&path, upload_id, OpAbortPendingUpload::new())
+            .await?;
+
+        Ok(())
+    }
+
     /// Read the entire file into bytes from given path.
     ///
     /// # Notes
@@ -1007,12 +1205,47 @@ impl Operator {
             );
         }
 
-        let (args, opts) = opts.into();
+        let (args, opts): (OpWrite, OpWriter) = opts.into();
+        let args = args.with_checkpoint(oio::WriteCheckpoint::default());
         let context = WriteContext::new(acc, path, args, opts);
         let w = Writer::new(context).await?;
         Ok(w)
     }
 
+    /// Resume an unfinished multipart upload of given path from the checkpoint.
+    ///
+    /// This function is a shortcut of `writer_with(path).resume(checkpoint)`.
+    ///
+    /// # Notes
+    ///
+    /// - The checkpoint can be exported by [`Writer::checkpoint`] and persisted via serde.
+    /// - Parts in the checkpoint won't be uploaded again, users should continue writing
+    ///   the data starting from [`UploadCheckpoint::bytes_committed`].
+    /// - Services that don't support [`Capability::write_can_resume`] will return
+    ///   [`ErrorKind::Unsupported`] error.
+    /// - Layers that transform the content while writing, like encryption, compression
+    ///   and checksum, don't support resume since their state can't be restored.
+    /// - GCS resumes XML multipart uploads instead of resumable upload sessions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use opendal::Result;
+    /// # use opendal::Operator;
+    /// use opendal::UploadCheckpoint;
+    ///
+    /// # async fn test(op: Operator, data: Vec<u8>, checkpoint: UploadCheckpoint) -> Result<()> {
+    /// let offset = checkpoint.bytes_committed as usize;
+    /// let mut w = op.resume_writer("path/to/file", checkpoint).await?;
+    /// w.write(data[offset..].to_vec()).await?;
+    /// w.close().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn resume_writer(&self, path: &str, checkpoint: UploadCheckpoint) -> Result<Writer> {
+        self.writer_with(path).resume(checkpoint).await
+    }
+
     /// Copy a file from `from` to `to`.
     ///
     /// # Notes
@@ -1192,12 +1425,209 @@ impl Operator {
         acc.copy(&from, &to, op).await.map(|_| ())
     }
 
-    /// Rename a file from `from` to `to`.
+    /// Copy a file from `from` in this operator to `to` in `dst`.
     ///
     /// # Notes
     ///
     /// - `from` and `to` must be a file.
     /// - `to` will be overwritten if it exists.
+    /// - If both operators point to the same service and the service supports
+    ///   `copy`, the server side copy is used.
+    /// - Otherwise, the content is streamed from a [`Reader`] into a [`Writer`].
+    ///   `content_type`, `cache_control`, `content_disposition` and `user_metadata`
+    ///   are preserved if `dst` supports writing them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use opendal::Result;
+    /// # use opendal::Operator;
+    ///
+    /// # async fn test(op: Operator, dst: Operator) -> Result<()> {
+    /// op.copy_to("path/to/file", &dst, "path/to/file2").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn copy_to(&self, from: &str, dst: &Operator, to: &str) -> Result<()> {
+        self.copy_to_options(from, dst, to, options::CopyToOptions::default())
+            .await
+    }
+
+    /// Copy a file from `from` in this operator to `to` in `dst` with additional options.
+    ///
+    /// # Options
+    ///
+    /// Visit [`options::CopyToOptions`] for all available options.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use opendal::Result;
+    /// # use opendal::Operator;
+    ///
+    /// # async fn test(op: Operator, dst: Operator) -> Result<()> {
+    /// op.copy_to_with("path/to/file", &dst, "path/to/file2")
+    ///     .chunk(8 * 1024 * 1024)
+    ///     .concurrent(4)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn copy_to_with(
+        &self,
+        from: &str,
+        dst: &Operator,
+        to: &str,
+    ) -> FutureCopyTo<impl Future<Output = Result<()>>> {
+        let from = normalize_path(from);
+        let to = normalize_path(to);
+
+        OperatorFuture::new(
+            self.inner().clone(),
+            from,
+            (options::CopyToOptions::default(), dst.clone(), to),
+            Self::copy_to_inner,
+        )
+    }
+
+    /// Copy a file from `from` in this operator to `to` in `dst` with additional options.
+    ///
+    /// # Options
+    ///
+    /// Check [`options::CopyToOptions`] for all available options.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use opendal::Result;
+    /// # use opendal::Operator;
+    /// # use opendal::options::CopyToOptions;
+    ///
+    /// # async fn test(op: Operator, dst: Operator) -> Result<()> {
+    /// let mut opts = CopyToOptions::default();
+    /// opts.concurrent = 4;
+    /// op.copy_to_options("path/to/file", &dst, "path/to/file2", opts)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn copy_to_options(
+        &self,
+        from: &str,
+        dst: &Operator,
+        to: &str,
+        opts: impl Into<options::CopyToOptions>,
+    ) -> Result<()> {
+        let from = normalize_path(from);
+        let to = normalize_path(to);
+        let opts = opts.into();
+
+        Self::copy_to_inner(self.inner().clone(), from, (opts, dst.clone(), to)).await
+    }
+
+    async fn copy_to_inner(
+        acc: Accessor,
+        from: String,
+        (opts, dst, to): (options::CopyToOptions, Operator, String),
+    ) -> Result<()> {
+        if !validate_path(&from, EntryMode::FILE) {
+            return Err(
+                Error::new(ErrorKind::IsADirectory, "from path is a directory")
+                    .with_operation("Operator::copy_to")
+                    .with_context("service", acc.info().scheme())
+                    .with_context("from", from),
+            );
+        }
+
+        if !validate_path(&to, EntryMode::FILE) {
+            return Err(
+                Error::new(ErrorKind::IsADirectory, "to path is a directory")
+                    .with_operation("Operator::copy_to")
+                    .with_context("service", dst.info().scheme())
+                    .with_context("to", to),
+            );
+        }
+
+        // Operators with the same scheme, name and root could still point to
+        // different storages, only trust the native copy if they share the accessor.
+        let same_service = Arc::ptr_eq(&acc, dst.inner());
+        let (src_info, dst_info) = (acc.info(), dst.inner().info());
+        if same_service && from == to {
+            return Err(
+                Error::new(ErrorKind::IsSameFile, "from and to paths are same")
+                    .with_operation("Operator::copy_to")
+                    .with_context("service", acc.info().scheme())
+                    .with_context("from", &from)
+                    .with_context("to", &to),
+            );
+        }
+        if same_service && src_info.full_capability().copy {
+            return acc.copy(&from, &to, OpCopy::new()).await.map(|_| ());
+        }
+
+        let src = Operator::from_inner(acc);
+        let meta = src.stat(&from).await?;
+
+        let cap = dst_info.full_capability();
+        let pick = |supported: bool, v: Option<&str>| v.filter(|_| supported).map(String::from);
+        let write_opts = options::WriteOptions {
+            content_type: pick(cap.write_with_content_type, meta.content_type()),
+            cache_control: pick(cap.write_with_cache_control, meta.cache_control()),
+            content_disposition: pick(
+                cap.write_with_content_disposition,
+                meta.content_disposition(),
+            ),
+            user_metadata: meta
+                .user_metadata()
+                .filter(|_| cap.write_with_user_metadata)
+                .cloned(),
+            chunk: opts.chunk,
+            concurrent: opts.concurrent,
+            ..Default::default()
+        };
+        let read_opts = options::ReaderOptions {
+            // Make sure we read the same content we just stat.
+            if_match: meta
+                .etag()
+                .filter(|_| src_info.full_capability().read_with_if_match)
+                .map(String::from),
+            chunk: opts.chunk,
+            concurrent: opts.concurrent,
+            ..Default::default()
+        };
+
+        let mut stream = src
+            .reader_options(&from, read_opts)
+            .await?
+            .into_stream(..)
+            .await?;
+        let mut w = dst.writer_options(&to, write_opts).await?;
+        loop {
+            let bs = match stream.try_next().await {
+                Ok(Some(bs)) => bs,
+                Ok(None) => break,
+                Err(err) => {
+                    let _ = w.abort().await;
+                    return Err(err);
+                }
+            };
+            if let Err(err) = w.write(bs).await {
+                let _ = w.abort().await;
+                return Err(err);
+            }
+        }
+        w.close().await?;
+        Ok(())
+    }
+
+    /// Rename a file from `from` to `to`.
+    ///
+    /// # Notes
+    ///
+    /// - `from` and `to` must be a file, unless the service supports
+    ///   [`Capability::rename_dir`], in which case both can be directories
+    ///   and every entry under `from` is moved under `to`.
+    /// - `to` will be overwritten if it exists.
     /// - If `from` and `to` are the same, an `IsSameFile` error will occur.
     ///
     /// # Examples
@@ -1213,8 +1643,13 @@ impl Operator {
     /// ```
     pub async fn rename(&self, from: &str, to: &str) -> Result<()> {
         let from = normalize_path(from);
+        let to = normalize_path(to);
 
-        if !validate_path(&from, EntryMode::FILE) {
+        let is_dir_rename = self.info().full_capability().rename_dir
+            && validate_path(&from, EntryMode::DIR)
+            && validate_path(&to, EntryMode::DIR);
+
+        if !is_dir_rename && !validate_path(&from, EntryMode::FILE) {
             return Err(
                 Error::new(ErrorKind::IsADirectory, "from path is a directory")
                     .with_operation("Operator::move_")
@@ -1223,9 +1658,7 @@ impl Operator {
             );
         }
 
-        let to = normalize_path(to);
-
-        if !validate_path(&to, EntryMode::FILE) {
+        if !is_dir_rename && !validate_path(&to, EntryMode::FILE) {
             return Err(
                 Error::new(ErrorKind::IsADirectory, "to path is a directory")
                     .with_operation("Operator::move_")
@@ -1244,6 +1677,16 @@ impl Operator {
             );
         }
 
+        if is_dir_rename && (to.starts_with(&from) || from == "/") {
+            return Err(
+                Error::new(ErrorKind::Unsupported, "can't move a directory into itself")
+                    .with_operation("Operator::move_")
+                    .with_context("service", self.info().scheme())
+                    .with_context("from", from)
+                    .with_context("to", to),
+            );
+        }
+
         self.inner().rename(&from, &to, OpRename::new()).await?;
 
         Ok(())
@@ -1460,8 +1903,38 @@ impl Operator {
             Err(e) => return Err(e),
         };
 
-        let lister = self.lister_with(path).recursive(true).await?;
-        self.delete_try_stream(lister).await?;
+        let mut lister = self.lister_with(path).recursive(true).await?;
+        let mut deleter = self.deleter().await?;
+
+        // Dirs that are waiting for their children to be deleted, the innermost
+        // dir is the last one.
+        //
+        // Services like fs can't remove a dir that still has children, and they
+        // may list dirs either before or after their children. So we delete a dir
+        // only after the lister has moved out of it and all pending deletes are
+        // done. This keeps memory bounded by the depth of dirs.
+        let mut dirs: Vec<Entry> = vec![];
+        while let Some(entry) = lister.try_next().await? {
+            while dirs
+                .last()
+                .is_some_and(|dir| !entry.path().starts_with(dir.path()))
+            {
+                let dir = dirs.pop().expect("dirs must not be empty");
+                deleter.close().await?;
+                deleter.delete(dir).await?;
+            }
+
+            if entry.metadata().is_dir() {
+                dirs.push(entry);
+            } else {
+                deleter.delete(entry).await?;
+            }
+        }
+        while let Some(dir) = dirs.pop() {
+            deleter.close().await?;
+            deleter.delete(dir).await?;
+        }
+        deleter.close().await?;
         Ok(())
     }
 
@@ -1498,7 +1971,7 @@ impl Operator {
     ///         EntryMode::DIR => {
     ///             println!("Handling dir {}", entry.path())
     ///         }
-    ///         EntryMode::Unknown => continue,
+    ///         EntryMode::Symlink | EntryMode::Unknown => continue,
     ///     }
     /// }
     /// # Ok(())
@@ -1540,7 +2013,7 @@ impl Operator {
     ///         EntryMode::DIR => {
     ///             println!("Handling dir like start a new list via meta.path()")
     ///         }
-    ///         EntryMode::Unknown => continue,
+    ///         EntryMode::Symlink | EntryMode::Unknown => continue,
     ///     }
     /// }
     /// # Ok(())
@@ -1595,7 +2068,7 @@ impl Operator {
     ///         EntryMode::DIR => {
     ///             println!("Handling dir like start a new list via meta.path()")
     ///         }
-    ///         EntryMode::Unknown => continue,
+    ///         EntryMode::Symlink | EntryMode::Unknown => continue,
     ///     }
     /// }
     /// # Ok(())
@@ -1612,8 +2085,7 @@ impl Operator {
         path: String,
         opts: options::ListOptions,
     ) -> Result<Vec<Entry>> {
-        let args = opts.into();
-        let lister = Lister::create(acc, &path, args).await?;
+        let lister = Lister::create(acc, &path, opts).await?;
         lister.try_collect().await
     }
 
@@ -1644,7 +2116,7 @@ impl Operator {
     ///         EntryMode::DIR => {
     ///             println!("Handling dir like start a new list via meta.path()")
     ///         }
-    ///         EntryMode::Unknown => continue,
+    ///         EntryMode::Symlink | EntryMode::Unknown => continue,
     ///     }
     /// }
     /// # Ok(())
@@ -1679,12 +2151,50 @@ impl Operator {
     ///         EntryMode::DIR => {
     ///             println!("Handling dir {}", entry.path())
     ///         }
-    ///         EntryMode::Unknown => continue,
+    ///         EntryMode::Symlink | EntryMode::Unknown => continue,
     ///     }
     /// }
     /// # Ok(())
     /// # }
     /// ```
+    ///
+    /// ## List files matching a glob
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use futures::TryStreamExt;
+    /// use opendal::Operator;
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// let mut lister = op
+    ///     .lister_with("path/to/dir/")
+    ///     .glob("**/*.csv")
+    ///     .size_range(1024..)
+    ///     .await?;
+    /// while let Some(entry) = lister.try_next().await? {
+    ///     println!("Handling csv file {}", entry.path())
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// ## List huge prefix concurrently
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use futures::TryStreamExt;
+    /// use opendal::Operator;
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// let mut lister = op
+    ///     .lister_with("path/to/dir/")
+    ///     .recursive(true)
+    ///     .concurrent(8)
+    ///     .await?;
+    /// while let Some(entry) = lister.try_next().await? {
+    ///     println!("Handling {}", entry.path())
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
     pub fn lister_with(&self, path: &str) -> FutureLister<impl Future<Output = Result<Lister>>> {
         let path = normalize_path(path);
 
@@ -1723,7 +2233,7 @@ impl Operator {
     ///         EntryMode::DIR => {
     ///             println!("Handling dir {}", entry.path())
     ///         }
-    ///         EntryMode::Unknown => continue,
+    ///         EntryMode::Symlink | EntryMode::Unknown => continue,
     ///     }
     /// }
     /// # Ok(())
@@ -1740,8 +2250,7 @@ impl Operator {
         path: String,
         opts: options::ListOptions,
     ) -> Result<Lister> {
-        let args = opts.into();
-        let lister = Lister::create(acc, &path, args).await?;
+        let lister = Lister::create(acc, &path, opts).await?;
         Ok(lister)
     }
 }
@@ -2232,3 +2741,21 @@ impl Operator {
         Ok(rp.into_presigned_request())
     }
 }
+
+#[cfg(all(test, feature = "services-dashmap"))]
+mod tests {
+    use super::*;
+    use crate::services;
+
+    #[tokio::test]
+    async fn test_copy_to_operator_with_same_info() -> Result<()> {
+        let src = Operator::new(services::Dashmap::default())?.finish();
+        let dst = Operator::new(services::Dashmap::default())?.finish();
+        assert_eq!(src.info().name(), dst.info().name());
+
+        src.write("file", "hello").await?;
+        src.copy_to("file", &dst, "file").await?;
+        assert_eq!(dst.read("file").await?.to_vec(), b"hello");
+        Ok(())
+    }
+}
@@ -124,6 +124,14 @@ impl<F: Future<Output = Result<Metadata>>> FutureStat<F> {
         self.args.version = Some(v.to_string());
         self
     }
+
+    /// Set whether to stat the symlink itself instead of its target.
+    ///
+    /// Refer to [`options::StatOptions::no_follow_symlink`] for more details.
+    pub fn no_follow_symlink(mut self, v: bool) -> Self {
+        self.args.no_follow_symlink = v;
+        self
+    }
 }
 
 /// Future that generated by [`Operator::presign_stat_with`].
@@ -906,6 +914,77 @@ impl<F: Future<Output = Result<Metadata>>> FutureWrite<F> {
         self.args.0.user_metadata = Some(HashMap::from_iter(data));
         self
     }
+
+    /// Sets the time to live of the written object.
+    ///
+    /// Refer to [`options::WriteOptions::expires_in`] for more details.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// # use std::time::Duration;
+    /// # use opendal::Result;
+    /// # use opendal::Operator;
+    ///
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// let _ = op
+    ///     .write_with("session/abc", vec![0; 4096])
+    ///     .expires_in(Duration::from_secs(3600))
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn expires_in(mut self, v: Duration) -> Self {
+        self.args.0.expires_in = Some(v);
+        self
+    }
+
+    /// Sets POSIX permission bits of the written file.
+    ///
+    /// Refer to [`options::WriteOptions::unix_mode`] for more details.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// # use opendal::Result;
+    /// # use opendal::Operator;
+    ///
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// let _ = op
+    ///     .write_with("bin/run.sh", "#!/bin/sh")
+    ///     .unix_mode(0o755)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn unix_mode(mut self, v: u32) -> Self {
+        self.args.0.unix_mode = Some(v);
+        self
+    }
+
+    /// Sets the modification time of the written file.
+    ///
+    /// Refer to [`options::WriteOptions::mtime`] for more details.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// # use opendal::Result;
+    /// # use opendal::Operator;
+    /// use jiff::Timestamp;
+    ///
+    /// # async fn test(op: Operator, mtime: Timestamp) -> Result<()> {
+    /// let _ = op
+    ///     .write_with("path/to/file", vec![0; 4096])
+    ///     .mtime(mtime)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn mtime(mut self, v: impl Into<Timestamp>) -> Self {
+        self.args.0.mtime = Some(v.into());
+        self
+    }
 }
 
 /// Future that generated by [`Operator::writer_with`].
@@ -1245,6 +1324,105 @@ impl<F: Future<Output = Result<Writer>>> FutureWriter<F> {
         self.args.user_metadata = Some(HashMap::from_iter(data));
         self
     }
+
+    /// Sets the time to live of the written object.
+    ///
+    /// Refer to [`options::WriteOptions::expires_in`] for more details.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// # use std::time::Duration;
+    /// # use opendal::Result;
+    /// # use opendal::Operator;
+    ///
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// let mut w = op
+    ///     .writer_with("tmp/artifact")
+    ///     .expires_in(Duration::from_secs(600))
+    ///     .await?;
+    /// w.write(vec![0; 4096]).await?;
+    /// w.close().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn expires_in(mut self, v: Duration) -> Self {
+        self.args.expires_in = Some(v);
+        self
+    }
+
+    /// Sets POSIX permission bits of the written file.
+    ///
+    /// Refer to [`options::WriteOptions::unix_mode`] for more details.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// # use opendal::Result;
+    /// # use opendal::Operator;
+    ///
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// let mut w = op.writer_with("bin/run.sh").unix_mode(0o755).await?;
+    /// w.write("#!/bin/sh").await?;
+    /// w.close().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn unix_mode(mut self, v: u32) -> Self {
+        self.args.unix_mode = Some(v);
+        self
+    }
+
+    /// Sets the modification time of the written file.
+    ///
+    /// Refer to [`options::WriteOptions::mtime`] for more details.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// # use opendal::Result;
+    /// # use opendal::Operator;
+    /// use jiff::Timestamp;
+    ///
+    /// # async fn test(op: Operator, mtime: Timestamp) -> Result<()> {
+    /// let mut w = op.writer_with("path/to/file").mtime(mtime).await?;
+    /// w.write(vec![0; 4096]).await?;
+    /// w.close().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn mtime(mut self, v: impl Into<Timestamp>) -> Self {
+        self.args.mtime = Some(v.into());
+        self
+    }
+
+    /// Resumes an unfinished multipart upload from the checkpoint.
+    ///
+    /// Refer to [`options::WriteOptions::resume`] for more details.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// # use opendal::Result;
+    /// # use opendal::Operator;
+    /// use opendal::UploadCheckpoint;
+    ///
+    /// # async fn test(op: Operator, data: Vec<u8>, checkpoint: UploadCheckpoint) -> Result<()> {
+    /// let offset = checkpoint.bytes_committed as usize;
+    /// let mut w = op
+    ///     .writer_with("path/to/file")
+    ///     .concurrent(8)
+    ///     .resume(checkpoint)
+    ///     .await?;
+    /// w.write(data[offset..].to_vec()).await?;
+    /// w.close().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn resume(mut self, checkpoint: UploadCheckpoint) -> Self {
+        self.args.resume = Some(checkpoint);
+        self
+    }
 }
 
 /// Future that generated by [`Operator::delete_with`].
@@ -1324,6 +1502,64 @@ impl<F: Future<Output = Result<Vec<Entry>>>> FutureList<F> {
         self.args.deleted = v;
         self
     }
+
+    /// Only return entries that match the glob.
+    ///
+    /// The glob is relative to the listing path, for example, `list_with("data/").glob("**/*.csv")`
+    /// returns all csv files under `data/`. The list will be recursive if the glob could match
+    /// nested entries.
+    ///
+    /// Refer to [`options::ListOptions::glob`] for more details.
+    pub fn glob(mut self, v: &str) -> Self {
+        self.args.glob = Some(v.to_string());
+        self
+    }
+
+    /// Only return entries whose content length is in the range.
+    ///
+    /// Refer to [`options::ListOptions::size_range`] for more details.
+    pub fn size_range(mut self, v: impl RangeBounds<u64>) -> Self {
+        self.args.size_range = Some((v.start_bound().cloned(), v.end_bound().cloned()));
+        self
+    }
+
+    /// Only return entries whose last modified time is in the range.
+    ///
+    /// Refer to [`options::ListOptions::last_modified_range`] for more details.
+    pub fn last_modified_range(mut self, v: impl RangeBounds<Timestamp>) -> Self {
+        self.args.last_modified_range = Some((v.start_bound().cloned(), v.end_bound().cloned()));
+        self
+    }
+
+    /// Only return entries with the given mode.
+    pub fn mode(mut self, v: EntryMode) -> Self {
+        self.args.mode = Some(v);
+        self
+    }
+
+    /// Resume the listing from the token returned by [`Lister::continuation_token`].
+    ///
+    /// Refer to [`options::ListOptions::continuation_token`] for more details.
+    pub fn continuation_token(mut self, v: &str) -> Self {
+        self.args.continuation_token = Some(v.to_string());
+        self
+    }
+
+    /// Split the recursive listing into partitions and list `v` of them concurrently.
+    ///
+    /// Refer to [`options::ListOptions::concurrent`] for more details.
+    pub fn concurrent(mut self, v: usize) -> Self {
+        self.args.concurrent = v.max(1);
+        self
+    }
+
+    /// Return entries as soon as they are listed while listing concurrently.
+    ///
+    /// Refer to [`options::ListOptions::unordered`] for more details.
+    pub fn unordered(mut self, v: bool) -> Self {
+        self.args.unordered = v;
+        self
+    }
 }
 
 /// Future that generated by [`Operator::list_with`] or [`Operator::lister_with`].
@@ -1385,6 +1621,64 @@ impl<F: Future<Output = Result<Lister>>> FutureLister<F> {
         self.args.deleted = v;
         self
     }
+
+    /// Only return entries that match the glob.
+    ///
+    /// The glob is relative to the listing path, for example, `list_with("data/").glob("**/*.csv")`
+    /// returns all csv files under `data/`. The list will be recursive if the glob could match
+    /// nested entries.
+    ///
+    /// Refer to [`options::ListOptions::glob`] for more details.
+    pub fn glob(mut self, v: &str) -> Self {
+        self.args.glob = Some(v.to_string());
+        self
+    }
+
+    /// Only return entries whose content length is in the range.
+    ///
+    /// Refer to [`options::ListOptions::size_range`] for more details.
+    pub fn size_range(mut self, v: impl RangeBounds<u64>) -> Self {
+        self.args.size_range = Some((v.start_bound().cloned(), v.end_bound().cloned()));
+        self
+    }
+
+    /// Only return entries whose last modified time is in the range.
+    ///
+    /// Refer to [`options::ListOptions::last_modified_range`] for more details.
+    pub fn last_modified_range(mut self, v: impl RangeBounds<Timestamp>) -> Self {
+        self.args.last_modified_range = Some((v.start_bound().cloned(), v.end_bound().cloned()));
+        self
+    }
+
+    /// Only return entries with the given mode.
+    pub fn mode(mut self, v: EntryMode) -> Self {
+        self.args.mode = Some(v);
+        self
+    }
+
+    /// Resume the listing from the token returned by [`Lister::continuation_token`].
+    ///
+    /// Refer to [`options::ListOptions::continuation_token`] for more details.
+    pub fn continuation_token(mut self, v: &str) -> Self {
+        self.args.continuation_token = Some(v.to_string());
+        self
+    }
+
+    /// Split the recursive listing into partitions and list `v` of them concurrently.
+    ///
+    /// Refer to [`options::ListOptions::concurrent`] for more details.
+    pub fn concurrent(mut self, v: usize) -> Self {
+        self.args.concurrent = v.max(1);
+        self
+    }
+
+    /// Return entries as soon as they are listed while listing concurrently.
+    ///
+    /// Refer to [`options::ListOptions::unordered`] for more details.
+    pub fn unordered(mut self, v: bool) -> Self {
+        self.args.unordered = v;
+        self
+    }
 }
 
 /// Future that generated by [`Operator::copy_with`].
@@ -1416,3 +1710,26 @@ impl<F: Future<Output = Result<()>>> FutureCopy<F> {
         self
     }
 }
+
+/// Future that generated by [`Operator::copy_to_with`].
+///
+/// Users can add more options by public functions provided by this struct.
+pub type FutureCopyTo<F> = OperatorFuture<(options::CopyToOptions, Operator, String), (), F>;
+
+impl<F: Future<Output = Result<()>>> FutureCopyTo<F> {
+    /// Set the chunk size for reading and writing.
+    ///
+    /// Refer to [`options::CopyToOptions::chunk`] for more details.
+    pub fn chunk(mut self, v: usize) -> Self {
+        self.args.0.chunk = Some(v);
+        self
+    }
+
+    /// Set the concurrent for reading and writing.
+    ///
+    /// Refer to [`options::CopyToOptions::concurrent`] for more details.
+    pub fn concurrent(mut self, v: usize) -> Self {
+        self.args.0.concurrent = v;
+        self
+    }
+}
@@ -83,6 +83,8 @@ fn register_builtin_services(registry: &OperatorRegistry) {
     registry.register::<crate::services::Memory>(crate::services::MEMORY_SCHEME);
     #[cfg(feature = "services-fs")]
     registry.register::<crate::services::Fs>(crate::services::FS_SCHEME);
+    #[cfg(feature = "services-route")]
+    registry.register::<crate::services::Route>(crate::services::ROUTE_SCHEME);
     #[cfg(feature = "services-s3")]
     registry.register::<crate::services::S3>(crate::services::S3_SCHEME);
     #[cfg(feature = "services-azblob")]
@@ -17,8 +17,12 @@
 
 //! Options module provides options definitions for operations.
 
+use crate::EntryMode;
+use crate::UploadCheckpoint;
 use crate::raw::{BytesRange, Timestamp};
 use std::collections::HashMap;
+use std::ops::Bound;
+use std::time::Duration;
 
 /// Options for delete operations.
 #[derive(Debug, Clone, Default, Eq, PartialEq)]
@@ -62,6 +66,48 @@ pub struct ListOptions {
     ///
     /// Default to `false`
     pub deleted: bool,
+    /// The glob that returned entries must match, relative to the root.
+    ///
+    /// The listing path is escaped and prepended to the glob, so `list_with("data/").glob("*.csv")`
+    /// returns entries matching `data/*.csv`. The longest literal prefix of the pattern will be
+    /// used as the listing path, and the list will be recursive if the pattern could match
+    /// nested entries.
+    ///
+    /// Supported syntax: `*`, `**`, `?`, `[a-z]`, `[!a-z]` and `{a,b}`.
+    pub glob: Option<String>,
+    /// Only entries whose content length is in this range will be returned.
+    ///
+    /// Dirs are never returned if this is set.
+    pub size_range: Option<(Bound<u64>, Bound<u64>)>,
+    /// Only entries whose last modified time is in this range will be returned.
+    ///
+    /// Entries without last modified time are never returned if this is set.
+    pub last_modified_range: Option<(Bound<Timestamp>, Bound<Timestamp>)>,
+    /// Only entries with this mode will be returned.
+    pub mode: Option<EntryMode>,
+    /// The continuation token returned by [`Lister::continuation_token`](crate::Lister::continuation_token)
+    /// of a previous listing, the listing will be resumed after the last returned entry.
+    ///
+    /// Other options must be the same as the previous listing.
+    pub continuation_token: Option<String>,
+    /// The number of partitions to list concurrently while listing recursively.
+    ///
+    /// The keyspace will be split by the sub-prefixes of the longest common prefix of all
+    /// keys if the service supports `list_with_start_after`, or by the dirs under the path
+    /// otherwise.
+    ///
+    /// It's ignored if the list is not recursive or `start_after` is set.
+    ///
+    /// Default to `1`, which means the list is not partitioned.
+    pub concurrent: usize,
+    /// Return entries as soon as they are listed instead of merging partitions in key order.
+    ///
+    /// It only takes effect if `concurrent` is larger than `1`. The order of entries is not
+    /// stable in this case, so [`Lister::continuation_token`](crate::Lister::continuation_token)
+    /// returns `None` and `continuation_token` can't be set.
+    ///
+    /// Default to `false`.
+    pub unordered: bool,
 }
 
 /// Options for read operations.
@@ -294,6 +340,18 @@ pub struct StatOptions {
     ///
     /// This option is only meaningful when used along with presign.
     pub override_content_disposition: Option<String>,
+
+    /// Stat the symlink itself instead of the path it points to.
+    ///
+    /// ### Capability
+    ///
+    /// Check [`Capability::symlink`] before using this option.
+    ///
+    /// ### Behavior
+    ///
+    /// - By default, symlinks are followed and the metadata of their target is returned.
+    /// - If set to `true`, a symlink is returned with [`EntryMode::Symlink`](crate::EntryMode::Symlink).
+    pub no_follow_symlink: bool,
 }
 
 /// Options for write operations.
@@ -414,6 +472,76 @@ pub struct WriteOptions {
     /// User metadata provides a way to attach custom metadata to objects during write operations.
     /// This metadata can be retrieved later when reading the object.
     pub user_metadata: Option<HashMap<String, String>>,
+    /// Sets the time to live of the written object.
+    ///
+    /// ### Capability
+    ///
+    /// Check [`Capability::write_with_ttl`] before using this feature.
+    ///
+    /// ### Behavior
+    ///
+    /// - If supported, the object expires and is removed by the service once
+    ///   the duration has elapsed since the write
+    /// - Overrides service-wide defaults like `default_ttl` for this object
+    /// - Services usually round it to whole seconds
+    /// - If not supported, an error will be returned
+    ///
+    /// The expiry of an object is exposed via [`Metadata::expires_at`] where available.
+    ///
+    /// [`Metadata::expires_at`]: crate::Metadata::expires_at
+    pub expires_in: Option<Duration>,
+    /// Sets POSIX permission bits of the written file, like `0o644`.
+    ///
+    /// ### Capability
+    ///
+    /// Check [`Capability::write_with_unix_mode`] before using this feature.
+    ///
+    /// ### Behavior
+    ///
+    /// - If supported, the permission bits are applied once the write is finished
+    /// - Only permission bits are used, file type bits will be ignored
+    /// - If not supported, an error will be returned
+    ///
+    /// The permission bits can be read back via [`Metadata::unix_mode`].
+    ///
+    /// [`Metadata::unix_mode`]: crate::Metadata::unix_mode
+    pub unix_mode: Option<u32>,
+    /// Sets the modification time of the written file.
+    ///
+    /// ### Capability
+    ///
+    /// Check [`Capability::write_with_mtime`] before using this feature.
+    ///
+    /// ### Behavior
+    ///
+    /// - If supported, the modification time is applied once the write is finished
+    /// - Services may truncate it to their own precision, like whole seconds
+    /// - If not supported, an error will be returned
+    ///
+    /// This allows preserving timestamps while copying files between services.
+    /// The modification time can be read back via [`Metadata::last_modified`].
+    ///
+    /// [`Metadata::last_modified`]: crate::Metadata::last_modified
+    pub mtime: Option<Timestamp>,
+    /// Resumes an unfinished multipart upload from the checkpoint.
+    ///
+    /// ### Capability
+    ///
+    /// Check [`Capability::write_can_resume`] before using this feature.
+    ///
+    /// ### Behavior
+    ///
+    /// - Parts recorded in the checkpoint will be kept and not uploaded again
+    /// - Data written to the writer will be uploaded after
+    ///   [`UploadCheckpoint::bytes_committed`]
+    /// - Other options like `content_type` should be the same as the original writer
+    /// - If not supported, an error will be returned
+    ///
+    /// The checkpoint can be exported by [`Writer::checkpoint`].
+    ///
+    /// [`UploadCheckpoint::bytes_committed`]: crate::UploadCheckpoint::bytes_committed
+    /// [`Writer::checkpoint`]: crate::Writer::checkpoint
+    pub resume: Option<UploadCheckpoint>,
 
     /// Sets If-Match header for this write request.
     ///
@@ -542,3 +670,21 @@ pub struct CopyOptions {
     /// without overwriting existing ones, useful for implementing "copy if not exists" logic.
     pub if_not_exists: bool,
 }
+
+/// Options for copy operations across operators.
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct CopyToOptions {
+    /// Set `chunk` for reading from the source and writing to the destination.
+    ///
+    /// OpenDAL will use services' preferred chunk size by default. Users can set chunk based on their own needs.
+    ///
+    /// Refer to [`crate::docs::performance`] for more details.
+    pub chunk: Option<usize>,
+    /// Set `concurrent` for reading from the source and writing to the destination.
+    ///
+    /// OpenDAL by default copies data without concurrent. By setting `concurrent`, chunks
+    /// will be fetched and uploaded concurrently on supported storage services.
+    ///
+    /// Refer to [`crate::docs::performance`] for more details.
+    pub concurrent: usize,
+}
@@ -126,6 +126,8 @@ pub enum Scheme {
     Redis,
     /// [rocksdb][crate::services::Rocksdb]: RocksDB services
     Rocksdb,
+    /// [route][crate::services::Route]: Route services
+    Route,
     /// [s3][crate::services::S3]: AWS S3 alike services.
     S3,
     /// [Seafile][crate::services::Seafile]: Seafile Services.
@@ -280,6 +282,8 @@ impl Scheme {
             Scheme::Redis,
             #[cfg(feature = "services-rocksdb")]
             Scheme::Rocksdb,
+            #[cfg(feature = "services-route")]
+            Scheme::Route,
             #[cfg(feature = "services-s3")]
             Scheme::S3,
             #[cfg(feature = "services-seafile")]
@@ -381,6 +385,7 @@ impl FromStr for Scheme {
             "redb" => Ok(Scheme::Redb),
             "redis" => Ok(Scheme::Redis),
             "rocksdb" => Ok(Scheme::Rocksdb),
+            "route" => Ok(Scheme::Route),
             "s3" => Ok(Scheme::S3),
             "seafile" => Ok(Scheme::Seafile),
             "sftp" => Ok(Scheme::Sftp),
@@ -450,6 +455,7 @@ impl From<Scheme> for &'static str {
             Scheme::Redb => "redb",
             Scheme::Redis => "redis",
             Scheme::Rocksdb => "rocksdb",
+            Scheme::Route => "route",
             Scheme::S3 => "s3",
             Scheme::Seafile => "seafile",
             Scheme::Sftp => "sftp",
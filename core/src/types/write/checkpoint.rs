@@ -0,0 +1,57 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+use serde::Deserialize;
+use serde::Serialize;
+
+/// UploadCheckpoint records the progress of an unfinished multipart upload.
+///
+/// It can be exported by [`Writer::checkpoint`] and persisted anywhere
+/// via serde. After the process restarts, pass it to
+/// [`Operator::resume_writer`] and continue writing the data starting
+/// from [`UploadCheckpoint::bytes_committed`].
+///
+/// [`Writer::checkpoint`]: crate::Writer::checkpoint
+/// [`Operator::resume_writer`]: crate::Operator::resume_writer
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UploadCheckpoint {
+    /// The id of the multipart upload.
+    ///
+    /// It's `None` for block based uploads like `azblob` that don't have an upload id.
+    pub upload_id: Option<String>,
+    /// The parts that have been uploaded, in order.
+    pub parts: Vec<UploadPart>,
+    /// The total size of uploaded parts.
+    ///
+    /// Data before this offset has been committed, resumed writer should
+    /// continue with the data after it.
+    pub bytes_committed: u64,
+}
+
+/// A part that has been uploaded in [`UploadCheckpoint`].
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UploadPart {
+    /// The number of the part returned by the service.
+    pub part_number: usize,
+    /// The etag of the part.
+    ///
+    /// For block based uploads like `azblob`, it's the id of the block.
+    pub etag: String,
+    /// The checksum of the part.
+    pub checksum: Option<String>,
+    /// The size of the part.
+    pub size: u64,
+}
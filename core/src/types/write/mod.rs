@@ -18,6 +18,13 @@
 mod writer;
 pub use writer::Writer;
 
+mod checkpoint;
+pub use checkpoint::UploadCheckpoint;
+pub use checkpoint::UploadPart;
+
+mod pending_upload;
+pub use pending_upload::PendingUpload;
+
 mod buffer_sink;
 pub use buffer_sink::BufferSink;
 mod futures_async_writer;
@@ -0,0 +1,66 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use crate::raw::*;
+
+/// PendingUpload is an unfinished multipart upload returned by
+/// [`Operator::list_pending_uploads`].
+///
+/// Unfinished uploads are left behind by writers that are neither closed
+/// nor aborted. Their parts are still stored (and billed) by the service
+/// until they are aborted via [`Operator::abort_pending_upload`].
+///
+/// [`Operator::list_pending_uploads`]: crate::Operator::list_pending_uploads
+/// [`Operator::abort_pending_upload`]: crate::Operator::abort_pending_upload
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PendingUpload {
+    path: String,
+    upload_id: String,
+    initiated: Option<Timestamp>,
+}
+
+impl PendingUpload {
+    /// Create a new pending upload.
+    pub fn new(path: &str, upload_id: &str) -> Self {
+        Self {
+            path: path.to_string(),
+            upload_id: upload_id.to_string(),
+            initiated: None,
+        }
+    }
+
+    /// Set the time when the upload was initiated.
+    pub fn with_initiated(mut self, initiated: Timestamp) -> Self {
+        self.initiated = Some(initiated);
+        self
+    }
+
+    /// Path of the upload, relative to operator's root.
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    /// Id of the upload.
+    pub fn upload_id(&self) -> &str {
+        &self.upload_id
+    }
+
+    /// The time when the upload was initiated.
+    pub fn initiated(&self) -> Option<Timestamp> {
+        self.initiated
+    }
+}
@@ -99,7 +99,7 @@ use crate::*;
 ///   creating writer with `append` enabled.
 pub struct Writer {
     /// Keep a reference to write context in writer.
-    _ctx: Arc<WriteContext>,
+    ctx: Arc<WriteContext>,
     inner: WriteGenerator<oio::Writer>,
 }
 
@@ -109,7 +109,7 @@ impl Writer {
         let ctx = Arc::new(ctx);
         let inner = WriteGenerator::create(ctx.clone()).await?;
 
-        Ok(Self { _ctx: ctx, inner })
+        Ok(Self { ctx, inner })
     }
 
     /// Write [`Buffer`] into writer.
@@ -159,6 +159,39 @@ impl Writer {
         self.write(bs).await
     }
 
+    /// Get the checkpoint of the unfinished multipart upload.
+    ///
+    /// The checkpoint can be persisted via serde and passed to
+    /// [`Operator::resume_writer`] to continue the upload later.
+    ///
+    /// ## Notes
+    ///
+    /// - Returns `None` if the upload hasn't been started as multipart upload,
+    ///   or has been closed or aborted.
+    /// - Parts that are still uploading are not included, so the checkpoint
+    ///   could lag behind the data that has been written.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use opendal::Operator;
+    /// use opendal::Result;
+    ///
+    /// async fn test(op: Operator) -> Result<()> {
+    ///     let mut w = op.writer_with("hello.txt").chunk(8 * 1024 * 1024).await?;
+    ///     w.write(vec![0; 16 * 1024 * 1024]).await?;
+    ///
+    ///     if let Some(checkpoint) = w.checkpoint() {
+    ///         let data = serde_json::to_string(&checkpoint).unwrap();
+    ///         // Persist data somewhere.
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn checkpoint(&self) -> Option<UploadCheckpoint> {
+        self.ctx.args().checkpoint().and_then(|v| v.get())
+    }
+
     /// Abort the writer and clean up all written data.
     ///
     /// ## Notes
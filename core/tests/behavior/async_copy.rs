@@ -45,6 +45,14 @@ pub fn tests(op: &Operator, tests: &mut Vec<Trial>) {
             test_copy_with_if_not_exists_to_existing_file
         ))
     }
+
+    if cap.read && cap.write {
+        tests.extend(async_trials!(
+            op,
+            test_copy_to_same_operator,
+            test_copy_to_other_operator
+        ))
+    }
 }
 
 /// Copy a file with ascii name and test contents.
@@ -313,3 +321,84 @@ pub async fn test_copy_with_if_not_exists_to_existing_file(op: Operator) -> Resu
     op.delete(&target_path).await.expect("delete must succeed");
     Ok(())
 }
+
+/// Copy a file to another path of the same operator via copy_to.
+pub async fn test_copy_to_same_operator(op: Operator) -> Result<()> {
+    let source_path = uuid::Uuid::new_v4().to_string();
+    let (source_content, _) = gen_bytes(op.info().full_capability());
+
+    op.write(&source_path, source_content.clone()).await?;
+
+    let target_path = uuid::Uuid::new_v4().to_string();
+    op.copy_to(&source_path, &op, &target_path).await?;
+
+    let target_content = op
+        .read(&target_path)
+        .await
+        .expect("read must succeed")
+        .to_bytes();
+    assert_eq!(
+        format!("{:x}", Sha256::digest(target_content)),
+        format!("{:x}", Sha256::digest(&source_content)),
+    );
+
+    let err = op
+        .copy_to(&source_path, &op, &source_path)
+        .await
+        .expect_err("copy to self must fail");
+    assert_eq!(err.kind(), ErrorKind::IsSameFile);
+
+    op.delete(&source_path).await.expect("delete must succeed");
+    op.delete(&target_path).await.expect("delete must succeed");
+    Ok(())
+}
+
+/// Copy a file to another operator via copy_to and keep its metadata.
+pub async fn test_copy_to_other_operator(op: Operator) -> Result<()> {
+    let dst = Operator::new(services::Memory::default())?.finish();
+
+    let source_path = uuid::Uuid::new_v4().to_string();
+    let (source_content, _) = gen_bytes(op.info().full_capability());
+
+    let cap = op.info().full_capability();
+    let mut write = op.write_with(&source_path, source_content.clone());
+    if cap.write_with_content_type {
+        write = write.content_type("text/plain");
+    }
+    if cap.write_with_user_metadata {
+        write = write.user_metadata([("key".to_string(), "value".to_string())]);
+    }
+    write.await?;
+
+    let target_path = uuid::Uuid::new_v4().to_string();
+    op.copy_to_with(&source_path, &dst, &target_path)
+        .chunk(1024)
+        .concurrent(2)
+        .await?;
+
+    let target_content = dst
+        .read(&target_path)
+        .await
+        .expect("read must succeed")
+        .to_bytes();
+    assert_eq!(
+        format!("{:x}", Sha256::digest(target_content)),
+        format!("{:x}", Sha256::digest(&source_content)),
+    );
+
+    let meta = dst.stat(&target_path).await?;
+    if cap.write_with_content_type {
+        assert_eq!(meta.content_type(), Some("text/plain"));
+    }
+    if cap.write_with_user_metadata {
+        assert_eq!(
+            meta.user_metadata()
+                .and_then(|v| v.get("key"))
+                .map(String::as_str),
+            Some("value")
+        );
+    }
+
+    op.delete(&source_path).await.expect("delete must succeed");
+    Ok(())
+}
@@ -43,6 +43,8 @@ pub fn tests(op: &Operator, tests: &mut Vec<Trial>) {
             tests.extend(async_trials!(op, test_remove_all_basic));
             if !cap.create_dir {
                 tests.extend(async_trials!(op, test_remove_all_with_prefix_exists));
+            } else {
+                tests.extend(async_trials!(op, test_remove_all_with_nested_dirs));
             }
         }
     }
@@ -209,6 +211,39 @@ pub async fn test_remove_all_with_prefix_exists(op: Operator) -> Result<()> {
     test_blocking_remove_all_with_objects(op, parent, ["a", "a/b", "a/c", "a/b/e"]).await
 }
 
+/// Remove all nested dirs and files, dirs must be removed after their children.
+pub async fn test_remove_all_with_nested_dirs(op: Operator) -> Result<()> {
+    let parent = uuid::Uuid::new_v4().to_string();
+    let (content, _) = gen_bytes(op.info().full_capability());
+
+    for path in ["a/", "a/b/", "a/b/c/", "a/d/"] {
+        op.create_dir(&format!("{parent}/{path}"))
+            .await
+            .expect("create dir must succeed");
+    }
+    for path in ["a/b/x", "a/b/c/y", "a/d/z", "a/z", "ab"] {
+        op.write(&format!("{parent}/{path}"), content.clone())
+            .await
+            .expect("write must succeed");
+    }
+
+    op.remove_all(&format!("{parent}/a/")).await?;
+
+    for path in ["a/", "a/b/", "a/b/c/", "a/b/c/y", "a/d/", "a/z"] {
+        assert!(
+            !op.exists(&format!("{parent}/{path}")).await?,
+            "{parent}/{path} should be removed"
+        );
+    }
+    assert!(
+        op.exists(&format!("{parent}/ab")).await?,
+        "{parent}/ab should not be removed"
+    );
+
+    op.delete(&format!("{parent}/ab")).await?;
+    Ok(())
+}
+
 pub async fn test_delete_with_version(op: Operator) -> Result<()> {
     if !op.info().full_capability().delete_with_version {
         return Ok(());
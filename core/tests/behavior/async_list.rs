@@ -42,6 +42,7 @@ pub fn tests(op: &Operator, tests: &mut Vec<Trial>) {
             test_list_nested_dir,
             test_list_dir_with_file_path,
             test_list_with_start_after,
+            test_list_with_recursive_and_start_after,
             test_list_non_exist_dir_with_recursive,
             test_list_dir_with_recursive,
             test_list_dir_with_recursive_no_trailing_slash,
@@ -51,7 +52,15 @@ pub fn tests(op: &Operator, tests: &mut Vec<Trial>) {
             test_list_files_with_versions,
             test_list_with_versions_and_limit,
             test_list_with_versions_and_start_after,
-            test_list_files_with_deleted
+            test_list_files_with_deleted,
+            test_list_with_glob,
+            test_list_with_filters,
+            test_list_with_continuation_token,
+            test_list_with_invalid_continuation_token,
+            test_list_dir_with_continuation_token,
+            test_list_with_continuation_token_after_key_removed,
+            test_list_with_concurrent,
+            test_list_with_concurrent_and_glob
         ))
     }
 
@@ -404,6 +413,40 @@ pub async fn test_list_with_start_after(op: Operator) -> Result<()> {
     Ok(())
 }
 
+/// List recursively with start after should return sorted entries after the specified key
+pub async fn test_list_with_recursive_and_start_after(op: Operator) -> Result<()> {
+    let cap = op.info().full_capability();
+    if !cap.list_with_recursive || !cap.list_with_start_after || !cap.create_dir {
+        return Ok(());
+    }
+
+    let parent = format!("{}/", uuid::Uuid::new_v4());
+    let paths = ["a/", "a/b", "a/c/", "a/c/d", "a-e", "f"];
+    for path in paths.iter() {
+        if path.ends_with('/') {
+            op.create_dir(&format!("{parent}{path}")).await?;
+        } else {
+            op.write(&format!("{parent}{path}"), "test_scan").await?;
+        }
+    }
+
+    let actual = op
+        .lister_with(&parent)
+        .recursive(true)
+        .start_after(&format!("{parent}a/b"))
+        .await?
+        .try_collect::<Vec<_>>()
+        .await?
+        .into_iter()
+        .map(|v| v.path().strip_prefix(&parent).unwrap().to_string())
+        .collect::<Vec<_>>();
+
+    assert_eq!(actual, vec!["a/c/", "a/c/d", "f"]);
+
+    op.remove_all(&parent).await?;
+    Ok(())
+}
+
 pub async fn test_list_non_exist_dir_with_recursive(op: Operator) -> Result<()> {
     let dir = format!("{}/", uuid::Uuid::new_v4());
 
@@ -739,3 +782,371 @@ pub async fn test_list_with_versions_and_start_after(op: Operator) -> Result<()>
 
     Ok(())
 }
+
+pub async fn test_list_with_glob(op: Operator) -> Result<()> {
+    let parent = uuid::Uuid::new_v4().to_string();
+
+    let paths = [
+        "a.csv",
+        "b.txt",
+        "x/c.csv",
+        "x/y/d.csv",
+        "x/y/e.txt",
+        "z/f.csv",
+    ];
+    for path in paths.iter() {
+        op.write(&format!("{parent}/{path}"), "test_glob").await?;
+    }
+
+    let cases: [(&str, &[&str]); 4] = [
+        ("**/*.csv", &["a.csv", "x/c.csv", "x/y/d.csv", "z/f.csv"]),
+        ("x/*.csv", &["x/c.csv"]),
+        ("x/**/*.txt", &["x/y/e.txt"]),
+        ("{a,b}.*", &["a.csv", "b.txt"]),
+    ];
+    for (glob, expected) in cases {
+        let mut actual = op
+            .lister_with(&format!("{parent}/"))
+            .glob(glob)
+            .await?
+            .try_collect::<Vec<_>>()
+            .await?
+            .into_iter()
+            .map(|v| {
+                v.path()
+                    .strip_prefix(&format!("{parent}/"))
+                    .unwrap()
+                    .to_string()
+            })
+            .collect::<Vec<_>>();
+        actual.sort();
+        assert_eq!(actual, expected, "list with glob {glob}");
+    }
+
+    op.remove_all(&format!("{parent}/")).await?;
+    Ok(())
+}
+
+pub async fn test_list_with_filters(op: Operator) -> Result<()> {
+    let parent = uuid::Uuid::new_v4().to_string();
+
+    op.write(&format!("{parent}/small"), vec![0; 1]).await?;
+    op.write(&format!("{parent}/large"), vec![0; 1024]).await?;
+    op.write(&format!("{parent}/sub/file"), vec![0; 1024])
+        .await?;
+
+    let list = |lister: Lister| async move {
+        let mut paths = lister
+            .try_collect::<Vec<_>>()
+            .await?
+            .into_iter()
+            .map(|v| v.name().to_string())
+            .collect::<Vec<_>>();
+        paths.sort();
+        Ok::<_, opendal::Error>(paths)
+    };
+
+    let actual = list(
+        op.lister_with(&format!("{parent}/"))
+            .size_range(512..)
+            .await?,
+    )
+    .await?;
+    assert_eq!(actual, ["large"]);
+
+    let actual = list(
+        op.lister_with(&format!("{parent}/"))
+            .size_range(..=1024)
+            .recursive(true)
+            .await?,
+    )
+    .await?;
+    assert_eq!(actual, ["file", "large", "small"]);
+
+    let actual = list(
+        op.lister_with(&format!("{parent}/"))
+            .mode(EntryMode::FILE)
+            .await?,
+    )
+    .await?;
+    assert_eq!(actual, ["large", "small"]);
+
+    let small = op.stat(&format!("{parent}/small")).await?.last_modified();
+    let large = op.stat(&format!("{parent}/large")).await?.last_modified();
+    if let (Some(small), Some(large)) = (small, large) {
+        let (start, end) = (small.min(large), small.max(large));
+
+        let actual = list(
+            op.lister_with(&format!("{parent}/"))
+                .last_modified_range(start..=end)
+                .await?,
+        )
+        .await?;
+        assert_eq!(actual, ["large", "small"]);
+
+        let actual = list(
+            op.lister_with(&format!("{parent}/"))
+                .last_modified_range(..start)
+                .await?,
+        )
+        .await?;
+        assert!(actual.is_empty());
+    }
+
+    op.remove_all(&format!("{parent}/")).await?;
+    Ok(())
+}
+
+pub async fn test_list_with_continuation_token(op: Operator) -> Result<()> {
+    let parent = uuid::Uuid::new_v4().to_string();
+
+    let paths = ["a", "b", "c/d", "c/e", "f", "g"];
+    for path in paths.iter() {
+        op.write(&format!("{parent}/{path}"), "test_continuation")
+            .await?;
+    }
+
+    let mut expected = op
+        .list_with(&format!("{parent}/"))
+        .recursive(true)
+        .await?
+        .into_iter()
+        .map(|v| v.path().to_string())
+        .collect::<Vec<_>>();
+    expected.sort();
+
+    let mut lister = op
+        .lister_with(&format!("{parent}/"))
+        .recursive(true)
+        .limit(2)
+        .await?;
+    let mut actual = vec![];
+    for _ in 0..3 {
+        let entry = lister.try_next().await?.expect("entry must exist");
+        actual.push(entry.path().to_string());
+    }
+    let token = lister.continuation_token().expect("token must exist");
+    drop(lister);
+
+    let mut lister = op
+        .lister_with(&format!("{parent}/"))
+        .recursive(true)
+        .limit(2)
+        .continuation_token(&token)
+        .await?;
+    while let Some(entry) = lister.try_next().await? {
+        actual.push(entry.path().to_string());
+    }
+    actual.sort();
+    assert_eq!(actual, expected);
+
+    // Resume from the token of a finished lister should return nothing.
+    let token = lister.continuation_token().expect("token must exist");
+    let rest = op
+        .list_with(&format!("{parent}/"))
+        .recursive(true)
+        .limit(2)
+        .continuation_token(&token)
+        .await?;
+    assert!(rest.is_empty(), "got {rest:?}");
+
+    op.remove_all(&format!("{parent}/")).await?;
+    Ok(())
+}
+
+/// Resuming a non-recursive list after every entry should return dirs only once.
+pub async fn test_list_dir_with_continuation_token(op: Operator) -> Result<()> {
+    let parent = uuid::Uuid::new_v4().to_string();
+
+    let paths = ["a", "b/c", "b/d", "e/f/g", "h"];
+    for path in paths.iter() {
+        op.write(&format!("{parent}/{path}"), "test_continuation")
+            .await?;
+    }
+
+    let mut expected = op
+        .list(&format!("{parent}/"))
+        .await?
+        .into_iter()
+        .map(|v| v.path().to_string())
+        .collect::<Vec<_>>();
+    expected.sort();
+
+    let mut actual = vec![];
+    let dir = format!("{parent}/");
+    let mut token: Option<String> = None;
+    loop {
+        let mut lister = op.lister_with(&dir);
+        if let Some(token) = &token {
+            lister = lister.continuation_token(token);
+        }
+        let mut lister = lister.await?;
+        let Some(entry) = lister.try_next().await? else {
+            break;
+        };
+        actual.push(entry.path().to_string());
+        token = lister.continuation_token();
+    }
+    actual.sort();
+    assert_eq!(actual, expected);
+
+    op.remove_all(&format!("{parent}/")).await?;
+    Ok(())
+}
+
+/// Resuming after the last returned key has been removed should never drop
+/// entries silently.
+pub async fn test_list_with_continuation_token_after_key_removed(op: Operator) -> Result<()> {
+    let cap = op.info().full_capability();
+    let parent = uuid::Uuid::new_v4().to_string();
+
+    let paths = ["a", "b", "c", "d"];
+    for path in paths.iter() {
+        op.write(&format!("{parent}/{path}"), "test_continuation")
+            .await?;
+    }
+
+    let mut lister = op
+        .lister_with(&format!("{parent}/"))
+        .recursive(true)
+        .await?;
+    let mut actual = vec![];
+    let removed = loop {
+        let entry = lister.try_next().await?.expect("entry must exist");
+        if entry.metadata().mode().is_file() {
+            break entry.path().to_string();
+        }
+        actual.push(entry.path().to_string());
+    };
+    let token = lister.continuation_token().expect("token must exist");
+    drop(lister);
+    op.delete(&removed).await?;
+
+    let mut expected = op
+        .list_with(&format!("{parent}/"))
+        .recursive(true)
+        .await?
+        .into_iter()
+        .map(|v| v.path().to_string())
+        .collect::<Vec<_>>();
+    expected.sort();
+
+    let res = op
+        .list_with(&format!("{parent}/"))
+        .recursive(true)
+        .continuation_token(&token)
+        .await;
+    if cap.list_with_start_after || cap.list_with_continuation {
+        actual.extend(res?.into_iter().map(|v| v.path().to_string()));
+        actual.sort();
+        assert_eq!(actual, expected);
+    } else {
+        assert_eq!(
+            res.err().map(|err| err.kind()),
+            Some(ErrorKind::ConditionNotMatch)
+        );
+    }
+
+    op.remove_all(&format!("{parent}/")).await?;
+    Ok(())
+}
+
+pub async fn test_list_with_invalid_continuation_token(op: Operator) -> Result<()> {
+    let res = op.lister_with("/").continuation_token("!invalid!").await;
+    assert!(res.is_err());
+    assert_eq!(res.err().unwrap().kind(), ErrorKind::ConfigInvalid);
+    Ok(())
+}
+
+pub async fn test_list_with_concurrent(op: Operator) -> Result<()> {
+    let parent = uuid::Uuid::new_v4().to_string();
+
+    let paths = [
+        "a", "b/c", "b/d/e", "b/d/f", "b.txt", "g/h", "i/j/k", "i/l", "m",
+    ];
+    for path in paths.iter() {
+        op.write(&format!("{parent}/{path}"), "test_concurrent")
+            .await?;
+    }
+
+    let expected = op
+        .list_with(&format!("{parent}/"))
+        .recursive(true)
+        .await?
+        .into_iter()
+        .map(|v| v.path().to_string())
+        .collect::<Vec<_>>();
+
+    let actual = op
+        .list_with(&format!("{parent}/"))
+        .recursive(true)
+        .concurrent(3)
+        .await?
+        .into_iter()
+        .map(|v| v.path().to_string())
+        .collect::<Vec<_>>();
+    let mut sorted = expected.clone();
+    sorted.sort();
+    // Partitions are merged in key order, so the output is sorted if the service lists in sorted order.
+    if expected == sorted {
+        assert_eq!(actual, expected);
+    } else {
+        let mut actual = actual;
+        actual.sort();
+        assert_eq!(actual, sorted);
+    }
+
+    let mut actual = op
+        .list_with(&format!("{parent}/"))
+        .recursive(true)
+        .concurrent(3)
+        .unordered(true)
+        .await?
+        .into_iter()
+        .map(|v| v.path().to_string())
+        .collect::<Vec<_>>();
+    actual.sort();
+    assert_eq!(actual, sorted);
+
+    // Unordered listing can't be resumed.
+    let mut lister = op
+        .lister_with(&format!("{parent}/"))
+        .recursive(true)
+        .concurrent(3)
+        .unordered(true)
+        .await?;
+    lister.try_next().await?;
+    assert_eq!(lister.continuation_token(), None);
+
+    op.remove_all(&format!("{parent}/")).await?;
+    Ok(())
+}
+
+pub async fn test_list_with_concurrent_and_glob(op: Operator) -> Result<()> {
+    let parent = uuid::Uuid::new_v4().to_string();
+
+    let paths = ["a.csv", "b/c.csv", "b/d.txt", "e/f/g.csv", "h.txt"];
+    for path in paths.iter() {
+        op.write(&format!("{parent}/{path}"), "test_concurrent")
+            .await?;
+    }
+
+    let mut actual = op
+        .list_with(&format!("{parent}/"))
+        .glob("**/*.csv")
+        .concurrent(4)
+        .await?
+        .into_iter()
+        .map(|v| {
+            v.path()
+                .strip_prefix(&format!("{parent}/"))
+                .unwrap()
+                .to_string()
+        })
+        .collect::<Vec<_>>();
+    actual.sort();
+    assert_eq!(actual, ["a.csv", "b/c.csv", "e/f/g.csv"]);
+
+    op.remove_all(&format!("{parent}/")).await?;
+    Ok(())
+}
@@ -36,6 +36,14 @@ pub fn tests(op: &Operator, tests: &mut Vec<Trial>) {
             test_rename_overwrite
         ))
     }
+
+    if cap.read && cap.write && cap.delete && cap.rename_dir {
+        tests.extend(async_trials!(
+            op,
+            test_rename_dir,
+            test_rename_dir_into_itself
+        ))
+    }
 }
 
 /// Rename a file and test with stat.
@@ -125,6 +133,69 @@ pub async fn test_rename_target_dir(op: Operator) -> Result<()> {
     Ok(())
 }
 
+/// Rename a dir should move every entry under it.
+pub async fn test_rename_dir(op: Operator) -> Result<()> {
+    let parent = uuid::Uuid::new_v4().to_string();
+    let source_path = format!("{parent}/src/");
+    let target_path = format!("{parent}/dst/");
+
+    let mut expected = vec![];
+    for name in ["a", "b/c", "b/d/e"] {
+        let (content, _) = gen_bytes(op.info().full_capability());
+        op.write(&format!("{source_path}{name}"), content.clone())
+            .await?;
+        expected.push((name, content));
+    }
+    // Entries that share the prefix but are not under the dir must not be moved.
+    op.write(&format!("{parent}/src-other"), "other").await?;
+
+    op.rename(&source_path, &target_path).await?;
+
+    for (name, content) in expected {
+        let err = op
+            .stat(&format!("{source_path}{name}"))
+            .await
+            .expect_err("stat must fail");
+        assert_eq!(err.kind(), ErrorKind::NotFound);
+
+        let target_content = op
+            .read(&format!("{target_path}{name}"))
+            .await
+            .expect("read must succeed")
+            .to_bytes();
+        assert_eq!(
+            format!("{:x}", Sha256::digest(target_content)),
+            format!("{:x}", Sha256::digest(&content)),
+        );
+    }
+    assert!(op.exists(&format!("{parent}/src-other")).await?);
+
+    op.remove_all(&format!("{parent}/"))
+        .await
+        .expect("remove must succeed");
+    Ok(())
+}
+
+/// Rename a dir into itself should return an error.
+pub async fn test_rename_dir_into_itself(op: Operator) -> Result<()> {
+    let source_path = format!("{}/", uuid::Uuid::new_v4());
+    let target_path = format!("{source_path}sub/");
+
+    op.write(&format!("{source_path}a"), "test").await?;
+
+    let err = op
+        .rename(&source_path, &target_path)
+        .await
+        .expect_err("rename must fail");
+    assert_eq!(err.kind(), ErrorKind::Unsupported);
+    assert!(op.exists(&format!("{source_path}a")).await?);
+
+    op.remove_all(&source_path)
+        .await
+        .expect("remove must succeed");
+    Ok(())
+}
+
 /// Rename a file to self should return an error.
 pub async fn test_rename_self(op: Operator) -> Result<()> {
     let source_path = uuid::Uuid::new_v4().to_string();
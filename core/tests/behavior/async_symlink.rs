@@ -0,0 +1,98 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use anyhow::Result;
+use futures::TryStreamExt;
+
+use crate::*;
+
+pub fn tests(op: &Operator, tests: &mut Vec<Trial>) {
+    let cap = op.info().full_capability();
+
+    if cap.read && cap.write && cap.stat && cap.symlink {
+        tests.extend(async_trials!(
+            op,
+            test_create_symlink,
+            test_stat_symlink_no_follow,
+            test_list_symlink
+        ))
+    }
+}
+
+/// Create a symlink and read through it.
+pub async fn test_create_symlink(op: Operator) -> Result<()> {
+    let (path, content, _) = TEST_FIXTURE.new_file(op.clone());
+    op.write(&path, content.clone()).await?;
+
+    let link = TEST_FIXTURE.new_file_path();
+    op.create_symlink(&link, &path).await?;
+
+    assert_eq!(op.read_link(&link).await?, path);
+
+    let meta = op.stat(&link).await?;
+    assert_eq!(meta.mode(), EntryMode::FILE);
+    assert_eq!(meta.content_length(), content.len() as u64);
+
+    let bs = op.read(&link).await?;
+    assert_eq!(bs.to_vec(), content);
+    Ok(())
+}
+
+/// Stat with `no_follow_symlink` should return the link itself.
+pub async fn test_stat_symlink_no_follow(op: Operator) -> Result<()> {
+    let (path, content, _) = TEST_FIXTURE.new_file(op.clone());
+    op.write(&path, content).await?;
+
+    let link = TEST_FIXTURE.new_file_path();
+    op.create_symlink(&link, &path).await?;
+
+    let meta = op.stat_with(&link).no_follow_symlink(true).await?;
+    assert_eq!(meta.mode(), EntryMode::Symlink);
+
+    let meta = op.stat_with(&path).no_follow_symlink(true).await?;
+    assert_eq!(meta.mode(), EntryMode::FILE);
+    Ok(())
+}
+
+/// Symlinks should be listed with symlink mode instead of being dropped.
+pub async fn test_list_symlink(op: Operator) -> Result<()> {
+    let parent = format!("{}/", uuid::Uuid::new_v4());
+    let path = format!("{parent}file");
+    let link = format!("{parent}link");
+    TEST_FIXTURE.add_path(path.clone());
+    TEST_FIXTURE.add_path(link.clone());
+    TEST_FIXTURE.add_path(parent.clone());
+
+    op.write(&path, "hello").await?;
+    op.create_symlink(&link, "file").await?;
+
+    let entries: Vec<_> = op
+        .lister_with(&parent)
+        .recursive(true)
+        .await?
+        .try_collect()
+        .await?;
+
+    let entry = entries
+        .iter()
+        .find(|e| e.path() == link)
+        .expect("symlink must be listed");
+    assert_eq!(entry.metadata().mode(), EntryMode::Symlink);
+
+    assert_eq!(op.read(&link).await?.to_vec(), b"hello");
+    Ok(())
+}
@@ -16,6 +16,7 @@
 // under the License.
 
 use std::collections::HashMap;
+use std::time::Duration;
 
 use anyhow::Result;
 use bytes::Bytes;
@@ -48,6 +49,10 @@ pub fn tests(op: &Operator, tests: &mut Vec<Trial>) {
             test_write_with_if_not_exists,
             test_write_with_if_match,
             test_write_with_user_metadata,
+            test_write_with_ttl,
+            test_write_with_unix_mode,
+            test_write_with_unix_mode_and_user_metadata,
+            test_write_with_mtime,
             test_write_returns_metadata,
             test_writer_write,
             test_writer_write_with_overwrite,
@@ -71,6 +76,14 @@ pub fn tests(op: &Operator, tests: &mut Vec<Trial>) {
             test_writer_with_append
         ))
     }
+
+    if cap.read && cap.write && cap.write_can_resume && cap.stat {
+        tests.extend(async_trials!(op, test_writer_with_resume))
+    }
+
+    if cap.write && cap.write_can_multi && cap.pending_upload {
+        tests.extend(async_trials!(op, test_writer_list_and_abort_pending_upload))
+    }
 }
 
 /// Write a single file and test with stat.
@@ -251,6 +264,92 @@ pub async fn test_write_with_user_metadata(op: Operator) -> Result<()> {
     Ok(())
 }
 
+/// write a single file with ttl should succeed and report the expiry if available.
+pub async fn test_write_with_ttl(op: Operator) -> Result<()> {
+    if !op.info().full_capability().write_with_ttl {
+        return Ok(());
+    }
+
+    let (path, content, _) = TEST_FIXTURE.new_file(op.clone());
+    let ttl = Duration::from_secs(3600);
+    let before = opendal::raw::Timestamp::now();
+    op.write_with(&path, content.clone())
+        .expires_in(ttl)
+        .await?;
+    let after = opendal::raw::Timestamp::now();
+
+    let bs = op.read(&path).await?;
+    assert_eq!(bs.to_bytes(), content, "read content must match");
+
+    let meta = op.stat(&path).await.expect("stat must succeed");
+    if let Some(expires_at) = meta.expires_at() {
+        // Allow services to round the expiry to whole seconds.
+        assert!(
+            expires_at >= before + ttl - Duration::from_secs(1),
+            "expires_at {expires_at} must be after {before} + ttl"
+        );
+        assert!(
+            expires_at <= after + ttl + Duration::from_secs(1),
+            "expires_at {expires_at} must be before {after} + ttl"
+        );
+    }
+
+    Ok(())
+}
+
+pub async fn test_write_with_unix_mode(op: Operator) -> Result<()> {
+    if !op.info().full_capability().write_with_unix_mode {
+        return Ok(());
+    }
+
+    let (path, content, _) = TEST_FIXTURE.new_file(op.clone());
+    op.write_with(&path, content).unix_mode(0o640).await?;
+
+    let meta = op.stat(&path).await.expect("stat must succeed");
+    assert_eq!(meta.unix_mode(), Some(0o640));
+
+    Ok(())
+}
+
+/// Read-only mode must not prevent user metadata from being stored.
+pub async fn test_write_with_unix_mode_and_user_metadata(op: Operator) -> Result<()> {
+    let cap = op.info().full_capability();
+    if !cap.write_with_unix_mode || !cap.write_with_user_metadata {
+        return Ok(());
+    }
+
+    let (path, content, _) = TEST_FIXTURE.new_file(op.clone());
+    let user_metadata = vec![("location".to_string(), "everywhere".to_string())];
+    op.write_with(&path, content)
+        .unix_mode(0o444)
+        .user_metadata(user_metadata.clone())
+        .await?;
+
+    let meta = op.stat(&path).await.expect("stat must succeed");
+    assert_eq!(meta.unix_mode(), Some(0o444));
+    assert_eq!(
+        meta.user_metadata().expect("user metadata must exist"),
+        &user_metadata.into_iter().collect::<HashMap<_, _>>()
+    );
+
+    Ok(())
+}
+
+pub async fn test_write_with_mtime(op: Operator) -> Result<()> {
+    if !op.info().full_capability().write_with_mtime {
+        return Ok(());
+    }
+
+    let (path, content, _) = TEST_FIXTURE.new_file(op.clone());
+    let mtime: jiff::Timestamp = "2020-01-02T03:04:05Z".parse()?;
+    op.write_with(&path, content).mtime(mtime).await?;
+
+    let meta = op.stat(&path).await.expect("stat must succeed");
+    assert_eq!(meta.last_modified(), Some(mtime.into()));
+
+    Ok(())
+}
+
 pub async fn test_write_returns_metadata(op: Operator) -> Result<()> {
     let (path, content, _) = TEST_FIXTURE.new_file(op.clone());
 
@@ -262,6 +361,69 @@ pub async fn test_write_returns_metadata(op: Operator) -> Result<()> {
     Ok(())
 }
 
+/// Resume an unfinished upload from the checkpoint should succeed.
+pub async fn test_writer_with_resume(op: Operator) -> Result<()> {
+    let path = TEST_FIXTURE.new_file_path();
+    let chunk = 5 * 1024 * 1024;
+    let content = gen_fixed_bytes(4 * chunk);
+
+    let mut w = op.writer_with(&path).chunk(chunk).await?;
+    w.write(content[..3 * chunk].to_vec()).await?;
+    let checkpoint = w.checkpoint().expect("checkpoint must exist");
+    assert!(checkpoint.bytes_committed > 0);
+    // Drop the writer without closing to simulate an interrupted upload.
+    drop(w);
+
+    // The checkpoint should survive serialization.
+    let checkpoint: UploadCheckpoint = serde_json::from_str(&serde_json::to_string(&checkpoint)?)?;
+    let offset = checkpoint.bytes_committed as usize;
+
+    let mut w = op.resume_writer(&path, checkpoint).await?;
+    w.write(content[offset..].to_vec()).await?;
+    w.close().await?;
+    assert!(w.checkpoint().is_none());
+
+    let meta = op.stat(&path).await.expect("stat must succeed");
+    assert_eq!(meta.content_length(), content.len() as u64);
+
+    let bs = op.read(&path).await?.to_bytes();
+    assert_eq!(
+        format!("{:x}", Sha256::digest(&bs)),
+        format!("{:x}", Sha256::digest(&content)),
+        "read content"
+    );
+
+    op.delete(&path).await.expect("delete must succeed");
+    Ok(())
+}
+
+/// Unfinished uploads should be listed and aborted.
+pub async fn test_writer_list_and_abort_pending_upload(op: Operator) -> Result<()> {
+    let dir = TEST_FIXTURE.new_dir_path();
+    let path = format!("{dir}{}", uuid::Uuid::new_v4());
+    let chunk = 5 * 1024 * 1024;
+
+    let mut w = op.writer_with(&path).chunk(chunk).await?;
+    w.write(gen_fixed_bytes(2 * chunk)).await?;
+    // Drop the writer without closing to leave the upload unfinished.
+    drop(w);
+
+    let uploads = op.list_pending_uploads(&dir).await?;
+    let upload = uploads
+        .iter()
+        .find(|v| v.path() == path)
+        .expect("pending upload must be listed");
+
+    op.abort_pending_upload(&path, upload.upload_id()).await?;
+
+    let uploads = op.list_pending_uploads(&dir).await?;
+    assert!(
+        uploads.iter().all(|v| v.path() != path),
+        "pending upload must be aborted"
+    );
+    Ok(())
+}
+
 /// Delete existing file should succeed.
 pub async fn test_writer_abort(op: Operator) -> Result<()> {
     let (path, content, _) = TEST_FIXTURE.new_file(op.clone());
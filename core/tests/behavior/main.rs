@@ -29,6 +29,7 @@ mod async_presign;
 mod async_read;
 mod async_rename;
 mod async_stat;
+mod async_symlink;
 mod async_write;
 
 // External dependencies
@@ -59,6 +60,7 @@ fn main() -> anyhow::Result<()> {
     async_read::tests(&op, &mut tests);
     async_rename::tests(&op, &mut tests);
     async_stat::tests(&op, &mut tests);
+    async_symlink::tests(&op, &mut tests);
     async_write::tests(&op, &mut tests);
 
     // Don't init logging while building operator which may break cargo
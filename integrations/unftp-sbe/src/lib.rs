@@ -96,7 +96,7 @@ impl storage::Metadata for OpendalMetadata {
     }
 
     fn is_symlink(&self) -> bool {
-        false
+        self.0.is_symlink()
     }
 
     fn modified(&self) -> storage::Result<std::time::SystemTime> {